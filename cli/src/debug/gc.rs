@@ -1,5 +1,8 @@
+use std::{fs::File, io::BufWriter};
+
 use boa_engine::{
-    js_string, object::ObjectInitializer, Context, JsObject, JsResult, JsValue, NativeFunction,
+    js_string, object::ObjectInitializer, property::Attribute, Context, JsNativeError, JsObject,
+    JsResult, JsValue, NativeFunction,
 };
 
 /// Trigger garbage collection.
@@ -8,6 +11,81 @@ fn collect(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
     Ok(JsValue::undefined())
 }
 
+/// Returns the statistics of the garbage collector.
+fn stats(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let stats = boa_gc::stats();
+    let object = ObjectInitializer::new(context)
+        .property(
+            js_string!("bytesAllocated"),
+            stats.bytes_allocated,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("externalBytes"),
+            stats.external_bytes,
+            Attribute::all(),
+        )
+        .property(js_string!("threshold"), stats.threshold, Attribute::all())
+        .property(
+            js_string!("objectCount"),
+            stats.object_count,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("ephemeronCount"),
+            stats.ephemeron_count,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("weakMapCount"),
+            stats.weak_map_count,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("collections"),
+            stats.collections,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("minorCollections"),
+            stats.minor_collections,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("youngObjectCount"),
+            stats.young_object_count,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("lastPause"),
+            stats.last_pause.as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .build();
+    Ok(object.into())
+}
+
+/// Writes a heap snapshot to the given file, in the `.heapsnapshot` format of Chrome `DevTools`.
+fn snapshot(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    let Some(path) = args.first().and_then(JsValue::as_string) else {
+        return Err(JsNativeError::typ()
+            .with_message("expected a file path argument")
+            .into());
+    };
+    let path = path.to_std_string_escaped();
+
+    let file = File::create(&path).map_err(|err| {
+        JsNativeError::error().with_message(format!("could not create `{path}`: {err}"))
+    })?;
+    boa_gc::heap_snapshot()
+        .write_json(BufWriter::new(file))
+        .map_err(|err| {
+            JsNativeError::error().with_message(format!("could not write `{path}`: {err}"))
+        })?;
+
+    Ok(JsValue::undefined())
+}
+
 pub(super) fn create_object(context: &mut Context) -> JsObject {
     ObjectInitializer::new(context)
         .function(
@@ -15,5 +93,11 @@ pub(super) fn create_object(context: &mut Context) -> JsObject {
             js_string!("collect"),
             0,
         )
+        .function(NativeFunction::from_fn_ptr(stats), js_string!("stats"), 0)
+        .function(
+            NativeFunction::from_fn_ptr(snapshot),
+            js_string!("snapshot"),
+            1,
+        )
         .build()
 }
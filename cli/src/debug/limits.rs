@@ -16,6 +16,17 @@ fn set_loop(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Js
     Ok(JsValue::undefined())
 }
 
+fn get_instructions(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let max = context.runtime_limits().instruction_limit();
+    Ok(JsValue::from(max))
+}
+
+fn set_instructions(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_length(context)?;
+    context.runtime_limits_mut().set_instruction_limit(value);
+    Ok(JsValue::undefined())
+}
+
 fn get_stack(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
     let max = context.runtime_limits().stack_size_limit();
     Ok(JsValue::from(max))
@@ -60,6 +71,21 @@ pub(super) fn create_object(context: &mut Context) -> JsObject {
             .length(1)
             .build();
 
+    let get_instructions = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_fn_ptr(get_instructions),
+    )
+    .name(js_string!("get instructions"))
+    .length(0)
+    .build();
+    let set_instructions = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_fn_ptr(set_instructions),
+    )
+    .name(js_string!("set instructions"))
+    .length(1)
+    .build();
+
     let get_stack =
         FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(get_stack))
             .name(js_string!("get stack"))
@@ -88,6 +114,12 @@ pub(super) fn create_object(context: &mut Context) -> JsObject {
             Some(set_loop),
             Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
         )
+        .accessor(
+            js_string!("instructions"),
+            Some(get_instructions),
+            Some(set_instructions),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .accessor(
             js_string!("stack"),
             Some(get_stack),
@@ -25,8 +25,17 @@ use colored::Colorize;
 use debug::init_boa_debug_object;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
 use std::{
-    cell::RefCell, collections::VecDeque, eprintln, fs::read, fs::OpenOptions, io, path::PathBuf,
-    println, rc::Rc,
+    cell::RefCell,
+    collections::VecDeque,
+    eprintln,
+    fs::read,
+    fs::OpenOptions,
+    io,
+    path::PathBuf,
+    println,
+    rc::Rc,
+    sync::Arc,
+    task::{self, Poll, Wake, Waker},
 };
 
 #[cfg(all(
@@ -445,18 +454,39 @@ fn add_runtime(context: &mut Context) {
 }
 
 #[derive(Default)]
-struct Jobs(RefCell<VecDeque<NativeJob>>);
+struct Jobs {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: RefCell<VecDeque<FutureJob>>,
+}
 
 impl JobQueue for Jobs {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
         loop {
-            let jobs = std::mem::take(&mut *self.0.borrow_mut());
+            let jobs = std::mem::take(&mut *self.jobs.borrow_mut());
             if jobs.is_empty() {
-                return;
+                // The futures are never blocked on; the ones that aren't ready are polled again
+                // the next time the jobs are run.
+                let waker = Waker::from(Arc::new(NoopWaker));
+                let cx = &mut task::Context::from_waker(&waker);
+                let mut ready = Vec::new();
+                self.futures
+                    .borrow_mut()
+                    .retain_mut(|future| match future.as_mut().poll(cx) {
+                        Poll::Ready(job) => {
+                            ready.push(job);
+                            false
+                        }
+                        Poll::Pending => true,
+                    });
+                if ready.is_empty() {
+                    return;
+                }
+                self.jobs.borrow_mut().extend(ready);
+                continue;
             }
             for job in jobs {
                 if let Err(e) = job.call(context) {
@@ -467,7 +497,13 @@ impl JobQueue for Jobs {
     }
 
     fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
-        let job = pollster::block_on(future);
-        self.0.borrow_mut().push_back(job);
+        self.futures.borrow_mut().push_back(future);
     }
 }
+
+/// A waker that does nothing, used to poll futures without blocking on them.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
@@ -19,6 +19,7 @@ use super::function::{
 };
 use crate::{
     function::ClassDeclaration,
+    try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
@@ -156,3 +157,98 @@ impl VisitWith for ModuleSpecifier {
         visitor.visit_sym_mut(&mut self.module)
     }
 }
+
+/// An import attribute.
+///
+/// This is equivalent to the [`ImportAttribute`] production, and is used to pass additional
+/// information to the host about how a module should be loaded (`with { type: "json" }`).
+///
+/// [`ImportAttribute`]: https://tc39.es/ecma262/#prod-ImportAttribute
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    key: Sym,
+    value: Sym,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Sym, value: Sym) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn key(self) -> Sym {
+        self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> Sym {
+        self.value
+    }
+}
+
+impl VisitWith for ImportAttribute {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        try_break!(visitor.visit_sym(&self.key));
+        visitor.visit_sym(&self.value)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        try_break!(visitor.visit_sym_mut(&mut self.key));
+        visitor.visit_sym_mut(&mut self.value)
+    }
+}
+
+/// A [`ModuleRequest`][spec] record.
+///
+/// Pairs a module specifier with the import attributes it was requested with. Two requests are
+/// equal if their specifiers are equal and they have the same set of attributes, regardless
+/// of the order in which the attributes were written.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModuleRequest {
+    specifier: Sym,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and its import attributes.
+    #[must_use]
+    pub fn new(specifier: Sym, attributes: &[ImportAttribute]) -> Self {
+        let mut attributes = attributes.to_vec();
+        attributes.sort_unstable_by_key(|attribute| attribute.key());
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+        }
+    }
+
+    /// Gets the module specifier of the request.
+    #[inline]
+    #[must_use]
+    pub const fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Gets the import attributes of the request, sorted by key.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+}
@@ -102,6 +102,18 @@ pub enum LexicalDeclaration {
     ///
     /// [let]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
     Let(VariableList),
+
+    /// A <code>[using]</code> declaration creates an immutable binding whose value is disposed
+    /// by calling its `[Symbol.dispose]` method when the enclosing scope is exited.
+    ///
+    /// [using]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+    Using(VariableList),
+
+    /// An <code>[await using]</code> declaration creates an immutable binding whose value is
+    /// disposed by awaiting its `[Symbol.asyncDispose]` method when the enclosing scope is exited.
+    ///
+    /// [await using]: https://tc39.es/proposal-explicit-resource-management/#prod-AwaitUsingDeclaration
+    AwaitUsing(VariableList),
 }
 
 impl LexicalDeclaration {
@@ -109,14 +121,30 @@ impl LexicalDeclaration {
     #[must_use]
     pub const fn variable_list(&self) -> &VariableList {
         match self {
-            Self::Const(list) | Self::Let(list) => list,
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list
+            }
         }
     }
 
-    /// Returns `true` if the declaration is a `const` declaration.
+    /// Returns `true` if the declaration creates immutable bindings.
+    ///
+    /// This is the case for `const`, `using` and `await using` declarations.
     #[must_use]
     pub const fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
+        matches!(self, Self::Const(_) | Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is a `using` or `await using` declaration.
+    #[must_use]
+    pub const fn is_using(&self) -> bool {
+        matches!(self, Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is an `await using` declaration.
+    #[must_use]
+    pub const fn is_await_using(&self) -> bool {
+        matches!(self, Self::AwaitUsing(_))
     }
 }
 
@@ -133,6 +161,8 @@ impl ToInternedString for LexicalDeclaration {
             match &self {
                 Self::Let(_) => "let",
                 Self::Const(_) => "const",
+                Self::Using(_) => "using",
+                Self::AwaitUsing(_) => "await using",
             },
             self.variable_list().to_interned_string(interner)
         )
@@ -145,7 +175,9 @@ impl VisitWith for LexicalDeclaration {
         V: Visitor<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list(vars)
+            }
         }
     }
 
@@ -154,7 +186,9 @@ impl VisitWith for LexicalDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list_mut(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list_mut(vars)
+            }
         }
     }
 }
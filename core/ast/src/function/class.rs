@@ -30,6 +30,7 @@ pub struct ClassDeclaration {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Scope,
@@ -50,10 +51,19 @@ impl ClassDeclaration {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             name_scope: Scope::default(),
         }
     }
 
+    /// Sets the decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class declaration.
     #[inline]
     #[must_use]
@@ -82,6 +92,13 @@ impl ClassDeclaration {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding.
     #[inline]
     #[must_use]
@@ -92,7 +109,11 @@ impl ClassDeclaration {
 
 impl ToIndentedString for ClassDeclaration {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = format!("class {}", interner.resolve_expect(self.name.sym()));
+        let mut buf = format!(
+            "{}class {}",
+            decorators_to_string(&self.decorators, interner),
+            interner.resolve_expect(self.name.sym())
+        );
         if let Some(super_ref) = self.super_ref.as_ref() {
             buf.push_str(&format!(
                 " extends {}",
@@ -125,6 +146,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            try_break!(visitor.visit_decorator(decorator));
+        }
         try_break!(visitor.visit_identifier(&self.name));
         if let Some(expr) = &self.super_ref {
             try_break!(visitor.visit_expression(expr));
@@ -142,6 +166,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            try_break!(visitor.visit_decorator_mut(decorator));
+        }
         try_break!(visitor.visit_identifier_mut(&mut self.name));
         if let Some(expr) = &mut self.super_ref {
             try_break!(visitor.visit_expression_mut(expr));
@@ -178,6 +205,7 @@ pub struct ClassExpression {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Option<Scope>,
@@ -204,10 +232,19 @@ impl ClassExpression {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             name_scope,
         }
     }
 
+    /// Sets the decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class expression.
     #[inline]
     #[must_use]
@@ -236,6 +273,13 @@ impl ClassExpression {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding if it exists.
     #[inline]
     #[must_use]
@@ -246,7 +290,8 @@ impl ClassExpression {
 
 impl ToIndentedString for ClassExpression {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = "class".to_string();
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        buf.push_str("class");
         if self.name_scope.is_some() {
             if let Some(name) = self.name {
                 buf.push_str(&format!(" {}", interner.resolve_expect(name.sym())));
@@ -290,6 +335,9 @@ impl VisitWith for ClassExpression {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            try_break!(visitor.visit_decorator(decorator));
+        }
         if let Some(ident) = &self.name {
             try_break!(visitor.visit_identifier(ident));
         }
@@ -309,6 +357,9 @@ impl VisitWith for ClassExpression {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            try_break!(visitor.visit_decorator_mut(decorator));
+        }
         if let Some(ident) = &mut self.name {
             try_break!(visitor.visit_identifier_mut(ident));
         }
@@ -390,12 +441,61 @@ pub enum ClassElement {
 
     /// A private static field definition, only accessible from static methods and fields inside the
     /// class declaration.
-    PrivateStaticFieldDefinition(PrivateName, Option<Expression>),
+    PrivateStaticFieldDefinition(PrivateFieldDefinition),
+
+    /// An auto-accessor definition, declared with the `accessor` keyword.
+    AccessorDefinition(ClassAccessorDefinition),
 
     /// A static block, where a class can have initialization logic for its static fields.
     StaticBlock(StaticBlockBody),
 }
 
+impl ClassElement {
+    /// Gets the list of decorators applied to the class element.
+    ///
+    /// Static blocks cannot be decorated, so this is always empty for them.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        match self {
+            Self::MethodDefinition(m) => &m.decorators,
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => &field.decorators,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                &field.decorators
+            }
+            Self::AccessorDefinition(accessor) => &accessor.decorators,
+            Self::StaticBlock(_) => &[],
+        }
+    }
+
+    /// Sets the list of decorators applied to the class element.
+    ///
+    /// Static blocks cannot be decorated, so they are returned unchanged.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(self, decorators: Box<[Decorator]>) -> Self {
+        match self {
+            Self::MethodDefinition(m) => Self::MethodDefinition(m.with_decorators(decorators)),
+            Self::FieldDefinition(field) => {
+                Self::FieldDefinition(field.with_decorators(decorators))
+            }
+            Self::StaticFieldDefinition(field) => {
+                Self::StaticFieldDefinition(field.with_decorators(decorators))
+            }
+            Self::PrivateFieldDefinition(field) => {
+                Self::PrivateFieldDefinition(field.with_decorators(decorators))
+            }
+            Self::PrivateStaticFieldDefinition(field) => {
+                Self::PrivateStaticFieldDefinition(field.with_decorators(decorators))
+            }
+            Self::AccessorDefinition(accessor) => {
+                Self::AccessorDefinition(accessor.with_decorators(decorators))
+            }
+            Self::StaticBlock(block) => Self::StaticBlock(block),
+        }
+    }
+}
+
 /// A non-private class element field definition.
 ///
 /// More information:
@@ -408,6 +508,7 @@ pub enum ClassElement {
 pub struct ClassFieldDefinition {
     pub(crate) name: PropertyName,
     pub(crate) field: Option<Expression>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -421,10 +522,19 @@ impl ClassFieldDefinition {
         Self {
             name,
             field,
+            decorators: Box::default(),
             scope: Scope::default(),
         }
     }
 
+    /// Sets the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class field definition.
     #[inline]
     #[must_use]
@@ -439,6 +549,13 @@ impl ClassFieldDefinition {
         self.field.as_ref()
     }
 
+    /// Returns the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the class field definition.
     #[inline]
     #[must_use]
@@ -459,6 +576,7 @@ impl ClassFieldDefinition {
 pub struct PrivateFieldDefinition {
     pub(crate) name: PrivateName,
     pub(crate) field: Option<Expression>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -472,10 +590,19 @@ impl PrivateFieldDefinition {
         Self {
             name,
             field,
+            decorators: Box::default(),
             scope: Scope::default(),
         }
     }
 
+    /// Sets the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the private field definition.
     #[inline]
     #[must_use]
@@ -490,6 +617,13 @@ impl PrivateFieldDefinition {
         self.field.as_ref()
     }
 
+    /// Returns the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the private field definition.
     #[inline]
     #[must_use]
@@ -498,22 +632,103 @@ impl PrivateFieldDefinition {
     }
 }
 
+/// An auto-accessor class element.
+///
+/// An auto-accessor defines a getter and a setter pair that read and write a private storage
+/// slot, which is initialized like a field.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#sec-class-definitions
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassAccessorDefinition {
+    pub(crate) name: ClassElementName,
+    pub(crate) field: Option<Expression>,
+    is_static: bool,
+    pub(crate) decorators: Box<[Decorator]>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) scope: Scope,
+}
+
+impl ClassAccessorDefinition {
+    /// Creates a new auto-accessor definition.
+    #[inline]
+    #[must_use]
+    pub fn new(name: ClassElementName, field: Option<Expression>, is_static: bool) -> Self {
+        Self {
+            name,
+            field,
+            is_static,
+            decorators: Box::default(),
+            scope: Scope::default(),
+        }
+    }
+
+    /// Sets the decorators applied to the auto-accessor definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
+    /// Returns the name of the auto-accessor definition.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &ClassElementName {
+        &self.name
+    }
+
+    /// Returns the initializer of the auto-accessor definition.
+    #[inline]
+    #[must_use]
+    pub const fn field(&self) -> Option<&Expression> {
+        self.field.as_ref()
+    }
+
+    /// Returns whether the auto-accessor definition is static.
+    #[inline]
+    #[must_use]
+    pub const fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    /// Returns the decorators applied to the auto-accessor definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
+    /// Returns the scope of the auto-accessor initializer.
+    #[inline]
+    #[must_use]
+    pub const fn scope(&self) -> &Scope {
+        &self.scope
+    }
+}
+
 impl ToIndentedString for ClassElement {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
         let indentation = "    ".repeat(indent_n + 1);
+        let decorators = decorators_to_string(self.decorators(), interner);
         match self {
             Self::MethodDefinition(m) => m.to_indented_string(interner, indent_n),
             Self::FieldDefinition(field) => match &field.field {
                 Some(expr) => {
                     format!(
-                        "{indentation}{} = {};\n",
+                        "{indentation}{decorators}{} = {};\n",
                         field.name.to_interned_string(interner),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}{};\n",
+                        "{indentation}{decorators}{};\n",
                         field.name.to_interned_string(interner),
                     )
                 }
@@ -521,14 +736,14 @@ impl ToIndentedString for ClassElement {
             Self::StaticFieldDefinition(field) => match &field.field {
                 Some(expr) => {
                     format!(
-                        "{indentation}static {} = {};\n",
+                        "{indentation}{decorators}static {} = {};\n",
                         field.name.to_interned_string(interner),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}static {};\n",
+                        "{indentation}{decorators}static {};\n",
                         field.name.to_interned_string(interner),
                     )
                 }
@@ -537,33 +752,57 @@ impl ToIndentedString for ClassElement {
             {
                 Some(expr) => {
                     format!(
-                        "{indentation}#{} = {};\n",
+                        "{indentation}{decorators}#{} = {};\n",
                         interner.resolve_expect(name.description()),
                         expr.to_no_indent_string(interner, indent_n + 1)
                     )
                 }
                 None => {
                     format!(
-                        "{indentation}#{};\n",
+                        "{indentation}{decorators}#{};\n",
                         interner.resolve_expect(name.description()),
                     )
                 }
             },
-            Self::PrivateStaticFieldDefinition(name, field) => match field {
-                Some(expr) => {
-                    format!(
-                        "{indentation}static #{} = {};\n",
-                        interner.resolve_expect(name.description()),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
+            Self::PrivateStaticFieldDefinition(PrivateFieldDefinition { name, field, .. }) => {
+                match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}{decorators}static #{} = {};\n",
+                            interner.resolve_expect(name.description()),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}{decorators}static #{};\n",
+                            interner.resolve_expect(name.description()),
+                        )
+                    }
                 }
-                None => {
-                    format!(
-                        "{indentation}static #{};\n",
-                        interner.resolve_expect(name.description()),
-                    )
+            }
+            Self::AccessorDefinition(accessor) => {
+                let prefix = if accessor.is_static {
+                    "static accessor"
+                } else {
+                    "accessor"
+                };
+                match &accessor.field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}{decorators}{prefix} {} = {};\n",
+                            accessor.name.to_interned_string(interner),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}{decorators}{prefix} {};\n",
+                            accessor.name.to_interned_string(interner),
+                        )
+                    }
                 }
-            },
+            }
             Self::StaticBlock(block) => {
                 format!(
                     "{indentation}static {}\n",
@@ -579,6 +818,9 @@ impl VisitWith for ClassElement {
     where
         V: Visitor<'a>,
     {
+        for decorator in self.decorators() {
+            try_break!(visitor.visit_decorator(decorator));
+        }
         match self {
             Self::MethodDefinition(m) => {
                 match &m.name {
@@ -601,7 +843,7 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition { name, field, .. })
-            | Self::PrivateStaticFieldDefinition(name, field) => {
+            | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition { name, field, .. }) => {
                 try_break!(visitor.visit_private_name(name));
                 if let Some(expr) = field {
                     visitor.visit_expression(expr)
@@ -609,6 +851,21 @@ impl VisitWith for ClassElement {
                     ControlFlow::Continue(())
                 }
             }
+            Self::AccessorDefinition(accessor) => {
+                match &accessor.name {
+                    ClassElementName::PropertyName(pn) => {
+                        try_break!(visitor.visit_property_name(pn));
+                    }
+                    ClassElementName::PrivateName(pn) => {
+                        try_break!(visitor.visit_private_name(pn));
+                    }
+                }
+                if let Some(expr) = &accessor.field {
+                    visitor.visit_expression(expr)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            }
             Self::StaticBlock(block) => visitor.visit_function_body(&block.body),
         }
     }
@@ -619,6 +876,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &mut *m.decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 match m.name {
                     ClassElementName::PropertyName(ref mut pn) => {
                         try_break!(visitor.visit_property_name_mut(pn));
@@ -631,6 +891,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body_mut(&mut m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 try_break!(visitor.visit_property_name_mut(&mut field.name));
                 if let Some(expr) = &mut field.field {
                     visitor.visit_expression_mut(expr)
@@ -638,8 +901,21 @@ impl VisitWith for ClassElement {
                     ControlFlow::Continue(())
                 }
             }
-            Self::PrivateFieldDefinition(PrivateFieldDefinition { name, field, .. })
-            | Self::PrivateStaticFieldDefinition(name, field) => {
+            Self::PrivateFieldDefinition(PrivateFieldDefinition {
+                name,
+                field,
+                decorators,
+                ..
+            })
+            | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
+                name,
+                field,
+                decorators,
+                ..
+            }) => {
+                for decorator in &mut **decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 try_break!(visitor.visit_private_name_mut(name));
                 if let Some(expr) = field {
                     visitor.visit_expression_mut(expr)
@@ -647,6 +923,24 @@ impl VisitWith for ClassElement {
                     ControlFlow::Continue(())
                 }
             }
+            Self::AccessorDefinition(accessor) => {
+                for decorator in &mut *accessor.decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
+                match accessor.name {
+                    ClassElementName::PropertyName(ref mut pn) => {
+                        try_break!(visitor.visit_property_name_mut(pn));
+                    }
+                    ClassElementName::PrivateName(ref mut pn) => {
+                        try_break!(visitor.visit_private_name_mut(pn));
+                    }
+                }
+                if let Some(expr) = &mut accessor.field {
+                    visitor.visit_expression_mut(expr)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            }
             Self::StaticBlock(block) => visitor.visit_function_body_mut(&mut block.body),
         }
     }
@@ -671,6 +965,7 @@ pub struct ClassMethodDefinition {
     pub(crate) contains_direct_eval: bool,
     kind: MethodDefinitionKind,
     is_static: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
@@ -696,10 +991,19 @@ impl ClassMethodDefinition {
             contains_direct_eval,
             kind,
             is_static,
+            decorators: Box::default(),
             scopes: FunctionScopes::default(),
         }
     }
 
+    /// Sets the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class method definition.
     #[inline]
     #[must_use]
@@ -735,6 +1039,13 @@ impl ClassMethodDefinition {
         self.is_static
     }
 
+    /// Returns the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns whether the class method definition is private.
     #[inline]
     #[must_use]
@@ -774,10 +1085,11 @@ impl ToIndentedString for ClassMethodDefinition {
             (false, MethodDefinitionKind::AsyncGenerator) => "async *",
             (false, MethodDefinitionKind::Async) => "async ",
         };
+        let decorators = decorators_to_string(&self.decorators, interner);
         let name = self.name.to_interned_string(interner);
         let parameters = join_nodes(interner, self.parameters.as_ref());
         let body = block_to_string(&self.body.statements, interner, indent_n + 1);
-        format!("{indentation}{prefix}{name}({parameters}) {body}\n")
+        format!("{indentation}{decorators}{prefix}{name}({parameters}) {body}\n")
     }
 }
 
@@ -858,3 +1170,68 @@ impl VisitWith for PrivateName {
         visitor.visit_sym_mut(&mut self.description)
     }
 }
+
+/// A decorator, applied to a class or to a class element with the `@` syntax.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+}
+
+impl Decorator {
+    /// Creates a new decorator from the expression evaluating to the decorator function.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self { expression }
+    }
+
+    /// Returns the expression evaluating to the decorator function.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match &self.expression {
+            Expression::Identifier(_) | Expression::PropertyAccess(_) | Expression::Call(_) => {
+                format!("@{}", self.expression.to_interned_string(interner))
+            }
+            expression => format!("@({})", expression.to_interned_string(interner)),
+        }
+    }
+}
+
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
+    }
+}
+
+/// Formats a list of decorators, each followed by a space.
+fn decorators_to_string(decorators: &[Decorator], interner: &Interner) -> String {
+    decorators.iter().fold(String::new(), |mut buf, decorator| {
+        buf.push_str(&decorator.to_interned_string(interner));
+        buf.push(' ');
+        buf
+    })
+}
@@ -40,8 +40,9 @@ pub use async_function::{AsyncFunctionDeclaration, AsyncFunctionExpression};
 pub use async_generator::{AsyncGeneratorDeclaration, AsyncGeneratorExpression};
 use boa_interner::{Interner, ToIndentedString};
 pub use class::{
-    ClassDeclaration, ClassElement, ClassElementName, ClassExpression, ClassFieldDefinition,
-    ClassMethodDefinition, PrivateFieldDefinition, PrivateName, StaticBlockBody,
+    ClassAccessorDefinition, ClassDeclaration, ClassElement, ClassElementName, ClassExpression,
+    ClassFieldDefinition, ClassMethodDefinition, Decorator, PrivateFieldDefinition, PrivateName,
+    StaticBlockBody,
 };
 pub use generator::{GeneratorDeclaration, GeneratorExpression};
 pub use ordinary_function::{FunctionDeclaration, FunctionExpression};
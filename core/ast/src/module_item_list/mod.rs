@@ -8,7 +8,7 @@
 use crate::{
     declaration::{
         ExportDeclaration, ExportEntry, ExportSpecifier, ImportDeclaration, ImportEntry,
-        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleSpecifier,
+        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleRequest,
         ReExportImportName, ReExportKind,
     },
     expression::Identifier,
@@ -203,9 +203,11 @@ impl ModuleItemList {
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
     #[inline]
     #[must_use]
-    pub fn requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+    pub fn requests(&self) -> IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>> {
         #[derive(Debug)]
-        struct RequestsVisitor<'vec>(&'vec mut IndexSet<Sym, BuildHasherDefault<FxHasher>>);
+        struct RequestsVisitor<'vec>(
+            &'vec mut IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
+        );
 
         impl<'ast> Visitor<'ast> for RequestsVisitor<'_> {
             type BreakTy = Infallible;
@@ -216,11 +218,26 @@ impl ModuleItemList {
             ) -> ControlFlow<Self::BreakTy> {
                 ControlFlow::Continue(())
             }
-            fn visit_module_specifier(
+            fn visit_import_declaration(
+                &mut self,
+                node: &'ast ImportDeclaration,
+            ) -> ControlFlow<Self::BreakTy> {
+                self.0.insert(node.module_request());
+                ControlFlow::Continue(())
+            }
+            fn visit_export_declaration(
                 &mut self,
-                node: &'ast ModuleSpecifier,
+                node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                self.0.insert(node.sym());
+                if let ExportDeclaration::ReExport {
+                    specifier,
+                    attributes,
+                    ..
+                } = node
+                {
+                    self.0
+                        .insert(ModuleRequest::new(specifier.sym(), attributes));
+                }
                 ControlFlow::Continue(())
             }
         }
@@ -259,11 +276,11 @@ impl ModuleItemList {
                 &mut self,
                 node: &'ast ImportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                let module = node.specifier().sym();
+                let module = node.module_request();
 
                 if let Some(default) = node.default() {
                     self.0.push(ImportEntry::new(
-                        module,
+                        module.clone(),
                         ImportName::Name(Sym::DEFAULT),
                         default,
                     ));
@@ -278,7 +295,7 @@ impl ModuleItemList {
                     ImportKind::Named { names } => {
                         for name in &**names {
                             self.0.push(ImportEntry::new(
-                                module,
+                                module.clone(),
                                 ImportName::Name(name.export_name()),
                                 name.binding(),
                             ));
@@ -325,8 +342,12 @@ impl ModuleItemList {
                 node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
                 let name = match node {
-                    ExportDeclaration::ReExport { kind, specifier } => {
-                        let module = specifier.sym();
+                    ExportDeclaration::ReExport {
+                        kind,
+                        specifier,
+                        attributes,
+                    } => {
+                        let module = ModuleRequest::new(specifier.sym(), attributes);
 
                         match kind {
                             ReExportKind::Namespaced { name } => {
@@ -349,7 +370,7 @@ impl ModuleItemList {
                                 for name in &**names {
                                     self.0.push(
                                         IndirectExportEntry::new(
-                                            module,
+                                            module.clone(),
                                             ReExportImportName::Name(name.private_name()),
                                             name.alias(),
                                         )
@@ -47,7 +47,7 @@ pub enum ContainsSymbol {
     SuperCall,
     /// A yield expression (`yield 5`).
     YieldExpression,
-    /// An await expression (`await 4`).
+    /// An await expression (`await 4`) or an `await using` declaration.
     AwaitExpression,
     /// The new target expression (`new.target`).
     NewTarget,
@@ -199,6 +199,9 @@ where
 
         // `ComputedPropertyContains`: https://tc39.es/ecma262/#sec-static-semantics-computedpropertycontains
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+            for decorator in node.decorators() {
+                try_break!(decorator.visit_with(self));
+            }
             match node {
                 ClassElement::MethodDefinition(m) => {
                     if self.0 == ContainsSymbol::DirectEval {
@@ -213,6 +216,13 @@ where
                 }
                 ClassElement::FieldDefinition(field)
                 | ClassElement::StaticFieldDefinition(field) => field.name.visit_with(self),
+                ClassElement::AccessorDefinition(accessor) => {
+                    if let ClassElementName::PropertyName(name) = accessor.name() {
+                        name.visit_with(self)
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }
                 _ => ControlFlow::Continue(()),
             }
         }
@@ -308,6 +318,30 @@ where
             node.visit_with(self)
         }
 
+        fn visit_lexical_declaration(
+            &mut self,
+            node: &'ast LexicalDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            if self.0 == ContainsSymbol::AwaitExpression && node.is_await_using() {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
+        fn visit_iterable_loop_initializer(
+            &mut self,
+            node: &'ast IterableLoopInitializer,
+        ) -> ControlFlow<Self::BreakTy> {
+            if self.0 == ContainsSymbol::AwaitExpression
+                && matches!(node, IterableLoopInitializer::AwaitUsing(_))
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
         fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
             if node == &Expression::This && self.0 == ContainsSymbol::This {
                 return ControlFlow::Break(());
@@ -405,6 +439,9 @@ where
 
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
             if let ClassElement::MethodDefinition(m) = node {
+                for decorator in m.decorators() {
+                    try_break!(decorator.visit_with(self));
+                }
                 if let ClassElementName::PropertyName(name) = m.name() {
                     return name.visit_with(self);
                 }
@@ -1168,6 +1205,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            try_break!(self.visit(decorator));
+        }
         if let Some(node) = node.super_ref() {
             try_break!(self.visit(node));
         }
@@ -1181,9 +1221,16 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                     }
                 }
                 ClassElement::PrivateFieldDefinition(PrivateFieldDefinition { name, .. })
-                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                | ClassElement::PrivateStaticFieldDefinition(PrivateFieldDefinition {
+                    name, ..
+                }) => {
                     names.push(name.description());
                 }
+                ClassElement::AccessorDefinition(accessor) => {
+                    if let ClassElementName::PrivateName(name) = accessor.name() {
+                        names.push(name.description());
+                    }
+                }
                 _ => {}
             }
         }
@@ -1195,6 +1242,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                try_break!(visitor.visit(decorator));
+            }
             match element {
                 ClassElement::MethodDefinition(m) => {
                     if let ClassElementName::PropertyName(name) = m.name() {
@@ -1211,11 +1261,22 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                     }
                 }
                 ClassElement::PrivateFieldDefinition(PrivateFieldDefinition { field, .. })
-                | ClassElement::PrivateStaticFieldDefinition(_, field) => {
+                | ClassElement::PrivateStaticFieldDefinition(PrivateFieldDefinition {
+                    field,
+                    ..
+                }) => {
                     if let Some(expression) = field {
                         try_break!(visitor.visit(expression));
                     }
                 }
+                ClassElement::AccessorDefinition(accessor) => {
+                    if let ClassElementName::PropertyName(name) = accessor.name() {
+                        try_break!(visitor.visit(name));
+                    }
+                    if let Some(expression) = accessor.field() {
+                        try_break!(visitor.visit(expression));
+                    }
+                }
                 ClassElement::StaticBlock(block) => {
                     try_break!(visitor.visit(&block.body));
                 }
@@ -1229,6 +1290,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            try_break!(self.visit(decorator));
+        }
         if let Some(node) = node.super_ref() {
             try_break!(self.visit(node));
         }
@@ -1242,9 +1306,16 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                     }
                 }
                 ClassElement::PrivateFieldDefinition(PrivateFieldDefinition { name, .. })
-                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                | ClassElement::PrivateStaticFieldDefinition(PrivateFieldDefinition {
+                    name, ..
+                }) => {
                     names.push(name.description());
                 }
+                ClassElement::AccessorDefinition(accessor) => {
+                    if let ClassElementName::PrivateName(name) = accessor.name() {
+                        names.push(name.description());
+                    }
+                }
                 _ => {}
             }
         }
@@ -1256,6 +1327,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                try_break!(visitor.visit(decorator));
+            }
             match element {
                 ClassElement::MethodDefinition(m) => {
                     if let ClassElementName::PropertyName(name) = m.name() {
@@ -1272,11 +1346,22 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                     }
                 }
                 ClassElement::PrivateFieldDefinition(PrivateFieldDefinition { field, .. })
-                | ClassElement::PrivateStaticFieldDefinition(_, field) => {
+                | ClassElement::PrivateStaticFieldDefinition(PrivateFieldDefinition {
+                    field,
+                    ..
+                }) => {
                     if let Some(expression) = field {
                         try_break!(visitor.visit(expression));
                     }
                 }
+                ClassElement::AccessorDefinition(accessor) => {
+                    if let ClassElementName::PropertyName(name) = accessor.name() {
+                        try_break!(visitor.visit(name));
+                    }
+                    if let Some(expression) = accessor.field() {
+                        try_break!(visitor.visit(expression));
+                    }
+                }
                 ClassElement::StaticBlock(block) => {
                     try_break!(visitor.visit(&block.body));
                 }
@@ -2412,7 +2497,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -2430,7 +2518,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
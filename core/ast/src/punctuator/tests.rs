@@ -24,6 +24,7 @@ fn all_punctuators() -> impl Iterator<Item = Punctuator> {
         Punctuator::AssignSub,
         Punctuator::AssignURightSh,
         Punctuator::AssignXor,
+        Punctuator::At,
         Punctuator::BoolAnd,
         Punctuator::BoolOr,
         Punctuator::CloseBlock,
@@ -207,6 +208,7 @@ fn as_str() {
             "-=" => assert_eq!(p, Punctuator::AssignSub),
             ">>>=" => assert_eq!(p, Punctuator::AssignURightSh),
             "^=" => assert_eq!(p, Punctuator::AssignXor),
+            "@" => assert_eq!(p, Punctuator::At),
             "&&" => assert_eq!(p, Punctuator::BoolAnd),
             "||" => assert_eq!(p, Punctuator::BoolOr),
             "??" => assert_eq!(p, Punctuator::Coalesce),
@@ -12,7 +12,7 @@ use crate::{
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
         AsyncGeneratorDeclaration, AsyncGeneratorExpression, ClassDeclaration, ClassElement,
-        ClassExpression, FormalParameterList, FunctionBody, FunctionDeclaration,
+        ClassElementName, ClassExpression, FormalParameterList, FunctionBody, FunctionDeclaration,
         FunctionExpression, GeneratorDeclaration, GeneratorExpression,
     },
     operations::{
@@ -379,6 +379,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            try_break!(self.visit_decorator_mut(decorator));
+        }
         node.name_scope.escape_all_bindings();
         std::mem::swap(&mut self.scope, &mut node.name_scope);
         if let Some(super_ref) = &mut node.super_ref {
@@ -399,6 +402,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            try_break!(self.visit_decorator_mut(decorator));
+        }
         if let Some(name_scope) = &mut node.name_scope {
             if self.direct_eval {
                 name_scope.escape_all_bindings();
@@ -427,27 +433,45 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
         match node {
-            ClassElement::MethodDefinition(node) => self.visit_function_like(
-                &mut node.parameters,
-                &mut node.body,
-                &mut node.scopes,
-                node.contains_direct_eval,
-            ),
+            ClassElement::MethodDefinition(node) => {
+                for decorator in &mut *node.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
+                self.visit_function_like(
+                    &mut node.parameters,
+                    &mut node.body,
+                    &mut node.scopes,
+                    node.contains_direct_eval,
+                )
+            }
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 try_break!(self.visit_property_name_mut(&mut field.name));
                 if let Some(e) = &mut field.field {
                     try_break!(self.visit_expression_mut(e));
                 }
                 ControlFlow::Continue(())
             }
-            ClassElement::PrivateFieldDefinition(field) => {
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 if let Some(e) = &mut field.field {
                     try_break!(self.visit_expression_mut(e));
                 }
                 ControlFlow::Continue(())
             }
-            ClassElement::PrivateStaticFieldDefinition(_, e) => {
-                if let Some(e) = e {
+            ClassElement::AccessorDefinition(accessor) => {
+                for decorator in &mut *accessor.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
+                if let ClassElementName::PropertyName(name) = &mut accessor.name {
+                    try_break!(self.visit_property_name_mut(name));
+                }
+                if let Some(e) = &mut accessor.field {
                     try_break!(self.visit_expression_mut(e));
                 }
                 ControlFlow::Continue(())
@@ -482,7 +506,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         node: &'ast mut ExportDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
         match node {
-            ExportDeclaration::ReExport { specifier, kind } => {
+            ExportDeclaration::ReExport {
+                specifier, kind, ..
+            } => {
                 try_break!(self.visit_module_specifier_mut(specifier));
                 self.visit_re_export_kind_mut(kind)
             }
@@ -748,6 +774,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            try_break!(self.visit_decorator_mut(decorator));
+        }
         let mut name_scope = Scope::new(self.scope.clone(), false);
         let name = node.name().to_js_string(self.interner);
         name_scope.create_immutable_binding(name, true);
@@ -770,6 +799,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            try_break!(self.visit_decorator_mut(decorator));
+        }
         let mut name_scope = None;
         if let Some(name) = node.name {
             if node.name_scope.is_some() {
@@ -802,6 +834,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     ) -> ControlFlow<Self::BreakTy> {
         match node {
             ClassElement::MethodDefinition(node) => {
+                for decorator in &mut *node.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 let strict = node.body.strict();
                 self.visit_function_like(
                     &mut node.body,
@@ -814,6 +849,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 )
             }
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 try_break!(self.visit_property_name_mut(&mut field.name));
                 let mut scope = Scope::new(self.scope.clone(), true);
                 std::mem::swap(&mut self.scope, &mut scope);
@@ -824,7 +862,11 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 field.scope = scope;
                 ControlFlow::Continue(())
             }
-            ClassElement::PrivateFieldDefinition(field) => {
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 let mut scope = Scope::new(self.scope.clone(), true);
                 std::mem::swap(&mut self.scope, &mut scope);
                 if let Some(e) = &mut field.field {
@@ -834,10 +876,20 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 field.scope = scope;
                 ControlFlow::Continue(())
             }
-            ClassElement::PrivateStaticFieldDefinition(_, e) => {
-                if let Some(e) = e {
+            ClassElement::AccessorDefinition(accessor) => {
+                for decorator in &mut *accessor.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
+                if let ClassElementName::PropertyName(name) = &mut accessor.name {
+                    try_break!(self.visit_property_name_mut(name));
+                }
+                let mut scope = Scope::new(self.scope.clone(), true);
+                std::mem::swap(&mut self.scope, &mut scope);
+                if let Some(e) = &mut accessor.field {
                     try_break!(self.visit_expression_mut(e));
                 }
+                std::mem::swap(&mut self.scope, &mut scope);
+                accessor.scope = scope;
                 ControlFlow::Continue(())
             }
             ClassElement::StaticBlock(node) => {
@@ -983,7 +1035,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_in_loop_mut(&mut self, node: &'ast mut ForInLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1016,7 +1070,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1050,7 +1106,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_of_loop_mut(&mut self, node: &'ast mut ForOfLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1083,7 +1141,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1361,6 +1421,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            try_break!(self.visit_decorator_mut(decorator));
+        }
         let index = self.index;
         if !node.name_scope.all_bindings_local() {
             self.index += 1;
@@ -1383,6 +1446,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            try_break!(self.visit_decorator_mut(decorator));
+        }
         let index = self.index;
         if let Some(scope) = &node.name_scope {
             if !scope.all_bindings_local() {
@@ -1409,6 +1475,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
     ) -> ControlFlow<Self::BreakTy> {
         match node {
             ClassElement::MethodDefinition(node) => {
+                for decorator in &mut *node.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 let contains_direct_eval = node.contains_direct_eval();
                 self.visit_function_like(
                     &mut node.body,
@@ -1420,6 +1489,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
                 )
             }
             ClassElement::FieldDefinition(field) | ClassElement::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 try_break!(self.visit_property_name_mut(&mut field.name));
                 let index = self.index;
                 self.index += 1;
@@ -1430,7 +1502,11 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
                 self.index = index;
                 ControlFlow::Continue(())
             }
-            ClassElement::PrivateFieldDefinition(field) => {
+            ClassElement::PrivateFieldDefinition(field)
+            | ClassElement::PrivateStaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
                 let index = self.index;
                 self.index += 1;
                 field.scope.set_index(self.index);
@@ -1440,10 +1516,20 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
                 self.index = index;
                 ControlFlow::Continue(())
             }
-            ClassElement::PrivateStaticFieldDefinition(_, e) => {
-                if let Some(e) = e {
+            ClassElement::AccessorDefinition(accessor) => {
+                for decorator in &mut *accessor.decorators {
+                    try_break!(self.visit_decorator_mut(decorator));
+                }
+                if let ClassElementName::PropertyName(name) = &mut accessor.name {
+                    try_break!(self.visit_property_name_mut(name));
+                }
+                let index = self.index;
+                self.index += 1;
+                accessor.scope.set_index(self.index);
+                if let Some(e) = &mut accessor.field {
                     try_break!(self.visit_expression_mut(e));
                 }
+                self.index = index;
                 ControlFlow::Continue(())
             }
             ClassElement::StaticBlock(node) => {
@@ -1720,7 +1806,11 @@ fn global_declaration_instantiation(
                         drop(env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         env.create_immutable_binding(name, true);
@@ -1759,7 +1849,12 @@ where
     // 3. For each element d of declarations, do
     for d in &declarations {
         // i. If IsConstantDeclaration of d is true, then
-        if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d {
+        if let LexicallyScopedDeclaration::LexicalDeclaration(
+            LexicalDeclaration::Const(d)
+            | LexicalDeclaration::Using(d)
+            | LexicalDeclaration::AwaitUsing(d),
+        ) = d
+        {
             // a. For each element dn of the BoundNames of d, do
             for dn in bound_names::<'_, VariableList>(d) {
                 // 1. Perform ! env.CreateImmutableBinding(dn, true).
@@ -2098,7 +2193,11 @@ fn function_declaration_instantiation(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
@@ -2168,7 +2267,11 @@ fn module_instantiation(module: &Module, env: &Scope, interner: &Interner) {
                 }
                 continue;
             }
-            LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(c)) => {
+            LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Const(c)
+                | LexicalDeclaration::Using(c)
+                | LexicalDeclaration::AwaitUsing(c),
+            ) => {
                 for name in bound_names(c) {
                     let name = name.to_js_string(interner);
                     env.create_immutable_binding(name, true);
@@ -2405,7 +2508,11 @@ pub(crate) fn eval_declaration_instantiation_scope(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
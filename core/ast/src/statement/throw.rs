@@ -1,7 +1,7 @@
 use crate::{
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Expression,
+    Expression, Position,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -22,9 +22,11 @@ use core::ops::ControlFlow;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/throw
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Throw {
     target: Expression,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    position: Option<Position>,
 }
 
 impl Throw {
@@ -34,10 +36,33 @@ impl Throw {
         &self.target
     }
 
+    /// Gets the position of this `Throw` statement in the source code, if known.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
     /// Creates a `Throw` AST node.
     #[must_use]
     pub const fn new(target: Expression) -> Self {
-        Self { target }
+        Self {
+            target,
+            position: None,
+        }
+    }
+
+    /// Sets the position of this `Throw` statement in the source code.
+    #[must_use]
+    pub const fn with_position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+// Source positions are only debug information, so they are not compared.
+impl PartialEq for Throw {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target
     }
 }
 
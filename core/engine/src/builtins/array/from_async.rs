@@ -0,0 +1,374 @@
+//! The steps of `Array.fromAsync` that run after its first `await`.
+//!
+//! `Array.fromAsync` is specified as an abstract closure run by `AsyncFunctionStart`, so every
+//! `Await` in the spec suspends the algorithm. Here the algorithm is a state machine that is
+//! resumed by the reactions of the awaited promises.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+
+use crate::{
+    builtins::{
+        iterable::{IteratorRecord, IteratorResult},
+        promise::{Promise, PromiseCapability},
+    },
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    string::StaticJsStrings,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsValue,
+};
+
+/// The source of the values of an `Array.fromAsync` call.
+#[derive(Debug, Trace, Finalize)]
+pub(super) enum FromAsyncSource {
+    /// An async iterator, or a sync iterator wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+    /// An array-like object of length `len`.
+    ArrayLike { array_like: JsObject, len: u64 },
+}
+
+/// What the value awaited by an `Array.fromAsync` call is.
+#[derive(Debug, Trace, Finalize)]
+enum Awaiting {
+    /// The result of calling the `next` method of the iterator.
+    NextResult,
+    /// The value at index `k` of the array-like object.
+    Value,
+    /// The result of calling the mapping function.
+    MappedValue,
+    /// The result of the `return` method of the iterator, called after `error` was thrown.
+    Close(JsError),
+}
+
+/// What an `Array.fromAsync` call has to do after running its steps.
+enum Step {
+    /// The algorithm awaits the value.
+    Await(JsValue),
+    /// The algorithm completed normally.
+    Done,
+}
+
+/// The state of an `Array.fromAsync` call.
+#[derive(Debug, Trace, Finalize)]
+pub(super) struct FromAsync {
+    capability: PromiseCapability,
+    array: JsObject,
+    mapper: Option<JsObject>,
+    this_arg: JsValue,
+    source: FromAsyncSource,
+    k: u64,
+    awaiting: Awaiting,
+}
+
+impl FromAsync {
+    /// Starts reading the values of `source` into `array`.
+    ///
+    /// The promise of `capability` is settled once all values are read, or an error is thrown.
+    pub(super) fn start(
+        capability: PromiseCapability,
+        array: JsObject,
+        mapper: Option<JsObject>,
+        this_arg: JsValue,
+        source: FromAsyncSource,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let mut state = Self {
+            capability,
+            array,
+            mapper,
+            this_arg,
+            source,
+            k: 0,
+            awaiting: Awaiting::NextResult,
+        };
+
+        let step = match &state.source {
+            FromAsyncSource::Iterator(_) => state.next(context),
+            FromAsyncSource::ArrayLike { .. } => state.next_value(context),
+        };
+        state.run(step, context)
+    }
+
+    /// Resumes the algorithm with the completion of the awaited value.
+    fn resume(mut self, completion: JsResult<JsValue>, context: &mut Context) -> JsResult<()> {
+        let step = self.step(completion, context);
+        self.run(step, context)
+    }
+
+    /// Drives the algorithm until it has to await a promise, or until it completes.
+    ///
+    /// Only returns an error if the error cannot be caught by the promise.
+    fn run(mut self, mut step: JsResult<Step>, context: &mut Context) -> JsResult<()> {
+        loop {
+            let value = match step {
+                Ok(Step::Await(value)) => value,
+                Ok(Step::Done) => {
+                    self.capability
+                        .resolve()
+                        .call(&JsValue::undefined(), &[self.array.clone().into()], context)
+                        .expect("default `resolve` function cannot throw");
+                    return Ok(());
+                }
+                Err(err) if err.is_catchable() => {
+                    let err = err.to_opaque(context);
+                    self.capability
+                        .reject()
+                        .call(&JsValue::undefined(), &[err], context)
+                        .expect("default `reject` function cannot throw");
+                    return Ok(());
+                }
+                Err(err) => return Err(err),
+            };
+
+            // Await ( value )
+            let promise = match Promise::promise_resolve(
+                &context.intrinsics().constructors().promise().constructor(),
+                value,
+                context,
+            ) {
+                Ok(promise) => promise,
+                Err(err) if err.is_catchable() => {
+                    step = self.step(Err(err), context);
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            let state = Gc::new(GcRefCell::new(Some(self)));
+
+            let on_fulfilled = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, state, context| {
+                        let state = state
+                            .borrow_mut()
+                            .take()
+                            .expect("`Array.fromAsync` can only be resumed once");
+                        state.resume(Ok(args.get_or_undefined(0).clone()), context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    state.clone(),
+                ),
+            )
+            .name(js_string!())
+            .length(1)
+            .build();
+
+            let on_rejected = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, state, context| {
+                        let state = state
+                            .borrow_mut()
+                            .take()
+                            .expect("`Array.fromAsync` can only be resumed once");
+                        state.resume(
+                            Err(JsError::from_opaque(args.get_or_undefined(0).clone())),
+                            context,
+                        )?;
+                        Ok(JsValue::undefined())
+                    },
+                    state,
+                ),
+            )
+            .name(js_string!())
+            .length(1)
+            .build();
+
+            Promise::perform_promise_then(
+                &promise,
+                Some(on_fulfilled),
+                Some(on_rejected),
+                None,
+                context,
+            );
+
+            return Ok(());
+        }
+    }
+
+    /// Runs the steps that follow the awaited value, until the next `Await`.
+    fn step(&mut self, completion: JsResult<JsValue>, context: &mut Context) -> JsResult<Step> {
+        match std::mem::replace(&mut self.awaiting, Awaiting::NextResult) {
+            Awaiting::NextResult => {
+                // 4. Set nextResult to ? Await(nextResult).
+                // 5. If nextResult is not an Object, throw a TypeError exception.
+                let next_result = IteratorResult::from_value(completion?)?;
+
+                // 6. Let done be ? IteratorComplete(nextResult).
+                // 7. If done is true,
+                if next_result.complete(context)? {
+                    // a. Perform ? Set(A, "length", 𝔽(k), true).
+                    self.array
+                        .set(StaticJsStrings::LENGTH, self.k, true, context)?;
+
+                    // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                    return Ok(Step::Done);
+                }
+
+                // 8. Let nextValue be ? IteratorValue(nextResult).
+                let next_value = next_result.value(context)?;
+
+                // 9. If mapping is true, then
+                if let Some(mapper) = &self.mapper {
+                    // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                    // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                    let mapped_value =
+                        match mapper.call(&self.this_arg, &[next_value, self.k.into()], context) {
+                            Ok(value) => value,
+                            Err(err) => return self.close(err, context),
+                        };
+
+                    // c. Set mappedValue to Await(mappedValue).
+                    self.awaiting = Awaiting::MappedValue;
+                    return Ok(Step::Await(mapped_value));
+                }
+
+                // 10. Else, let mappedValue be nextValue.
+                self.define(next_value, context)
+            }
+            Awaiting::Value => {
+                // 4. Set kValue to ? Await(kValue).
+                let k_value = completion?;
+
+                // 5. If mapping is true, then
+                if let Some(mapper) = &self.mapper {
+                    // a. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+                    let mapped_value =
+                        mapper.call(&self.this_arg, &[k_value, self.k.into()], context)?;
+
+                    // b. Set mappedValue to ? Await(mappedValue).
+                    self.awaiting = Awaiting::MappedValue;
+                    return Ok(Step::Await(mapped_value));
+                }
+
+                // 6. Else, let mappedValue be kValue.
+                self.define(k_value, context)
+            }
+            Awaiting::MappedValue => match (completion, &self.source) {
+                (Ok(mapped_value), _) => self.define(mapped_value, context),
+                // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                (Err(err), FromAsyncSource::Iterator(_)) => self.close(err, context),
+                (Err(err), FromAsyncSource::ArrayLike { .. }) => Err(err),
+            },
+            // The result of the `return` method is ignored, since the original completion is
+            // a throw completion.
+            Awaiting::Close(ref err) => Err(err.clone()),
+        }
+    }
+
+    /// Defines the value at index `k` of the array, then continues with the next index.
+    fn define(&mut self, mapped_value: JsValue, context: &mut Context) -> JsResult<Step> {
+        // Let defineStatus be Completion(CreateDataPropertyOrThrow(A, Pk, mappedValue)).
+        let define_status = self
+            .array
+            .create_data_property_or_throw(self.k, mapped_value, context);
+
+        if let Err(err) = define_status {
+            match self.source {
+                // If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+                FromAsyncSource::Iterator(_) => return self.close(err, context),
+                FromAsyncSource::ArrayLike { .. } => return Err(err),
+            }
+        }
+
+        // Set k to k + 1.
+        self.k += 1;
+
+        match self.source {
+            FromAsyncSource::Iterator(_) => self.next(context),
+            FromAsyncSource::ArrayLike { .. } => self.next_value(context),
+        }
+    }
+
+    /// Calls the `next` method of the iterator, and awaits its result.
+    fn next(&mut self, context: &mut Context) -> JsResult<Step> {
+        let FromAsyncSource::Iterator(iterator_record) = &self.source else {
+            unreachable!("the source must be an iterator");
+        };
+
+        // 1. If k ≥ 2**53 - 1, then
+        if self.k >= 9_007_199_254_740_991 {
+            // a. Let error be ThrowCompletion(a newly created TypeError object).
+            // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+            let error = JsNativeError::typ()
+                .with_message("Array.fromAsync: array length exceeds the maximum safe integer")
+                .into();
+            return self.close(error, context);
+        }
+
+        // 2. Let Pk be ! ToString(𝔽(k)).
+        // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        let next_result = iterator_record.next_method().call(
+            &iterator_record.iterator().clone().into(),
+            &[],
+            context,
+        )?;
+
+        // 4. Set nextResult to ? Await(nextResult).
+        self.awaiting = Awaiting::NextResult;
+        Ok(Step::Await(next_result))
+    }
+
+    /// Gets the value at index `k` of the array-like object, and awaits it.
+    fn next_value(&mut self, context: &mut Context) -> JsResult<Step> {
+        let FromAsyncSource::ArrayLike { array_like, len } = &self.source else {
+            unreachable!("the source must be an array-like object");
+        };
+
+        // vii. Repeat, while k < len,
+        if self.k >= *len {
+            // viii. Perform ? Set(A, "length", 𝔽(len), true).
+            self.array
+                .set(StaticJsStrings::LENGTH, *len, true, context)?;
+
+            // ix. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+            return Ok(Step::Done);
+        }
+
+        // 1. Let Pk be ! ToString(𝔽(k)).
+        // 2. Let kValue be ? Get(arrayLike, Pk).
+        let k_value = array_like.get(self.k, context)?;
+
+        // 3. Set kValue to ? Await(kValue).
+        self.awaiting = Awaiting::Value;
+        Ok(Step::Await(k_value))
+    }
+
+    /// Abstract operation [`AsyncIteratorClose ( iteratorRecord, completion )`][spec], for a
+    /// throw `completion`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+    fn close(&mut self, error: JsError, context: &mut Context) -> JsResult<Step> {
+        let FromAsyncSource::Iterator(iterator_record) = &self.source else {
+            unreachable!("only iterators can be closed");
+        };
+        let iterator = iterator_record.iterator().clone();
+
+        // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+        // 4. If innerResult.[[Type]] is normal, then
+        //     a. Let return be innerResult.[[Value]].
+        //     b. If return is undefined, return ? completion.
+        //     c. Set innerResult to Completion(Call(return, iterator)).
+        //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+        // 5. If completion.[[Type]] is throw, return ? completion.
+        let inner_result = match iterator.get_method(js_string!("return"), context) {
+            Ok(Some(r#return)) => r#return.call(&iterator.into(), &[], context),
+            Ok(None) => return Err(error),
+            Err(err) => Err(err),
+        };
+        let inner_result = match inner_result {
+            Ok(inner_result) => inner_result,
+            Err(err) if !err.is_catchable() => return Err(err),
+            Err(_) => return Err(error),
+        };
+
+        self.awaiting = Awaiting::Close(error);
+        Ok(Step::Await(inner_result))
+    }
+}
@@ -14,7 +14,11 @@ use boa_profiler::Profiler;
 use thin_vec::ThinVec;
 
 use crate::{
-    builtins::{iterable::if_abrupt_close_iterator, BuiltInObject, Number},
+    builtins::{
+        iterable::{if_abrupt_close_iterator, AsyncFromSyncIterator},
+        promise::PromiseCapability,
+        BuiltInObject, Number,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
@@ -38,7 +42,10 @@ use std::cmp::{min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod from_async;
+
 pub(crate) use array_iterator::ArrayIterator;
+use from_async::{FromAsync, FromAsyncSource};
 #[cfg(test)]
 mod tests;
 
@@ -107,6 +114,7 @@ impl IntrinsicObject for Array {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             // Static Methods
             .static_method(Self::from, js_string!("from"), 1)
+            .static_method(Self::from_async, js_string!("fromAsync"), 1)
             .static_method(Self::is_array, js_string!("isArray"), 1)
             .static_method(Self::of, js_string!("of"), 0)
             .static_accessor(
@@ -659,6 +667,141 @@ impl Array {
         iterator_record.close(error, context)
     }
 
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// The `Array.fromAsync()` static method creates a new, shallow-copied Array instance from
+    /// an async iterable, iterable, or array-like object, and returns a promise for it.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures
+        //    C, mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        let result = Self::from_async_start(this, args, capability.clone(), context);
+        match result {
+            Err(err) if err.is_catchable() => {
+                let err = err.to_opaque(context);
+                capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+                    .expect("default `reject` function cannot throw");
+            }
+            result => result?,
+        }
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise().clone().into())
+    }
+
+    /// The steps of the `fromAsyncClosure` of `Array.fromAsync` until its first `Await`.
+    fn from_async_start(
+        this: &JsValue,
+        args: &[JsValue],
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let async_items = args.get_or_undefined(0);
+        let mapfn = args.get_or_undefined(1);
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapping = match mapfn {
+            JsValue::Undefined => None,
+            JsValue::Object(o) if o.is_callable() => Some(o.clone()),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("`{}` is not callable", mapfn.type_of()))
+                    .into())
+            }
+        };
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let using_async_iterator = async_items.get_method(JsSymbol::async_iterator(), context)?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        // e. Let iteratorRecord be undefined.
+        let iterator_record = if let Some(using_async_iterator) = using_async_iterator {
+            // f. If usingAsyncIterator is not undefined, then
+            //     i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+            Some(async_items.get_iterator_from_method(&using_async_iterator, context)?)
+        } else if let Some(using_sync_iterator) =
+            async_items.get_method(JsSymbol::iterator(), context)?
+        {
+            // g. Else if usingSyncIterator is not undefined, then
+            //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(? GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+            let sync_iterator_record =
+                async_items.get_iterator_from_method(&using_sync_iterator, context)?;
+            Some(AsyncFromSyncIterator::create(sync_iterator_record, context))
+        } else {
+            None
+        };
+
+        // h. If iteratorRecord is not undefined, then
+        let (array, source) = if let Some(iterator_record) = iterator_record {
+            // i. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C).
+            // ii. Else,
+            //     1. Let A be ! ArrayCreate(0).
+            let array = match this.as_constructor() {
+                Some(constructor) => constructor.construct(&[], None, context)?,
+                None => Self::array_create(0, None, context)
+                    .expect("creating an empty array cannot fail"),
+            };
+
+            // iii. Let k be 0.
+            // iv. Repeat,
+            //     ...
+            (array, FromAsyncSource::Iterator(iterator_record))
+        } else {
+            // i. Else,
+            //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+            //     ii. Let arrayLike be ! ToObject(asyncItems).
+            let array_like = async_items
+                .to_object(context)
+                .expect("should not fail according to spec");
+
+            // iii. Let len be ? LengthOfArrayLike(arrayLike).
+            let len = array_like.length_of_array_like(context)?;
+
+            // iv. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C, « 𝔽(len) »).
+            // v. Else,
+            //     1. Let A be ? ArrayCreate(len).
+            let array = match this.as_constructor() {
+                Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+                None => Self::array_create(len, None, context)?,
+            };
+
+            // vi. Let k be 0.
+            // vii. Repeat, while k < len,
+            //     ...
+            (array, FromAsyncSource::ArrayLike { array_like, len })
+        };
+
+        FromAsync::start(capability, array, mapping, this_arg, source, context)
+    }
+
     /// `Array.isArray( arg )`
     ///
     /// The isArray function takes one argument arg, and returns the Boolean value true
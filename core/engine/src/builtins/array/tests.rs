@@ -951,3 +951,67 @@ fn array_sort() {
             "#}),
     ]);
 }
+
+#[test]
+fn array_from_async() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var results = {};
+            function record(name, promise) {
+                promise.then(
+                    (value) => { results[name] = value; },
+                    (error) => { results[name] = error; },
+                );
+            }
+
+            async function* gen() {
+                yield 1;
+                yield Promise.resolve(2);
+                yield 3;
+            }
+
+            record("async", Array.fromAsync(gen()));
+            record("sync", Array.fromAsync([Promise.resolve(4), 5]));
+            record("arrayLike", Array.fromAsync({ length: 2, 0: "a", 1: Promise.resolve("b") }));
+            record("mapped", Array.fromAsync([1, 2], async (x, i) => x * 10 + i));
+            record("notCallable", Array.fromAsync([], 5));
+            record("rejected", Array.fromAsync([Promise.reject("error")]));
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("arrayEquals(results.async, [1, 2, 3])"),
+        TestAction::assert("arrayEquals(results.sync, [4, 5])"),
+        TestAction::assert("arrayEquals(results.arrayLike, ['a', 'b'])"),
+        TestAction::assert("arrayEquals(results.mapped, [10, 21])"),
+        TestAction::assert("results.notCallable instanceof TypeError"),
+        TestAction::assert_eq("results.rejected", js_str!("error")),
+    ]);
+}
+
+#[test]
+fn array_from_async_closes_iterator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var closed = false;
+            var result;
+            var iterable = {
+                [Symbol.asyncIterator]() {
+                    return {
+                        next() {
+                            return Promise.resolve({ value: 1, done: false });
+                        },
+                        return() {
+                            closed = true;
+                            return Promise.resolve({ done: true });
+                        },
+                    };
+                },
+            };
+
+            Array.fromAsync(iterable, () => { throw "mapper"; }).catch((e) => { result = e; });
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("closed"),
+        TestAction::assert_eq("result", js_str!("mapper")),
+    ]);
+}
@@ -31,9 +31,10 @@ use crate::{
     realm::Realm,
     string::StaticJsStrings,
     symbol::JsSymbol,
+    vm::check_heap_allocation,
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
 };
-use boa_gc::{Finalize, GcRef, GcRefMut, Trace};
+use boa_gc::{ExternalMemory, Finalize, GcRef, GcRefMut, Trace};
 use boa_profiler::Profiler;
 
 use self::utils::{SliceRef, SliceRefMut};
@@ -205,17 +206,33 @@ pub struct ArrayBuffer {
 
     /// The `[[ArrayBufferDetachKey]]` internal slot.
     detach_key: JsValue,
+
+    /// The size of the data, reported to the garbage collector to count it towards the heap
+    /// limit.
+    memory: ExternalMemory,
 }
 
 impl ArrayBuffer {
-    pub(crate) fn from_data(data: Vec<u8>, detach_key: JsValue) -> Self {
+    /// Creates a new `ArrayBuffer` from its internal slots.
+    fn new(data: Option<Vec<u8>>, max_byte_len: Option<u64>, detach_key: JsValue) -> Self {
+        let memory = ExternalMemory::new(data.as_ref().map_or(0, Vec::capacity));
         Self {
-            data: Some(data),
-            max_byte_len: None,
+            data,
+            max_byte_len,
             detach_key,
+            memory,
         }
     }
 
+    pub(crate) fn from_data(data: Vec<u8>, detach_key: JsValue) -> Self {
+        Self::new(Some(data), None, detach_key)
+    }
+
+    /// Reports the current size of the data to the garbage collector.
+    fn report_memory(&mut self) {
+        self.memory.set(self.data.as_ref().map_or(0, Vec::capacity));
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.data.as_ref().map_or(0, Vec::len)
     }
@@ -265,7 +282,9 @@ impl ArrayBuffer {
                 .into());
         }
 
-        Ok(self.data.take())
+        let data = self.data.take();
+        self.report_memory();
+        Ok(data)
     }
 
     /// `IsDetachedBuffer ( arrayBuffer )`
@@ -580,8 +599,9 @@ impl ArrayBuffer {
         let new_byte_length = args.get_or_undefined(0).to_index(context)?;
 
         let mut buf = buf.borrow_mut();
+        let buf = &mut buf.data;
         // 5. If IsDetachedBuffer(O) is true, throw a TypeError exception.
-        let Some(buf) = buf.data.vec_mut() else {
+        let Some(bytes) = buf.vec_mut() else {
             return Err(JsNativeError::typ()
                 .with_message("ArrayBuffer.resize: cannot resize a detached buffer")
                 .into());
@@ -609,7 +629,8 @@ impl ArrayBuffer {
         //     Implementations may implement this method as in-place growth or shrinkage.
         // 14. Set O.[[ArrayBufferData]] to newBlock.
         // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
-        buf.resize(new_byte_length as usize, 0);
+        bytes.resize(new_byte_length as usize, 0);
+        buf.report_memory();
 
         // 16. Return undefined.
         Ok(JsValue::undefined())
@@ -813,6 +834,8 @@ impl ArrayBuffer {
             bytes.shrink_to_fit();
         }
 
+        buf.borrow_mut().data.report_memory();
+
         let prototype = context
             .intrinsics()
             .constructors()
@@ -822,11 +845,7 @@ impl ArrayBuffer {
         Ok(JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ArrayBuffer {
-                data: Some(bytes),
-                max_byte_len: new_max_len,
-                detach_key: JsValue::undefined(),
-            },
+            ArrayBuffer::new(Some(bytes), new_max_len, JsValue::undefined()),
         )
         .into())
     }
@@ -873,15 +892,11 @@ impl ArrayBuffer {
         let obj = JsObject::new(
             context.root_shape(),
             prototype,
-            Self {
-                // 6. Set obj.[[ArrayBufferData]] to block.
-                // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
-                data: Some(block),
-                // 8. If allocatingResizableBuffer is true, then
-                //    c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
-                max_byte_len,
-                detach_key: JsValue::Undefined,
-            },
+            // 6. Set obj.[[ArrayBufferData]] to block.
+            // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
+            // 8. If allocatingResizableBuffer is true, then
+            //    c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+            Self::new(Some(block), max_byte_len, JsValue::Undefined),
         );
 
         // 9. Return obj.
@@ -937,6 +952,10 @@ pub(crate) fn create_byte_data_block(
         JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
+    // The data block counts towards the heap limit, so a block that is too big throws before
+    // being allocated.
+    check_heap_allocation(alloc_size)?;
+
     let mut data_block = Vec::new();
     data_block.try_reserve_exact(alloc_size).map_err(|e| {
         JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
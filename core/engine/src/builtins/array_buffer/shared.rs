@@ -8,7 +8,7 @@ use std::{
 use boa_profiler::Profiler;
 use portable_atomic::{AtomicU8, AtomicUsize};
 
-use boa_gc::{Finalize, Trace};
+use boa_gc::{ExternalMemory, Finalize, Trace};
 use sptr::Strict;
 
 use crate::{
@@ -19,6 +19,7 @@ use crate::{
     property::Attribute,
     realm::Realm,
     string::StaticJsStrings,
+    vm::check_heap_allocation,
     Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
 };
 
@@ -28,11 +29,26 @@ use super::{get_max_byte_len, utils::copy_shared_to_shared};
 ///
 /// This struct implements `Send` and `Sync`, meaning it can be shared between threads
 /// running different JS code at the same time.
-#[derive(Debug, Clone, Trace, Finalize, JsData)]
+#[derive(Debug, Trace, Finalize, JsData)]
 pub struct SharedArrayBuffer {
     // Shared buffers cannot be detached.
     #[unsafe_ignore_trace]
     data: Arc<Inner>,
+
+    /// The size of the buffer, reported to the garbage collector of the thread owning the
+    /// `SharedArrayBuffer` object to count it towards the heap limit.
+    memory: ExternalMemory,
+}
+
+impl Clone for SharedArrayBuffer {
+    /// Clones can be sent to other threads, so they don't report the size of the buffer until
+    /// they are owned by an object.
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            memory: ExternalMemory::default(),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -55,9 +71,16 @@ impl SharedArrayBuffer {
     pub fn empty() -> Self {
         Self {
             data: Arc::default(),
+            memory: ExternalMemory::default(),
         }
     }
 
+    /// Reports the size of the buffer to the garbage collector of the current thread, before
+    /// storing it in an object.
+    pub(crate) fn report_memory(&mut self) {
+        self.memory.set(self.data.buffer.len());
+    }
+
     /// Gets the length of this `SharedArrayBuffer`.
     pub(crate) fn len(&self, ordering: Ordering) -> usize {
         self.data
@@ -531,6 +554,7 @@ impl SharedArrayBuffer {
             context.root_shape(),
             prototype,
             Self {
+                memory: ExternalMemory::new(block.len()),
                 data: Arc::new(Inner {
                     buffer: block,
                     current_len,
@@ -573,6 +597,10 @@ pub(crate) fn create_shared_byte_data_block(
         return Ok(Box::default());
     }
 
+    // The data block counts towards the heap limit, so a block that is too big throws before
+    // being allocated.
+    check_heap_allocation(size)?;
+
     // 2. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
     // 3. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose
     //    [[AgentSignifier]] is AgentSignifier().
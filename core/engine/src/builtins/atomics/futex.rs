@@ -133,13 +133,21 @@
 // In a future point in time, "Thread 1" will be notified, which will proceed with the
 // exact same steps as "Thread 2", emptying the wait queue and finishing the execution of our
 // program.
+//
+// `Atomics.waitAsync` reuses the same wait queues, but instead of sleeping on `cond_var` the
+// waiter is heap allocated and owned by an `AsyncWaiter` future. The future stores its `Waker`
+// inside the `FutexWaiter`, which `notify_many` wakes after setting `waiting` to false. Waiters
+// with a finite timeout additionally register their `Waker` with a timer thread shared by all
+// waiters, which wakes the future after the timeout elapses, at which point the future removes
+// itself from the wait queue. The timer is cancelled when the waiter is dropped. On WebAssembly
+// there is no timer thread, so the deadline is only checked whenever the future is polled.
 
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(clippy::undocumented_unsafe_blocks)]
 #![allow(clippy::expl_impl_clone_on_copy)]
 #![allow(unstable_name_collisions)]
 
-use std::{cell::UnsafeCell, sync::atomic::Ordering};
+use std::{cell::UnsafeCell, future::Future, pin::Pin, sync::atomic::Ordering, task::Poll};
 
 use sptr::Strict;
 
@@ -153,7 +161,10 @@ use crate::{
 };
 
 mod sync {
-    use std::sync::{Condvar, Mutex, MutexGuard};
+    use std::{
+        sync::{Condvar, Mutex, MutexGuard},
+        task::Waker,
+    };
 
     use intrusive_collections::{intrusive_adapter, LinkedList, LinkedListLink, UnsafeRef};
 
@@ -167,6 +178,7 @@ mod sync {
     pub(crate) struct FutexWaiter {
         pub(super) link: LinkedListLink,
         pub(super) cond_var: Condvar,
+        pub(super) waker: Option<Waker>,
         pub(super) waiting: bool,
         addr: usize,
     }
@@ -220,7 +232,11 @@ mod sync {
 
                 // SAFETY: all elements of the waiters list are guaranteed to be valid.
                 unsafe {
-                    (*UnsafeRef::into_raw(elem)).waiting = false;
+                    let elem = UnsafeRef::into_raw(elem);
+                    (*elem).waiting = false;
+                    if let Some(waker) = (*elem).waker.take() {
+                        waker.wake();
+                    }
                 }
             }
 
@@ -276,6 +292,124 @@ mod sync {
     }
 }
 
+/// The timer waking the async waiters with a finite timeout.
+///
+/// All the timers are handled by a single thread, which is only started once a timer is
+/// registered.
+#[cfg(not(target_family = "wasm"))]
+mod timer {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Condvar, Mutex, MutexGuard, OnceLock, PoisonError,
+        },
+        task::Waker,
+    };
+
+    use crate::sys::time::Instant;
+
+    /// Identifies a registered timer.
+    pub(super) type TimerId = (Instant, u64);
+
+    /// The registered timers, ordered by deadline.
+    static TIMERS: Mutex<BTreeMap<TimerId, Waker>> = Mutex::new(BTreeMap::new());
+
+    /// Notifies the timer thread that the timers changed.
+    static TIMERS_CHANGED: Condvar = Condvar::new();
+
+    fn lock_timers() -> MutexGuard<'static, BTreeMap<TimerId, Waker>> {
+        // The timers are always left in a valid state, so a poisoned lock can still be used.
+        TIMERS.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Starts the timer thread if it isn't running, returning `false` if it cannot be started.
+    fn start_thread() -> bool {
+        static STARTED: OnceLock<bool> = OnceLock::new();
+
+        *STARTED.get_or_init(|| {
+            std::thread::Builder::new()
+                .name("boa-atomics-timer".to_string())
+                .spawn(run)
+                .is_ok()
+        })
+    }
+
+    /// Wakes the timers as their deadlines pass.
+    fn run() {
+        let mut timers = lock_timers();
+        loop {
+            let Some((&(deadline, id), _)) = timers.first_key_value() else {
+                timers = TIMERS_CHANGED
+                    .wait(timers)
+                    .unwrap_or_else(PoisonError::into_inner);
+                continue;
+            };
+
+            let now = Instant::now();
+            if now < deadline {
+                timers = TIMERS_CHANGED
+                    .wait_timeout(timers, deadline - now)
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0;
+                continue;
+            }
+
+            let waker = timers.remove(&(deadline, id));
+            drop(timers);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+            timers = lock_timers();
+        }
+    }
+
+    /// Registers a timer that wakes `waker` at `deadline`, returning `None` if timers are not
+    /// available.
+    pub(super) fn register(deadline: Instant, waker: Waker) -> Option<TimerId> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        if !start_thread() {
+            return None;
+        }
+
+        let id = (deadline, NEXT_ID.fetch_add(1, Ordering::Relaxed));
+        lock_timers().insert(id, waker);
+        TIMERS_CHANGED.notify_one();
+        Some(id)
+    }
+
+    /// Cancels a timer registered with [`register`].
+    pub(super) fn cancel(id: TimerId) {
+        lock_timers().remove(&id);
+    }
+}
+
+/// The timer waking the async waiters with a finite timeout.
+///
+/// Threads are not available on WebAssembly, so finite timeouts are not supported there: a waiter
+/// only observes its deadline when the embedder's event loop polls it again, and nothing wakes it
+/// once the deadline passes.
+#[cfg(target_family = "wasm")]
+mod timer {
+    use std::task::Waker;
+
+    use crate::sys::time::Instant;
+
+    /// Identifies a registered timer.
+    pub(super) type TimerId = (Instant, u64);
+
+    /// Registers a timer that wakes `waker` at `deadline`, returning `None` if timers are not
+    /// available.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(super) fn register(_deadline: Instant, _waker: Waker) -> Option<TimerId> {
+        None
+    }
+
+    /// Cancels a timer registered with [`register`].
+    pub(super) fn cancel(_id: TimerId) {}
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) enum AtomicsWaitResult {
     NotEqual,
@@ -395,6 +529,158 @@ pub(super) unsafe fn wait<E: Element + PartialEq>(
     Ok(result)
 }
 
+/// The result of registering an async waiter with [`wait_async`].
+pub(super) enum AsyncWaitResult {
+    /// The waiter finished without being added to the wait queue.
+    Sync(AtomicsWaitResult),
+    /// The waiter was added to the wait queue, and will finish after polling the returned future.
+    Async(AsyncWaiter),
+}
+
+/// A waiter registered by `Atomics.waitAsync`.
+///
+/// Resolves to [`AtomicsWaitResult::Ok`] when notified, or to [`AtomicsWaitResult::TimedOut`]
+/// after its timeout elapses. Dropping the waiter removes it from its wait queue.
+pub(super) struct AsyncWaiter {
+    node: *mut sync::FutexWaiter,
+    deadline: Option<Instant>,
+    timer: Option<timer::TimerId>,
+    // Keeps the address of the waiter alive until the waiter is removed.
+    _buffer: SharedArrayBuffer,
+}
+
+impl Future for AsyncWaiter {
+    type Output = JsResult<AtomicsWaitResult>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut waiters = sync::FutexWaiters::get()?;
+
+        // The timer is registered again with the current waker if the waiter is still pending.
+        if let Some(timer) = this.timer.take() {
+            timer::cancel(timer);
+        }
+
+        // SAFETY: `node` is valid until `this` is dropped, and can only be accessed while holding
+        // the critical section.
+        unsafe {
+            if !(*this.node).waiting {
+                return Poll::Ready(Ok(AtomicsWaitResult::Ok));
+            }
+
+            if let Some(deadline) = this.deadline {
+                if Instant::now() >= deadline {
+                    waiters.remove_waiter(this.node);
+                    (*this.node).waiting = false;
+                    return Poll::Ready(Ok(AtomicsWaitResult::TimedOut));
+                }
+
+                // Without a timer, the deadline is only checked when the embedder polls the
+                // waiter again.
+                this.timer = timer::register(deadline, cx.waker().clone());
+            }
+
+            (*this.node).waker = Some(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for AsyncWaiter {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer::cancel(timer);
+        }
+
+        // If the critical section is poisoned no other agent can access the waiter anymore.
+        if let Ok(mut waiters) = sync::FutexWaiters::get() {
+            // SAFETY: `node` is valid and contained in its waiter list if `waiting == true`.
+            unsafe {
+                if (*self.node).waiting {
+                    waiters.remove_waiter(self.node);
+                }
+            }
+        }
+
+        // SAFETY: `node` was created by `Box::into_raw` in `wait_async`, and it is not linked to
+        // any waiter list anymore.
+        drop(unsafe { Box::from_raw(self.node) });
+    }
+}
+
+/// Adds an async waiter to the wait queue for the address pointed to by `buffer[offset..]`.
+///
+/// # Safety
+///
+/// - `addr` must be a multiple of `std::mem::size_of::<E>()`.
+/// - `buffer` must contain at least `std::mem::size_of::<E>()` bytes to read starting from `usize`.
+pub(super) unsafe fn wait_async<E: Element + PartialEq>(
+    buffer: &SharedArrayBuffer,
+    buf_len: usize,
+    offset: usize,
+    check: E,
+    timeout: Option<Duration>,
+) -> JsResult<AsyncWaitResult> {
+    // 17. Perform EnterCriticalSection(WL).
+    let mut waiters = sync::FutexWaiters::get()?;
+
+    let bytes = &buffer.bytes_with_len(buf_len)[offset..];
+
+    // 18. Let elementType be TypedArrayElementType(typedArray).
+    // 19. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, seq-cst).
+
+    // SAFETY: The safety of this operation is guaranteed by the caller.
+    let value = unsafe { E::read(SliceRef::AtomicSlice(bytes)).load(Ordering::SeqCst) };
+
+    // 20. If v ≠ w, then
+    //     a. Perform LeaveCriticalSection(WL).
+    //     b. If mode is sync, return "not-equal".
+    //     c. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+    //     d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "not-equal").
+    //     e. Return resultObject.
+    if check != value {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::NotEqual));
+    }
+
+    // 21. If t = 0 and mode is async, then
+    //     a. NOTE: There is no special handling of synchronous immediate timeouts. Asynchronous
+    //        immediate timeouts have special handling in order to fail fast and avoid
+    //        unnecessary Promise jobs.
+    //     b. Perform LeaveCriticalSection(WL).
+    //     c. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+    //     d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "timed-out").
+    //     e. Return resultObject.
+    if timeout.is_some_and(|t| t.is_zero()) {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::TimedOut));
+    }
+
+    // 22. Let thisAgent be AgentSignifier().
+    // 23. Let now be the time value (UTC) identifying the current time.
+    // 24. Let additionalTimeout be an implementation-defined non-negative mathematical value.
+    // 25. Let timeoutTime be ℝ(now) + t + additionalTimeout.
+    // 26. NOTE: When t is +∞, timeoutTime is also +∞.
+    // 27. Let waiterRecord be a new Waiter Record { [[AgentSignifier]]: thisAgent, [[PromiseCapability]]: promiseCapability, [[TimeoutTime]]: timeoutTime, [[Result]]: "ok" }.
+    let node = Box::into_raw(Box::default());
+
+    // 28. Perform AddWaiter(WL, waiterRecord).
+    // SAFETY: `node` is valid and owned by the returned `AsyncWaiter`, which removes it from
+    // its list when dropped.
+    unsafe {
+        waiters.add_waiter(node, bytes.as_ptr().addr());
+    }
+
+    // 31. Perform LeaveCriticalSection(WL).
+    drop(waiters);
+
+    Ok(AsyncWaitResult::Async(AsyncWaiter {
+        node,
+        deadline: timeout.map(|timeout| Instant::now() + timeout),
+        timer: None,
+        _buffer: buffer.clone(),
+    }))
+}
+
 /// Notifies at most `count` agents waiting on the memory address pointed to by `buffer[offset..]`.
 pub(super) fn notify(buffer: &SharedArrayBuffer, offset: usize, count: u64) -> JsResult<u64> {
     let addr = buffer.as_ptr().addr() + offset;
@@ -0,0 +1,148 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use indoc::indoc;
+
+use crate::{
+    context::ContextBuilder,
+    job::{FutureJob, JobQueue, NativeJob},
+    js_str, run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, JsValue, Source,
+    TestAction,
+};
+
+/// A job queue that only polls its future jobs when running the queue.
+#[derive(Default)]
+struct DeferredQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: RefCell<Vec<FutureJob>>,
+}
+
+impl JobQueue for DeferredQueue {
+    fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
+        self.jobs.borrow_mut().push_back(job);
+    }
+
+    fn run_jobs(&self, context: &mut Context) {
+        for future in std::mem::take(&mut *self.futures.borrow_mut()) {
+            let job = futures_lite::future::block_on(future);
+            self.jobs.borrow_mut().push_back(job);
+        }
+
+        loop {
+            let Some(job) = self.jobs.borrow_mut().pop_front() else {
+                return;
+            };
+            job.call(context).expect("job should not fail");
+        }
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.futures.borrow_mut().push(future);
+    }
+}
+
+#[test]
+fn wait_async_sync_results() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ia = new Int32Array(new SharedArrayBuffer(8));
+            var notEqual = Atomics.waitAsync(ia, 0, 1);
+            var timedOut = Atomics.waitAsync(ia, 0, 0, 0);
+        "#}),
+        TestAction::assert_eq("notEqual.async", false),
+        TestAction::assert_eq("notEqual.value", js_str!("not-equal")),
+        TestAction::assert_eq("timedOut.async", false),
+        TestAction::assert_eq("timedOut.value", js_str!("timed-out")),
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Int32Array(4), 0, 0)",
+            JsNativeErrorKind::Type,
+            "cannot use `ArrayBuffer` for an atomic wait",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Uint32Array(new SharedArrayBuffer(8)), 0, 0)",
+            JsNativeErrorKind::Type,
+            "can only atomically wait using Int32 or BigInt64 arrays",
+        ),
+    ]);
+}
+
+#[test]
+fn wait_async_notify_and_timeout() {
+    let context = &mut ContextBuilder::new()
+        .job_queue(Rc::new(DeferredQueue::default()))
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var ia = new BigInt64Array(new SharedArrayBuffer(16));
+                var notified, timedOut;
+
+                var first = Atomics.waitAsync(ia, 0, 0n);
+                first.value.then(v => notified = v);
+                Atomics.waitAsync(ia, 1, 0n, 10).value.then(v => timedOut = v);
+
+                var count = Atomics.notify(ia, 0);
+            "#}),
+            TestAction::assert_eq("first.async", true),
+            TestAction::assert("first.value instanceof Promise"),
+            TestAction::assert_eq("count", 1),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert_eq("notified", js_str!("ok")),
+            TestAction::assert_eq("timedOut", js_str!("timed-out")),
+            TestAction::assert_eq("Atomics.notify(ia, 1)", 0),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn wait_async_notified_by_another_waiter() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ia = new Int32Array(new SharedArrayBuffer(8));
+            var notified, timedOut;
+
+            Atomics.waitAsync(ia, 0, 0).value.then(v => notified = v);
+            Atomics.waitAsync(ia, 1, 0, 10).value.then(v => {
+                timedOut = v;
+                Atomics.notify(ia, 0);
+            });
+        "#}),
+        TestAction::inspect_context(run_jobs_until_notified),
+        TestAction::assert_eq("timedOut", js_str!("timed-out")),
+        TestAction::assert_eq("notified", js_str!("ok")),
+    ]);
+}
+
+/// Runs the jobs until `notified` is set, since the default queue never blocks on its futures.
+fn run_jobs_until_notified(context: &mut Context) {
+    for _ in 0..1000 {
+        context.run_jobs();
+        if context
+            .eval(Source::from_bytes("notified !== undefined"))
+            .expect("should evaluate")
+            .to_boolean()
+        {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    panic!("the waiter was never notified");
+}
+
+#[test]
+fn wait_async_without_timeout_does_not_block() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ia = new Int32Array(new SharedArrayBuffer(8));
+            var notified;
+            Atomics.waitAsync(ia, 0, 0).value.then(v => notified = v);
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("notified", JsValue::undefined()),
+        TestAction::assert_eq("Atomics.notify(ia, 0)", 1),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("notified", js_str!("ok")),
+    ]);
+}
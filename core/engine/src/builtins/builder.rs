@@ -387,6 +387,7 @@ impl BuiltInConstructorWithPrototype<'_> {
                 &mut prototype.properties_mut().storage,
                 self.prototype_storage,
             );
+            prototype.properties_mut().report_heap_size();
 
             debug_assert_eq!(prototype_old_storage.len(), 0);
         }
@@ -407,6 +408,7 @@ impl BuiltInConstructorWithPrototype<'_> {
 
         let object_old_storage =
             std::mem::replace(&mut object.properties_mut().storage, self.object_storage);
+        object.properties_mut().report_heap_size();
 
         debug_assert_eq!(object_old_storage.len(), 0);
     }
@@ -433,6 +435,7 @@ impl BuiltInConstructorWithPrototype<'_> {
 
         let object_old_storage =
             std::mem::replace(&mut object.properties_mut().storage, self.object_storage);
+        object.properties_mut().report_heap_size();
 
         debug_assert_eq!(object_old_storage.len(), 0);
     }
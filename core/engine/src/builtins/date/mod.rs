@@ -30,7 +30,7 @@ use crate::{
     string::StaticJsStrings,
     symbol::JsSymbol,
     value::{JsValue, PreferredType},
-    Context, JsArgs, JsData, JsError, JsResult, JsString,
+    Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
 use boa_macros::js_str;
@@ -1497,13 +1497,43 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
     pub(crate) fn to_locale_date_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                format_date_time_locale, FormatDefaults, FormatType,
+            };
+
+            // 1. Let dateObject be the this value.
+            // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+            // 3. Let x be dateObject.[[DateValue]].
+            let x = this
+                .as_object()
+                .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+                .0;
+
+            // 4. If x is NaN, return "Invalid Date".
+            // 5. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, date, date).
+            // 6. Return ! FormatDateTime(dateFormat, x).
+            format_date_time_locale(
+                x,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Date,
+                FormatDefaults::Date,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_date_string(this, &[], context)
+        }
     }
 
     /// [`Date.prototype.toLocaleString()`][spec].
@@ -1516,19 +1546,49 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
     pub(crate) fn to_locale_string(
-        _this: &JsValue,
-        _: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                format_date_time_locale, FormatDefaults, FormatType,
+            };
+
+            // 1. Let dateObject be the this value.
+            // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+            // 3. Let x be dateObject.[[DateValue]].
+            let x = this
+                .as_object()
+                .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+                .0;
+
+            // 4. If x is NaN, return "Invalid Date".
+            // 5. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, any, all).
+            // 6. Return ! FormatDateTime(dateFormat, x).
+            format_date_time_locale(
+                x,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Any,
+                FormatDefaults::All,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_string(this, &[], context)
+        }
     }
 
     /// [`Date.prototype.toLocaleTimeString()`][spec].
     ///
-    /// The `toLocaleTimeString()` method returns the time portion of a Date object in human readable
-    /// form in American English.
+    /// The `toLocaleTimeString()` method returns the time portion of a Date object according to
+    /// language-specific conventions.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
@@ -1536,13 +1596,43 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
     pub(crate) fn to_locale_time_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                format_date_time_locale, FormatDefaults, FormatType,
+            };
+
+            // 1. Let dateObject be the this value.
+            // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+            // 3. Let x be dateObject.[[DateValue]].
+            let x = this
+                .as_object()
+                .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+                .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+                .0;
+
+            // 4. If x is NaN, return "Invalid Date".
+            // 5. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, time, time).
+            // 6. Return ! FormatDateTime(dateFormat, x).
+            format_date_time_locale(
+                x,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Time,
+                FormatDefaults::Time,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_time_string(this, &[], context)
+        }
     }
 
     /// [`Date.prototype.toString()`][spec].
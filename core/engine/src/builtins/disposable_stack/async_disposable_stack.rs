@@ -0,0 +1,481 @@
+//! Boa's implementation of ECMAScript's `AsyncDisposableStack` builtin object.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        promise::{Promise, PromiseCapability},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{adopt_closure, DisposeCapability, DisposeHint};
+
+/// Boa's implementation of ECMAScript's `AsyncDisposableStack` builtin object.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    /// `[[AsyncDisposableState]]`, `true` if the stack was disposed.
+    disposed: bool,
+
+    /// `[[DisposeCapability]]`
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async_function = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// [`AsyncDisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncDisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                disposed: false,
+                capability: DisposeCapability::default(),
+            },
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Gets the `AsyncDisposableStack` object of `this`, throwing if it was already disposed.
+    fn this_pending_stack<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let Some(object) = this
+            .as_object()
+            .filter(|object| object.downcast_ref::<Self>().is_some())
+        else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method} called with invalid `this`"
+                ))
+                .into());
+        };
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        if object
+            .downcast_ref::<Self>()
+            .is_some_and(|stack| stack.disposed)
+        {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method} called on a disposed stack"
+                ))
+                .into());
+        }
+
+        Ok(object)
+    }
+
+    /// Adds a resource to the `[[DisposeCapability]]` of a pending `AsyncDisposableStack` object.
+    fn add_resource(
+        stack: &JsObject,
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // Take the capability out of the object while validating the resource, since that may
+        // call user code.
+        let mut capability = std::mem::take(
+            &mut stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack object")
+                .capability,
+        );
+        let result = capability.add_resource(value, hint, method, context);
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack object")
+            .capability = capability;
+        result
+    }
+
+    /// [`AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let Some(on_dispose) = args.get_or_undefined(1).as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "AsyncDisposableStack.prototype.adopt: onDisposeAsync is not callable",
+                )
+                .into());
+        };
+
+        // 5-6. Let F be a new Abstract Closure that calls onDisposeAsync with value.
+        let f = adopt_closure(value.clone(), on_dispose.clone(), context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        Self::add_resource(
+            stack,
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(f),
+            context,
+        )?;
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`AsyncDisposableStack.prototype.defer ( onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let Some(on_dispose) = args.get_or_undefined(0).as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "AsyncDisposableStack.prototype.defer: onDisposeAsync is not callable",
+                )
+                .into());
+        };
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        Self::add_resource(
+            stack,
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(on_dispose.clone()),
+            context,
+        )?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`AsyncDisposableStack.prototype.disposeAsync ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let Some(mut stack) = this.as_object().and_then(JsObject::downcast_mut::<Self>) else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = JsNativeError::typ()
+                .with_message(
+                    "AsyncDisposableStack.prototype.disposeAsync called with invalid `this`",
+                )
+                .to_opaque(context);
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[error.into()], context)
+                .expect("cannot fail per the spec");
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+        if stack.disposed {
+            drop(stack);
+
+            // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            promise_capability
+                .resolve()
+                .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                .expect("cannot fail per the spec");
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        }
+
+        // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        stack.disposed = true;
+        let capability = std::mem::take(&mut stack.capability);
+        drop(stack);
+
+        // 6. Let result be Completion(DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined))).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        continue_dispose(capability, &promise_capability, context)?;
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+
+    /// [`get AsyncDisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let Some(stack) = this.as_object().and_then(JsObject::downcast_ref::<Self>) else {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "get AsyncDisposableStack.prototype.disposed called with invalid `this`",
+                )
+                .into());
+        };
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.disposed.into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "move")?;
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .async_disposable_stack()
+            .prototype();
+
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack object");
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                disposed: false,
+                capability,
+            },
+        )
+        .into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0);
+        Self::add_resource(stack, value.clone(), DisposeHint::Async, None, context)?;
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+}
+
+/// Drives an asynchronous `DisposeResources` operation, settling `promise_capability` with its
+/// completion once every resource has been disposed.
+///
+/// Each value that must be awaited is resolved to a promise, and the disposal continues from
+/// the reactions of that promise.
+fn continue_dispose(
+    mut capability: DisposeCapability,
+    promise_capability: &PromiseCapability,
+    context: &mut Context,
+) -> JsResult<()> {
+    while let Some(value) = capability.dispose_step(context)? {
+        // Await(value)
+        let promise = match Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        ) {
+            Ok(promise) => promise,
+            Err(err) if err.is_catchable() => {
+                capability.record_error(err, context);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let state = Gc::new(GcRefCell::new(Some(capability)));
+
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, (state, promise_capability), context| {
+                    let capability = state
+                        .borrow_mut()
+                        .take()
+                        .expect("the disposal can only be resumed once");
+                    continue_dispose(capability, promise_capability, context)?;
+                    Ok(JsValue::undefined())
+                },
+                (state.clone(), promise_capability.clone()),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        let on_rejected = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, (state, promise_capability), context| {
+                    let mut capability = state
+                        .borrow_mut()
+                        .take()
+                        .expect("the disposal can only be resumed once");
+                    capability.record_error(
+                        JsError::from_opaque(args.get_or_undefined(0).clone()),
+                        context,
+                    );
+                    continue_dispose(capability, promise_capability, context)?;
+                    Ok(JsValue::undefined())
+                },
+                (state, promise_capability.clone()),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        Promise::perform_promise_then(
+            &promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            None,
+            context,
+        );
+
+        return Ok(());
+    }
+
+    match capability.finish() {
+        Ok(()) => promise_capability.resolve().call(
+            &JsValue::undefined(),
+            &[JsValue::undefined()],
+            context,
+        )?,
+        Err(err) => {
+            let err = err.to_opaque(context);
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[err], context)?
+        }
+    };
+
+    Ok(())
+}
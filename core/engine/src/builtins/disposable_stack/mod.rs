@@ -0,0 +1,689 @@
+//! Boa's implementation of ECMAScript's `DisposableStack` builtin object, and of the disposable
+//! resource records shared by `using` declarations and the disposable stacks.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        error::SuppressedError,
+        promise::{if_abrupt_reject_promise, PromiseCapability},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+mod async_disposable_stack;
+
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+
+#[cfg(test)]
+mod tests;
+
+/// The hint of a disposable resource, which selects the method used to dispose it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    /// The resource is disposed by its `[Symbol.dispose]` method.
+    Sync,
+    /// The resource is disposed by its `[Symbol.asyncDispose]` method, whose result is awaited.
+    Async,
+}
+
+/// A `DisposableResource` Record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct DisposableResource {
+    /// `[[ResourceValue]]`
+    value: JsValue,
+    /// `[[Hint]]`
+    #[unsafe_ignore_trace]
+    hint: DisposeHint,
+    /// `[[DisposeMethod]]`
+    method: Option<JsObject>,
+}
+
+/// A `DisposeCapability` Record, along with the state of an ongoing `DisposeResources` operation.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    /// `[[DisposableResourceStack]]`
+    stack: Vec<DisposableResource>,
+
+    /// The error of the completion the resources are being disposed with.
+    error: Option<JsError>,
+
+    /// `needsAwait` of `DisposeResources`.
+    needs_await: bool,
+
+    /// `hasAwaited` of `DisposeResources`.
+    has_awaited: bool,
+}
+
+impl DisposeCapability {
+    /// Abstract operation [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn add_resource(
+        &mut self,
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let resource = if let Some(method) = method {
+            // 2. Else,
+            //     a. Assert: V is undefined.
+            //     b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+            DisposableResource {
+                value,
+                hint,
+                method: Some(method),
+            }
+        } else {
+            // 1. If method is not present, then
+            //     a. If V is either null or undefined and hint is sync-dispose, return unused.
+            if value.is_null_or_undefined() && hint == DisposeHint::Sync {
+                return Ok(());
+            }
+
+            //     b. NOTE: When V is either null or undefined and hint is async-dispose, we record
+            //        that the resource was evaluated to ensure we will still perform an Await when
+            //        resources are later disposed.
+            //     c. Let resource be ? CreateDisposableResource(V, hint).
+            create_disposable_resource(value, hint, context)?
+        };
+
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        self.stack.push(resource);
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// Records an abrupt completion that happened while disposing the resources, suppressing the
+    /// error of the current completion if there is one.
+    ///
+    /// This implements the steps 2.c.iii of [`DisposeResources`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn record_error(&mut self, error: JsError, context: &mut Context) {
+        self.error = Some(match self.error.take() {
+            // 1. If completion is a throw completion, then
+            Some(suppressed) => {
+                // a. Set result to result.[[Value]].
+                // b. Let suppressed be completion.[[Value]].
+                // c. Let error be a newly created SuppressedError object.
+                // d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+                // e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+                // f. Set completion to ThrowCompletion(error).
+                let error = error.to_opaque(context);
+                let suppressed = suppressed.to_opaque(context);
+                JsError::from_opaque(SuppressedError::create(error, suppressed, context).into())
+            }
+            // 2. Else,
+            //     a. Set completion to result.
+            None => error,
+        });
+    }
+
+    /// Runs [`DisposeResources ( disposeCapability, completion )`][spec] until a value must be
+    /// awaited, returning that value.
+    ///
+    /// After awaiting the returned value, its result must be reported with [`Self::record_error`]
+    /// if it was a rejection, and this must be called again. Once this returns `None`, all
+    /// resources were disposed and [`Self::finish`] returns the resulting completion.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_step(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // 2. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        while let Some(resource) = self.stack.pop() {
+            // a. Let value be resource.[[ResourceValue]].
+            // b. Let hint be resource.[[Hint]].
+            // c. Let method be resource.[[DisposeMethod]].
+            // d. If hint is sync-dispose and needsAwait is true and hasAwaited is false, then
+            if resource.hint == DisposeHint::Sync && self.needs_await && !self.has_awaited {
+                // i. Perform ! Await(undefined).
+                // ii. Set needsAwait to false.
+                self.stack.push(resource);
+                self.needs_await = false;
+                return Ok(Some(JsValue::undefined()));
+            }
+
+            // e. If method is not undefined, then
+            if let Some(method) = &resource.method {
+                // i. Let result be Completion(Call(method, value)).
+                match method.call(&resource.value, &[], context) {
+                    // ii. If result is a normal completion and hint is async-dispose, then
+                    Ok(result) if resource.hint == DisposeHint::Async => {
+                        // 1. Set result to Completion(Await(result)).
+                        // 2. Set hasAwaited to true.
+                        self.has_awaited = true;
+                        return Ok(Some(result));
+                    }
+                    Ok(_) => {}
+                    // iii. If result is a throw completion, then
+                    Err(err) if err.is_catchable() => self.record_error(err, context),
+                    Err(err) => return Err(err),
+                }
+            } else {
+                // f. Else,
+                //     i. Assert: hint is async-dispose.
+                debug_assert_eq!(resource.hint, DisposeHint::Async);
+                //     ii. Set needsAwait to true.
+                //     iii. NOTE: This can only indicate a case where either null or undefined was
+                //          the initialized value of an await using declaration.
+                self.needs_await = true;
+            }
+        }
+
+        // 3. If needsAwait is true and hasAwaited is false, then
+        if self.needs_await && !self.has_awaited {
+            // a. Perform ! Await(undefined).
+            self.needs_await = false;
+            return Ok(Some(JsValue::undefined()));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the completion of a finished `DisposeResources` operation.
+    pub(crate) fn finish(mut self) -> JsResult<()> {
+        // 4. NOTE: After disposeCapability has been disposed, it will never be used again.
+        //    The contents of disposeCapability.[[DisposableResourceStack]] can be discarded in
+        //    implementations, such as by garbage collection, at this point.
+        // 5. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 6. Return ? completion.
+        self.error.take().map_or(Ok(()), Err)
+    }
+
+    /// Synchronously disposes all resources of this capability, returning the resulting
+    /// completion.
+    ///
+    /// This must only be used with capabilities that only contain `sync-dispose` resources.
+    pub(crate) fn dispose_sync(mut self, context: &mut Context) -> JsResult<()> {
+        let awaited = self.dispose_step(context)?;
+        debug_assert!(
+            awaited.is_none(),
+            "synchronous dispose capabilities cannot await values"
+        );
+        self.finish()
+    }
+}
+
+/// Abstract operation [`CreateDisposableResource ( V, hint [ , method ] )`][spec], when `method`
+/// is not present.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+fn create_disposable_resource(
+    value: JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<DisposableResource> {
+    // 1. If method is not present, then
+    //     a. If V is either null or undefined, then
+    if value.is_null_or_undefined() {
+        //     i. Set V to undefined.
+        //     ii. Set method to undefined.
+        return Ok(DisposableResource {
+            value: JsValue::undefined(),
+            hint,
+            method: None,
+        });
+    }
+
+    //     b. Else,
+    //         i. If V is not an Object, throw a TypeError exception.
+    let Some(object) = value.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("disposable resource must be an object, null or undefined")
+            .into());
+    };
+
+    //         ii. Set method to ? GetDisposeMethod(V, hint).
+    //         iii. If method is undefined, throw a TypeError exception.
+    let Some(method) = get_dispose_method(object, hint, context)? else {
+        return Err(JsNativeError::typ()
+            .with_message(match hint {
+                DisposeHint::Sync => "disposable resource does not have a [Symbol.dispose] method",
+                DisposeHint::Async => {
+                    "disposable resource does not have a [Symbol.asyncDispose] or [Symbol.dispose] method"
+                }
+            })
+            .into());
+    };
+
+    // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+    Ok(DisposableResource {
+        value,
+        hint,
+        method: Some(method),
+    })
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsObject,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        //     i. Set method to ? GetMethod(V, @@dispose).
+        //     ii. If method is not undefined, then
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        // 1. Let closure be a new Abstract Closure with no parameters that captures method and
+        //    performs the following steps when called:
+        // 2. Return CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method, context| {
+                    // a. Let O be the this value.
+                    // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                    let promise_capability = PromiseCapability::new(
+                        &context.intrinsics().constructors().promise().constructor(),
+                        context,
+                    )
+                    .expect("cannot fail with the %Promise% constructor");
+
+                    // c. Let result be Completion(Call(method, O)).
+                    let result = method.call(this, &[], context);
+
+                    // d. IfAbruptRejectPromise(result, promiseCapability).
+                    if_abrupt_reject_promise!(result, promise_capability, context);
+
+                    // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                    promise_capability.resolve().call(
+                        &JsValue::undefined(),
+                        &[JsValue::undefined()],
+                        context,
+                    )?;
+
+                    // f. Return promiseCapability.[[Promise]].
+                    Ok(promise_capability.promise().clone().into())
+                },
+                method,
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// Creates the closure used by the `adopt` methods of the disposable stacks.
+///
+/// The closure calls `on_dispose` with `value` as its only argument, and returns its result.
+pub(crate) fn adopt_closure(value: JsValue, on_dispose: JsObject, context: &Context) -> JsObject {
+    // a. Let F be a new Abstract Closure with no parameters that captures value and onDispose and
+    //    performs the following steps when called:
+    //     i. Return ? Call(onDispose, undefined, « value »).
+    // b. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (value, on_dispose), context| {
+                on_dispose.call(&JsValue::undefined(), std::slice::from_ref(value), context)
+            },
+            (value, on_dispose),
+        ),
+    )
+    .name(js_string!())
+    .length(0)
+    .build()
+    .into()
+}
+
+/// Boa's implementation of ECMAScript's `DisposableStack` builtin object.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    /// `[[DisposableState]]`, `true` if the stack was disposed.
+    disposed: bool,
+
+    /// `[[DisposeCapability]]`
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_function = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::dispose(),
+                dispose_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// [`DisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                disposed: false,
+                capability: DisposeCapability::default(),
+            },
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Gets the `DisposableStack` object of `this`, throwing if it was already disposed.
+    fn this_pending_stack<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let Some(object) = this
+            .as_object()
+            .filter(|object| object.downcast_ref::<Self>().is_some())
+        else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method} called with invalid `this`"
+                ))
+                .into());
+        };
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        if object
+            .downcast_ref::<Self>()
+            .is_some_and(|stack| stack.disposed)
+        {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method} called on a disposed stack"
+                ))
+                .into());
+        }
+
+        Ok(object)
+    }
+
+    /// Adds a resource to the `[[DisposeCapability]]` of a pending `DisposableStack` object.
+    fn add_resource(
+        stack: &JsObject,
+        value: JsValue,
+        method: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // Take the capability out of the object while validating the resource, since that may
+        // call user code.
+        let mut capability = std::mem::take(
+            &mut stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack object")
+                .capability,
+        );
+        let result = capability.add_resource(value, DisposeHint::Sync, method, context);
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack object")
+            .capability = capability;
+        result
+    }
+
+    /// [`DisposableStack.prototype.adopt ( value, onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let Some(on_dispose) = args.get_or_undefined(1).as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack.prototype.adopt: onDispose is not callable")
+                .into());
+        };
+
+        // 5-6. Let F be a new Abstract Closure that calls onDispose with value.
+        let f = adopt_closure(value.clone(), on_dispose.clone(), context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        Self::add_resource(stack, JsValue::undefined(), Some(f), context)?;
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`DisposableStack.prototype.defer ( onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let Some(on_dispose) = args.get_or_undefined(0).as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack.prototype.defer: onDispose is not callable")
+                .into());
+        };
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        Self::add_resource(
+            stack,
+            JsValue::undefined(),
+            Some(on_dispose.clone()),
+            context,
+        )?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.dispose ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let Some(mut stack) = this.as_object().and_then(JsObject::downcast_mut::<Self>) else {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack.prototype.dispose called with invalid `this`")
+                .into());
+        };
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+        if stack.disposed {
+            return Ok(JsValue::undefined());
+        }
+
+        // 4. Set disposableStack.[[DisposableState]] to disposed.
+        stack.disposed = true;
+        let capability = std::mem::take(&mut stack.capability);
+        drop(stack);
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_sync(context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// [`get DisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let Some(stack) = this.as_object().and_then(JsObject::downcast_ref::<Self>) else {
+            return Err(JsNativeError::typ()
+                .with_message("get DisposableStack.prototype.disposed called with invalid `this`")
+                .into());
+        };
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.disposed.into())
+    }
+
+    /// [`DisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "move")?;
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .disposable_stack()
+            .prototype();
+
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack object");
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                disposed: false,
+                capability,
+            },
+        )
+        .into())
+    }
+
+    /// [`DisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        let stack = Self::this_pending_stack(this, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        Self::add_resource(stack, value.clone(), None, context)?;
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+}
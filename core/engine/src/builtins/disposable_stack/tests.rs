@@ -0,0 +1,253 @@
+use crate::{run_test_actions, Context, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+const RESOURCE: &str = indoc! {r#"
+    var log = [];
+    function resource(name) {
+        return {
+            [Symbol.dispose]() {
+                log.push(name);
+            },
+        };
+    }
+    function asyncResource(name) {
+        return {
+            async [Symbol.asyncDispose]() {
+                log.push(name);
+            },
+        };
+    }
+"#};
+
+#[test]
+fn using_block() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            {
+                using a = resource("a"), b = resource("b");
+                using c = null;
+                log.push("body");
+                using d = undefined, e = resource("e");
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("body,e,b,a")),
+    ]);
+}
+
+#[test]
+fn using_exits() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            function f() {
+                using a = resource("return");
+                return "value";
+            }
+            var result = f();
+
+            for (let i = 0; i < 2; i++) {
+                using b = resource("continue" + i);
+                if (i === 0) {
+                    continue;
+                }
+                using c = resource("break");
+                break;
+            }
+
+            switch (1) {
+                case 1:
+                    using d = resource("switch");
+                case 2:
+                    log.push("fallthrough");
+            }
+        "#}),
+        TestAction::assert_eq("result", js_str!("value")),
+        TestAction::assert_eq(
+            "log.join()",
+            js_str!("return,continue0,break,continue1,fallthrough,switch"),
+        ),
+    ]);
+}
+
+#[test]
+fn using_loops() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            for (using x of [resource("a"), resource("b")]) {
+                log.push("body");
+            }
+
+            for (using y = resource("c"), i = null; log.length < 6; ) {
+                log.push("loop");
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("body,a,body,b,loop,loop,c")),
+    ]);
+}
+
+#[test]
+fn using_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var caught;
+            try {
+                using a = { [Symbol.dispose]() { throw "a"; } };
+                using b = { [Symbol.dispose]() { throw "b"; } };
+                throw "body";
+            } catch (e) {
+                caught = e;
+            }
+        "#}),
+        TestAction::assert("caught instanceof SuppressedError"),
+        TestAction::assert_eq("caught.error", js_str!("a")),
+        TestAction::assert("caught.suppressed instanceof SuppressedError"),
+        TestAction::assert_eq("caught.suppressed.error", js_str!("b")),
+        TestAction::assert_eq("caught.suppressed.suppressed", js_str!("body")),
+        TestAction::assert_native_error(
+            "{ using x = 1; }",
+            JsNativeErrorKind::Type,
+            "disposable resource must be an object, null or undefined",
+        ),
+        TestAction::assert_native_error(
+            "{ using x = {}; }",
+            JsNativeErrorKind::Type,
+            "disposable resource does not have a [Symbol.dispose] method",
+        ),
+    ]);
+}
+
+#[test]
+fn using_generator_return() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            function* g() {
+                using a = resource("a");
+                yield 1;
+                yield 2;
+            }
+            var it = g();
+            it.next();
+            var result = it.return(3);
+        "#}),
+        TestAction::assert_eq("result.value", 3),
+        TestAction::assert("result.done"),
+        TestAction::assert_eq("log.join()", js_str!("a")),
+    ]);
+}
+
+#[test]
+fn await_using() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            var done = false;
+            async function f() {
+                await using a = asyncResource("a"), b = resource("b"), c = null;
+                log.push("body");
+            }
+            f().then(() => { done = true; });
+            log.push("sync");
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("done"),
+        TestAction::assert_eq("log.join()", js_str!("body,b,sync,a")),
+    ]);
+}
+
+#[test]
+fn disposable_stack() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            var stack = new DisposableStack();
+            stack.use(resource("use"));
+            stack.defer(() => log.push("defer"));
+            var adopted = stack.adopt("value", (value) => log.push("adopt " + value));
+            var moved = stack.move();
+        "#}),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("!moved.disposed"),
+        TestAction::assert_eq("adopted", js_str!("value")),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(moved)",
+            js_str!("[object DisposableStack]"),
+        ),
+        TestAction::assert(
+            "DisposableStack.prototype[Symbol.dispose] === DisposableStack.prototype.dispose",
+        ),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.defer called on a disposed stack",
+        ),
+        TestAction::assert_native_error(
+            "moved.defer(1)",
+            JsNativeErrorKind::Type,
+            "DisposableStack.prototype.defer: onDispose is not callable",
+        ),
+        TestAction::assert_native_error(
+            "DisposableStack()",
+            JsNativeErrorKind::Type,
+            "DisposableStack: cannot call constructor without `new`",
+        ),
+        TestAction::run("{ using s = moved; }"),
+        TestAction::assert("moved.disposed"),
+        TestAction::assert_eq("log.join()", js_str!("adopt value,defer,use")),
+        TestAction::assert("moved.dispose() === undefined"),
+    ]);
+}
+
+#[test]
+fn disposable_stack_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var stack = new DisposableStack();
+            stack.defer(() => { throw "first"; });
+            stack.defer(() => { throw "second"; });
+            var caught;
+            try {
+                stack.dispose();
+            } catch (e) {
+                caught = e;
+            }
+        "#}),
+        TestAction::assert("caught instanceof SuppressedError"),
+        TestAction::assert_eq("caught.error", js_str!("first")),
+        TestAction::assert_eq("caught.suppressed", js_str!("second")),
+        TestAction::assert_eq("caught.message", js_str!("")),
+        TestAction::assert("!Object.hasOwn(caught, 'message')"),
+    ]);
+}
+
+#[test]
+fn async_disposable_stack() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            var stack = new AsyncDisposableStack();
+            stack.use(resource("sync"));
+            stack.use(asyncResource("async"));
+            stack.defer(() => { throw "deferred"; });
+            var result;
+            stack.disposeAsync().then(
+                () => { result = "resolved"; },
+                (e) => { result = e; },
+            );
+        "#}),
+        TestAction::assert("stack.disposed"),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("result", js_str!("deferred")),
+        TestAction::assert_eq("log.join()", js_str!("async,sync")),
+        TestAction::assert(
+            "AsyncDisposableStack.prototype[Symbol.asyncDispose] === AsyncDisposableStack.prototype.disposeAsync",
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(stack)",
+            js_str!("[object AsyncDisposableStack]"),
+        ),
+    ]);
+}
@@ -132,6 +132,8 @@ impl BuiltInConstructor for AggregateError {
         )
         .expect("should not fail according to spec");
 
+        Error::install_error_stack(&o, None, context);
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -13,10 +13,10 @@
 use crate::{
     builtins::BuiltInObject,
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
-    error::JsNativeError,
+    error::{Backtrace, JsNativeError},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::StaticJsStrings,
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
@@ -29,6 +29,7 @@ pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -41,6 +42,7 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
@@ -125,6 +127,14 @@ pub enum ErrorObject {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-urierror
     Uri,
+
+    /// The `SuppressedError` object type.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
 }
 
 /// Built-in `Error` object.
@@ -137,6 +147,16 @@ impl IntrinsicObject for Error {
 
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_property(
+                js_string!("stackTraceLimit"),
+                Self::DEFAULT_STACK_TRACE_LIMIT,
+                Attribute::all(),
+            )
+            .static_method(
+                Self::capture_stack_trace,
+                js_string!("captureStackTrace"),
+                2,
+            )
             .property(js_string!("name"), Self::NAME, attribute)
             .property(js_string!("message"), js_string!(), attribute)
             .method(Self::to_string, js_string!("toString"), 0)
@@ -155,7 +175,7 @@ impl BuiltInObject for Error {
 impl BuiltInConstructor for Error {
     const LENGTH: usize = 1;
     const P: usize = 2;
-    const SP: usize = 0;
+    const SP: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::error;
@@ -200,12 +220,17 @@ impl BuiltInConstructor for Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        Self::install_error_stack(&o, None, context);
+
         // 5. Return O.
         Ok(o.into())
     }
 }
 
 impl Error {
+    /// The initial value of `Error.stackTraceLimit`.
+    const DEFAULT_STACK_TRACE_LIMIT: i32 = 10;
+
     pub(crate) fn install_error_cause(
         o: &JsObject,
         options: &JsValue,
@@ -224,6 +249,122 @@ impl Error {
         Ok(())
     }
 
+    /// Gets the value of `Error.stackTraceLimit` in the current realm, or `None` if it is not a
+    /// number, in which case no stack traces should be captured.
+    pub(crate) fn stack_trace_limit(context: &Context) -> Option<usize> {
+        let limit = context
+            .intrinsics()
+            .constructors()
+            .error()
+            .constructor()
+            .borrow()
+            .properties()
+            .get(&js_string!("stackTraceLimit").into())?
+            .value()?
+            .as_number()?;
+
+        // Negative and `NaN` limits saturate to zero, and `Infinity` to the maximum limit.
+        Some(limit as usize)
+    }
+
+    /// Installs the non-standard `stack` property on the error object `o`, using the frames of
+    /// `backtrace`, or the currently active frames if `backtrace` is `None`.
+    ///
+    /// This is a no-op if `Error.stackTraceLimit` is not a number.
+    pub(crate) fn install_error_stack(
+        o: &JsObject,
+        backtrace: Option<&Backtrace>,
+        context: &mut Context,
+    ) {
+        let Some(limit) = Self::stack_trace_limit(context) else {
+            return;
+        };
+
+        let stack = match backtrace {
+            Some(backtrace) => Self::format_stack(o, backtrace),
+            None => Self::format_stack(o, &Backtrace::capture(context, None, limit)),
+        };
+
+        o.create_non_enumerable_data_property_or_throw(js_string!("stack"), stack, context);
+    }
+
+    /// Formats the V8-compatible `stack` string of the error object `o`.
+    ///
+    /// The first line mirrors `Error.prototype.toString`, but only looks at data properties to
+    /// avoid running user code while creating errors.
+    fn format_stack(o: &JsObject, backtrace: &Backtrace) -> JsString {
+        let get_string = |key: JsString| {
+            o.get_property(&key.into())
+                .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+        };
+
+        let name = get_string(js_string!("name")).unwrap_or_else(|| js_string!("Error"));
+        let msg = get_string(js_string!("message")).unwrap_or_default();
+
+        let header = if name.is_empty() {
+            msg
+        } else if msg.is_empty() {
+            name
+        } else {
+            js_string!(&name, js_str!(": "), &msg)
+        };
+
+        if backtrace.frames().is_empty() {
+            return header;
+        }
+
+        js_string!(
+            &header,
+            js_str!("\n"),
+            &JsString::from(backtrace.to_string())
+        )
+    }
+
+    /// `Error.captureStackTrace ( targetObject [ , constructorOpt ] )`
+    ///
+    /// Creates a `stack` property on `targetObject`, describing the script frames that are
+    /// currently active. If `constructorOpt` is a function, the frames above its topmost call,
+    /// including the call itself, are left out.
+    ///
+    /// This is a non-standard V8 extension, supported by most engines.
+    ///
+    /// More information:
+    ///  - [V8 documentation][v8]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [v8]: https://v8.dev/docs/stack-trace-api#stack-trace-collection-for-custom-exceptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/captureStackTrace
+    fn capture_stack_trace(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let Some(target) = args.get_or_undefined(0).as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("Error.captureStackTrace: target must be an object")
+                .into());
+        };
+
+        let Some(limit) = Self::stack_trace_limit(context) else {
+            return Ok(JsValue::undefined());
+        };
+
+        let backtrace = Backtrace::capture(context, args.get_or_undefined(1).as_callable(), limit);
+        let stack = Self::format_stack(target, &backtrace);
+
+        target.define_property_or_throw(
+            js_string!("stack"),
+            PropertyDescriptor::builder()
+                .value(stack)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        Ok(JsValue::undefined())
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The `toString()` method returns a string representing the specified Error object.
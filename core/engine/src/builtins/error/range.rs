@@ -100,6 +100,8 @@ impl BuiltInConstructor for RangeError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        Error::install_error_stack(&o, None, context);
+
         // 5. Return O.
         Ok(o.into())
     }
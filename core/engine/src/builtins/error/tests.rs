@@ -1,4 +1,5 @@
-use crate::{run_test_actions, TestAction};
+use crate::{js_string, run_test_actions, JsNativeErrorKind, Source, TestAction};
+use boa_ast::Position;
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -68,3 +69,98 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function inner() { return new RangeError("boom"); }
+            function outer() {
+                return inner();
+            }
+            var error = outer();
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_str!(
+                "RangeError: boom\n    at inner (<anonymous>:1:27)\n    at outer (<anonymous>:3:12)\n    at <anonymous>:5:13"
+            ),
+        ),
+        TestAction::assert(indoc! {r#"
+            const desc = Object.getOwnPropertyDescriptor(error, "stack");
+            desc.writable && !desc.enumerable && desc.configurable
+        "#}),
+        TestAction::assert_eq(
+            "try { JSON.parse(undefined); } catch (e) { e.stack.split('\\n')[1] }",
+            js_str!("    at <anonymous>:1:7"),
+        ),
+        TestAction::assert_eq("new Error().stack", js_str!("Error\n    at <anonymous>:1:1")),
+    ]);
+}
+
+#[test]
+fn error_stack_trace_limit() {
+    run_test_actions([
+        TestAction::assert_eq("Error.stackTraceLimit", 10),
+        TestAction::run(indoc! {r#"
+            function recurse(n) {
+                return n === 0 ? new Error() : recurse(n - 1);
+            }
+        "#}),
+        TestAction::assert_eq("recurse(20).stack.split('\\n').length", 11),
+        TestAction::run("Error.stackTraceLimit = 2"),
+        TestAction::assert_eq("recurse(20).stack.split('\\n').length", 3),
+        TestAction::run("Error.stackTraceLimit = 'none'"),
+        TestAction::assert("!Object.hasOwn(recurse(20), 'stack')"),
+    ]);
+}
+
+#[test]
+fn error_capture_stack_trace() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function MyError(message) {
+                this.message = message;
+                Error.captureStackTrace(this, MyError);
+            }
+            MyError.prototype.name = "MyError";
+            function create() { return new MyError("custom"); }
+            var target = {};
+            Error.captureStackTrace(target);
+        "#}),
+        TestAction::assert_eq("target.stack", js_str!("Error\n    at <anonymous>:8:1")),
+        TestAction::assert_eq(
+            "create().stack",
+            js_str!("MyError: custom\n    at create (<anonymous>:6:28)\n    at <anonymous>:1:1"),
+        ),
+        TestAction::assert_native_error(
+            "Error.captureStackTrace(1)",
+            JsNativeErrorKind::Type,
+            "Error.captureStackTrace: target must be an object",
+        ),
+    ]);
+}
+
+#[test]
+fn error_backtrace() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let error = context
+            .eval(Source::from_bytes(indoc! {r#"
+                function thrower() {
+                    null.property;
+                }
+                thrower();
+            "#}))
+            .unwrap_err();
+
+        let frames = error
+            .backtrace()
+            .expect("thrown errors must have a backtrace")
+            .frames();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].function_name(), &js_string!("thrower"));
+        assert_eq!(frames[0].position(), None);
+        assert!(frames[1].function_name().is_empty());
+        assert_eq!(frames[1].position(), Some(Position::new(4, 1)));
+    })]);
+}
@@ -25,6 +25,7 @@ use crate::{
 use boa_ast::{
     operations::{contains, contains_arguments, ContainsSymbol},
     scope::Scope,
+    Script,
 };
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
@@ -76,28 +77,9 @@ impl Eval {
         x: &JsValue,
         direct: bool,
         lexical_scope: Option<Scope>,
-        mut strict: bool,
+        strict: bool,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        bitflags::bitflags! {
-            /// Flags used to throw early errors on invalid `eval` calls.
-            #[derive(Default)]
-            struct Flags: u8 {
-                const IN_FUNCTION = 0b0001;
-                const IN_METHOD = 0b0010;
-                const IN_DERIVED_CONSTRUCTOR = 0b0100;
-                const IN_CLASS_FIELD_INITIALIZER = 0b1000;
-            }
-        }
-
-        /// Possible actions that can be executed after exiting this function to restore the environment to its
-        /// original state.
-        #[derive(Debug)]
-        enum EnvStackAction {
-            Truncate(usize),
-            Restore(Vec<Environment>),
-        }
-
         // 1. Assert: If direct is false, then strictCaller is also false.
         debug_assert!(direct || !strict);
 
@@ -119,6 +101,34 @@ impl Eval {
             .host_hooks()
             .ensure_can_compile_strings(eval_realm, &[], x, direct, context)?;
 
+        let body = Self::parse_eval_body(x, direct, strict, context)?;
+
+        Self::evaluate_eval_body(body, direct, lexical_scope, strict, context)
+    }
+
+    /// Parses the source text of an `eval` call, throwing any early errors of the resulting
+    /// `ScriptBody`.
+    ///
+    /// This comprises steps 6 to 11 of [`PerformEval`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn parse_eval_body(
+        x: &JsString,
+        direct: bool,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<Script> {
+        bitflags::bitflags! {
+            /// Flags used to throw early errors on invalid `eval` calls.
+            #[derive(Default)]
+            struct Flags: u8 {
+                const IN_FUNCTION = 0b0001;
+                const IN_METHOD = 0b0010;
+                const IN_DERIVED_CONSTRUCTOR = 0b0100;
+                const IN_CLASS_FIELD_INITIALIZER = 0b1000;
+            }
+        }
+
         // 11. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
         //     a. Let script be ParseText(StringToCodePoints(x), Script).
         //     b. If script is a List of errors, throw a SyntaxError exception.
@@ -130,7 +140,7 @@ impl Eval {
         if strict {
             parser.set_strict();
         }
-        let mut body = parser.parse_eval(direct, context.interner_mut())?;
+        let body = parser.parse_eval(direct, context.interner_mut())?;
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -195,6 +205,30 @@ impl Eval {
                 .into());
         }
 
+        Ok(body)
+    }
+
+    /// Evaluates the already parsed `ScriptBody` of an `eval` call in the current realm.
+    ///
+    /// This comprises steps 12 to 33 of [`PerformEval`][spec], including the call to
+    /// `EvalDeclarationInstantiation`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn evaluate_eval_body(
+        mut body: Script,
+        direct: bool,
+        lexical_scope: Option<Scope>,
+        mut strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// Possible actions that can be executed after exiting this function to restore the environment to its
+        /// original state.
+        #[derive(Debug)]
+        enum EnvStackAction {
+            Truncate(usize),
+            Restore(Vec<Environment>),
+        }
+
         strict |= body.strict();
 
         // Because our environment model does not map directly to the spec, this section looks very different.
@@ -0,0 +1,475 @@
+//! Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use std::{cell::Cell, rc::Rc};
+
+use boa_gc::{Finalize, Trace, WeakGc};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        weak::{can_be_held_weakly, WeakKeyEphemeron, WeakKeyRef},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::NativeJob,
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsFunction, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Flag shared between a `FinalizationRegistry` and the cells registered on it.
+///
+/// The garbage collector drops the value of an [`Ephemeron`] when its key becomes unreachable,
+/// which runs [`Finalize::finalize`] on it. This is used to notify the registry that at least
+/// one of its targets was collected and a cleanup job needs to be scheduled.
+#[derive(Debug, Clone, Trace)]
+struct CleanupSignal(#[unsafe_ignore_trace] Rc<Cell<bool>>);
+
+impl Finalize for CleanupSignal {
+    fn finalize(&self) {
+        self.0.set(true);
+    }
+}
+
+/// A `Record` from the `[[Cells]]` list of a `FinalizationRegistry`.
+#[derive(Trace, Finalize)]
+struct RegistryCell {
+    /// `[[WeakRefTarget]]`
+    target: WeakKeyEphemeron<CleanupSignal>,
+    /// `[[HeldValue]]`
+    held_value: JsValue,
+    /// `[[UnregisterToken]]`
+    unregister_token: Option<WeakKeyRef>,
+}
+
+impl std::fmt::Debug for RegistryCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegistryCell")
+            .field("held_value", &self.held_value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RegistryCell {
+    /// Returns `true` if the target of this cell was already collected.
+    fn is_empty(&self) -> bool {
+        !self.target.has_value()
+    }
+}
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// A `FinalizationRegistry` allows requesting a callback to be called after an object is
+/// garbage collected. Cleanup callbacks are enqueued on the [`JobQueue`][crate::job::JobQueue]
+/// of the context the next time its jobs are run after a collection.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct FinalizationRegistry {
+    /// `[[Realm]]`
+    realm: Realm,
+    /// `[[CleanupCallback]]`
+    cleanup_callback: JsFunction,
+    /// `[[Cells]]`
+    cells: Vec<RegistryCell>,
+    /// Set by the garbage collector when the target of any cell is collected.
+    #[unsafe_ignore_trace]
+    needs_cleanup: Rc<Cell<bool>>,
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, js_string!("register"), 2)
+            .method(Self::unregister, js_string!("unregister"), 1)
+            .method(Self::cleanup_some, js_string!("cleanupSome"), 0)
+            .build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: JsString = StaticJsStrings::FINALIZATION_REGISTRY;
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    /// The amount of arguments the `FinalizationRegistry` constructor takes.
+    const LENGTH: usize = 1;
+    const P: usize = 4;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// `FinalizationRegistry ( cleanupCallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("FinalizationRegistry: cleanup callback must be callable")
+            })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                realm: context.realm().clone(),
+                cleanup_callback,
+                cells: Vec::new(),
+                needs_cleanup: Rc::default(),
+            },
+        );
+
+        // Track the registry so that its cleanup jobs can be enqueued after a collection.
+        context
+            .finalization_registries
+            .push(WeakGc::new(registry.inner()));
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// `FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let mut registry = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.register: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        let target = args.get_or_undefined(0);
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let Some(target_key) = can_be_held_weakly(target) else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.register: expected target argument of type `object` or `symbol`, got target of type `{}`",
+                    target.type_of()
+                ))
+                .into());
+        };
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if JsValue::same_value(target, held_value) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: target and held value must be different",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = match can_be_held_weakly(unregister_token) {
+            Some(token) => Some(WeakKeyRef::new(&token)),
+            // b. Set unregisterToken to empty.
+            None if unregister_token.is_undefined() => None,
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "FinalizationRegistry.prototype.register: expected unregister token of type `object` or `symbol`, got token of type `{}`",
+                        unregister_token.type_of()
+                    ))
+                    .into());
+            }
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        let signal = CleanupSignal(registry.needs_cleanup.clone());
+        let cell = RegistryCell {
+            target: WeakKeyEphemeron::new(&target_key, signal),
+            held_value: held_value.clone(),
+            unregister_token,
+        };
+
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry.cells.push(cell);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister ( unregisterToken )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let mut registry = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.unregister: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let unregister_token = args.get_or_undefined(0);
+        if can_be_held_weakly(unregister_token).is_none() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.unregister: expected unregister token of type `object` or `symbol`, got token of type `{}`",
+                    unregister_token.type_of()
+                ))
+                .into());
+        }
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of
+        //    finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let len = registry.cells.len();
+        registry.cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .and_then(WeakKeyRef::upgrade)
+                .is_some_and(|token| JsValue::same_value(&token, unregister_token))
+        });
+        let removed = registry.cells.len() != len;
+
+        // 6. Return removed.
+        Ok(removed.into())
+    }
+
+    /// `FinalizationRegistry.prototype.cleanupSome ( [ callback ] )`
+    ///
+    /// Synchronously runs the cleanup of all the cells of this registry whose targets were
+    /// already collected, calling `callback` (or the registry's cleanup callback if it is
+    /// `undefined`) with their held values.
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome
+    pub(crate) fn cleanup_some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|obj| obj.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.cleanupSome: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If callback is present and not undefined, and IsCallable(callback) is false, throw a TypeError exception.
+        let callback = args.get_or_undefined(0);
+        let callback = if callback.is_undefined() {
+            None
+        } else {
+            Some(
+                callback
+                    .as_callable()
+                    .cloned()
+                    .and_then(JsFunction::from_object)
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message(
+                            "FinalizationRegistry.prototype.cleanupSome: callback must be callable",
+                        )
+                    })?,
+            )
+        };
+
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        Self::cleanup(registry, callback, context)?;
+
+        // 5. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation [`CleanupFinalizationRegistry ( finalizationRegistry )`][spec].
+    ///
+    /// If `callback` is `None`, the `[[CleanupCallback]]` of the registry is used instead.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(
+        registry: &JsObject,
+        callback: Option<JsFunction>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        let Some(callback) = callback.or_else(|| {
+            registry
+                .downcast_ref::<Self>()
+                .map(|data| data.cleanup_callback.clone())
+        }) else {
+            return Ok(());
+        };
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty,
+        //    an implementation may perform the following steps:
+        loop {
+            // a. Choose any such cell.
+            // b. Remove cell from finalizationRegistry.[[Cells]].
+            let held_value = {
+                let Some(mut data) = registry.downcast_mut::<Self>() else {
+                    return Ok(());
+                };
+                let Some(index) = data.cells.iter().position(RegistryCell::is_empty) else {
+                    // All the collected cells were removed, so no further cleanup is needed
+                    // until the next collection.
+                    data.needs_cleanup.set(false);
+                    return Ok(());
+                };
+                data.cells.swap_remove(index).held_value.clone()
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call(&JsValue::undefined(), &[held_value], context)?;
+        }
+
+        // 4. Return unused.
+    }
+
+    /// Abstract operation [`HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`][spec].
+    ///
+    /// Enqueues a cleanup job for every registry of the context that had any of its targets
+    /// collected since its last cleanup.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn enqueue_cleanup_jobs(context: &mut Context) {
+        let mut registries = std::mem::take(&mut context.finalization_registries);
+
+        registries.retain(|registry| {
+            let Some(registry) = registry.upgrade() else {
+                return false;
+            };
+            let registry = JsObject::from(registry);
+
+            let realm = {
+                let Some(data) = registry.downcast_ref::<Self>() else {
+                    return false;
+                };
+                if !data.needs_cleanup.replace(false) {
+                    return true;
+                }
+                data.realm.clone()
+            };
+
+            // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures
+            //    finalizationRegistry and performs the following steps when called:
+            //     a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+            //     b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+            //     c. Return unused.
+            let job = NativeJob::with_realm(
+                move |context| {
+                    Self::cleanup(&registry, None, context)?;
+                    Ok(JsValue::undefined())
+                },
+                realm,
+                context,
+            );
+
+            // 2. An implementation of HostEnqueueFinalizationRegistryCleanupJob schedules
+            //    cleanupJob to be performed at some future time, if possible.
+            context.enqueue_job(job);
+
+            true
+        });
+
+        registries.append(&mut context.finalization_registries);
+        context.finalization_registries = registries;
+    }
+}
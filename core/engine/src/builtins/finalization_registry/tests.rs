@@ -0,0 +1,141 @@
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "FinalizationRegistry(() => {})",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry: cannot call constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "new FinalizationRegistry(1)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry: cleanup callback must be callable",
+        ),
+        TestAction::assert(
+            "Object.getPrototypeOf(new FinalizationRegistry(() => {})) === FinalizationRegistry.prototype",
+        ),
+    ]);
+}
+
+#[test]
+fn register_and_unregister() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var registry = new FinalizationRegistry(() => {});
+            var target = {};
+            var token = {};
+        "#}),
+        TestAction::assert_native_error(
+            "registry.register(1, 'held')",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.register: expected target argument of type `object` or `symbol`, got target of type `number`",
+        ),
+        TestAction::assert_native_error(
+            "registry.register(target, target)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.register: target and held value must be different",
+        ),
+        TestAction::assert_native_error(
+            "registry.register(target, 'held', 1)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.register: expected unregister token of type `object` or `symbol`, got token of type `number`",
+        ),
+        TestAction::assert_eq("registry.register(target, 'held', token)", JsValue::undefined()),
+        TestAction::assert_eq("registry.register(target, 'other')", JsValue::undefined()),
+        TestAction::assert_eq("registry.unregister(token)", true),
+        TestAction::assert_eq("registry.unregister(token)", false),
+        TestAction::assert_native_error(
+            "registry.unregister(undefined)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.unregister: expected unregister token of type `object` or `symbol`, got token of type `undefined`",
+        ),
+    ]);
+}
+
+#[test]
+fn cleanup_job_after_collection() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var held = [];
+            var registry = new FinalizationRegistry((value) => held.push(value));
+            var alive = {};
+            {
+                let dead = {};
+                let unregistered = {};
+                let token = {};
+                registry.register(dead, "dead");
+                registry.register(alive, "alive");
+                registry.register(unregistered, "unregistered", token);
+                registry.unregister(token);
+            }
+        "#}),
+        TestAction::inspect_context(|context| {
+            context.clear_kept_objects();
+            boa_gc::force_collect();
+            context.run_jobs();
+        }),
+        TestAction::assert_eq("held.length", 1),
+        TestAction::assert_eq("held[0]", js_str!("dead")),
+    ]);
+}
+
+#[test]
+fn cleanup_some() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var held = [];
+            var registry = new FinalizationRegistry(() => { throw new Error("unreachable"); });
+            {
+                let dead = {};
+                registry.register(dead, "dead");
+            }
+        "#}),
+        TestAction::inspect_context(|context| {
+            context.clear_kept_objects();
+            boa_gc::force_collect();
+        }),
+        TestAction::run("registry.cleanupSome((value) => held.push(value))"),
+        TestAction::assert_eq("held.length", 1),
+        TestAction::assert_native_error(
+            "registry.cleanupSome(1)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.cleanupSome: callback must be callable",
+        ),
+    ]);
+}
+
+#[test]
+fn symbol_targets() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var held = [];
+            var registry = new FinalizationRegistry((value) => held.push(value));
+            var alive = Symbol("alive");
+            {
+                let dead = Symbol("dead");
+                let token = Symbol("token");
+                registry.register(dead, "dead");
+                registry.register(alive, "alive", token);
+                registry.register(Symbol.iterator, "well-known");
+                var unregistered = registry.unregister(token);
+            }
+        "#}),
+        TestAction::assert("unregistered"),
+        TestAction::assert_native_error(
+            "registry.register(Symbol.for('registered'), 1)",
+            JsNativeErrorKind::Type,
+            "FinalizationRegistry.prototype.register: expected target argument of type `object` or `symbol`, got target of type `symbol`",
+        ),
+        TestAction::inspect_context(|context| {
+            context.clear_kept_objects();
+            boa_gc::force_collect();
+            context.run_jobs();
+        }),
+        TestAction::assert_eq("held.length", 1),
+        TestAction::assert_eq("held[0]", js_str!("dead")),
+    ]);
+}
@@ -138,20 +138,31 @@ impl ConstructorKind {
 #[derive(Clone, Debug, Finalize)]
 pub enum ClassFieldDefinition {
     /// A class field definition with a `string` or `symbol` as a name.
-    Public(PropertyKey, JsFunction, Option<PropertyKey>),
+    ///
+    /// The last element holds the initializers added by field decorators.
+    Public(
+        PropertyKey,
+        JsFunction,
+        Option<PropertyKey>,
+        ThinVec<JsFunction>,
+    ),
 
     /// A class field definition with a private name.
-    Private(PrivateName, JsFunction),
+    ///
+    /// The last element holds the initializers added by field decorators.
+    Private(PrivateName, JsFunction, ThinVec<JsFunction>),
 }
 
 unsafe impl Trace for ClassFieldDefinition {
     custom_trace! {this, mark, {
         match this {
-            Self::Public(_key, func, _) => {
+            Self::Public(_key, func, _, initializers) => {
                 mark(func);
+                mark(initializers);
             }
-            Self::Private(_, func) => {
+            Self::Private(_, func, initializers) => {
                 mark(func);
+                mark(initializers);
             }
         }
     }}
@@ -185,6 +196,10 @@ pub struct OrdinaryFunction {
 
     /// The `[[PrivateMethods]]` internal slot.
     private_methods: ThinVec<(PrivateName, PrivateElement)>,
+
+    /// The `[[Initializers]]` internal slot, containing the extra initializers added by
+    /// decorators of non-static class elements.
+    initializers: ThinVec<JsFunction>,
 }
 
 impl JsData for OrdinaryFunction {
@@ -223,6 +238,7 @@ impl OrdinaryFunction {
             realm,
             fields: ThinVec::default(),
             private_methods: ThinVec::default(),
+            initializers: ThinVec::default(),
         }
     }
 
@@ -269,13 +285,41 @@ impl OrdinaryFunction {
         value: JsFunction,
         function_name: Option<PropertyKey>,
     ) {
-        self.fields
-            .push(ClassFieldDefinition::Public(key, value, function_name));
+        self.fields.push(ClassFieldDefinition::Public(
+            key,
+            value,
+            function_name,
+            ThinVec::new(),
+        ));
     }
 
     /// Pushes a private value to the `[[Fields]]` internal slot if present.
     pub(crate) fn push_field_private(&mut self, name: PrivateName, value: JsFunction) {
-        self.fields.push(ClassFieldDefinition::Private(name, value));
+        self.fields
+            .push(ClassFieldDefinition::Private(name, value, ThinVec::new()));
+    }
+
+    /// Pushes a decorator initializer to the field at `index` of the `[[Fields]]` internal slot.
+    pub(crate) fn push_field_initializer(&mut self, index: usize, initializer: JsFunction) {
+        match &mut self.fields[index] {
+            ClassFieldDefinition::Public(_, _, _, initializers)
+            | ClassFieldDefinition::Private(_, _, initializers) => initializers.push(initializer),
+        }
+    }
+
+    /// Returns the values of the `[[Initializers]]` internal slot.
+    pub(crate) fn get_initializers(&self) -> &[JsFunction] {
+        &self.initializers
+    }
+
+    /// Pushes an extra initializer to the `[[Initializers]]` internal slot.
+    pub(crate) fn push_initializer(&mut self, initializer: JsFunction) {
+        self.initializers.push(initializer);
+    }
+
+    /// Returns the number of values in the `[[Fields]]` internal slot.
+    pub(crate) fn fields_len(&self) -> usize {
+        self.fields.len()
     }
 
     /// Returns the values of the `[[PrivateMethods]]` internal slot.
@@ -734,60 +778,11 @@ impl BuiltInFunctionObject {
         // 3. Let F be ? BoundFunctionCreate(Target, thisArg, args).
         let f = BoundFunction::create(target.clone(), this_arg, bound_args, context)?;
 
-        // 4. Let L be 0.
-        let mut l = JsValue::new(0);
-
-        // 5. Let targetHasLength be ? HasOwnProperty(Target, "length").
-        // 6. If targetHasLength is true, then
-        if target.has_own_property(StaticJsStrings::LENGTH, context)? {
-            // a. Let targetLen be ? Get(Target, "length").
-            let target_len = target.get(StaticJsStrings::LENGTH, context)?;
-            // b. If Type(targetLen) is Number, then
-            if target_len.is_number() {
-                // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
-                match target_len
-                    .to_integer_or_infinity(context)
-                    .expect("to_integer_or_infinity cannot fail for a number")
-                {
-                    // i. If targetLen is +∞𝔽, set L to +∞.
-                    IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
-                    // ii. Else if targetLen is -∞𝔽, set L to 0.
-                    IntegerOrInfinity::NegativeInfinity => {}
-                    // iii. Else,
-                    IntegerOrInfinity::Integer(target_len) => {
-                        // 2. Assert: targetLenAsInt is finite.
-                        // 3. Let argCount be the number of elements in args.
-                        // 4. Set L to max(targetLenAsInt - argCount, 0).
-                        l = (target_len - arg_count).max(0).into();
-                    }
-                }
-            }
-        }
+        // 4. Let argCount be the number of elements in args.
+        // 5. Perform ? CopyNameAndLength(F, Target, "bound", argCount).
+        copy_name_and_length(&f, target, Some(js_str!("bound")), arg_count, context)?;
 
-        // 7. Perform ! SetFunctionLength(F, L).
-        f.define_property_or_throw(
-            StaticJsStrings::LENGTH,
-            PropertyDescriptor::builder()
-                .value(l)
-                .writable(false)
-                .enumerable(false)
-                .configurable(true),
-            context,
-        )
-        .expect("defining the `length` property for a new object should not fail");
-
-        // 8. Let targetName be ? Get(Target, "name").
-        let target_name = target.get(js_string!("name"), context)?;
-
-        // 9. If Type(targetName) is not String, set targetName to the empty String.
-        let target_name = target_name
-            .as_string()
-            .map_or_else(JsString::default, Clone::clone);
-
-        // 10. Perform SetFunctionName(F, targetName, "bound").
-        set_function_name(&f, &target_name.into(), Some(js_str!("bound")), context);
-
-        // 11. Return F.
+        // 6. Return F.
         Ok(f.into())
     }
 
@@ -896,6 +891,77 @@ impl BuiltInFunctionObject {
     }
 }
 
+/// Abstract operation `CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`
+///
+/// Copies the `length` and `name` properties of `target` to the newly created function `f`,
+/// as done by `Function.prototype.bind` and by the wrapped functions of `ShadowRealm`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+pub(crate) fn copy_name_and_length(
+    f: &JsObject,
+    target: &JsObject,
+    prefix: Option<JsStr<'_>>,
+    arg_count: i64,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut l = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property(StaticJsStrings::LENGTH, context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get(StaticJsStrings::LENGTH, context)?;
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                IntegerOrInfinity::Integer(target_len) => {
+                    // 2. Assert: targetLenAsInt is finite.
+                    // 3. Set L to max(targetLenAsInt - argCount, 0).
+                    l = (target_len - arg_count).max(0).into();
+                }
+            }
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    f.define_property_or_throw(
+        StaticJsStrings::LENGTH,
+        PropertyDescriptor::builder()
+            .value(l)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    // 6. Let targetName be ? Get(Target, "name").
+    let target_name = target.get(js_string!("name"), context)?;
+
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target_name
+        .as_string()
+        .map_or_else(JsString::default, Clone::clone);
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(f, &target_name.into(), prefix, context);
+
+    Ok(())
+}
+
 /// Abstract operation `SetFunctionName`
 ///
 /// More information:
@@ -0,0 +1,1559 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+
+use crate::{
+    builtins::{
+        date::utils::{
+            date_from_time, hour_from_time, local_timezone_offset_seconds, min_from_time,
+            month_from_time, ms_from_time, sec_from_time, time_clip, year_from_time,
+        },
+        options::get_option,
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    error::JsNativeError,
+    js_string,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectInitializer,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsResult, JsString, JsSymbol, JsValue, NativeFunction,
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_calendar::{types::NanoSecond, DateTime, Gregorian};
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    options::{components, length, preferences::HourCycle},
+    pattern::{
+        runtime::{Pattern, PatternPlurals},
+        CoarseHourCycle, PatternItem,
+    },
+    provider::calendar::{
+        patterns::LengthPatternsV1, DateSkeletonPatternsV1Marker, GregorianDateLengthsV1Marker,
+        TimeLengthsV1Marker,
+    },
+    skeleton::{create_best_pattern_for_fields, BestSkeleton},
+    time_zone::TimeZoneFormatterOptions,
+    DateTimeFormatterOptions, TypedZonedDateTimeFormatter,
+};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::{key, value, Value},
+    Locale,
+};
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
+    KeyedDataMarker,
+};
+use icu_timezone::{CustomTimeZone, GmtOffset};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The time zone used to format dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatTimeZone {
+    /// The time zone of the host, as reported by the [`HostHooks`][crate::context::HostHooks].
+    Local,
+    /// The `UTC` time zone.
+    Utc,
+    /// A fixed offset from UTC, in minutes.
+    Offset(i16),
+}
+
+impl FormatTimeZone {
+    /// Parses a time zone identifier, returning `None` if the identifier is not supported.
+    ///
+    /// Only `UTC` and UTC offsets (`±HH`, `±HHMM` or `±HH:MM`) are supported, since there's
+    /// no time zone database available to the engine.
+    fn parse(identifier: &str) -> Option<Self> {
+        if ["utc", "etc/utc", "gmt", "etc/gmt"]
+            .iter()
+            .any(|id| identifier.eq_ignore_ascii_case(id))
+        {
+            return Some(Self::Utc);
+        }
+
+        let mut chars = identifier.chars();
+        let sign = match chars.next()? {
+            '+' => 1,
+            '-' | '\u{2212}' => -1,
+            _ => return None,
+        };
+        let digits = chars.filter(|&c| c != ':').collect::<String>();
+        let has_separator = identifier.contains(':');
+        let (hours, minutes) = match digits.len() {
+            2 if !has_separator => (&digits[..2], "00"),
+            4 if !has_separator || identifier.find(':') == Some(3) => (&digits[..2], &digits[2..]),
+            _ => return None,
+        };
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let hours = hours.parse::<i16>().ok().filter(|h| *h < 24)?;
+        let minutes = minutes.parse::<i16>().ok().filter(|m| *m < 60)?;
+
+        Some(Self::Offset(sign * (hours * 60 + minutes)))
+    }
+
+    /// Gets the offset from UTC of this time zone at the time value `t`, in seconds.
+    fn offset_seconds(self, t: f64, context: &Context) -> i32 {
+        match self {
+            Self::Local => local_timezone_offset_seconds(t, context.host_hooks()),
+            Self::Utc => 0,
+            Self::Offset(minutes) => i32::from(minutes) * 60,
+        }
+    }
+
+    /// Gets the identifier of this time zone.
+    ///
+    /// The identifier of the local time zone is its current offset from UTC.
+    fn identifier(self, context: &Context) -> JsString {
+        let minutes = match self {
+            Self::Utc => return js_string!("UTC"),
+            Self::Offset(minutes) => minutes,
+            Self::Local => {
+                let now = context.host_hooks().utc_now() as f64;
+                let offset = local_timezone_offset_seconds(now, context.host_hooks()) / 60;
+                if offset == 0 {
+                    return js_string!("UTC");
+                }
+                offset as i16
+            }
+        };
+
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let minutes = minutes.unsigned_abs();
+        js_string!(format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60))
+    }
+}
+
+/// The format requested for a `DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatOptions {
+    /// The `dateStyle` and `timeStyle` options.
+    Styles {
+        date: Option<DateTimeStyle>,
+        time: Option<DateTimeStyle>,
+    },
+    /// The individual date and time components.
+    Components(DateTimeComponents),
+}
+
+impl FormatOptions {
+    /// Converts these options into the equivalent ICU4X formatter options.
+    fn to_icu(self, hour_cycle: HourCycle) -> DateTimeFormatterOptions {
+        match self {
+            Self::Styles { date, time } => {
+                let mut bag = length::Bag::empty();
+                bag.date = date.map(DateTimeStyle::to_date_length);
+                bag.time = time.map(DateTimeStyle::to_time_length);
+                bag.into()
+            }
+            Self::Components(components) => components.to_bag(hour_cycle).into(),
+        }
+    }
+}
+
+/// JavaScript `Intl.DateTimeFormat` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DateTimeFormat {
+    #[unsafe_ignore_trace]
+    locale: Locale,
+    #[unsafe_ignore_trace]
+    numbering_system: Option<Value>,
+    #[unsafe_ignore_trace]
+    time_zone: FormatTimeZone,
+    #[unsafe_ignore_trace]
+    hour_cycle: Option<HourCycle>,
+    #[unsafe_ignore_trace]
+    format: FormatOptions,
+    #[unsafe_ignore_trace]
+    pattern: Pattern<'static>,
+    #[unsafe_ignore_trace]
+    formatter: TypedZonedDateTimeFormatter<Gregorian>,
+    bound_format: Option<JsFunction>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct DateTimeFormatLocaleOptions {
+    calendar: Option<Value>,
+    numbering_system: Option<Value>,
+    hour_cycle: Option<HourCycle>,
+    hour12: bool,
+}
+
+impl Service for DateTimeFormat {
+    type LangMarker = GregorianDateLengthsV1Marker;
+
+    type LocaleOptions = DateTimeFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let keywords = &locale.extensions.unicode.keywords;
+
+        let calendar = options
+            .calendar
+            .take()
+            .or_else(|| keywords.get(&key!("ca")).cloned())
+            .filter(is_supported_calendar);
+
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    provider,
+                )
+            })
+            .or_else(|| {
+                keywords.get(&key!("nu")).cloned().filter(|nu| {
+                    validate_extension::<DecimalSymbolsV1Marker>(
+                        locale.id.clone(),
+                        key!("nu"),
+                        nu,
+                        provider,
+                    )
+                })
+            });
+
+        // The `hour12` option overrides both the `hourCycle` option and the `hc` extension key.
+        let hour_cycle = if options.hour12 {
+            None
+        } else {
+            options.hour_cycle.or_else(|| {
+                keywords
+                    .get(&key!("hc"))
+                    .and_then(Value::as_single_subtag)
+                    .and_then(|hc| match &**hc {
+                        "h11" => Some(HourCycle::H11),
+                        "h12" => Some(HourCycle::H12),
+                        "h23" => Some(HourCycle::H23),
+                        "h24" => Some(HourCycle::H24),
+                        _ => None,
+                    })
+            })
+        };
+
+        locale.extensions.unicode.clear();
+
+        if let Some(ca) = calendar.clone() {
+            locale.extensions.unicode.keywords.set(key!("ca"), ca);
+        }
+        if let Some(hc) = hour_cycle {
+            locale
+                .extensions
+                .unicode
+                .keywords
+                .set(key!("hc"), hour_cycle_to_value(hc));
+        }
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.calendar = calendar;
+        options.numbering_system = numbering_system;
+        options.hour_cycle = hour_cycle;
+    }
+}
+
+impl IntrinsicObject for DateTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
+            .name(js_string!("get format"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DateTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("format"),
+                Some(get_format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DateTimeFormat {
+    const NAME: JsString = StaticJsStrings::DATE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for DateTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 6;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::date_time_format;
+
+    /// The `Intl.DateTimeFormat` constructor is the `%DateTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target_inner = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .date_time_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            new_target_inner,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+        let date_time_format = Self::create(
+            locales,
+            options,
+            FormatType::Any,
+            FormatDefaults::Date,
+            context,
+        )?;
+        let date_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            date_time_format,
+        );
+
+        // 3. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // ChainDateTimeFormat ( dateTimeFormat, newTarget, this )
+        // <https://tc39.es/ecma402/#sec-chaindatetimeformat>
+
+        let this = context.vm.frame().this(&context.vm);
+        let Some(this_obj) = this.as_object() else {
+            return Ok(date_time_format.into());
+        };
+
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .date_time_format()
+            .constructor();
+
+        // 1. If newTarget is undefined and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, this) is true, then
+        if new_target.is_undefined()
+            && JsValue::ordinary_has_instance(&constructor.into(), &this, context)?
+        {
+            let fallback_symbol = context
+                .intrinsics()
+                .objects()
+                .intl()
+                .borrow()
+                .data
+                .fallback_symbol();
+
+            // a. Perform ? DefinePropertyOrThrow(this, %Intl%.[[FallbackSymbol]], PropertyDescriptor{ [[Value]]: dateTimeFormat, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+            this_obj.define_property_or_throw(
+                fallback_symbol,
+                PropertyDescriptor::builder()
+                    .value(date_time_format)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(false),
+                context,
+            )?;
+            // b. Return this.
+            Ok(this)
+        } else {
+            // 2. Return dateTimeFormat.
+            Ok(date_time_format.into())
+        }
+    }
+}
+
+impl DateTimeFormat {
+    /// Abstract operation [`CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`][spec].
+    ///
+    /// Creates the internal data of a `DateTimeFormat`. Allocating the object itself is left
+    /// to the caller, which allows builtins such as `Date.prototype.toLocaleString` to format
+    /// dates without creating a new object.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn create(
+        locales: &JsValue,
+        options: &JsValue,
+        required: FormatType,
+        defaults: FormatDefaults,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 4. Let opt be a new Record.
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 6. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 7. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 8. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 9. Set opt.[[ca]] to calendar.
+        let calendar = get_option::<Value>(&options, js_string!("calendar"), context)?;
+
+        // 10. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 11. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 12. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<Value>(&options, js_string!("numberingSystem"), context)?;
+
+        // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+        let hour12 = get_option::<bool>(&options, js_string!("hour12"), context)?;
+
+        // 14. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        // 15. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        // 16. Set opt.[[hc]] to hourCycle.
+        let hour_cycle = get_option::<HourCycle>(&options, js_string!("hourCycle"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: DateTimeFormatLocaleOptions {
+                calendar,
+                numbering_system,
+                hour_cycle,
+                hour12: hour12.is_some(),
+            },
+        };
+
+        // 17. Let localeData be %DateTimeFormat%.[[LocaleData]].
+        // 18. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %DateTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        // 19. Set dateTimeFormat.[[Locale]] to r.[[locale]].
+        // 20. Let resolvedCalendar be r.[[ca]].
+        // 21. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+        // 22. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 23. Let dataLocale be r.[[dataLocale]].
+        let mut data_locale = DataLocale::from(&locale);
+
+        // 24. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 25. If hour12 is true, then
+        //     a. Let hc be dataLocaleData.[[hourCycle12]].
+        // 26. Else if hour12 is false, then
+        //     a. Let hc be dataLocaleData.[[hourCycle24]].
+        // 27. Else,
+        //     a. Assert: hour12 is undefined.
+        //     b. Let hc be r.[[hc]].
+        //     c. If hc is null, set hc to dataLocaleData.[[hourCycle]].
+        let hc = match hour12 {
+            Some(true) => HourCycle::H12,
+            Some(false) => HourCycle::H23,
+            None => match intl_options.service_options.hour_cycle {
+                Some(hc) => hc,
+                None => {
+                    match load_data::<TimeLengthsV1Marker>(context.intl_provider(), &data_locale)?
+                        .get()
+                        .preferred_hour_cycle
+                    {
+                        CoarseHourCycle::H11H12 => HourCycle::H12,
+                        CoarseHourCycle::H23H24 => HourCycle::H23,
+                    }
+                }
+            },
+        };
+        data_locale.set_unicode_ext(key!("hc"), hour_cycle_to_value(hc));
+
+        // 28. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get(js_string!("timeZone"), context)?;
+
+        // 29. If timeZone is undefined, then
+        let time_zone = if time_zone.is_undefined() {
+            // a. Set timeZone to DefaultTimeZone().
+            FormatTimeZone::Local
+        } else {
+            // 30. Else,
+            //     a. Set timeZone to ? ToString(timeZone).
+            let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+
+            // 31. If IsTimeZoneOffsetString(timeZone) is true, then
+            //     a. Let parseResult be ParseText(StringToCodePoints(timeZone), UTCOffset).
+            //     b. Assert: parseResult is a Parse Node.
+            //     c. If parseResult contains more than one MinuteSecond Parse Node, throw a RangeError exception.
+            //     d. Let offsetNanoseconds be ParseTimeZoneOffsetString(timeZone).
+            //     e. Let offsetMinutes be offsetNanoseconds / (6 × 10^10).
+            //     f. Assert: offsetMinutes is an integer.
+            //     g. Set timeZone to FormatOffsetTimeZoneIdentifier(offsetMinutes).
+            // 32. Else if IsValidTimeZoneName(timeZone) is true, then
+            //     a. Set timeZone to CanonicalizeTimeZoneName(timeZone).
+            // 33. Else,
+            //     a. Throw a RangeError exception.
+            FormatTimeZone::parse(&time_zone).ok_or_else(|| {
+                JsNativeError::range().with_message(format!("unsupported time zone `{time_zone}`"))
+            })?
+        };
+
+        // 34. Set dateTimeFormat.[[TimeZone]] to timeZone.
+        // 35. Let formatOptions be a new Record.
+        // 36. Set formatOptions.[[hourCycle]] to hc.
+        // 37. Let hasExplicitFormatComponents be false.
+        // 38. For each row of Table 16, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the current row.
+        //     b. If prop is "fractionalSecondDigits", then
+        //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        //     c. Else,
+        //         i. Let values be a List whose elements are the strings given in the Values column of the current row.
+        //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+        //     d. Set formatOptions.[[<prop>]] to value.
+        //     e. If value is not undefined, then
+        //         i. Set hasExplicitFormatComponents to true.
+        let mut components = DateTimeComponents::from_options(&options, context)?;
+
+        // 39. Let formatMatcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+        // ICU4X only provides a best fit matcher, so the option is read but otherwise ignored.
+        let _format_matcher =
+            get_option::<FormatMatcher>(&options, js_string!("formatMatcher"), context)?;
+
+        // 40. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 41. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+        let date_style = get_option::<DateTimeStyle>(&options, js_string!("dateStyle"), context)?;
+
+        // 42. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 43. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+        let time_style = get_option::<DateTimeStyle>(&options, js_string!("timeStyle"), context)?;
+
+        // 44. If dateStyle is not undefined or timeStyle is not undefined, then
+        let format = if date_style.is_some() || time_style.is_some() {
+            // a. If hasExplicitFormatComponents is true, then
+            if !components.is_empty() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("cannot use `dateStyle` or `timeStyle` with explicit components")
+                    .into());
+            }
+
+            // b. If required is date and timeStyle is not undefined, then
+            if required == FormatType::Date && time_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("cannot use `timeStyle` when formatting only dates")
+                    .into());
+            }
+
+            // c. If required is time and dateStyle is not undefined, then
+            if required == FormatType::Time && date_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("cannot use `dateStyle` when formatting only times")
+                    .into());
+            }
+
+            // d. Let styles be dataLocaleData.[[styles]].[[<resolvedCalendar>]].
+            // e. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+            FormatOptions::Styles {
+                date: date_style,
+                time: time_style,
+            }
+        } else {
+            // 45. Else,
+            //     a. Let needDefaults be true.
+            //     b. If required is date or any, then
+            //         i. For each property name prop of « "weekday", "year", "month", "day" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            //     c. If required is time or any, then
+            //         i. For each property name prop of « "dayPeriod", "hour", "minute", "second", "fractionalSecondDigits" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            let need_defaults = !(matches!(required, FormatType::Date | FormatType::Any)
+                && components.has_date()
+                || matches!(required, FormatType::Time | FormatType::Any) && components.has_time());
+
+            if need_defaults {
+                // d. If needDefaults is true and defaults is either date or all, then
+                if matches!(defaults, FormatDefaults::Date | FormatDefaults::All) {
+                    // i. For each property name prop of « "year", "month", "day" », do
+                    //     1. Set formatOptions.[[<prop>]] to "numeric".
+                    components.year = Some(NumericStyle::Numeric);
+                    components.month = Some(MonthStyle::Numeric);
+                    components.day = Some(NumericStyle::Numeric);
+                }
+
+                // e. If needDefaults is true and defaults is either time or all, then
+                if matches!(defaults, FormatDefaults::Time | FormatDefaults::All) {
+                    // i. For each property name prop of « "hour", "minute", "second" », do
+                    //     1. Set formatOptions.[[<prop>]] to "numeric".
+                    components.hour = Some(NumericStyle::Numeric);
+                    components.minute = Some(NumericStyle::Numeric);
+                    components.second = Some(NumericStyle::Numeric);
+                }
+            }
+
+            // f. Let formats be dataLocaleData.[[formats]].[[<resolvedCalendar>]].
+            // g. If formatMatcher is "basic", then
+            //     i. Let bestFormat be BasicFormatMatcher(formatOptions, formats).
+            // h. Else,
+            //     i. Let bestFormat be BestFitFormatMatcher(formatOptions, formats).
+            FormatOptions::Components(components)
+        };
+
+        // 46. Set dateTimeFormat.[[DateTimeFormat]] to bestFormat.
+        let formatter = TypedZonedDateTimeFormatter::<Gregorian>::try_new_experimental_unstable(
+            context.intl_provider(),
+            &data_locale,
+            format.to_icu(hc),
+            TimeZoneFormatterOptions::default(),
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let pattern = select_pattern(format, hc, &data_locale, context.intl_provider())?;
+
+        // 47. If bestFormat has a field [[hour]], then
+        //     a. Set dateTimeFormat.[[HourCycle]] to hc.
+        let has_hour = pattern.items.iter().any(|item| {
+            matches!(
+                item,
+                PatternItem::Field(Field {
+                    symbol: FieldSymbol::Hour(_),
+                    ..
+                })
+            )
+        });
+
+        // 48. Return dateTimeFormat.
+        Ok(Self {
+            locale,
+            numbering_system: intl_options.service_options.numbering_system,
+            time_zone,
+            hour_cycle: has_hour.then_some(hc),
+            format,
+            pattern,
+            formatter,
+            bound_format: None,
+        })
+    }
+
+    /// Converts the time value `x` into the date and time zone used to format it.
+    fn to_zoned_date_time(
+        &self,
+        x: f64,
+        context: &Context,
+    ) -> JsResult<(DateTime<Gregorian>, CustomTimeZone)> {
+        // 1. Let x be TimeClip(x).
+        let x = time_clip(x);
+
+        // 2. If x is NaN, throw a RangeError exception.
+        if x.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format an invalid date")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
+        // 4. Let tm be ToLocalTime(epochNanoseconds, dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let offset = self.time_zone.offset_seconds(x, context);
+        let t = x + f64::from(offset) * 1000.0;
+
+        let mut date_time = DateTime::try_new_gregorian_datetime(
+            year_from_time(t),
+            month_from_time(t) + 1,
+            date_from_time(t),
+            hour_from_time(t),
+            min_from_time(t),
+            sec_from_time(t),
+        )
+        .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+        date_time.time.nanosecond = NanoSecond::try_from(u32::from(ms_from_time(t)) * 1_000_000)
+            .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+        let offset = GmtOffset::try_from_offset_seconds(offset)
+            .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+        Ok((date_time, CustomTimeZone::new_with_offset(offset)))
+    }
+
+    /// Abstract operation [`FormatDateTime ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format_date_time(&self, x: f64, context: &Context) -> JsResult<String> {
+        let (date_time, time_zone) = self.to_zoned_date_time(x, context)?;
+        Ok(self.formatter.format_to_string(&date_time, &time_zone))
+    }
+
+    /// Abstract operation [`FormatDateTimeToParts ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetimetoparts
+    fn format_date_time_to_parts(
+        &self,
+        x: f64,
+        context: &Context,
+    ) -> JsResult<Vec<(&'static str, String)>> {
+        let formatted = self.format_date_time(x, context)?;
+        Ok(split_into_parts(&self.pattern, formatted))
+    }
+
+    /// Abstract operation [`PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`][spec].
+    ///
+    /// Returns the parts of the formatted range, along with the source of each part.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+    fn partition_date_time_range(
+        &self,
+        x: f64,
+        y: f64,
+        context: &Context,
+    ) -> JsResult<Vec<(&'static str, String, &'static str)>> {
+        let start = self.format_date_time_to_parts(x, context)?;
+        let end = self.format_date_time_to_parts(y, context)?;
+
+        // If both dates format to the same string, the range collapses into a single date.
+        if start == end {
+            return Ok(start
+                .into_iter()
+                .map(|(typ, value)| (typ, value, "shared"))
+                .collect());
+        }
+
+        // TODO: ICU4X doesn't support interval formats yet, so the dates are always joined
+        // with the CLDR interval fallback pattern.
+        let mut parts = Vec::with_capacity(start.len() + end.len() + 1);
+        parts.extend(
+            start
+                .into_iter()
+                .map(|(typ, value)| (typ, value, "startRange")),
+        );
+        parts.push(("literal", String::from("\u{2009}–\u{2009}"), "shared"));
+        parts.extend(end.into_iter().map(|(typ, value)| (typ, value, "endRange")));
+        Ok(parts)
+    }
+
+    /// [`Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in date and
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`get Intl.DateTimeFormat.prototype.format`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf_clone = dtf.clone();
+        let mut dtf = dtf.borrow_mut();
+
+        let bound_format = if let Some(f) = dtf.data.bound_format.clone() {
+            f
+        } else {
+            // 4. If dtf.[[BoundFormat]] is undefined, then
+            //     a. Let F be a new built-in function object as defined in DateTime Format Functions (11.5.4.1).
+            //     b. Set F.[[DateTimeFormat]] to dtf.
+            //     c. Set dtf.[[BoundFormat]] to F.
+            let bound_format = FunctionObjectBuilder::new(
+                context.realm(),
+                // DateTime Format Functions
+                // <https://tc39.es/ecma402/#sec-datetime-format-functions>
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, dtf, context| {
+                        // 1. Let dtf be F.[[DateTimeFormat]].
+                        // 2. Assert: dtf is an Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+                        // 3. If date is not provided or is undefined, then
+                        //     a. Let x be ! Call(%Date.now%, undefined).
+                        // 4. Else,
+                        //     a. Let x be ? ToNumber(date).
+                        let x = to_date_time_value(args.get_or_undefined(0), context)?;
+
+                        // 5. Return ? FormatDateTime(dtf, x).
+                        let formatted = dtf.borrow().data.format_date_time(x, context)?;
+                        Ok(js_string!(formatted).into())
+                    },
+                    dtf_clone,
+                ),
+            )
+            .length(1)
+            .build();
+
+            dtf.data.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec].
+    ///
+    /// Returns an array of objects representing the date string in parts that can be used for
+    /// custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DateTimeFormat` object",
+                )
+            })?;
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = to_date_time_value(args.get_or_undefined(0), context)?;
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let parts = dtf.borrow().data.format_date_time_to_parts(x, context)?;
+        Ok(create_parts_array(
+            parts.into_iter().map(|(typ, value)| (typ, value, None)),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec].
+    ///
+    /// Formats a date range in the most concise way based on the locale and options provided
+    /// when instantiating the `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let (dtf, x, y) = range_arguments(this, args, "formatRange", context)?;
+
+        // 7. Return ? FormatDateTimeRange(dtf, x, y).
+        let parts = dtf.borrow().data.partition_date_time_range(x, y, context)?;
+        let result = parts
+            .into_iter()
+            .map(|(_, value, _)| value)
+            .collect::<String>();
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRangeToParts ( startDate, endDate )`][spec].
+    ///
+    /// Returns an array of locale-specific tokens representing each part of the formatted date
+    /// range produced by `Intl.DateTimeFormat` formatters.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatRangeToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let (dtf, x, y) = range_arguments(this, args, "formatRangeToParts", context)?;
+
+        // 7. Return ? FormatDateTimeRangeToParts(dtf, x, y).
+        let parts = dtf.borrow().data.partition_date_time_range(x, y, context)?;
+        Ok(create_parts_array(
+            parts
+                .into_iter()
+                .map(|(typ, value, source)| (typ, value, Some(source))),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and date and time formatting
+    /// options computed during the construction of the current `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf = dtf.borrow();
+        let dtf = &dtf.data;
+
+        let time_zone = dtf.time_zone.identifier(context);
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 18, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If there is an Internal Slot value in the current row, then
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Else,
+        //         i. Let format be dtf.[[DateTimeFormat]].
+        //         ii. If format has a field [[<p>]] and dtf.[[DateStyle]] is undefined and dtf.[[TimeStyle]] is undefined, then
+        //             1. Let v be format.[[<p>]].
+        //         iii. Else,
+        //             1. Let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, then
+        //             1. Let conversion be the Conversion value of the current row.
+        //             2. If conversion is hour12, then
+        //                 a. If v is "h11" or "h12", set v to true. Otherwise, set v to false.
+        //             3. Else,
+        //                 a. Assert: conversion is number.
+        //                 b. Set v to 𝔽(v).
+        //         ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(dtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("calendar"),
+                js_string!("gregory"),
+                Attribute::all(),
+            );
+        if let Some(nu) = &dtf.numbering_system {
+            options.property(
+                js_string!("numberingSystem"),
+                js_string!(nu.to_string()),
+                Attribute::all(),
+            );
+        }
+        options.property(js_string!("timeZone"), time_zone, Attribute::all());
+        if let Some(hc) = dtf.hour_cycle {
+            options
+                .property(
+                    js_string!("hourCycle"),
+                    hour_cycle_to_js_string(hc),
+                    Attribute::all(),
+                )
+                .property(
+                    js_string!("hour12"),
+                    matches!(hc, HourCycle::H11 | HourCycle::H12),
+                    Attribute::all(),
+                );
+        }
+
+        match dtf.format {
+            FormatOptions::Styles { date, time } => {
+                if let Some(date) = date {
+                    options.property(
+                        js_string!("dateStyle"),
+                        date.to_js_string(),
+                        Attribute::all(),
+                    );
+                }
+                if let Some(time) = time {
+                    options.property(
+                        js_string!("timeStyle"),
+                        time.to_js_string(),
+                        Attribute::all(),
+                    );
+                }
+            }
+            FormatOptions::Components(requested) => {
+                let bag = components::Bag::from(&PatternPlurals::from(dtf.pattern.clone()));
+                let resolved = DateTimeComponents::from_bag(&bag, requested.time_zone_name);
+
+                let text = [
+                    (
+                        js_string!("weekday"),
+                        resolved.weekday.map(TextStyle::to_js_string),
+                    ),
+                    (js_string!("era"), resolved.era.map(TextStyle::to_js_string)),
+                    (
+                        js_string!("year"),
+                        resolved.year.map(NumericStyle::to_js_string),
+                    ),
+                    (
+                        js_string!("month"),
+                        resolved.month.map(MonthStyle::to_js_string),
+                    ),
+                    (
+                        js_string!("day"),
+                        resolved.day.map(NumericStyle::to_js_string),
+                    ),
+                    (
+                        js_string!("dayPeriod"),
+                        resolved.day_period.map(TextStyle::to_js_string),
+                    ),
+                    (
+                        js_string!("hour"),
+                        resolved.hour.map(NumericStyle::to_js_string),
+                    ),
+                    (
+                        js_string!("minute"),
+                        resolved.minute.map(NumericStyle::to_js_string),
+                    ),
+                    (
+                        js_string!("second"),
+                        resolved.second.map(NumericStyle::to_js_string),
+                    ),
+                ];
+                for (key, value) in text {
+                    if let Some(value) = value {
+                        options.property(key, value, Attribute::all());
+                    }
+                }
+                if let Some(digits) = resolved.fractional_second_digits {
+                    options.property(
+                        js_string!("fractionalSecondDigits"),
+                        digits,
+                        Attribute::all(),
+                    );
+                }
+                if let Some(tz) = resolved.time_zone_name {
+                    options.property(
+                        js_string!("timeZoneName"),
+                        tz.to_js_string(),
+                        Attribute::all(),
+                    );
+                }
+            }
+        }
+
+        // 6. Return options.
+        Ok(options.build().into())
+    }
+}
+
+/// Abstract operation [`UnwrapDateTimeFormat ( dtf )`][spec].
+///
+/// This also checks that the returned object is a `DateTimeFormat`, which skips the
+/// call to `RequireInternalSlot`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-unwrapdatetimeformat
+fn unwrap_date_time_format(
+    dtf: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject<DateTimeFormat>> {
+    // 1. If Type(dtf) is not Object, throw a TypeError exception.
+    let dtf_o = dtf.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("value was not an `Intl.DateTimeFormat` object")
+    })?;
+
+    if let Ok(dtf) = dtf_o.clone().downcast::<DateTimeFormat>() {
+        // 3. Return dtf.
+        return Ok(dtf);
+    }
+
+    // 2. If dtf does not have an [[InitializedDateTimeFormat]] internal slot and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, dtf)
+    //    is true, then
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .date_time_format()
+        .constructor();
+    if JsValue::ordinary_has_instance(&constructor.into(), dtf, context)? {
+        let fallback_symbol = context
+            .intrinsics()
+            .objects()
+            .intl()
+            .borrow()
+            .data
+            .fallback_symbol();
+
+        //    a. Return ? Get(dtf, %Intl%.[[FallbackSymbol]]).
+        let dtf = dtf_o.get(fallback_symbol, context)?;
+        if let JsValue::Object(dtf) = dtf {
+            if let Ok(dtf) = dtf.downcast::<DateTimeFormat>() {
+                return Ok(dtf);
+            }
+        }
+    }
+
+    Err(JsNativeError::typ()
+        .with_message("object was not an `Intl.DateTimeFormat` object")
+        .into())
+}
+
+/// Gets the time value of the `date` argument of the formatting methods, defaulting to the
+/// current time if the argument is undefined.
+fn to_date_time_value(date: &JsValue, context: &mut Context) -> JsResult<f64> {
+    if date.is_undefined() {
+        Ok(context.host_hooks().utc_now() as f64)
+    } else {
+        date.to_number(context)
+    }
+}
+
+/// Shared steps of `formatRange` and `formatRangeToParts`.
+fn range_arguments(
+    this: &JsValue,
+    args: &[JsValue],
+    method: &str,
+    context: &mut Context,
+) -> JsResult<(JsObject<DateTimeFormat>, f64, f64)> {
+    // 1. Let dtf be this value.
+    // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+    let dtf = this
+        .as_object()
+        .and_then(|o| o.clone().downcast::<DateTimeFormat>().ok())
+        .ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "`{method}` can only be called on an `Intl.DateTimeFormat` object"
+            ))
+        })?;
+
+    let start_date = args.get_or_undefined(0);
+    let end_date = args.get_or_undefined(1);
+
+    // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+    if start_date.is_undefined() || end_date.is_undefined() {
+        return Err(JsNativeError::typ()
+            .with_message(format!("`{method}` requires both a start and an end date"))
+            .into());
+    }
+
+    // 4. Let x be ? ToNumber(startDate).
+    let x = start_date.to_number(context)?;
+
+    // 5. Let y be ? ToNumber(endDate).
+    let y = end_date.to_number(context)?;
+
+    Ok((dtf, x, y))
+}
+
+/// Creates an array of part objects, with an optional `source` property.
+fn create_parts_array(
+    parts: impl IntoIterator<Item = (&'static str, String, Option<&'static str>)>,
+    context: &mut Context,
+) -> JsObject {
+    let parts = parts
+        .into_iter()
+        .map(|(typ, value, source)| {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+            if let Some(source) = source {
+                o.create_data_property_or_throw(js_string!("source"), js_string!(source), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            o.into()
+        })
+        .collect::<Vec<JsValue>>();
+
+    Array::create_array_from_list(parts, context)
+}
+
+/// Abstract operation [`FormatDateTime`][spec] for the `toLocaleString`, `toLocaleDateString`
+/// and `toLocaleTimeString` methods of `Date.prototype`.
+///
+/// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
+pub(crate) fn format_date_time_locale(
+    x: f64,
+    locales: &JsValue,
+    options: &JsValue,
+    required: FormatType,
+    defaults: FormatDefaults,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 3. If x is NaN, return "Invalid Date".
+    if x.is_nan() {
+        return Ok(js_string!("Invalid Date").into());
+    }
+
+    // 4. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, required, defaults).
+    let date_format = DateTimeFormat::create(locales, options, required, defaults, context)?;
+
+    // 5. Return ! FormatDateTime(dateFormat, x).
+    Ok(js_string!(date_format.format_date_time(x, context)?).into())
+}
+
+/// Returns `true` if `Intl.DateTimeFormat` can format dates using the calendar `ca`.
+pub(in crate::builtins::intl) fn is_supported_calendar(ca: &Value) -> bool {
+    // TODO: support calendars other than the gregorian calendar.
+    ca == &value!("gregory")
+}
+
+/// Converts an hour cycle into its Unicode extension value.
+fn hour_cycle_to_value(hc: HourCycle) -> Value {
+    match hc {
+        HourCycle::H11 => value!("h11"),
+        HourCycle::H12 => value!("h12"),
+        HourCycle::H23 => value!("h23"),
+        HourCycle::H24 => value!("h24"),
+    }
+}
+
+/// Loads the data of the marker `M` for `locale`.
+fn load_data<M: KeyedDataMarker>(
+    provider: &IntlProvider,
+    locale: &DataLocale,
+) -> JsResult<DataPayload<M>>
+where
+    IntlProvider: DataProvider<M>,
+{
+    provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+}
+
+/// Selects the pattern used by ICU4X to format dates with the provided options.
+///
+/// ICU4X doesn't expose the pattern of its formatters, which is needed to split formatted dates
+/// into parts, so this replicates the pattern selection of `TypedZonedDateTimeFormatter`.
+fn select_pattern(
+    format: FormatOptions,
+    hour_cycle: HourCycle,
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<Pattern<'static>> {
+    fn by_style<'a>(patterns: &'a LengthPatternsV1<'a>, style: DateTimeStyle) -> Pattern<'a> {
+        match style {
+            DateTimeStyle::Full => patterns.full.clone(),
+            DateTimeStyle::Long => patterns.long.clone(),
+            DateTimeStyle::Medium => patterns.medium.clone(),
+            DateTimeStyle::Short => patterns.short.clone(),
+        }
+    }
+
+    let date_lengths = load_data::<GregorianDateLengthsV1Marker>(provider, locale)?;
+    let date_lengths = date_lengths.get();
+
+    let components = match format {
+        FormatOptions::Styles { date, time } => {
+            let time_lengths = load_data::<TimeLengthsV1Marker>(provider, locale)?;
+            let time_lengths = time_lengths.get();
+            let time_patterns = match hour_cycle {
+                HourCycle::H11 | HourCycle::H12 => &time_lengths.time_h11_h12,
+                HourCycle::H23 | HourCycle::H24 => &time_lengths.time_h23_h24,
+            };
+
+            let date_pattern = date.map(|style| by_style(&date_lengths.date, style));
+            let time_pattern = time.map(|style| by_style(time_patterns, style));
+
+            return match (date, date_pattern, time_pattern) {
+                (Some(style), Some(date), Some(time)) => {
+                    let combinations = &date_lengths.length_combinations;
+                    let generic = match style {
+                        DateTimeStyle::Full => &combinations.full,
+                        DateTimeStyle::Long => &combinations.long,
+                        DateTimeStyle::Medium => &combinations.medium,
+                        DateTimeStyle::Short => &combinations.short,
+                    };
+                    generic
+                        .clone()
+                        .combined(date, time)
+                        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+                }
+                (_, Some(pattern), None) | (_, None, Some(pattern)) => Ok(pattern.into_owned()),
+                _ => Ok(Pattern::default()),
+            };
+        }
+        FormatOptions::Components(components) => components,
+    };
+
+    let mut skeleton_locale = locale.clone();
+    skeleton_locale.set_unicode_ext(key!("ca"), value!("gregory"));
+    let skeletons = load_data::<DateSkeletonPatternsV1Marker>(provider, &skeleton_locale)?;
+
+    match create_best_pattern_for_fields(
+        skeletons.get(),
+        &date_lengths.length_combinations,
+        &component_fields(&components, hour_cycle),
+        &components.to_bag(hour_cycle),
+        false,
+    ) {
+        BestSkeleton::AllFieldsMatch(patterns) | BestSkeleton::MissingOrExtraFields(patterns) => {
+            Ok(patterns
+                .patterns_iter()
+                .next()
+                .cloned()
+                .unwrap_or_default()
+                .into_owned())
+        }
+        BestSkeleton::NoMatch => Err(JsNativeError::typ()
+            .with_message("could not find a pattern for the requested date and time components")
+            .into()),
+    }
+}
+
+/// Gets the skeleton fields of the requested components.
+///
+/// This mirrors the conversion done internally by ICU4X for components bags.
+fn component_fields(components: &DateTimeComponents, hour_cycle: HourCycle) -> Vec<Field> {
+    let numeric = |style| match style {
+        NumericStyle::Numeric => FieldLength::One,
+        NumericStyle::TwoDigit => FieldLength::TwoDigit,
+    };
+
+    let mut fields = Vec::new();
+    if let Some(era) = components.era {
+        fields.push(Field {
+            symbol: FieldSymbol::Era,
+            length: match era {
+                TextStyle::Short => FieldLength::Abbreviated,
+                TextStyle::Long => FieldLength::Wide,
+                TextStyle::Narrow => FieldLength::Narrow,
+            },
+        });
+    }
+    if let Some(year) = components.year {
+        fields.push(Field {
+            symbol: FieldSymbol::Year(fields::Year::Calendar),
+            length: numeric(year),
+        });
+    }
+    if let Some(month) = components.month {
+        fields.push(Field {
+            symbol: FieldSymbol::Month(fields::Month::Format),
+            length: match month {
+                MonthStyle::Numeric => FieldLength::One,
+                MonthStyle::TwoDigit => FieldLength::TwoDigit,
+                MonthStyle::Long => FieldLength::Wide,
+                MonthStyle::Short => FieldLength::Abbreviated,
+                MonthStyle::Narrow => FieldLength::Narrow,
+            },
+        });
+    }
+    if let Some(day) = components.day {
+        fields.push(Field {
+            symbol: FieldSymbol::Day(fields::Day::DayOfMonth),
+            length: numeric(day),
+        });
+    }
+    if let Some(weekday) = components.weekday {
+        fields.push(Field {
+            symbol: FieldSymbol::Weekday(fields::Weekday::Format),
+            length: match weekday {
+                TextStyle::Long => FieldLength::Wide,
+                TextStyle::Short => FieldLength::One,
+                TextStyle::Narrow => FieldLength::Narrow,
+            },
+        });
+    }
+    if let Some(hour) = components.hour {
+        fields.push(Field {
+            symbol: FieldSymbol::Hour(match hour_cycle {
+                HourCycle::H11 | HourCycle::H12 => fields::Hour::H12,
+                HourCycle::H23 | HourCycle::H24 => fields::Hour::H23,
+            }),
+            length: numeric(hour),
+        });
+    }
+    if let Some(minute) = components.minute {
+        fields.push(Field {
+            symbol: FieldSymbol::Minute,
+            length: numeric(minute),
+        });
+    }
+    if let Some(second) = components.second {
+        fields.push(Field {
+            symbol: FieldSymbol::Second(fields::Second::Second),
+            length: numeric(second),
+        });
+    }
+    if let Some(digits) = components.fractional_second_digits {
+        fields.push(Field {
+            symbol: FieldSymbol::Second(fields::Second::FractionalSecond),
+            length: FieldLength::Fixed(digits),
+        });
+    }
+    if components.time_zone_name.is_some() {
+        fields.push(Field {
+            symbol: FieldSymbol::TimeZone(fields::TimeZone::LowerV),
+            length: FieldLength::One,
+        });
+    }
+    fields
+}
+
+/// Splits a formatted date into the parts described by `pattern`.
+///
+/// The literals of the pattern are used to find the boundaries of each field. If the formatted
+/// string doesn't match the pattern, the whole string is returned as a single literal part.
+fn split_into_parts(pattern: &Pattern<'_>, formatted: String) -> Vec<(&'static str, String)> {
+    enum Segment {
+        Literal(String),
+        Field(&'static str),
+    }
+
+    let mut segments = Vec::new();
+    for item in pattern.items.iter() {
+        match item {
+            PatternItem::Literal(c) => {
+                if let Some(Segment::Literal(literal)) = segments.last_mut() {
+                    literal.push(c);
+                } else {
+                    segments.push(Segment::Literal(c.into()));
+                }
+            }
+            PatternItem::Field(field) => segments.push(Segment::Field(match field.symbol {
+                FieldSymbol::Era => "era",
+                FieldSymbol::Year(_) => "year",
+                FieldSymbol::Month(_) => "month",
+                FieldSymbol::Day(_) => "day",
+                FieldSymbol::Weekday(_) => "weekday",
+                FieldSymbol::DayPeriod(_) => "dayPeriod",
+                FieldSymbol::Hour(_) => "hour",
+                FieldSymbol::Minute => "minute",
+                FieldSymbol::Second(fields::Second::FractionalSecond) => "fractionalSecond",
+                FieldSymbol::Second(_) => "second",
+                FieldSymbol::TimeZone(_) => "timeZoneName",
+                FieldSymbol::Week(_) => "literal",
+            })),
+        }
+    }
+
+    let split = || {
+        let mut parts = Vec::with_capacity(segments.len());
+        let mut rest = formatted.as_str();
+        let mut segments = segments.iter().peekable();
+        while let Some(segment) = segments.next() {
+            match segment {
+                Segment::Literal(literal) => {
+                    rest = rest.strip_prefix(literal.as_str())?;
+                    parts.push(("literal", literal.clone()));
+                }
+                Segment::Field(typ) => {
+                    let end = match segments.peek() {
+                        None => rest.len(),
+                        Some(Segment::Literal(literal)) => {
+                            // Fields are never empty, so the search starts after the first character.
+                            let start = rest.chars().next()?.len_utf8();
+                            start + rest[start..].find(literal.as_str())?
+                        }
+                        Some(Segment::Field(_)) => return None,
+                    };
+                    if end == 0 {
+                        return None;
+                    }
+                    parts.push((*typ, rest[..end].to_owned()));
+                    rest = &rest[end..];
+                }
+            }
+        }
+        rest.is_empty().then_some(parts)
+    };
+
+    match split() {
+        Some(parts) => parts,
+        None => vec![("literal", formatted)],
+    }
+}
@@ -0,0 +1,439 @@
+use icu_datetime::options::{
+    components::{self, Bag},
+    length,
+    preferences::{self, HourCycle},
+};
+
+use crate::{
+    builtins::{intl::options::get_number_option, options::get_option, options::OptionType},
+    js_string, Context, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+
+impl OptionType for HourCycle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `h11`, `h12`, `h23` or `h24`")
+                .into()),
+        }
+    }
+}
+
+/// Converts an hour cycle into its string representation.
+pub(crate) fn hour_cycle_to_js_string(hc: HourCycle) -> JsString {
+    match hc {
+        HourCycle::H11 => js_string!("h11"),
+        HourCycle::H12 => js_string!("h12"),
+        HourCycle::H23 => js_string!("h23"),
+        HourCycle::H24 => js_string!("h24"),
+    }
+}
+
+/// The `weekday`, `era` and `dayPeriod` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TextStyle {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl TextStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+
+    const fn to_icu(self) -> components::Text {
+        match self {
+            Self::Narrow => components::Text::Narrow,
+            Self::Short => components::Text::Short,
+            Self::Long => components::Text::Long,
+        }
+    }
+
+    const fn from_icu(text: components::Text) -> Option<Self> {
+        match text {
+            components::Text::Narrow => Some(Self::Narrow),
+            components::Text::Short => Some(Self::Short),
+            components::Text::Long => Some(Self::Long),
+            _ => None,
+        }
+    }
+}
+
+impl OptionType for TextStyle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `narrow`, `short` or `long`")
+                .into()),
+        }
+    }
+}
+
+/// The `year`, `day`, `hour`, `minute` and `second` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NumericStyle {
+    Numeric,
+    TwoDigit,
+}
+
+impl NumericStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+        }
+    }
+
+    const fn to_icu(self) -> components::Numeric {
+        match self {
+            Self::Numeric => components::Numeric::Numeric,
+            Self::TwoDigit => components::Numeric::TwoDigit,
+        }
+    }
+
+    const fn from_icu(numeric: components::Numeric) -> Option<Self> {
+        match numeric {
+            components::Numeric::Numeric => Some(Self::Numeric),
+            components::Numeric::TwoDigit => Some(Self::TwoDigit),
+            _ => None,
+        }
+    }
+}
+
+impl OptionType for NumericStyle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `numeric` or `2-digit`")
+                .into()),
+        }
+    }
+}
+
+/// The `month` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MonthStyle {
+    Numeric,
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl MonthStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+}
+
+impl OptionType for MonthStyle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(JsNativeError::range()
+                .with_message(
+                    "provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`",
+                )
+                .into()),
+        }
+    }
+}
+
+/// The `timeZoneName` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZoneNameStyle {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneNameStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+            Self::ShortOffset => js_string!("shortOffset"),
+            Self::LongOffset => js_string!("longOffset"),
+            Self::ShortGeneric => js_string!("shortGeneric"),
+            Self::LongGeneric => js_string!("longGeneric"),
+        }
+    }
+
+    const fn to_icu(self) -> components::TimeZoneName {
+        // TODO: ICU4X doesn't distinguish between the short and long localized GMT formats.
+        match self {
+            Self::Short => components::TimeZoneName::ShortSpecific,
+            Self::Long => components::TimeZoneName::LongSpecific,
+            Self::ShortOffset | Self::LongOffset => components::TimeZoneName::GmtOffset,
+            Self::ShortGeneric => components::TimeZoneName::ShortGeneric,
+            Self::LongGeneric => components::TimeZoneName::LongGeneric,
+        }
+    }
+}
+
+impl OptionType for TimeZoneNameStyle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            "shortOffset" => Ok(Self::ShortOffset),
+            "longOffset" => Ok(Self::LongOffset),
+            "shortGeneric" => Ok(Self::ShortGeneric),
+            "longGeneric" => Ok(Self::LongGeneric),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not a valid time zone name style")
+                .into()),
+        }
+    }
+}
+
+/// The `formatMatcher` option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum FormatMatcher {
+    Basic,
+    #[default]
+    BestFit,
+}
+
+impl OptionType for FormatMatcher {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "basic" => Ok(Self::Basic),
+            "best fit" => Ok(Self::BestFit),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `basic` or `best fit`")
+                .into()),
+        }
+    }
+}
+
+/// The `dateStyle` and `timeStyle` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl DateTimeStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Full => js_string!("full"),
+            Self::Long => js_string!("long"),
+            Self::Medium => js_string!("medium"),
+            Self::Short => js_string!("short"),
+        }
+    }
+
+    pub(crate) const fn to_date_length(self) -> length::Date {
+        match self {
+            Self::Full => length::Date::Full,
+            Self::Long => length::Date::Long,
+            Self::Medium => length::Date::Medium,
+            Self::Short => length::Date::Short,
+        }
+    }
+
+    pub(crate) const fn to_time_length(self) -> length::Time {
+        match self {
+            Self::Full => length::Time::Full,
+            Self::Long => length::Time::Long,
+            Self::Medium => length::Time::Medium,
+            Self::Short => length::Time::Short,
+        }
+    }
+}
+
+impl OptionType for DateTimeStyle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `full`, `long`, `medium` or `short`")
+                .into()),
+        }
+    }
+}
+
+/// The date and time components of a format, as listed in [Table 16][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-datetimeformat-components
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct DateTimeComponents {
+    pub(crate) weekday: Option<TextStyle>,
+    pub(crate) era: Option<TextStyle>,
+    pub(crate) year: Option<NumericStyle>,
+    pub(crate) month: Option<MonthStyle>,
+    pub(crate) day: Option<NumericStyle>,
+    pub(crate) day_period: Option<TextStyle>,
+    pub(crate) hour: Option<NumericStyle>,
+    pub(crate) minute: Option<NumericStyle>,
+    pub(crate) second: Option<NumericStyle>,
+    pub(crate) fractional_second_digits: Option<u8>,
+    pub(crate) time_zone_name: Option<TimeZoneNameStyle>,
+}
+
+impl DateTimeComponents {
+    /// Reads every component option from `options`, in table order.
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            weekday: get_option(options, js_string!("weekday"), context)?,
+            era: get_option(options, js_string!("era"), context)?,
+            year: get_option(options, js_string!("year"), context)?,
+            month: get_option(options, js_string!("month"), context)?,
+            day: get_option(options, js_string!("day"), context)?,
+            day_period: get_option(options, js_string!("dayPeriod"), context)?,
+            hour: get_option(options, js_string!("hour"), context)?,
+            minute: get_option(options, js_string!("minute"), context)?,
+            second: get_option(options, js_string!("second"), context)?,
+            fractional_second_digits: get_number_option(
+                options,
+                js_string!("fractionalSecondDigits"),
+                1,
+                3,
+                context,
+            )?,
+            time_zone_name: get_option(options, js_string!("timeZoneName"), context)?,
+        })
+    }
+
+    /// Returns `true` if no component was provided.
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns `true` if any of the date components was provided.
+    pub(crate) const fn has_date(&self) -> bool {
+        self.weekday.is_some() || self.year.is_some() || self.month.is_some() || self.day.is_some()
+    }
+
+    /// Returns `true` if any of the time components was provided.
+    pub(crate) const fn has_time(&self) -> bool {
+        self.day_period.is_some()
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+
+    /// Converts the requested components into an ICU4X components bag.
+    pub(crate) fn to_bag(self, hour_cycle: HourCycle) -> Bag {
+        // TODO: ICU4X doesn't support flexible day periods in components bags yet.
+        let mut bag = Bag::default();
+        bag.era = self.era.map(TextStyle::to_icu);
+        bag.year = self.year.map(|year| match year {
+            NumericStyle::Numeric => components::Year::Numeric,
+            NumericStyle::TwoDigit => components::Year::TwoDigit,
+        });
+        bag.month = self.month.map(|month| match month {
+            MonthStyle::Numeric => components::Month::Numeric,
+            MonthStyle::TwoDigit => components::Month::TwoDigit,
+            MonthStyle::Narrow => components::Month::Narrow,
+            MonthStyle::Short => components::Month::Short,
+            MonthStyle::Long => components::Month::Long,
+        });
+        bag.day = self.day.map(|day| match day {
+            NumericStyle::Numeric => components::Day::NumericDayOfMonth,
+            NumericStyle::TwoDigit => components::Day::TwoDigitDayOfMonth,
+        });
+        bag.weekday = self.weekday.map(TextStyle::to_icu);
+        bag.hour = self.hour.map(NumericStyle::to_icu);
+        bag.minute = self.minute.map(NumericStyle::to_icu);
+        bag.second = self.second.map(NumericStyle::to_icu);
+        bag.fractional_second = self.fractional_second_digits;
+        bag.time_zone_name = self.time_zone_name.map(TimeZoneNameStyle::to_icu);
+        if bag.hour.is_some() {
+            bag.preferences = Some(preferences::Bag::from_hour_cycle(hour_cycle));
+        }
+        bag
+    }
+
+    /// Gets the components that were resolved from an ICU4X components bag.
+    ///
+    /// ICU4X merges both offset styles into a single variant, so the time zone name style
+    /// must be provided separately.
+    pub(crate) fn from_bag(bag: &Bag, time_zone_name: Option<TimeZoneNameStyle>) -> Self {
+        let numeric = |n: Option<components::Numeric>| n.and_then(NumericStyle::from_icu);
+        Self {
+            weekday: bag.weekday.and_then(TextStyle::from_icu),
+            era: bag.era.and_then(TextStyle::from_icu),
+            year: bag.year.and_then(|year| match year {
+                components::Year::Numeric | components::Year::NumericWeekOf => {
+                    Some(NumericStyle::Numeric)
+                }
+                components::Year::TwoDigit | components::Year::TwoDigitWeekOf => {
+                    Some(NumericStyle::TwoDigit)
+                }
+                _ => None,
+            }),
+            month: bag.month.and_then(|month| match month {
+                components::Month::Numeric => Some(MonthStyle::Numeric),
+                components::Month::TwoDigit => Some(MonthStyle::TwoDigit),
+                components::Month::Narrow => Some(MonthStyle::Narrow),
+                components::Month::Short => Some(MonthStyle::Short),
+                components::Month::Long => Some(MonthStyle::Long),
+                _ => None,
+            }),
+            day: bag.day.and_then(|day| match day {
+                components::Day::NumericDayOfMonth | components::Day::DayOfWeekInMonth => {
+                    Some(NumericStyle::Numeric)
+                }
+                components::Day::TwoDigitDayOfMonth => Some(NumericStyle::TwoDigit),
+                _ => None,
+            }),
+            day_period: None,
+            hour: numeric(bag.hour),
+            minute: numeric(bag.minute),
+            second: numeric(bag.second),
+            fractional_second_digits: bag.fractional_second.filter(|&digits| digits > 0),
+            time_zone_name: bag.time_zone_name.and(time_zone_name),
+        }
+    }
+}
+
+/// Represents the `required` argument of the abstract operation `CreateDateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormatType {
+    Date,
+    Time,
+    Any,
+}
+
+/// Represents the `defaults` argument of the abstract operation `CreateDateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormatDefaults {
+    Date,
+    Time,
+    All,
+}
@@ -0,0 +1,176 @@
+use icu_datetime::pattern::runtime::Pattern;
+
+use super::{split_into_parts, FormatTimeZone};
+
+#[test]
+fn parse_time_zone() {
+    for utc in ["UTC", "utc", "Etc/UTC", "GMT", "etc/gmt"] {
+        assert_eq!(FormatTimeZone::parse(utc), Some(FormatTimeZone::Utc));
+    }
+
+    assert_eq!(
+        FormatTimeZone::parse("+01"),
+        Some(FormatTimeZone::Offset(60))
+    );
+    assert_eq!(
+        FormatTimeZone::parse("-0530"),
+        Some(FormatTimeZone::Offset(-330))
+    );
+    assert_eq!(
+        FormatTimeZone::parse("+13:45"),
+        Some(FormatTimeZone::Offset(825))
+    );
+
+    for invalid in [
+        "",
+        "+",
+        "+1",
+        "+123",
+        "+24",
+        "+01:60",
+        "+0:100",
+        "01:00",
+        "Europe/Berlin",
+    ] {
+        assert_eq!(FormatTimeZone::parse(invalid), None, "{invalid}");
+    }
+}
+
+#[test]
+fn split_formatted_date_into_parts() {
+    let pattern = "MMM d, y".parse::<Pattern<'_>>().unwrap();
+    assert_eq!(
+        split_into_parts(&pattern, String::from("Oct 17, 2026")),
+        vec![
+            ("month", String::from("Oct")),
+            ("literal", String::from(" ")),
+            ("day", String::from("17")),
+            ("literal", String::from(", ")),
+            ("year", String::from("2026")),
+        ]
+    );
+
+    let pattern = "d. MMMM y 'um' HH:mm".parse::<Pattern<'_>>().unwrap();
+    assert_eq!(
+        split_into_parts(&pattern, String::from("17. Oktober 2026 um 09:05")),
+        vec![
+            ("day", String::from("17")),
+            ("literal", String::from(". ")),
+            ("month", String::from("Oktober")),
+            ("literal", String::from(" ")),
+            ("year", String::from("2026")),
+            ("literal", String::from(" um ")),
+            ("hour", String::from("09")),
+            ("literal", String::from(":")),
+            ("minute", String::from("05")),
+        ]
+    );
+
+    // Strings that don't match the pattern are returned as a single literal.
+    assert_eq!(
+        split_into_parts(&pattern, String::from("17.10.2026")),
+        vec![("literal", String::from("17.10.2026"))]
+    );
+}
+
+#[cfg(feature = "intl_bundled")]
+mod data {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+    #[test]
+    fn format() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var date = Date.UTC(2026, 9, 17, 14, 5, 9);
+                var en = new Intl.DateTimeFormat("en", {
+                    year: "numeric", month: "short", day: "numeric", timeZone: "UTC"
+                });
+                var de = new Intl.DateTimeFormat("de", { dateStyle: "long", timeZone: "UTC" });
+                var time = new Intl.DateTimeFormat("en", {
+                    hour: "2-digit", minute: "2-digit", second: "2-digit",
+                    hour12: false, timeZone: "+02:00"
+                });
+            "#}),
+            TestAction::assert_eq("en.format(date)", js_string!("Oct 17, 2026")),
+            TestAction::assert_eq("de.format(date)", js_string!("17. Oktober 2026")),
+            TestAction::assert_eq("time.format(date)", js_string!("16:05:09")),
+            TestAction::assert_eq("[1, 2].map(time.format).length", 2),
+            TestAction::assert_eq(
+                "en.formatToParts(date).map(p => p.type).join()",
+                js_string!("month,literal,day,literal,year"),
+            ),
+            TestAction::assert_eq("en.formatRange(date, date)", js_string!("Oct 17, 2026")),
+            TestAction::assert_eq(
+                "en.formatRangeToParts(date, date + 86400000).map(p => p.source).join()",
+                js_string!(
+                    "startRange,startRange,startRange,startRange,startRange,shared,\
+                    endRange,endRange,endRange,endRange,endRange"
+                ),
+            ),
+            TestAction::assert_native_error(
+                "en.format(NaN)",
+                JsNativeErrorKind::Range,
+                "cannot format an invalid date",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn resolved_options() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var options = new Intl.DateTimeFormat("de", {
+                    hour: "numeric", minute: "numeric", timeZone: "-05:30"
+                }).resolvedOptions();
+            "#}),
+            TestAction::assert_eq("options.locale", js_string!("de")),
+            TestAction::assert_eq("options.calendar", js_string!("gregory")),
+            TestAction::assert_eq("options.timeZone", js_string!("-05:30")),
+            TestAction::assert_eq("options.hourCycle", js_string!("h23")),
+            TestAction::assert_eq("options.hour12", false),
+            TestAction::assert_eq("options.hour", js_string!("2-digit")),
+            TestAction::assert_eq("options.dateStyle", JsValue::undefined()),
+            TestAction::assert_eq(
+                r#"new Intl.DateTimeFormat("en", { timeStyle: "short" }).resolvedOptions().timeStyle"#,
+                js_string!("short"),
+            ),
+            TestAction::assert_native_error(
+                r#"new Intl.DateTimeFormat("en", { dateStyle: "short", year: "numeric" })"#,
+                JsNativeErrorKind::Type,
+                "cannot use `dateStyle` or `timeStyle` with explicit components",
+            ),
+            TestAction::assert_native_error(
+                r#"new Intl.DateTimeFormat("en", { timeZone: "Mars/Olympus_Mons" })"#,
+                JsNativeErrorKind::Range,
+                "unsupported time zone `Mars/Olympus_Mons`",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn date_to_locale_string() {
+        run_test_actions([
+            TestAction::run("var date = new Date(Date.UTC(2026, 9, 17, 14, 5, 9));"),
+            TestAction::assert_eq(
+                r#"date.toLocaleDateString("de", { timeZone: "UTC" })"#,
+                js_string!("17.10.2026"),
+            ),
+            TestAction::assert_eq(
+                r#"date.toLocaleTimeString("de", { timeZone: "UTC" })"#,
+                js_string!("14:05:09"),
+            ),
+            TestAction::assert_eq(
+                r#"date.toLocaleString("de", { timeZone: "UTC" })"#,
+                js_string!("17.10.2026, 14:05:09"),
+            ),
+            TestAction::assert_eq("new Date(NaN).toLocaleString()", js_string!("Invalid Date")),
+            TestAction::assert_native_error(
+                r#"date.toLocaleDateString("en", { timeStyle: "short" })"#,
+                JsNativeErrorKind::Type,
+                "cannot use `timeStyle` when formatting only dates",
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,557 @@
+//! This module implements the global `Intl.DisplayNames` object.
+//!
+//! `Intl.DisplayNames` is a built-in object that enables the consistent translation of language,
+//! region and script display names.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-displaynames-objects
+
+use std::fmt;
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_experimental::displaynames::{
+    provider::LanguageDisplayNamesV1Marker, DisplayNamesOptions, LanguageDisplayNames,
+    LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+};
+use icu_locid::{
+    extensions::unicode::Value,
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::Currency,
+    options::IntlOptions,
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Intl.DisplayNames` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayNamesType,
+    fallback: Fallback,
+    language_display: LanguageDisplay,
+    names: NamesData,
+}
+
+/// The locale data used to look up the display names of a type of code.
+enum NamesData {
+    Language {
+        languages: LanguageDisplayNames,
+        locales: Box<LocaleDisplayNamesFormatter>,
+    },
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    // TODO: Missing support from ICU4X for currency, calendar and date time field display names.
+    Unsupported,
+}
+
+impl fmt::Debug for NamesData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Language { .. } => f.write_str("Language"),
+            Self::Region(_) => f.write_str("Region"),
+            Self::Script(_) => f.write_str("Script"),
+            Self::Unsupported => f.write_str("Unsupported"),
+        }
+    }
+}
+
+impl Service for DisplayNames {
+    type LangMarker = LanguageDisplayNamesV1Marker;
+
+    type LocaleOptions = ();
+
+    fn resolve(locale: &mut Locale, _options: &mut Self::LocaleOptions, _provider: &IntlProvider) {
+        // `Intl.DisplayNames` doesn't have any relevant extension keys.
+        locale.extensions.unicode.clear();
+    }
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+    const P: usize = 3;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object with a `type`")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 9. Let r be ResolveLocale(%Intl.DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %Intl.DisplayNames%.[[RelevantExtensionKeys]], %Intl.DisplayNames%.[[LocaleData]]).
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        )?;
+
+        // 10. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 11. Set displayNames.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 12. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 13. If type is undefined, throw a TypeError exception.
+        // 14. Set displayNames.[[Type]] to type.
+        let typ: DisplayNamesType =
+            get_option(&options, js_string!("type"), context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message("`Intl.DisplayNames` requires a `type` option")
+            })?;
+
+        // 15. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 16. Set displayNames.[[Fallback]] to fallback.
+        let fallback = get_option(&options, js_string!("fallback"), context)?.unwrap_or_default();
+
+        // 17. Set displayNames.[[Locale]] to r.[[Locale]].
+        // 18. Let resolvedLocaleData be r.[[LocaleData]].
+        // 19. Let types be resolvedLocaleData.[[types]].
+        // 20. Assert: types is a Record (see 12.2.3).
+        // 21. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        // 22. Let typeFields be types.[[<type>]].
+        // 23. Assert: typeFields is a Record (see 12.2.3).
+        // 24. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let typeFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: typeFields is a Record (see 12.2.3).
+        let language_display =
+            get_option(&options, js_string!("languageDisplay"), context)?.unwrap_or_default();
+
+        // 25. Let styleFields be typeFields.[[<style>]].
+        // 26. Assert: styleFields is a Record (see 12.2.3).
+        // 27. Set displayNames.[[Fields]] to styleFields.
+        let names = NamesData::try_new(
+            typ,
+            style,
+            language_display,
+            &DataLocale::from(&locale),
+            context.intl_provider(),
+        )?;
+
+        // 28. Return displayNames.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display,
+                names,
+            },
+        )
+        .into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the translated display name of `code`, according to the locale and the type of
+    /// this `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`of` can only be called on an `Intl.DisplayNames` object")
+        })?;
+        let display_names = display_names.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`of` can only be called on an `Intl.DisplayNames` object")
+        })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let (code, name) = match &display_names.names {
+            NamesData::Language { languages, locales } => {
+                let locale = canonical_language_code(&code, context)?;
+                let name = languages
+                    .of(locale.id.language)
+                    .is_some()
+                    .then(|| locales.of(&locale).into_owned());
+                (locale.to_string(), name)
+            }
+            NamesData::Region(regions) => {
+                let region = Region::try_from_bytes(code.as_bytes())
+                    .map_err(|_| invalid_code(display_names.typ))?;
+                (region.to_string(), regions.of(region).map(str::to_owned))
+            }
+            NamesData::Script(scripts) => {
+                let script = Script::try_from_bytes(code.as_bytes())
+                    .map_err(|_| invalid_code(display_names.typ))?;
+                (script.to_string(), scripts.of(script).map(str::to_owned))
+            }
+            NamesData::Unsupported => {
+                (canonical_code_without_data(display_names.typ, &code)?, None)
+            }
+        };
+
+        if let Some(name) = name {
+            return Ok(js_string!(name).into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        if display_names.fallback == Fallback::Code {
+            return Ok(js_string!(code).into());
+        }
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+            )
+        })?;
+        let display_names = display_names.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 18, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(display_names.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                display_names.style.to_js_string(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("type"),
+                display_names.typ.to_js_string(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("fallback"),
+                display_names.fallback.to_js_string(),
+                Attribute::all(),
+            );
+
+        // [[LanguageDisplay]] is only set for the "language" type.
+        if display_names.typ == DisplayNamesType::Language {
+            options.property(
+                js_string!("languageDisplay"),
+                display_names.language_display.to_js_string(),
+                Attribute::all(),
+            );
+        }
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+}
+
+impl NamesData {
+    /// Loads the display names of `typ` for the provided locale and options.
+    fn try_new(
+        typ: DisplayNamesType,
+        style: Style,
+        language_display: LanguageDisplay,
+        locale: &DataLocale,
+        provider: &IntlProvider,
+    ) -> JsResult<Self> {
+        let mut options = DisplayNamesOptions::default();
+        options.style = match style {
+            Style::Narrow => Some(icu_experimental::displaynames::Style::Narrow),
+            Style::Short => Some(icu_experimental::displaynames::Style::Short),
+            // The long names are the default names of the locale data.
+            Style::Long => None,
+        };
+        options.language_display = match language_display {
+            LanguageDisplay::Dialect => icu_experimental::displaynames::LanguageDisplay::Dialect,
+            LanguageDisplay::Standard => icu_experimental::displaynames::LanguageDisplay::Standard,
+        };
+
+        let names = match typ {
+            DisplayNamesType::Language => LanguageDisplayNames::try_new_unstable(
+                provider, locale, options,
+            )
+            .and_then(|languages| {
+                Ok(Self::Language {
+                    languages,
+                    locales: Box::new(LocaleDisplayNamesFormatter::try_new_unstable(
+                        provider, locale, options,
+                    )?),
+                })
+            }),
+            DisplayNamesType::Region => {
+                RegionDisplayNames::try_new_unstable(provider, locale, options).map(Self::Region)
+            }
+            DisplayNamesType::Script => {
+                ScriptDisplayNames::try_new_unstable(provider, locale, options).map(Self::Script)
+            }
+            DisplayNamesType::Currency
+            | DisplayNamesType::Calendar
+            | DisplayNamesType::DateTimeField => Ok(Self::Unsupported),
+        };
+
+        names.map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+    }
+}
+
+/// Creates the error thrown when `code` is not a valid code for `typ`.
+fn invalid_code(typ: DisplayNamesType) -> JsNativeError {
+    JsNativeError::range().with_message(format!(
+        "invalid code for the `{}` display names type",
+        typ.to_js_string().to_std_string_escaped()
+    ))
+}
+
+/// The `language` part of the abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_language_code(code: &str, context: &Context) -> JsResult<Locale> {
+    // 1. If type is "language", then
+    //     a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+    //     b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+    if code.contains('_') {
+        return Err(invalid_code(DisplayNamesType::Language).into());
+    }
+    let id = LanguageIdentifier::try_from_bytes(code.as_bytes())
+        .map_err(|_| invalid_code(DisplayNamesType::Language))?;
+
+    //     c. Return CanonicalizeUnicodeLocaleId(code).
+    let mut locale = Locale::from(id);
+    context
+        .intl_provider()
+        .locale_canonicalizer()?
+        .canonicalize(&mut locale);
+    Ok(locale)
+}
+
+/// The steps of the abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec]
+/// for the types without locale data.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_without_data(typ: DisplayNamesType, code: &str) -> JsResult<String> {
+    match typ {
+        // 4. If type is "calendar", then
+        DisplayNamesType::Calendar => {
+            // a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+            // b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+            if code.is_empty() || code.contains('_') {
+                return Err(invalid_code(typ).into());
+            }
+            let value = Value::try_from_bytes(code.as_bytes()).map_err(|_| invalid_code(typ))?;
+
+            // c. Return the ASCII-lowercase of code.
+            Ok(value.to_string())
+        }
+        // 5. If type is "dateTimeField", then
+        DisplayNamesType::DateTimeField => {
+            // a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+            // b. Return code.
+            is_valid_date_time_field_code(code)
+                .then(|| code.to_owned())
+                .ok_or_else(|| invalid_code(typ).into())
+        }
+        // 6. Assert: type is "currency".
+        // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+        // 8. Return the ASCII-uppercase of code.
+        _ => code
+            .parse::<Currency>()
+            .map(|currency| currency.to_js_string().to_std_string_escaped())
+            .map_err(|_| invalid_code(typ).into()),
+    }
+}
+
+/// Abstract operation [`IsValidDateTimeFieldCode ( field )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-isvaliddatetimefieldcode
+fn is_valid_date_time_field_code(field: &str) -> bool {
+    // 1. If field is listed in the Code column of Table 19, return true.
+    // 2. Return false.
+    matches!(
+        field,
+        "era"
+            | "year"
+            | "quarter"
+            | "month"
+            | "weekOfYear"
+            | "weekday"
+            | "day"
+            | "dayPeriod"
+            | "hour"
+            | "minute"
+            | "second"
+            | "timeZoneName"
+    )
+}
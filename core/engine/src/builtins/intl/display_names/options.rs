@@ -0,0 +1,180 @@
+use std::{fmt, str::FromStr};
+
+use crate::{builtins::options::ParsableOptionType, js_string, JsString};
+
+/// The `style` option of `Intl.DisplayNames`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Style {
+    Narrow,
+    Short,
+    #[default]
+    Long,
+}
+
+impl Style {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for Style {}
+
+/// The `type` option of `Intl.DisplayNames`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisplayNamesType {
+    Language,
+    Region,
+    Script,
+    Currency,
+    Calendar,
+    DateTimeField,
+}
+
+impl DisplayNamesType {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Language => js_string!("language"),
+            Self::Region => js_string!("region"),
+            Self::Script => js_string!("script"),
+            Self::Currency => js_string!("currency"),
+            Self::Calendar => js_string!("calendar"),
+            Self::DateTimeField => js_string!("dateTimeField"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesTypeError;
+
+impl fmt::Display for ParseDisplayNamesTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(
+            "provided string was not `language`, `region`, `script`, `currency`, \
+            `calendar` or `dateTimeField`",
+        )
+    }
+}
+
+impl FromStr for DisplayNamesType {
+    type Err = ParseDisplayNamesTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "language" => Ok(Self::Language),
+            "region" => Ok(Self::Region),
+            "script" => Ok(Self::Script),
+            "currency" => Ok(Self::Currency),
+            "calendar" => Ok(Self::Calendar),
+            "dateTimeField" => Ok(Self::DateTimeField),
+            _ => Err(ParseDisplayNamesTypeError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesType {}
+
+/// The `fallback` option of `Intl.DisplayNames`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Fallback {
+    #[default]
+    Code,
+    None,
+}
+
+impl Fallback {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Code => js_string!("code"),
+            Self::None => js_string!("none"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFallbackError;
+
+impl fmt::Display for ParseFallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `code` or `none`")
+    }
+}
+
+impl FromStr for Fallback {
+    type Err = ParseFallbackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "code" => Ok(Self::Code),
+            "none" => Ok(Self::None),
+            _ => Err(ParseFallbackError),
+        }
+    }
+}
+
+impl ParsableOptionType for Fallback {}
+
+/// The `languageDisplay` option of `Intl.DisplayNames`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum LanguageDisplay {
+    #[default]
+    Dialect,
+    Standard,
+}
+
+impl LanguageDisplay {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Dialect => js_string!("dialect"),
+            Self::Standard => js_string!("standard"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseLanguageDisplayError;
+
+impl fmt::Display for ParseLanguageDisplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `dialect` or `standard`")
+    }
+}
+
+impl FromStr for LanguageDisplay {
+    type Err = ParseLanguageDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dialect" => Ok(Self::Dialect),
+            "standard" => Ok(Self::Standard),
+            _ => Err(ParseLanguageDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for LanguageDisplay {}
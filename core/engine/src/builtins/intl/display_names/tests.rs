@@ -0,0 +1,169 @@
+use super::{canonical_code_without_data, is_valid_date_time_field_code, DisplayNamesType};
+
+#[test]
+fn date_time_field_codes() {
+    for field in [
+        "era",
+        "year",
+        "quarter",
+        "month",
+        "weekOfYear",
+        "weekday",
+        "day",
+        "dayPeriod",
+        "hour",
+        "minute",
+        "second",
+        "timeZoneName",
+    ] {
+        assert!(is_valid_date_time_field_code(field), "{field}");
+    }
+
+    for invalid in ["", "Year", "week", "millisecond", "timezonename"] {
+        assert!(!is_valid_date_time_field_code(invalid), "{invalid}");
+    }
+}
+
+#[test]
+fn canonicalize_codes_without_data() {
+    assert_eq!(
+        canonical_code_without_data(DisplayNamesType::Currency, "eur").unwrap(),
+        "EUR"
+    );
+    assert_eq!(
+        canonical_code_without_data(DisplayNamesType::Calendar, "Islamic-Civil").unwrap(),
+        "islamic-civil"
+    );
+    assert_eq!(
+        canonical_code_without_data(DisplayNamesType::DateTimeField, "weekday").unwrap(),
+        "weekday"
+    );
+
+    for (typ, invalid) in [
+        (DisplayNamesType::Currency, "EU"),
+        (DisplayNamesType::Currency, "E1R"),
+        (DisplayNamesType::Calendar, ""),
+        (DisplayNamesType::Calendar, "gc"),
+        (DisplayNamesType::Calendar, "islamic_civil"),
+        (DisplayNamesType::DateTimeField, "WEEKDAY"),
+    ] {
+        assert!(
+            canonical_code_without_data(typ, invalid).is_err(),
+            "{typ:?} {invalid}"
+        );
+    }
+}
+
+#[cfg(feature = "intl_bundled")]
+mod data {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+    #[test]
+    fn of() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var languages = new Intl.DisplayNames("en", { type: "language" });
+                var standard = new Intl.DisplayNames("en", {
+                    type: "language", languageDisplay: "standard"
+                });
+                var regions = new Intl.DisplayNames("en", { type: "region" });
+                var short = new Intl.DisplayNames("en", { type: "region", style: "short" });
+                var scripts = new Intl.DisplayNames("en", { type: "script" });
+                var de = new Intl.DisplayNames("de", { type: "language" });
+                var none = new Intl.DisplayNames("en", { type: "region", fallback: "none" });
+            "#}),
+            TestAction::assert_eq("languages.of('fr')", js_string!("French")),
+            TestAction::assert_eq("languages.of('en-GB')", js_string!("British English")),
+            TestAction::assert_eq("languages.of('en-DE')", js_string!("English (Germany)")),
+            TestAction::assert_eq(
+                "standard.of('en-GB')",
+                js_string!("English (United Kingdom)"),
+            ),
+            TestAction::assert_eq("languages.of('xyz')", js_string!("xyz")),
+            TestAction::assert_eq("regions.of('us')", js_string!("United States")),
+            TestAction::assert_eq("regions.of('419')", js_string!("Latin America")),
+            TestAction::assert_eq("short.of('GB')", js_string!("UK")),
+            TestAction::assert_eq("short.of('DE')", js_string!("Germany")),
+            TestAction::assert_eq("scripts.of('latn')", js_string!("Latin")),
+            TestAction::assert_eq("de.of('en')", js_string!("Englisch")),
+            TestAction::assert_eq("none.of('ZZ')", JsValue::undefined()),
+            TestAction::assert_eq("regions.of('ZZ')", js_string!("ZZ")),
+            TestAction::assert_native_error(
+                "regions.of('USA')",
+                JsNativeErrorKind::Range,
+                "invalid code for the `region` display names type",
+            ),
+            TestAction::assert_native_error(
+                "languages.of('en_US')",
+                JsNativeErrorKind::Range,
+                "invalid code for the `language` display names type",
+            ),
+            TestAction::assert_native_error(
+                "languages.of('en-u-ca-gregory')",
+                JsNativeErrorKind::Range,
+                "invalid code for the `language` display names type",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn types_without_data() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var currencies = new Intl.DisplayNames("en", { type: "currency" });
+                var calendars = new Intl.DisplayNames("en", { type: "calendar", fallback: "none" });
+                var fields = new Intl.DisplayNames("en", { type: "dateTimeField" });
+            "#}),
+            TestAction::assert_eq("currencies.of('usd')", js_string!("USD")),
+            TestAction::assert_eq("calendars.of('gregory')", JsValue::undefined()),
+            TestAction::assert_eq("fields.of('weekday')", js_string!("weekday")),
+            TestAction::assert_native_error(
+                "fields.of('week')",
+                JsNativeErrorKind::Range,
+                "invalid code for the `dateTimeField` display names type",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn constructor_and_resolved_options() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var options = new Intl.DisplayNames("en-u-ca-gregory", {
+                    type: "language", style: "short", languageDisplay: "standard"
+                }).resolvedOptions();
+                var region = new Intl.DisplayNames("en", { type: "region" }).resolvedOptions();
+            "#}),
+            TestAction::assert_eq("options.locale", js_string!("en")),
+            TestAction::assert_eq("options.style", js_string!("short")),
+            TestAction::assert_eq("options.type", js_string!("language")),
+            TestAction::assert_eq("options.fallback", js_string!("code")),
+            TestAction::assert_eq("options.languageDisplay", js_string!("standard")),
+            TestAction::assert_eq("'languageDisplay' in region", false),
+            TestAction::assert_eq("Intl.DisplayNames.length", 2),
+            TestAction::assert_native_error(
+                "new Intl.DisplayNames('en')",
+                JsNativeErrorKind::Type,
+                "`Intl.DisplayNames` requires an options object with a `type`",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DisplayNames('en', {})",
+                JsNativeErrorKind::Type,
+                "`Intl.DisplayNames` requires a `type` option",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DisplayNames('en', { type: 'unit' })",
+                JsNativeErrorKind::Range,
+                "provided string was not `language`, `region`, `script`, `currency`, \
+                `calendar` or `dateTimeField`",
+            ),
+            TestAction::assert_native_error(
+                "Intl.DisplayNames('en', { type: 'region' })",
+                JsNativeErrorKind::Type,
+                "cannot call `Intl.DisplayNames` constructor without `new`",
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,1626 @@
+//! This module implements the global `Intl.DurationFormat` object.
+//!
+//! `Intl.DurationFormat` is a built-in object that allows language-sensitive duration
+//! formatting.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#durationformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, Sign, SignDisplay};
+use icu_decimal::{
+    options::{FixedDecimalFormatterOptions, GroupingStrategy},
+    provider::DecimalSymbolsV1Marker,
+    FixedDecimalFormatter,
+};
+use icu_list::{provider::UnitListV1Marker, ListFormatter};
+use icu_locid::{
+    extensions::unicode::{key, Value},
+    Locale,
+};
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
+};
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object, RoundingMode},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, Number,
+        OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+use super::{
+    list_format::{create_parts_from_list, ListPart},
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
+    number_format::{
+        number_parts, DigitFormatOptions, Extrema, RoundingIncrement, RoundingPriority,
+        RoundingType, TrailingZeroDisplay,
+    },
+    options::{get_number_option, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The separator between numeric hours, minutes and seconds.
+// TODO: Use the locale's digital format once ICU4X provides it.
+const DIGITAL_SEPARATOR: &str = ":";
+
+/// JavaScript `Intl.DurationFormat` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DurationFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DurationFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: Style,
+    units: [UnitOptions; 10],
+    fractional_digits: Option<u8>,
+    number_format: FixedDecimalFormatter,
+    numeric_format: FixedDecimalFormatter,
+    decimal_symbols: DataPayload<DecimalSymbolsV1Marker>,
+    list_format: ListFormatter,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct DurationFormatLocaleOptions {
+    numbering_system: Option<Value>,
+}
+
+impl Service for DurationFormat {
+    type LangMarker = UnitListV1Marker;
+
+    type LocaleOptions = DurationFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    provider,
+                )
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<DecimalSymbolsV1Marker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            provider,
+                        )
+                    })
+            });
+
+        locale.extensions.unicode.clear();
+
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.numbering_system = numbering_system;
+    }
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    /// [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DurationFormatPrototype%",
+        //    « [[InitializedDurationFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]],
+        //    [[YearsStyle]], [[YearsDisplay]], [[MonthsStyle]], [[MonthsDisplay]], [[WeeksStyle]],
+        //    [[WeeksDisplay]], [[DaysStyle]], [[DaysDisplay]], [[HoursStyle]], [[HoursDisplay]],
+        //    [[MinutesStyle]], [[MinutesDisplay]], [[SecondsStyle]], [[SecondsDisplay]],
+        //    [[MillisecondsStyle]], [[MillisecondsDisplay]], [[MicrosecondsStyle]],
+        //    [[MicrosecondsDisplay]], [[NanosecondsStyle]], [[NanosecondsDisplay]],
+        //    [[FractionalDigits]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        let duration_format =
+            Self::try_new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 19. Return durationFormat.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            duration_format,
+        )
+        .into())
+    }
+}
+
+impl DurationFormat {
+    /// Creates a new `DurationFormat` from the provided `locales` and `options`.
+    ///
+    /// This corresponds to the steps 3 to 18 of the `Intl.DurationFormat` constructor.
+    pub(crate) fn try_new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        let numbering_system = get_option(&options, js_string!("numberingSystem"), context)?;
+
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: DurationFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let r be ResolveLocale(%Intl.DurationFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.DurationFormat%.[[RelevantExtensionKeys]], %Intl.DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        // 12. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 13. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 14. Set durationFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 15. Let prevStyle be the empty String.
+        let mut prev_style = None;
+        let mut units = [UnitOptions {
+            style: UnitStyle::Short,
+            display: Display::Auto,
+        }; 10];
+
+        // 16. For each row of Table 20, except the header row, in table order, do
+        for unit in Unit::ALL {
+            // a. Let styleSlot be the Style Slot value of the current row.
+            // b. Let displaySlot be the Display Slot value of the current row.
+            // c. Let unit be the Unit value of the current row.
+            // d. Let valueList be the Values value of the current row.
+            // e. Let digitalBase be the Digital Default value of the current row.
+            // f. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, valueList, digitalBase, prevStyle).
+            let unit_options =
+                get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // g. Set the value of the styleSlot slot of durationFormat to unitOptions.[[Style]].
+            // h. Set the value of the displaySlot slot of durationFormat to unitOptions.[[Display]].
+            units[unit as usize] = unit_options;
+
+            // i. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            //     i. Set prevStyle to unitOptions.[[Style]].
+            // The date units can never be numeric, so always updating `prevStyle` is equivalent.
+            prev_style = Some(unit_options.style);
+        }
+
+        // 17. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, js_string!("fractionalDigits"), 0, 9, context)?;
+
+        // 18. Set durationFormat.[[DataLocale]] to r.[[dataLocale]].
+        let data_locale = DataLocale::from(&locale);
+        let provider = context.intl_provider();
+
+        let mut numeric_options = FixedDecimalFormatterOptions::default();
+        numeric_options.grouping_strategy = GroupingStrategy::Never;
+        let number_format = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            &data_locale,
+            FixedDecimalFormatterOptions::default(),
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let numeric_format =
+            FixedDecimalFormatter::try_new_unstable(provider, &data_locale, numeric_options)
+                .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let decimal_symbols = DataProvider::<DecimalSymbolsV1Marker>::load(
+            provider,
+            DataRequest {
+                locale: &data_locale,
+                metadata: DataRequestMetadata::default(),
+            },
+        )
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let list_format = ListFormatter::try_new_unit_with_length_unstable(
+            provider,
+            &data_locale,
+            style.list_length(),
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        Ok(Self {
+            locale,
+            numbering_system: intl_options.service_options.numbering_system,
+            style,
+            units,
+            fractional_digits,
+            number_format,
+            numeric_format,
+            decimal_symbols,
+            list_format,
+        })
+    }
+
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Returns a language-sensitive formatted string representing the provided duration.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on an `Intl.DurationFormat` object")
+        })?;
+        let df = df.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on an `Intl.DurationFormat` object")
+        })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 7. Return result.
+        df.format_to_string(&record).map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the locale-specific parts of the formatted
+    /// duration.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`formatToParts` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+        let df = df.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`formatToParts` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df.partition_duration_format_pattern(&record)?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let obj = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            obj.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            obj.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = part.unit {
+                obj.create_data_property_or_throw(
+                    js_string!("unit"),
+                    js_string!(unit.number_format_unit()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            result
+                .create_data_property_or_throw(n, obj, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and duration formatting
+    /// options computed during the construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+        let df = df.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 21, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is "fractionalDigits", then
+        //         i. If v is not undefined, set v to 𝔽(v).
+        //     d. Else,
+        //         i. Assert: v is not undefined.
+        //     e. If v is "fractional", then
+        //         i. Assert: The Internal Slot value of the current row is [[MillisecondsStyle]],
+        //            [[MicrosecondsStyle]], or [[NanosecondsStyle]] .
+        //         ii. Set v to "numeric".
+        //     f. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options.property(
+            js_string!("locale"),
+            js_string!(df.locale.to_string()),
+            Attribute::all(),
+        );
+        if let Some(nu) = &df.numbering_system {
+            options.property(
+                js_string!("numberingSystem"),
+                js_string!(nu.to_string()),
+                Attribute::all(),
+            );
+        }
+        options.property(
+            js_string!("style"),
+            df.style.to_js_string(),
+            Attribute::all(),
+        );
+        for unit in Unit::ALL {
+            let UnitOptions { style, display } = df.units[unit as usize];
+            options
+                .property(
+                    js_string!(unit.name()),
+                    style.to_js_string(),
+                    Attribute::all(),
+                )
+                .property(
+                    js_string!(unit.display_field()),
+                    display.to_js_string(),
+                    Attribute::all(),
+                );
+        }
+        if let Some(fractional_digits) = df.fractional_digits {
+            options.property(
+                js_string!("fractionalDigits"),
+                fractional_digits,
+                Attribute::all(),
+            );
+        }
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+
+    /// Formats `duration` into a string, concatenating the values of the parts returned by
+    /// `PartitionDurationFormatPattern`.
+    pub(crate) fn format_to_string(&self, duration: &DurationRecord) -> JsResult<JsString> {
+        let parts = self.partition_duration_format_pattern(duration)?;
+        Ok(js_string!(parts
+            .into_iter()
+            .map(|part| part.value)
+            .collect::<String>()))
+    }
+
+    /// Gets the options of `unit`.
+    fn unit_options(&self, unit: Unit) -> UnitOptions {
+        self.units[unit as usize]
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    fn partition_duration_format_pattern(
+        &self,
+        duration: &DurationRecord,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. Let signDisplayed be true.
+        let mut sign_displayed = true;
+
+        // 3. Let numericUnitFound be false.
+        // 4. While numericUnitFound is false, repeat for each row in Table 20 in table order, except the header row:
+        for unit in Unit::ALL {
+            // a. Let value be the value of duration's field whose name is the Value Field value of the current row.
+            // b. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // c. Let display be the value of durationFormat's internal slot whose name is the Display Slot value of the current row.
+            // d. Let unit be the Unit value of the current row.
+            let UnitOptions { style, display } = self.unit_options(unit);
+
+            // e. If style is "numeric" or "2-digit", then
+            if matches!(style, UnitStyle::Numeric | UnitStyle::TwoDigit) {
+                // i. Append FormatNumericUnits(durationFormat, duration, unit, signDisplayed) to result.
+                result.push(self.format_numeric_units(duration, unit, sign_displayed));
+
+                // ii. Set numericUnitFound to true.
+                break;
+            }
+
+            // f. Else,
+            //     i. Let nfOpts be OrdinaryObjectCreate(null).
+            let mut fraction_digits = None;
+            let mut value = integer_value(duration.get(unit));
+
+            //     ii. If unit is "seconds", "milliseconds", or "microseconds", then
+            //         1. If NextUnitFractional(durationFormat, unit) is true, then
+            let numeric_unit_found = self.next_unit_fractional(unit);
+            if numeric_unit_found {
+                // a. Set value to value + AddFractionalDigits(durationFormat, duration).
+                value = self.add_fractional_digits(duration.get(unit), duration);
+
+                // b. If durationFormat.[[FractionalDigits]] is undefined, then
+                //     i. Let maximumFractionDigits be 9𝔽.
+                //     ii. Let minimumFractionDigits be +0𝔽.
+                // c. Else,
+                //     i. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+                //     ii. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+                // d. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+                // e. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+                // f. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+                fraction_digits = Some(self.fraction_digits());
+
+                // g. Set numericUnitFound to true.
+            }
+
+            //     iii. If display is "always" or value is not 0, then
+            if display == Display::Always || !value.is_zero() {
+                // 1. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", durationFormat.[[NumberingSystem]]).
+                // 2. If signDisplayed is true, then
+                let sign_display = if sign_displayed {
+                    // a. Set signDisplayed to false.
+                    sign_displayed = false;
+
+                    // b. If value is 0 and DurationSign(duration) is -1, then
+                    if value.is_zero() && duration.sign() == -1 {
+                        // i. Set value to negative-zero.
+                        value.set_sign(Sign::Negative);
+                    }
+
+                    SignDisplay::Auto
+                } else {
+                    // 3. Else,
+                    //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                    SignDisplay::Never
+                };
+
+                // 4. Let numberFormatUnit be the NumberFormat Unit value of the current row.
+                // 5. Perform ! CreateDataPropertyOrThrow(nfOpts, "style", "unit").
+                // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "unit", numberFormatUnit).
+                // 7. Perform ! CreateDataPropertyOrThrow(nfOpts, "unitDisplay", style).
+                // 8. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+                // 9. Let parts be PartitionNumberPattern(nf, value).
+                // 10. Let list be a new empty List.
+                // 11. For each Record { [[Type]], [[Value]] } part of parts, do
+                //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: numberFormatUnit } to list.
+                // 12. Append list to result.
+                result.push(self.format_unit(value, unit, style, fraction_digits, sign_display));
+            }
+
+            if numeric_unit_found {
+                break;
+            }
+        }
+
+        // 5. Return ListFormatParts(durationFormat, result).
+        self.list_format_parts(result)
+    }
+
+    /// Abstract operation [`FormatNumericUnits ( durationFormat, duration, firstNumericUnit, signDisplayed )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumericunits
+    fn format_numeric_units(
+        &self,
+        duration: &DurationRecord,
+        first_numeric_unit: Unit,
+        sign_displayed: bool,
+    ) -> Vec<DurationPart> {
+        // 1. Assert: firstNumericUnit is "hours", "minutes", or "seconds".
+        debug_assert!(matches!(
+            first_numeric_unit,
+            Unit::Hours | Unit::Minutes | Unit::Seconds
+        ));
+
+        // 2. Let numericPartsList be a new empty List.
+        let mut numeric_parts = Vec::new();
+
+        // 3. Let hoursValue be duration.[[Hours]].
+        let mut hours_value = integer_value(duration.hours);
+        // 4. Let hoursDisplay be durationFormat.[[HoursDisplay]].
+        let hours_display = self.unit_options(Unit::Hours).display;
+        // 5. Let minutesValue be duration.[[Minutes]].
+        let mut minutes_value = integer_value(duration.minutes);
+        // 6. Let minutesDisplay be durationFormat.[[MinutesDisplay]].
+        let minutes_display = self.unit_options(Unit::Minutes).display;
+
+        // 7. Let secondsValue be duration.[[Seconds]].
+        // 8. If duration.[[Milliseconds]] is not 0 or duration.[[Microseconds]] is not 0 or duration.[[Nanoseconds]] is not 0, then
+        //     a. Set secondsValue to secondsValue + AddFractionalDigits(durationFormat, duration).
+        let mut seconds_value = self.add_fractional_digits(duration.seconds, duration);
+        // 9. Let secondsDisplay be durationFormat.[[SecondsDisplay]].
+        let seconds_display = self.unit_options(Unit::Seconds).display;
+
+        // 10. Let hoursFormatted be false.
+        // 11. If firstNumericUnit is "hours", then
+        //     a. If hoursValue is not 0 or hoursDisplay is "always", then
+        //         i. Set hoursFormatted to true.
+        let hours_formatted = first_numeric_unit == Unit::Hours
+            && (!hours_value.is_zero() || hours_display == Display::Always);
+
+        // 12. If secondsValue is not 0 or secondsDisplay is "always", then
+        //     a. Let secondsFormatted be true.
+        // 13. Else,
+        //     a. Let secondsFormatted be false.
+        let seconds_formatted = !seconds_value.is_zero() || seconds_display == Display::Always;
+
+        // 14. Let minutesFormatted be false.
+        // 15. If firstNumericUnit is "hours" or firstNumericUnit is "minutes", then
+        //     a. If hoursFormatted is true and secondsFormatted is true, then
+        //         i. Set minutesFormatted to true.
+        //     b. Else if minutesValue is not 0 or minutesDisplay is "always", then
+        //         i. Set minutesFormatted to true.
+        let minutes_formatted = matches!(first_numeric_unit, Unit::Hours | Unit::Minutes)
+            && ((hours_formatted && seconds_formatted)
+                || !minutes_value.is_zero()
+                || minutes_display == Display::Always);
+
+        let mut sign_displayed = sign_displayed;
+        let negative_zero = |value: &mut FixedDecimal, sign_displayed: bool| {
+            // If signDisplayed is true, then
+            //     If value is 0 and DurationSign(duration) is -1, then
+            //         Set value to negative-zero.
+            if sign_displayed && value.is_zero() && duration.sign() == -1 {
+                value.set_sign(Sign::Negative);
+            }
+        };
+
+        // 16. If hoursFormatted is true, then
+        if hours_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If hoursValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set hoursValue to negative-zero.
+            negative_zero(&mut hours_value, sign_displayed);
+
+            // b. Append FormatNumericHours(durationFormat, hoursValue, signDisplayed) to numericPartsList.
+            numeric_parts.extend(self.format_numeric_unit(
+                hours_value,
+                Unit::Hours,
+                false,
+                sign_displayed,
+            ));
+
+            // c. Set signDisplayed to false.
+            sign_displayed = false;
+        }
+
+        // 17. If minutesFormatted is true, then
+        if minutes_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If minutesValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set minutesValue to negative-zero.
+            negative_zero(&mut minutes_value, sign_displayed);
+
+            // b. Append FormatNumericMinutes(durationFormat, minutesValue, hoursFormatted, signDisplayed) to numericPartsList.
+            numeric_parts.extend(self.format_numeric_unit(
+                minutes_value,
+                Unit::Minutes,
+                hours_formatted,
+                sign_displayed,
+            ));
+
+            // c. Set signDisplayed to false.
+            sign_displayed = false;
+        }
+
+        // 18. If secondsFormatted is true, then
+        if seconds_formatted {
+            negative_zero(&mut seconds_value, sign_displayed);
+
+            // a. Append FormatNumericSeconds(durationFormat, secondsValue, minutesFormatted, signDisplayed) to numericPartsList.
+            numeric_parts.extend(self.format_numeric_unit(
+                seconds_value,
+                Unit::Seconds,
+                minutes_formatted,
+                sign_displayed,
+            ));
+        }
+
+        // 19. Return numericPartsList.
+        numeric_parts
+    }
+
+    /// Abstract operations [`FormatNumericHours ( durationFormat, hoursValue, signDisplayed )`][hours],
+    /// [`FormatNumericMinutes ( durationFormat, minutesValue, hoursDisplayed, signDisplayed )`][minutes]
+    /// and [`FormatNumericSeconds ( durationFormat, secondsValue, minutesDisplayed, signDisplayed )`][seconds].
+    ///
+    /// [hours]: https://tc39.es/ecma402/#sec-formatnumerichours
+    /// [minutes]: https://tc39.es/ecma402/#sec-formatnumericminutes
+    /// [seconds]: https://tc39.es/ecma402/#sec-formatnumericseconds
+    fn format_numeric_unit(
+        &self,
+        mut value: FixedDecimal,
+        unit: Unit,
+        separator_displayed: bool,
+        sign_displayed: bool,
+    ) -> Vec<DurationPart> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. If hoursDisplayed (resp. minutesDisplayed) is true, then
+        if separator_displayed {
+            // a. Let separator be durationFormat.[[DigitalFormat]].[[HoursMinutesSeparator]]
+            //    (resp. [[MinutesSecondsSeparator]]).
+            // b. Append the Record { [[Type]]: "literal", [[Value]]: separator, [[Unit]]: empty } to result.
+            result.push(DurationPart {
+                typ: "literal",
+                value: DIGITAL_SEPARATOR.to_owned(),
+                unit: None,
+            });
+        }
+
+        // 3. Let style be durationFormat.[[<unit>Style]].
+        // 4. Let nfOpts be OrdinaryObjectCreate(null).
+        // 5. Let numberingSystem be durationFormat.[[NumberingSystem]].
+        // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", numberingSystem).
+        // 7. If style is "2-digit", then
+        //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumIntegerDigits", 2𝔽).
+        let minimum_integer_digits = if self.unit_options(unit).style == UnitStyle::TwoDigit {
+            2
+        } else {
+            1
+        };
+
+        // 8. If signDisplayed is false, then
+        //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+        let sign_display = if sign_displayed {
+            SignDisplay::Auto
+        } else {
+            SignDisplay::Never
+        };
+
+        // 9. Perform ! CreateDataPropertyOrThrow(nfOpts, "useGrouping", false).
+        // 10. If the unit is "seconds", then
+        //     a. If durationFormat.[[FractionalDigits]] is undefined, then
+        //         i. Let maximumFractionDigits be 9𝔽.
+        //         ii. Let minimumFractionDigits be +0𝔽.
+        //     b. Else,
+        //         i. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+        //         ii. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+        //     c. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+        //     d. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+        //     e. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+        let fraction_digits = (unit == Unit::Seconds).then(|| self.fraction_digits());
+
+        // 11. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+        // 12. Let parts be PartitionNumberPattern(nf, value).
+        // 13. For each Record { [[Type]], [[Value]] } part of parts, do
+        //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: unit } to result.
+        result.extend(self.format_number(
+            &mut value,
+            unit,
+            &NumberOptions {
+                minimum_integer_digits,
+                fraction_digits,
+                sign_display,
+                use_grouping: false,
+            },
+        ));
+
+        // 14. Return result.
+        result
+    }
+
+    /// Formats `value` as a number followed by the label of `unit`.
+    ///
+    /// This is equivalent to formatting `value` with an `Intl.NumberFormat` using the "unit" style.
+    // TODO: ICU4X doesn't support unit formatting yet, so this uses the English unit patterns
+    // for every locale.
+    fn format_unit(
+        &self,
+        mut value: FixedDecimal,
+        unit: Unit,
+        style: UnitStyle,
+        fraction_digits: Option<Extrema<u8>>,
+        sign_display: SignDisplay,
+    ) -> Vec<DurationPart> {
+        let mut parts = self.format_number(
+            &mut value,
+            unit,
+            &NumberOptions {
+                minimum_integer_digits: 1,
+                fraction_digits,
+                sign_display,
+                use_grouping: true,
+            },
+        );
+
+        if style != UnitStyle::Narrow {
+            parts.push(DurationPart {
+                typ: "literal",
+                value: String::from(" "),
+                unit: Some(unit),
+            });
+        }
+        // English only uses the singular form for exactly one, without any fraction digits.
+        let plural = value.magnitude_range() != (0..=0) || value.digit_at(0) != 1;
+        parts.push(DurationPart {
+            typ: "unit",
+            value: unit.label(style, plural).to_owned(),
+            unit: Some(unit),
+        });
+
+        parts
+    }
+
+    /// Abstract operation [`PartitionNumberPattern ( numberFormat, x )`][spec], specialized
+    /// for the number formats created by `Intl.DurationFormat`.
+    ///
+    /// `value` is rounded in place to the digits that are displayed.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    fn format_number(
+        &self,
+        value: &mut FixedDecimal,
+        unit: Unit,
+        options: &NumberOptions,
+    ) -> Vec<DurationPart> {
+        let (minimum, maximum, rounding_mode) = match options.fraction_digits {
+            Some(Extrema { minimum, maximum }) => (minimum, maximum, RoundingMode::Trunc),
+            None => (0, 3, RoundingMode::HalfExpand),
+        };
+        DigitFormatOptions {
+            minimum_integer_digits: options.minimum_integer_digits,
+            rounding_increment: RoundingIncrement::from_u16(1)
+                .expect("1 must be a valid rounding increment"),
+            rounding_mode,
+            trailing_zero_display: TrailingZeroDisplay::Auto,
+            rounding_type: RoundingType::FractionDigits(Extrema { minimum, maximum }),
+            rounding_priority: RoundingPriority::Auto,
+        }
+        .format_fixed_decimal(value);
+        value.apply_sign_display(options.sign_display);
+
+        let number_format = if options.use_grouping {
+            &self.number_format
+        } else {
+            &self.numeric_format
+        };
+        let formatted = number_format.format_to_string(value);
+
+        let symbols = self.decimal_symbols.get();
+        let mut parts = Vec::new();
+        let mut number = formatted.as_str();
+        if value.sign() == Sign::Negative {
+            if let Some(rest) = number.strip_prefix(&*symbols.minus_sign_affixes.prefix) {
+                parts.push(DurationPart {
+                    typ: "minusSign",
+                    value: symbols.minus_sign_affixes.prefix.to_string(),
+                    unit: Some(unit),
+                });
+                number = rest;
+            }
+        }
+        parts.extend(
+            number_parts(
+                number,
+                &symbols.decimal_separator,
+                &symbols.grouping_separator,
+            )
+            .into_iter()
+            .map(|(typ, value)| DurationPart {
+                typ,
+                value,
+                unit: Some(unit),
+            }),
+        );
+
+        parts
+    }
+
+    /// Abstract operation [`NextUnitFractional ( durationFormat, unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-nextunitfractional
+    fn next_unit_fractional(&self, unit: Unit) -> bool {
+        let next = match unit {
+            // 1. If unit is "seconds" and durationFormat.[[MillisecondsStyle]] is "fractional", return true.
+            Unit::Seconds => Unit::Milliseconds,
+            // 2. Else if unit is "milliseconds" and durationFormat.[[MicrosecondsStyle]] is "fractional", return true.
+            Unit::Milliseconds => Unit::Microseconds,
+            // 3. Else if unit is "microseconds" and durationFormat.[[NanosecondsStyle]] is "fractional", return true.
+            Unit::Microseconds => Unit::Nanoseconds,
+            // 4. Return false.
+            _ => return false,
+        };
+        self.unit_options(next).style == UnitStyle::Fractional
+    }
+
+    /// Computes `value + AddFractionalDigits ( durationFormat, duration )`.
+    ///
+    /// The addition is done using the decimal digits of the units, which avoids the rounding
+    /// errors of floating point arithmetic.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-addfractionaldigits
+    fn add_fractional_digits(&self, value: f64, duration: &DurationRecord) -> FixedDecimal {
+        // 1. Let result be 0.
+        // 2. Let exponent be 3.
+        // `result` holds the digits of `value + result`, and `exponent` the number of digits
+        // after the decimal point.
+        let mut result = value as i128;
+        let mut exponent = 0;
+
+        // 3. For each row of Table 20, except the header row, in table order, do
+        for unit in [Unit::Milliseconds, Unit::Microseconds, Unit::Nanoseconds] {
+            // a. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // b. If style is "fractional", then
+            if self.unit_options(unit).style == UnitStyle::Fractional {
+                // i. Assert: The Unit value of the current row is "milliseconds", "microseconds", or "nanoseconds".
+                // ii. Let value be the value of duration's field whose name is the Value Field value of the current row.
+                // iii. Set value to value / 10**exponent.
+                // iv. Set result to result + value.
+                // v. Set exponent to exponent + 3.
+                // All fields of a valid duration have the same sign and are small enough to
+                // not overflow an `i128`.
+                result = result * 1000 + duration.get(unit) as i128;
+                exponent += 3;
+            }
+        }
+
+        // 4. Return result.
+        FixedDecimal::from(result).multiplied_pow10(-exponent)
+    }
+
+    /// Gets the fraction digits used to format the fractional units.
+    fn fraction_digits(&self) -> Extrema<u8> {
+        match self.fractional_digits {
+            None => Extrema {
+                minimum: 0,
+                maximum: 9,
+            },
+            Some(digits) => Extrema {
+                minimum: digits,
+                maximum: digits,
+            },
+        }
+    }
+
+    /// Abstract operation [`ListFormatParts ( durationFormat, partitionedPartsList )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-listformatparts
+    fn list_format_parts(
+        &self,
+        partitioned_parts_list: Vec<Vec<DurationPart>>,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let lfOpts be OrdinaryObjectCreate(null).
+        // 2. Perform ! CreateDataPropertyOrThrow(lfOpts, "type", "unit").
+        // 3. Let listStyle be durationFormat.[[Style]].
+        // 4. If listStyle is "digital", then
+        //     a. Set listStyle to "short".
+        // 5. Perform ! CreateDataPropertyOrThrow(lfOpts, "style", listStyle).
+        // 6. Let lf be ! Construct(%Intl.ListFormat%, « durationFormat.[[Locale]], lfOpts »).
+        // The list formatter is created on construction.
+
+        // 7. Let strings be a new empty List.
+        // 8. For each element parts of partitionedPartsList, do
+        //     a. Let string be the empty String.
+        //     b. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //         i. Set string to the string-concatenation of string and part.[[Value]].
+        //     c. Append string to strings.
+        // Empty elements would be dropped by the list formatter, so skip them beforehand to
+        // keep the elements aligned with their parts.
+        let partitioned_parts_list = partitioned_parts_list
+            .into_iter()
+            .filter(|parts| !parts.is_empty())
+            .collect::<Vec<_>>();
+        let strings = partitioned_parts_list
+            .iter()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|part| part.value.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        // 9. Let formattedPartsList be CreatePartsFromList(lf, strings).
+        let formatted_parts_list =
+            create_parts_from_list(&self.list_format, strings.iter().map(String::as_str))?;
+
+        // 10. Let partitionedPartsIndex be 0.
+        // 11. Let partitionedLength be the number of elements in partitionedPartsList.
+        let mut partitioned_parts = partitioned_parts_list.into_iter();
+
+        // 12. Let flattenedPartsList be a new empty List.
+        let mut flattened_parts_list = Vec::new();
+
+        // 13. For each Record { [[Type]], [[Value]] } listPart in formattedPartsList, do
+        for list_part in formatted_parts_list {
+            match list_part {
+                // a. If listPart.[[Type]] is "element", then
+                ListPart::Element(_) => {
+                    // i. Assert: partitionedPartsIndex < partitionedLength.
+                    // ii. Let parts be partitionedPartsList[partitionedPartsIndex].
+                    // iii. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+                    //     1. Append part to flattenedPartsList.
+                    // iv. Set partitionedPartsIndex to partitionedPartsIndex + 1.
+                    let parts = partitioned_parts.next().ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("list formatter returned an unexpected element")
+                    })?;
+                    flattened_parts_list.extend(parts);
+                }
+                // b. Else,
+                //     i. Assert: listPart.[[Type]] is "literal".
+                //     ii. Append the Record { [[Type]]: "literal", [[Value]]: listPart.[[Value]], [[Unit]]: empty } to flattenedPartsList.
+                ListPart::Literal(value) => flattened_parts_list.push(DurationPart {
+                    typ: "literal",
+                    value,
+                    unit: None,
+                }),
+            }
+        }
+
+        // 14. Return flattenedPartsList.
+        Ok(flattened_parts_list)
+    }
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: Unit,
+    options: &JsObject,
+    base_style: Style,
+    prev_style: Option<UnitStyle>,
+    context: &mut Context,
+) -> JsResult<UnitOptions> {
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<UnitStyle>(options, js_string!(unit.name()), context)?
+        .map(|style| unit.validate_style(style))
+        .transpose()?;
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = Display::Always;
+
+    // 3. If style is undefined, then
+    let mut style = match (style, base_style) {
+        (Some(style), _) => style,
+        // a. If baseStyle is "digital", then
+        (None, Style::Digital) => {
+            // i. If unit is not one of "hours", "minutes", or "seconds", then
+            if !matches!(unit, Unit::Hours | Unit::Minutes | Unit::Seconds) {
+                // 1. Set displayDefault to "auto".
+                display_default = Display::Auto;
+            }
+
+            // ii. Set style to digitalBase.
+            unit.digital_base()
+        }
+        // b. Else,
+        //     i. If prevStyle is "fractional", "numeric" or "2-digit", then
+        (None, _) if prev_style.is_some_and(UnitStyle::is_numeric) => {
+            // 1. If unit is not one of "minutes" or "seconds", then
+            if !matches!(unit, Unit::Minutes | Unit::Seconds) {
+                // a. Set displayDefault to "auto".
+                display_default = Display::Auto;
+            }
+
+            // 2. Set style to "numeric".
+            UnitStyle::Numeric
+        }
+        //     ii. Else,
+        //         1. Set displayDefault to "auto".
+        //         2. Set style to baseStyle.
+        (None, Style::Long) => {
+            display_default = Display::Auto;
+            UnitStyle::Long
+        }
+        (None, Style::Short) => {
+            display_default = Display::Auto;
+            UnitStyle::Short
+        }
+        (None, Style::Narrow) => {
+            display_default = Display::Auto;
+            UnitStyle::Narrow
+        }
+    };
+
+    // 4. If style is "numeric" and unit is one of "milliseconds", "microseconds", or "nanoseconds", then
+    if style == UnitStyle::Numeric
+        && matches!(
+            unit,
+            Unit::Milliseconds | Unit::Microseconds | Unit::Nanoseconds
+        )
+    {
+        // a. Set style to "fractional".
+        style = UnitStyle::Fractional;
+        // b. Set displayDefault to "auto".
+        display_default = Display::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display =
+        get_option(options, js_string!(unit.display_field()), context)?.unwrap_or(display_default);
+
+    // 7. If display is "always" and style is "fractional", then
+    if display == Display::Always && style == UnitStyle::Fractional {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` cannot be `always` for a fractional unit",
+                unit.display_field()
+            ))
+            .into());
+    }
+
+    // 8. If prevStyle is "fractional", then
+    //     a. If style is not "fractional", then
+    if prev_style == Some(UnitStyle::Fractional) && style != UnitStyle::Fractional {
+        // i. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` must be fractional after a fractional unit",
+                unit.name()
+            ))
+            .into());
+    }
+
+    // 9. If prevStyle is "numeric" or "2-digit", then
+    if matches!(prev_style, Some(UnitStyle::Numeric | UnitStyle::TwoDigit)) {
+        // a. If style is not "fractional", "numeric" or "2-digit", then
+        if !style.is_numeric() {
+            // i. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` must be numeric after a numeric unit",
+                    unit.name()
+                ))
+                .into());
+        }
+
+        // b. If unit is "minutes" or "seconds", then
+        if matches!(unit, Unit::Minutes | Unit::Seconds) {
+            // i. Set style to "2-digit".
+            style = UnitStyle::TwoDigit;
+        }
+    }
+
+    // 10. Return the Record { [[Style]]: style, [[Display]]: display  }.
+    Ok(UnitOptions { style, display })
+}
+
+/// The resolved style and display of a unit.
+#[derive(Debug, Clone, Copy)]
+struct UnitOptions {
+    style: UnitStyle,
+    display: Display,
+}
+
+/// The options of the number formats created by `Intl.DurationFormat`.
+#[derive(Debug)]
+struct NumberOptions {
+    minimum_integer_digits: u8,
+    fraction_digits: Option<Extrema<u8>>,
+    sign_display: SignDisplay,
+    use_grouping: bool,
+}
+
+/// A part of a formatted duration.
+#[derive(Debug)]
+struct DurationPart {
+    typ: &'static str,
+    value: String,
+    unit: Option<Unit>,
+}
+
+/// Converts an integral duration field into a `FixedDecimal`.
+fn integer_value(value: f64) -> FixedDecimal {
+    FixedDecimal::from(value as i128)
+}
+
+/// The units of a duration, in table order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Unit {
+    const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// The name of the unit, which is also the name of its style option.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// The name of the display option of the unit.
+    const fn display_field(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// The `Intl.NumberFormat` unit identifier of the unit.
+    const fn number_format_unit(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// The label of the unit for `style`, taken from the unit patterns of the English locale.
+    ///
+    /// `plural` selects the plural form of the long and short labels.
+    const fn label(self, style: UnitStyle, plural: bool) -> &'static str {
+        match (style, self, plural) {
+            (UnitStyle::Narrow, Self::Years, _) => "y",
+            (UnitStyle::Narrow, Self::Months, _) => "mo",
+            (UnitStyle::Narrow, Self::Weeks, _) => "w",
+            (UnitStyle::Narrow, Self::Days, _) => "d",
+            (UnitStyle::Narrow, Self::Hours, _) => "h",
+            (UnitStyle::Narrow, Self::Minutes, _) => "m",
+            (UnitStyle::Narrow, Self::Seconds, _) => "s",
+            (UnitStyle::Long, Self::Years, false) => "year",
+            (UnitStyle::Long, Self::Years, true) => "years",
+            (UnitStyle::Long, Self::Months, false) => "month",
+            (UnitStyle::Long, Self::Months, true) => "months",
+            (UnitStyle::Long, Self::Weeks, false) => "week",
+            (UnitStyle::Long, Self::Weeks, true) => "weeks",
+            (UnitStyle::Long, Self::Days, false) => "day",
+            (UnitStyle::Long, Self::Days, true) => "days",
+            (UnitStyle::Long, Self::Hours, false) => "hour",
+            (UnitStyle::Long, Self::Hours, true) => "hours",
+            (UnitStyle::Long, Self::Minutes, false) => "minute",
+            (UnitStyle::Long, Self::Minutes, true) => "minutes",
+            (UnitStyle::Long, Self::Seconds, false) => "second",
+            (UnitStyle::Long, Self::Seconds, true) => "seconds",
+            (UnitStyle::Long, Self::Milliseconds, false) => "millisecond",
+            (UnitStyle::Long, Self::Milliseconds, true) => "milliseconds",
+            (UnitStyle::Long, Self::Microseconds, false) => "microsecond",
+            (UnitStyle::Long, Self::Microseconds, true) => "microseconds",
+            (UnitStyle::Long, Self::Nanoseconds, false) => "nanosecond",
+            (UnitStyle::Long, Self::Nanoseconds, true) => "nanoseconds",
+            (_, Self::Years, false) => "yr",
+            (_, Self::Years, true) => "yrs",
+            (_, Self::Months, false) => "mth",
+            (_, Self::Months, true) => "mths",
+            (_, Self::Weeks, false) => "wk",
+            (_, Self::Weeks, true) => "wks",
+            (_, Self::Days, false) => "day",
+            (_, Self::Days, true) => "days",
+            (_, Self::Hours, _) => "hr",
+            (_, Self::Minutes, _) => "min",
+            (_, Self::Seconds, _) => "sec",
+            (_, Self::Milliseconds, _) => "ms",
+            (_, Self::Microseconds, _) => "μs",
+            (_, Self::Nanoseconds, _) => "ns",
+        }
+    }
+
+    /// The style of the unit when the base style is "digital".
+    const fn digital_base(self) -> UnitStyle {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => UnitStyle::Short,
+            _ => UnitStyle::Numeric,
+        }
+    }
+
+    /// Checks that `style` is one of the values allowed for the unit.
+    fn validate_style(self, style: UnitStyle) -> JsResult<UnitStyle> {
+        match (self, style) {
+            (
+                Self::Years | Self::Months | Self::Weeks | Self::Days,
+                UnitStyle::Numeric | UnitStyle::TwoDigit,
+            ) => Err(JsNativeError::range()
+                .with_message("provided string was not `long`, `short` or `narrow`")
+                .into()),
+            (Self::Milliseconds | Self::Microseconds | Self::Nanoseconds, UnitStyle::TwoDigit) => {
+                Err(JsNativeError::range()
+                    .with_message("provided string was not `long`, `short`, `narrow` or `numeric`")
+                    .into())
+            }
+            _ => Ok(style),
+        }
+    }
+}
+
+/// A [Duration Record][spec] formatted by `Intl.DurationFormat`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-duration-records
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct DurationRecord {
+    pub(crate) years: f64,
+    pub(crate) months: f64,
+    pub(crate) weeks: f64,
+    pub(crate) days: f64,
+    pub(crate) hours: f64,
+    pub(crate) minutes: f64,
+    pub(crate) seconds: f64,
+    pub(crate) milliseconds: f64,
+    pub(crate) microseconds: f64,
+    pub(crate) nanoseconds: f64,
+}
+
+impl DurationRecord {
+    /// Abstract operation [`ToDurationRecord ( input )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-todurationrecord
+    pub(crate) fn from_value(input: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If input is not an Object, then
+        let Some(input) = input.as_object() else {
+            // a. If input is a String, throw a RangeError exception.
+            if input.is_string() {
+                return Err(JsNativeError::range()
+                    .with_message("cannot format a string as a duration")
+                    .into());
+            }
+
+            // b. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("the duration to format must be an object")
+                .into());
+        };
+
+        // 2. Let result be a new Duration Record with each field set to 0.
+        let mut result = Self::default();
+        let mut any_defined = false;
+
+        // 3-12. For each field of the Duration Record, in alphabetical order, do
+        for unit in [
+            Unit::Days,
+            Unit::Hours,
+            Unit::Microseconds,
+            Unit::Milliseconds,
+            Unit::Minutes,
+            Unit::Months,
+            Unit::Nanoseconds,
+            Unit::Seconds,
+            Unit::Weeks,
+            Unit::Years,
+        ] {
+            // a. Let value be ? Get(input, field).
+            let value = input.get(js_string!(unit.name()), context)?;
+
+            // b. If value is not undefined, set result.[[<field>]] to ? ToIntegerIfIntegral(value).
+            if !value.is_undefined() {
+                any_defined = true;
+                let number = value.to_number(context)?;
+                if !Number::is_float_integer(number) {
+                    return Err(JsNativeError::range()
+                        .with_message(format!("`{}` must be an integral number", unit.name()))
+                        .into());
+                }
+                // Adding zero normalizes negative zeroes.
+                *result.get_mut(unit) = number + 0.0;
+            }
+        }
+
+        // 13. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds,
+        //     and nanoseconds are all undefined, throw a TypeError exception.
+        if !any_defined {
+            return Err(JsNativeError::typ()
+                .with_message("the duration to format must have at least one duration field")
+                .into());
+        }
+
+        // 14. If IsValidDuration( ... ) is false, then
+        if !result.is_valid() {
+            // a. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("the duration to format is not valid")
+                .into());
+        }
+
+        // 15. Return result.
+        Ok(result)
+    }
+
+    const fn get(&self, unit: Unit) -> f64 {
+        match unit {
+            Unit::Years => self.years,
+            Unit::Months => self.months,
+            Unit::Weeks => self.weeks,
+            Unit::Days => self.days,
+            Unit::Hours => self.hours,
+            Unit::Minutes => self.minutes,
+            Unit::Seconds => self.seconds,
+            Unit::Milliseconds => self.milliseconds,
+            Unit::Microseconds => self.microseconds,
+            Unit::Nanoseconds => self.nanoseconds,
+        }
+    }
+
+    fn get_mut(&mut self, unit: Unit) -> &mut f64 {
+        match unit {
+            Unit::Years => &mut self.years,
+            Unit::Months => &mut self.months,
+            Unit::Weeks => &mut self.weeks,
+            Unit::Days => &mut self.days,
+            Unit::Hours => &mut self.hours,
+            Unit::Minutes => &mut self.minutes,
+            Unit::Seconds => &mut self.seconds,
+            Unit::Milliseconds => &mut self.milliseconds,
+            Unit::Microseconds => &mut self.microseconds,
+            Unit::Nanoseconds => &mut self.nanoseconds,
+        }
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-durationsign
+    fn sign(&self) -> i8 {
+        // 1. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        //     a. If v < 0, return -1.
+        //     b. If v > 0, return 1.
+        // 2. Return 0.
+        Unit::ALL
+            .into_iter()
+            .map(|unit| self.get(unit))
+            .find(|v| *v != 0.0)
+            .map_or(0, |v| if v < 0.0 { -1 } else { 1 })
+    }
+
+    /// Abstract operation [`IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidduration
+    fn is_valid(&self) -> bool {
+        const MAX_CALENDAR_UNIT: f64 = 4_294_967_296.0;
+        const MAX_NANOSECONDS: i128 = (1 << 53) * 1_000_000_000;
+
+        // 1. Let sign be DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        for unit in Unit::ALL {
+            let v = self.get(unit);
+            // a. If 𝔽(v) is not finite, return false.
+            // b. If v < 0 and sign > 0, return false.
+            // c. If v > 0 and sign < 0, return false.
+            if !v.is_finite() || (v < 0.0 && sign > 0) || (v > 0.0 && sign < 0) {
+                return false;
+            }
+        }
+
+        // 3. If abs(years) ≥ 2**32, return false.
+        // 4. If abs(months) ≥ 2**32, return false.
+        // 5. If abs(weeks) ≥ 2**32, return false.
+        if [self.years, self.months, self.weeks]
+            .into_iter()
+            .any(|v| v.abs() >= MAX_CALENDAR_UNIT)
+        {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) × 10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+        // 7. NOTE: The above step cannot be implemented directly using floating-point arithmetic. [...]
+        let normalized_nanoseconds = [
+            (self.days, 86_400_000_000_000),
+            (self.hours, 3_600_000_000_000),
+            (self.minutes, 60_000_000_000),
+            (self.seconds, 1_000_000_000),
+            (self.milliseconds, 1_000_000),
+            (self.microseconds, 1_000),
+            (self.nanoseconds, 1),
+        ]
+        .into_iter()
+        .try_fold(0i128, |total, (v, factor)| {
+            (v as i128)
+                .checked_mul(factor)
+                .and_then(|v| total.checked_add(v))
+        });
+
+        // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
+        // 9. Return true.
+        normalized_nanoseconds.is_some_and(|ns| ns.abs() < MAX_NANOSECONDS)
+    }
+}
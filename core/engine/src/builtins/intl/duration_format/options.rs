@@ -0,0 +1,156 @@
+use std::{fmt, str::FromStr};
+
+use icu_list::ListLength;
+
+use crate::{builtins::options::ParsableOptionType, js_string, JsString};
+
+/// The base `style` option of `Intl.DurationFormat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Style {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl Style {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Digital => js_string!("digital"),
+        }
+    }
+
+    /// Gets the length of the list used to join the formatted units.
+    pub(crate) const fn list_length(self) -> ListLength {
+        match self {
+            Self::Long => ListLength::Wide,
+            // The "digital" style uses short lists for the units that are not displayed
+            // numerically.
+            Self::Short | Self::Digital => ListLength::Short,
+            Self::Narrow => ListLength::Narrow,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for Style {}
+
+/// The style of a single unit of `Intl.DurationFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    Fractional,
+}
+
+impl UnitStyle {
+    /// Returns `true` if the unit is displayed as part of a numeric group (e.g. `1:02:03.4`).
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            // Fractional units are reported as "numeric".
+            Self::Numeric | Self::Fractional => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitStyleError;
+
+impl fmt::Display for ParseUnitStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow`, `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for UnitStyle {
+    type Err = ParseUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitStyle {}
+
+/// The display option of a single unit of `Intl.DurationFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Display {
+    Auto,
+    Always,
+}
+
+impl Display {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Auto => js_string!("auto"),
+            Self::Always => js_string!("always"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayError;
+
+impl fmt::Display for ParseDisplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `auto` or `always`")
+    }
+}
+
+impl FromStr for Display {
+    type Err = ParseDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(ParseDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for Display {}
@@ -0,0 +1,260 @@
+use super::DurationRecord;
+
+#[test]
+fn valid_duration_records() {
+    let valid = [
+        DurationRecord::default(),
+        DurationRecord {
+            years: -1.0,
+            nanoseconds: -5.0,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            seconds: 9_007_199_254_740_991.0,
+            nanoseconds: 999_999_999.0,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            weeks: 4_294_967_295.0,
+            ..DurationRecord::default()
+        },
+    ];
+    for record in valid {
+        assert!(record.is_valid(), "{record:?}");
+    }
+
+    let invalid = [
+        DurationRecord {
+            years: 1.0,
+            days: -1.0,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            months: 4_294_967_296.0,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            seconds: 9_007_199_254_740_992.0,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            seconds: 9_007_199_254_740_991.0,
+            milliseconds: 1000.0,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            days: f64::MAX,
+            ..DurationRecord::default()
+        },
+        DurationRecord {
+            hours: f64::INFINITY,
+            ..DurationRecord::default()
+        },
+    ];
+    for record in invalid {
+        assert!(!record.is_valid(), "{record:?}");
+    }
+}
+
+#[cfg(feature = "intl_bundled")]
+mod data {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+    #[test]
+    fn format() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var duration = { hours: 1, minutes: 2, seconds: 3, milliseconds: 45 };
+                var short = new Intl.DurationFormat("en");
+                var narrow = new Intl.DurationFormat("en", { style: "narrow" });
+                var digital = new Intl.DurationFormat("en", { style: "digital" });
+                var fractional = new Intl.DurationFormat("en", {
+                    style: "digital", fractionalDigits: 2
+                });
+                var mixed = new Intl.DurationFormat("en", { minutes: "numeric" });
+            "#}),
+            TestAction::assert_eq(
+                "short.format(duration)",
+                js_string!("1 hr, 2 min, 3 sec, 45 ms"),
+            ),
+            TestAction::assert_eq("short.format({ days: 0 })", js_string!("")),
+            TestAction::assert_eq("short.format({ years: 12345 })", js_string!("12,345 yrs")),
+            TestAction::assert_eq("narrow.format(duration)", js_string!("1h 2m 3s 45ms")),
+            TestAction::assert_eq("digital.format(duration)", js_string!("1:02:03.045")),
+            TestAction::assert_eq(
+                "digital.format({ days: 2, seconds: 5 })",
+                js_string!("2 days, 0:00:05"),
+            ),
+            TestAction::assert_eq(
+                "digital.format({ hours: -1, minutes: -2 })",
+                js_string!("-1:02:00"),
+            ),
+            TestAction::assert_eq("digital.format({ minutes: -2 })", js_string!("-0:02:00")),
+            TestAction::assert_eq(
+                "fractional.format({ seconds: 1, milliseconds: 999, nanoseconds: 1 })",
+                js_string!("0:00:01.99"),
+            ),
+            TestAction::assert_eq(
+                "mixed.format({ hours: 3, minutes: 4, seconds: 5, microseconds: 6 })",
+                js_string!("3 hr, 4:05.000006"),
+            ),
+            TestAction::assert_native_error(
+                "short.format({})",
+                JsNativeErrorKind::Type,
+                "the duration to format must have at least one duration field",
+            ),
+            TestAction::assert_native_error(
+                "short.format({ hours: 1.5 })",
+                JsNativeErrorKind::Range,
+                "`hours` must be an integral number",
+            ),
+            TestAction::assert_native_error(
+                "short.format({ hours: 1, minutes: -1 })",
+                JsNativeErrorKind::Range,
+                "the duration to format is not valid",
+            ),
+            TestAction::assert_native_error(
+                "short.format('PT1H')",
+                JsNativeErrorKind::Range,
+                "cannot format a string as a duration",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn format_unit_styles() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var duration = {
+                    years: 1, months: 2, weeks: 3, days: 1, hours: 4, minutes: 5,
+                    seconds: 6, milliseconds: 7, microseconds: 8, nanoseconds: 9
+                };
+                var long = new Intl.DurationFormat("en", { style: "long" });
+                var short = new Intl.DurationFormat("en", { style: "short" });
+                var narrow = new Intl.DurationFormat("en", { style: "narrow" });
+                var always = new Intl.DurationFormat("en", {
+                    style: "long", yearsDisplay: "always"
+                });
+                var fractional = new Intl.DurationFormat("en", {
+                    style: "long", milliseconds: "numeric"
+                });
+                var padded = new Intl.DurationFormat("en", {
+                    seconds: "long", milliseconds: "numeric", fractionalDigits: 2
+                });
+            "#}),
+            TestAction::assert_eq(
+                "long.format(duration)",
+                js_string!(
+                    "1 year, 2 months, 3 weeks, 1 day, 4 hours, 5 minutes, 6 seconds, \
+                     7 milliseconds, 8 microseconds, 9 nanoseconds"
+                ),
+            ),
+            TestAction::assert_eq(
+                "short.format(duration)",
+                js_string!("1 yr, 2 mths, 3 wks, 1 day, 4 hr, 5 min, 6 sec, 7 ms, 8 μs, 9 ns"),
+            ),
+            TestAction::assert_eq(
+                "narrow.format(duration)",
+                js_string!("1y 2mo 3w 1d 4h 5m 6s 7ms 8μs 9ns"),
+            ),
+            TestAction::assert_eq(
+                "long.format({ hours: -1, seconds: -1, milliseconds: -500 })",
+                js_string!("-1 hour, 1 second, 500 milliseconds"),
+            ),
+            TestAction::assert_eq(
+                "always.format({ years: 0, days: 1 })",
+                js_string!("0 years, 1 day"),
+            ),
+            TestAction::assert_eq(
+                "fractional.format({ seconds: 1, milliseconds: 500 })",
+                js_string!("1.5 seconds"),
+            ),
+            TestAction::assert_eq("padded.format({ seconds: 1 })", js_string!("1.00 seconds")),
+        ]);
+    }
+
+    #[test]
+    fn format_to_parts() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var parts = new Intl.DurationFormat("en", { style: "digital" })
+                    .formatToParts({ hours: -1, minutes: -2 });
+                var units = new Intl.DurationFormat("en", { style: "long" })
+                    .formatToParts({ days: 1, seconds: 2 });
+            "#}),
+            TestAction::assert_eq(
+                "parts.map(p => p.type).join()",
+                js_string!("minusSign,integer,literal,integer,literal,integer"),
+            ),
+            TestAction::assert_eq(
+                "parts.map(p => p.value).join('|')",
+                js_string!("-|1|:|02|:|00"),
+            ),
+            TestAction::assert_eq(
+                "parts.map(p => p.unit).join()",
+                js_string!("hour,hour,,minute,,second"),
+            ),
+            TestAction::assert_eq(
+                "units.map(p => p.type).join()",
+                js_string!("integer,literal,unit,literal,integer,literal,unit"),
+            ),
+            TestAction::assert_eq(
+                "units.map(p => p.unit).join()",
+                js_string!("day,day,day,,second,second,second"),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn resolved_options() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var options = new Intl.DurationFormat("en-u-ca-gregory", {
+                    style: "digital", fractionalDigits: 3, hoursDisplay: "auto"
+                }).resolvedOptions();
+                var defaults = new Intl.DurationFormat("en").resolvedOptions();
+            "#}),
+            TestAction::assert_eq("options.locale", js_string!("en")),
+            TestAction::assert_eq("options.style", js_string!("digital")),
+            TestAction::assert_eq("options.days", js_string!("short")),
+            TestAction::assert_eq("options.daysDisplay", js_string!("auto")),
+            TestAction::assert_eq("options.hours", js_string!("numeric")),
+            TestAction::assert_eq("options.hoursDisplay", js_string!("auto")),
+            TestAction::assert_eq("options.minutes", js_string!("2-digit")),
+            TestAction::assert_eq("options.minutesDisplay", js_string!("always")),
+            TestAction::assert_eq("options.milliseconds", js_string!("numeric")),
+            TestAction::assert_eq("options.fractionalDigits", 3),
+            TestAction::assert_eq("defaults.style", js_string!("short")),
+            TestAction::assert_eq("defaults.seconds", js_string!("short")),
+            TestAction::assert_eq("defaults.secondsDisplay", js_string!("auto")),
+            TestAction::assert_eq("'fractionalDigits' in defaults", false),
+            TestAction::assert_native_error(
+                "new Intl.DurationFormat('en', { days: 'numeric' })",
+                JsNativeErrorKind::Range,
+                "provided string was not `long`, `short` or `narrow`",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+                JsNativeErrorKind::Range,
+                "`minutes` must be numeric after a numeric unit",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DurationFormat('en', { milliseconds: 'numeric', microseconds: 'long' })",
+                JsNativeErrorKind::Range,
+                "`microseconds` must be fractional after a fractional unit",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DurationFormat('en', { style: 'digital', millisecondsDisplay: 'always' })",
+                JsNativeErrorKind::Range,
+                "`millisecondsDisplay` cannot be `always` for a fractional unit",
+            ),
+            TestAction::assert_native_error(
+                "Intl.DurationFormat()",
+                JsNativeErrorKind::Type,
+                "cannot call `Intl.DurationFormat` constructor without `new`",
+            ),
+        ]);
+    }
+}
@@ -5,6 +5,7 @@ use boa_profiler::Profiler;
 use icu_list::{provider::AndListV1Marker, ListFormatter, ListLength};
 use icu_locid::Locale;
 use icu_provider::DataLocale;
+use writeable::{PartsWrite, Writeable};
 
 use crate::{
     builtins::{
@@ -265,87 +266,6 @@ impl ListFormat {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: maybe try to move this into icu4x?
-        use writeable::{PartsWrite, Writeable};
-
-        #[derive(Debug, Clone)]
-        enum Part {
-            Literal(String),
-            Element(String),
-        }
-
-        impl Part {
-            const fn typ(&self) -> &'static str {
-                match self {
-                    Self::Literal(_) => "literal",
-                    Self::Element(_) => "element",
-                }
-            }
-
-            #[allow(clippy::missing_const_for_fn)]
-            fn value(self) -> String {
-                match self {
-                    Self::Literal(s) | Self::Element(s) => s,
-                }
-            }
-        }
-
-        #[derive(Debug, Clone)]
-        struct WriteString(String);
-
-        impl Write for WriteString {
-            fn write_str(&mut self, s: &str) -> std::fmt::Result {
-                self.0.write_str(s)
-            }
-
-            fn write_char(&mut self, c: char) -> std::fmt::Result {
-                self.0.write_char(c)
-            }
-        }
-
-        impl PartsWrite for WriteString {
-            type SubPartsWrite = Self;
-
-            fn with_part(
-                &mut self,
-                _part: writeable::Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
-            ) -> std::fmt::Result {
-                f(self)
-            }
-        }
-
-        #[derive(Debug, Clone)]
-        struct PartsCollector(Vec<Part>);
-
-        impl Write for PartsCollector {
-            fn write_str(&mut self, _: &str) -> std::fmt::Result {
-                Ok(())
-            }
-        }
-
-        impl PartsWrite for PartsCollector {
-            type SubPartsWrite = WriteString;
-
-            fn with_part(
-                &mut self,
-                part: writeable::Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> core::fmt::Result,
-            ) -> core::fmt::Result {
-                assert!(part.category == "list");
-                let mut string = WriteString(String::new());
-                f(&mut string)?;
-                if !string.0.is_empty() {
-                    match part.value {
-                        "element" => self.0.push(Part::Element(string.0)),
-                        "literal" => self.0.push(Part::Literal(string.0)),
-                        _ => unreachable!(),
-                    };
-                }
-                Ok(())
-            }
-        }
-
         // 1. Let lf be the this value.
         // 2. Perform ? RequireInternalSlot(lf, [[InitializedListFormat]]).
         let lf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
@@ -369,11 +289,7 @@ impl ListFormat {
         // https://tc39.es/ecma402/#sec-formatlisttoparts
 
         // 1. Let parts be ! CreatePartsFromList(listFormat, list).
-        let mut parts = PartsCollector(Vec::new());
-        lf.native
-            .format(strings)
-            .write_to_parts(&mut parts)
-            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let parts = create_parts_from_list(&lf.native, strings)?;
 
         // 2. Let result be ! ArrayCreate(0).
         let result = Array::array_create(0, None, context)
@@ -381,7 +297,7 @@ impl ListFormat {
 
         // 3. Let n be 0.
         // 4. For each Record { [[Type]], [[Value]] } part in parts, do
-        for (n, part) in parts.0.into_iter().enumerate() {
+        for (n, part) in parts.into_iter().enumerate() {
             // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
             let o = context
                 .intrinsics()
@@ -479,6 +395,106 @@ impl ListFormat {
     }
 }
 
+/// A part of a list formatted by a [`ListFormatter`].
+#[derive(Debug, Clone)]
+pub(crate) enum ListPart {
+    Literal(String),
+    Element(String),
+}
+
+impl ListPart {
+    pub(crate) const fn typ(&self) -> &'static str {
+        match self {
+            Self::Literal(_) => "literal",
+            Self::Element(_) => "element",
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub(crate) fn value(self) -> String {
+        match self {
+            Self::Literal(s) | Self::Element(s) => s,
+        }
+    }
+}
+
+/// Abstract operation [`CreatePartsFromList ( listFormat, list )`][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createpartsfromlist
+pub(crate) fn create_parts_from_list<W, I>(
+    formatter: &ListFormatter,
+    list: I,
+) -> JsResult<Vec<ListPart>>
+where
+    W: Writeable,
+    I: Iterator<Item = W> + Clone,
+{
+    // TODO: maybe try to move this into icu4x?
+    #[derive(Debug, Clone)]
+    struct WriteString(String);
+
+    impl Write for WriteString {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0.write_str(s)
+        }
+
+        fn write_char(&mut self, c: char) -> std::fmt::Result {
+            self.0.write_char(c)
+        }
+    }
+
+    impl PartsWrite for WriteString {
+        type SubPartsWrite = Self;
+
+        fn with_part(
+            &mut self,
+            _part: writeable::Part,
+            mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+        ) -> std::fmt::Result {
+            f(self)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct PartsCollector(Vec<ListPart>);
+
+    impl Write for PartsCollector {
+        fn write_str(&mut self, _: &str) -> std::fmt::Result {
+            Ok(())
+        }
+    }
+
+    impl PartsWrite for PartsCollector {
+        type SubPartsWrite = WriteString;
+
+        fn with_part(
+            &mut self,
+            part: writeable::Part,
+            mut f: impl FnMut(&mut Self::SubPartsWrite) -> core::fmt::Result,
+        ) -> core::fmt::Result {
+            assert!(part.category == "list");
+            let mut string = WriteString(String::new());
+            f(&mut string)?;
+            if !string.0.is_empty() {
+                match part.value {
+                    "element" => self.0.push(ListPart::Element(string.0)),
+                    "literal" => self.0.push(ListPart::Literal(string.0)),
+                    _ => unreachable!(),
+                };
+            }
+            Ok(())
+        }
+    }
+
+    let mut parts = PartsCollector(Vec::new());
+    formatter
+        .format(list)
+        .write_to_parts(&mut parts)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    Ok(parts.0)
+}
+
 /// Abstract operation [`StringListFromIterable ( iterable )`][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-createstringlistfromiterable
@@ -1,10 +1,22 @@
-use crate::{builtins::options::get_option, realm::Realm, string::StaticJsStrings};
+use crate::{
+    builtins::{options::get_option, Array},
+    context::icu::IcuError,
+    object::ObjectInitializer,
+    realm::Realm,
+    string::StaticJsStrings,
+};
 use boa_profiler::Profiler;
+use icu_calendar::{types::IsoWeekday, week::WeekCalculator, AnyCalendar};
 use icu_collator::CaseFirst;
-use icu_datetime::options::preferences::HourCycle;
+use icu_datetime::{
+    options::preferences::HourCycle, pattern::CoarseHourCycle,
+    provider::calendar::TimeLengthsV1Marker,
+};
 use icu_locid::{
     extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
 };
+use icu_locid_transform::{Direction, LocaleDirectionality};
+use icu_provider::{DataLocale, DataProvider, DataRequest, DataRequestMetadata, DataResponse};
 
 #[cfg(all(test, feature = "intl_bundled"))]
 mod tests;
@@ -24,7 +36,10 @@ use crate::{
     Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
 };
 
-use super::options::coerce_options_to_object;
+use super::{
+    options::coerce_options_to_object,
+    supported_values::{available_time_zones, collations_of, default_numbering_system},
+};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Locale;
@@ -82,6 +97,17 @@ impl IntrinsicObject for Locale {
             .method(Self::maximize, js_string!("maximize"), 0)
             .method(Self::minimize, js_string!("minimize"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::get_calendars, js_string!("getCalendars"), 0)
+            .method(Self::get_collations, js_string!("getCollations"), 0)
+            .method(Self::get_hour_cycles, js_string!("getHourCycles"), 0)
+            .method(
+                Self::get_numbering_systems,
+                js_string!("getNumberingSystems"),
+                0,
+            )
+            .method(Self::get_time_zones, js_string!("getTimeZones"), 0)
+            .method(Self::get_text_info, js_string!("getTextInfo"), 0)
+            .method(Self::get_week_info, js_string!("getWeekInfo"), 0)
             .accessor(
                 js_string!("baseName"),
                 Some(base_name),
@@ -156,7 +182,7 @@ impl BuiltInObject for Locale {
 
 impl BuiltInConstructor for Locale {
     const LENGTH: usize = 1;
-    const P: usize = 14;
+    const P: usize = 21;
     const SP: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -773,4 +799,394 @@ impl Locale {
             .map(|sc| js_string!(sc.to_string()).into())
             .unwrap_or_default())
     }
+
+    /// [`Intl.Locale.prototype.getCalendars ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCalendars
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCalendars
+    pub(crate) fn get_calendars(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCalendars` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCalendars` can only be called on a `Locale` object")
+        })?;
+
+        // 3. Return ! CalendarsOfLocale(loc).
+        // CalendarsOfLocale ( loc )
+        // 1. If loc.[[Calendar]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[Calendar]] »).
+        // 2. Let locale be loc.[[Locale]].
+        // 3. Assert: locale matches the unicode_locale_id production.
+        // 4. Let list be a List of one or more unique canonical calendar identifiers, which must be
+        //    lower case String values conforming to the type sequence from UTS 35 Unicode Locale
+        //    Identifier, section 3.2, sorted in descending preference of those in common use for
+        //    date and time formatting in locale.
+        let calendar = if let Some(ca) = loc.extensions.unicode.keywords.get(&key!("ca")) {
+            ca.to_string()
+        } else {
+            AnyCalendar::try_new_for_locale_unstable(
+                context.intl_provider(),
+                &DataLocale::from(&loc.id),
+            )
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?
+            .kind()
+            .as_bcp47_string()
+            .to_owned()
+        };
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([js_string!(calendar).into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getCollations ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCollations
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations
+    pub(crate) fn get_collations(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCollations` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCollations` can only be called on a `Locale` object")
+        })?;
+
+        // 3. Return ! CollationsOfLocale(loc).
+        // CollationsOfLocale ( loc )
+        // 1. If loc.[[Collation]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[Collation]] »).
+        // 2. Let locale be loc.[[Locale]].
+        // 3. Assert: locale matches the unicode_locale_id production.
+        // 4. Let list be a List of one or more unique canonical collation identifiers, which must
+        //    be lower case String values conforming to the type sequence from UTS 35 Unicode Locale
+        //    Identifier, section 3.2, ordered as if an Array of the same values had been sorted,
+        //    using %Array.prototype.sort% using undefined as comparefn, of those in common use for
+        //    string comparison in locale. The values "standard" and "search" must be excluded from
+        //    list.
+        let collations = match loc.extensions.unicode.keywords.get(&key!("co")) {
+            Some(co) => vec![co.clone()],
+            None => collations_of(&loc.id, context.intl_provider()),
+        };
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(
+            collations
+                .iter()
+                .map(|co| js_string!(co.to_string()).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getHourCycles ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getHourCycles
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getHourCycles
+    pub(crate) fn get_hour_cycles(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getHourCycles` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getHourCycles` can only be called on a `Locale` object")
+        })?;
+
+        // 3. Return ! HourCyclesOfLocale(loc).
+        // HourCyclesOfLocale ( loc )
+        // 1. If loc.[[HourCycle]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[HourCycle]] »).
+        // 2. Let locale be loc.[[Locale]].
+        // 3. Assert: locale matches the unicode_locale_id production.
+        // 4. Let list be a List of one or more unique hour cycle identifiers, which must be lower
+        //    case String values indicating either the 12-hour format ("h11", "h12") or the 24-hour
+        //    format ("h23", "h24"), sorted in descending preference of those in common use for
+        //    date and time formatting in locale.
+        let hour_cycle = if let Some(hc) = loc.extensions.unicode.keywords.get(&key!("hc")) {
+            js_string!(hc.to_string())
+        } else {
+            let lengths = DataProvider::<TimeLengthsV1Marker>::load(
+                context.intl_provider(),
+                DataRequest {
+                    locale: &DataLocale::from(&loc.id),
+                    metadata: DataRequestMetadata::default(),
+                },
+            )
+            .and_then(DataResponse::take_payload)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+            match lengths.get().preferred_hour_cycle {
+                CoarseHourCycle::H11H12 => js_string!("h12"),
+                CoarseHourCycle::H23H24 => js_string!("h23"),
+            }
+        };
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([hour_cycle.into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getNumberingSystems ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getNumberingSystems
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getNumberingSystems
+    pub(crate) fn get_numbering_systems(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getNumberingSystems` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getNumberingSystems` can only be called on a `Locale` object")
+        })?;
+
+        // 3. Return ! NumberingSystemsOfLocale(loc).
+        // NumberingSystemsOfLocale ( loc )
+        // 1. If loc.[[NumberingSystem]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[NumberingSystem]] »).
+        // 2. Let locale be loc.[[Locale]].
+        // 3. Assert: locale matches the unicode_locale_id production.
+        // 4. Let list be a List of one or more unique canonical numbering system identifiers,
+        //    which must be lower case String values conforming to the type sequence from UTS 35
+        //    Unicode Locale Identifier, section 3.2, sorted in descending preference of those in
+        //    common use for formatting numeric values in locale.
+        let numbering_system = loc
+            .extensions
+            .unicode
+            .keywords
+            .get(&key!("nu"))
+            .cloned()
+            .or_else(|| default_numbering_system(&loc.id, context.intl_provider()))
+            .unwrap_or(value!("latn"));
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(
+            [js_string!(numbering_system.to_string()).into()],
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getTimeZones ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTimeZones
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTimeZones
+    pub(crate) fn get_time_zones(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTimeZones` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTimeZones` can only be called on a `Locale` object")
+        })?;
+
+        // 3. Let locale be loc.[[Locale]].
+        // 4. If the unicode_language_id production of locale does not contain the
+        //    ["-" unicode_region_subtag] sequence, return undefined.
+        let Some(region) = loc.id.region else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. Return ! TimeZonesOfLocale(loc).
+        // TimeZonesOfLocale ( loc )
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Assert: locale matches the unicode_locale_id production.
+        // 3. Let region be the substring of locale corresponding to the unicode_region_subtag
+        //    production.
+        // 4. Let list be a List of unique canonical time zone identifiers, which must be String
+        //    values indicating a canonical Zone name of the IANA Time Zone Database, ordered as if
+        //    an Array of the same values had been sorted using %Array.prototype.sort% using
+        //    undefined as comparefn, of those in common use in region. If no time zones are
+        //    commonly used in region, let list be a new empty List.
+        let time_zones = available_time_zones(Some(region), context.intl_provider())?;
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(
+            time_zones.into_iter().map(|tz| js_string!(tz).into()),
+            context,
+        )
+        .into())
+    }
+
+    /// [`Intl.Locale.prototype.getTextInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTextInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTextInfo
+    pub(crate) fn get_text_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTextInfo` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTextInfo` can only be called on a `Locale` object")
+        })?;
+
+        // 4. Let dir be ! TextDirectionOfLocale(loc).
+        // TextDirectionOfLocale ( loc )
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Assert: locale matches the unicode_locale_id production.
+        // 3. Let script be the default script of locale, as specified in UTS 35.
+        // 4. If the default general ordering of characters (characterOrder) within a line in
+        //    script is right-to-left, return "rtl".
+        // 5. If the default general ordering of characters (characterOrder) within a line in
+        //    script is left-to-right, return "ltr".
+        // 6. Return undefined.
+        let direction = LocaleDirectionality::try_new_unstable(context.intl_provider())
+            .map_err(IcuError::from)?
+            .get(&loc.id)
+            .and_then(|direction| match direction {
+                Direction::LeftToRight => Some(js_string!("ltr")),
+                Direction::RightToLeft => Some(js_string!("rtl")),
+                _ => None,
+            });
+
+        // 3. Let info be ! OrdinaryObjectCreate(%Object.prototype%).
+        // 5. Perform ! CreateDataPropertyOrThrow(info, "direction", dir).
+        // 6. Return info.
+        Ok(ObjectInitializer::new(context)
+            .property(
+                js_string!("direction"),
+                direction.map(JsValue::from).unwrap_or_default(),
+                Attribute::all(),
+            )
+            .build()
+            .into())
+    }
+
+    /// [`Intl.Locale.prototype.getWeekInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getWeekInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getWeekInfo
+    pub(crate) fn get_week_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getWeekInfo` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getWeekInfo` can only be called on a `Locale` object")
+        })?;
+
+        // 4. Let wi be ! WeekInfoOfLocale(loc).
+        // WeekInfoOfLocale ( loc )
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Assert: locale matches the unicode_locale_id production.
+        // 3. Return a record whose fields are defined by Table 1, with values based on locale.
+        //
+        // Week data depends on the region of the locale, so the likely region is used if the
+        // locale doesn't have one.
+        let mut id = loc.id.clone();
+        context.intl_provider().locale_expander()?.maximize(&mut id);
+        let week = WeekCalculator::try_new_unstable(context.intl_provider(), &DataLocale::from(id))
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        // The `fw` extension key overrides the first day of the week of the locale.
+        let first_day = loc
+            .extensions
+            .unicode
+            .keywords
+            .get(&key!("fw"))
+            .and_then(Value::as_single_subtag)
+            .and_then(|fw| match &**fw {
+                "mon" => Some(IsoWeekday::Monday),
+                "tue" => Some(IsoWeekday::Tuesday),
+                "wed" => Some(IsoWeekday::Wednesday),
+                "thu" => Some(IsoWeekday::Thursday),
+                "fri" => Some(IsoWeekday::Friday),
+                "sat" => Some(IsoWeekday::Saturday),
+                "sun" => Some(IsoWeekday::Sunday),
+                _ => None,
+            })
+            .unwrap_or(week.first_weekday);
+
+        // 5. Let we be ! CreateArrayFromList(wi.[[Weekend]]).
+        let mut weekend = week.weekend().map(|day| day as u8).collect::<Vec<_>>();
+        weekend.sort_unstable();
+        let weekend =
+            Array::create_array_from_list(weekend.into_iter().map(JsValue::from), context);
+
+        // 3. Let info be ! OrdinaryObjectCreate(%Object.prototype%).
+        // 6. Perform ! CreateDataPropertyOrThrow(info, "firstDay", wi.[[FirstDay]]).
+        // 7. Perform ! CreateDataPropertyOrThrow(info, "weekend", we).
+        // 8. Perform ! CreateDataPropertyOrThrow(info, "minimalDays", wi.[[MinimalDays]]).
+        // 9. Return info.
+        Ok(ObjectInitializer::new(context)
+            .property(js_string!("firstDay"), first_day as u8, Attribute::all())
+            .property(js_string!("weekend"), weekend, Attribute::all())
+            .property(
+                js_string!("minimalDays"),
+                week.min_week_days,
+                Attribute::all(),
+            )
+            .build()
+            .into())
+    }
 }
@@ -8,6 +8,7 @@ use icu_locid::{
 };
 use icu_plurals::provider::CardinalV1Marker;
 use icu_provider::{DataLocale, DataProvider, DataRequest, DataRequestMetadata};
+use indoc::indoc;
 
 use crate::{
     builtins::intl::{
@@ -16,6 +17,7 @@ use crate::{
         Service,
     },
     context::icu::IntlProvider,
+    js_string, run_test_actions, JsValue, TestAction,
 };
 
 #[derive(Debug)]
@@ -112,3 +114,43 @@ fn locale_resolution() {
         resolve_locale::<TestService>([locale!("es-AR")], &mut options, &provider).unwrap();
     assert_eq!(locale, "es-u-hc-h23".parse().unwrap());
 }
+
+#[test]
+fn locale_info() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var de = new Intl.Locale("de-DE");
+            var en = new Intl.Locale("en-US");
+            var extensions = new Intl.Locale("de-u-ca-japanese-co-phonebk-hc-h11-nu-thai-fw-wed");
+        "#}),
+        TestAction::assert_eq("de.getCalendars().join()", js_string!("gregory")),
+        TestAction::assert_eq(
+            "new Intl.Locale('th').getCalendars().join()",
+            js_string!("buddhist"),
+        ),
+        TestAction::assert_eq("de.getCollations().includes('phonebk')", true),
+        TestAction::assert_eq("en.getCollations().includes('phonebk')", false),
+        TestAction::assert_eq("de.getHourCycles().join()", js_string!("h23")),
+        TestAction::assert_eq("en.getHourCycles().join()", js_string!("h12")),
+        TestAction::assert_eq("en.getNumberingSystems().join()", js_string!("latn")),
+        TestAction::assert_eq("de.getTimeZones().join()", js_string!("Europe/Berlin")),
+        TestAction::assert_eq("new Intl.Locale('de').getTimeZones()", JsValue::undefined()),
+        TestAction::assert_eq("de.getTextInfo().direction", js_string!("ltr")),
+        TestAction::assert_eq(
+            "new Intl.Locale('ar').getTextInfo().direction",
+            js_string!("rtl"),
+        ),
+        TestAction::assert_eq("de.getWeekInfo().firstDay", 1),
+        TestAction::assert_eq("de.getWeekInfo().weekend.join()", js_string!("6,7")),
+        TestAction::assert_eq("de.getWeekInfo().minimalDays", 4),
+        TestAction::assert_eq("en.getWeekInfo().firstDay", 7),
+        TestAction::assert_eq("extensions.getCalendars().join()", js_string!("japanese")),
+        TestAction::assert_eq("extensions.getCollations().join()", js_string!("phonebk")),
+        TestAction::assert_eq("extensions.getHourCycles().join()", js_string!("h11")),
+        TestAction::assert_eq(
+            "extensions.getNumberingSystems().join()",
+            js_string!("thai"),
+        ),
+        TestAction::assert_eq("extensions.getWeekInfo().firstDay", 3),
+    ]);
+}
@@ -22,7 +22,7 @@ use crate::{
     realm::Realm,
     string::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -32,26 +32,36 @@ use static_assertions::const_assert;
 
 pub(crate) mod collator;
 pub(crate) mod date_time_format;
+pub(crate) mod display_names;
+pub(crate) mod duration_format;
 pub(crate) mod list_format;
 pub(crate) mod locale;
 pub(crate) mod number_format;
 pub(crate) mod plural_rules;
+pub(crate) mod relative_time_format;
 pub(crate) mod segmenter;
 
 pub(crate) use self::{
-    collator::Collator, date_time_format::DateTimeFormat, list_format::ListFormat, locale::Locale,
-    number_format::NumberFormat, plural_rules::PluralRules, segmenter::Segmenter,
+    collator::Collator, date_time_format::DateTimeFormat, display_names::DisplayNames,
+    duration_format::DurationFormat, list_format::ListFormat, locale::Locale,
+    number_format::NumberFormat, plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
 };
 
 mod options;
+mod supported_values;
 
 // No singletons are allowed as lang markers.
 // Hopefully, we'll be able to migrate this to the definition of `Service` in the future
 // (https://github.com/rust-lang/rust/issues/76560)
 const_assert! {!<Collator as Service>::LangMarker::KEY.metadata().singleton}
+const_assert! {!<DateTimeFormat as Service>::LangMarker::KEY.metadata().singleton}
+const_assert! {!<DisplayNames as Service>::LangMarker::KEY.metadata().singleton}
+const_assert! {!<DurationFormat as Service>::LangMarker::KEY.metadata().singleton}
 const_assert! {!<ListFormat as Service>::LangMarker::KEY.metadata().singleton}
 const_assert! {!<NumberFormat as Service>::LangMarker::KEY.metadata().singleton}
 const_assert! {!<PluralRules as Service>::LangMarker::KEY.metadata().singleton}
+const_assert! {!<RelativeTimeFormat as Service>::LangMarker::KEY.metadata().singleton}
 const_assert! {!<Segmenter as Service>::LangMarker::KEY.metadata().singleton}
 
 /// JavaScript `Intl` object.
@@ -135,11 +145,43 @@ impl IntrinsicObject for Intl {
                     .constructor(),
                 NumberFormat::ATTRIBUTE,
             )
+            .static_property(
+                RelativeTimeFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .relative_time_format()
+                    .constructor(),
+                RelativeTimeFormat::ATTRIBUTE,
+            )
+            .static_property(
+                DisplayNames::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .display_names()
+                    .constructor(),
+                DisplayNames::ATTRIBUTE,
+            )
+            .static_property(
+                DurationFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .duration_format()
+                    .constructor(),
+                DurationFormat::ATTRIBUTE,
+            )
             .static_method(
                 Self::get_canonical_locales,
                 js_string!("getCanonicalLocales"),
                 1,
             )
+            .static_method(
+                Self::supported_values_of,
+                js_string!("supportedValuesOf"),
+                1,
+            )
             .build();
     }
 
@@ -179,6 +221,85 @@ impl Intl {
             context,
         )))
     }
+
+    /// `Intl.supportedValuesOf ( key )`
+    ///
+    /// Returns an array containing the supported calendar, collation, currency, numbering system,
+    /// time zone or unit identifiers.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN docs][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.supportedvaluesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/supportedValuesOf
+    pub(crate) fn supported_values_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let key be ? ToString(key).
+        let key = args.get_or_undefined(0).to_string(context)?;
+
+        let provider = context.intl_provider();
+        let list: Vec<JsString> = match key.to_std_string_escaped().as_str() {
+            // 2. If key is "calendar", then
+            //     a. Let list be a new empty List.
+            //     b. For each element identifier of AvailableCalendars(), do
+            //         i. Let canonical be CanonicalizeUValue("ca", identifier).
+            //         ii. If identifier is canonical, then
+            //             1. Append identifier to list.
+            "calendar" => supported_values::available_calendars()
+                .iter()
+                .map(|ca| js_string!(ca.to_string()))
+                .collect(),
+            // 3. Else if key is "collation", then
+            //     a. Let list be AvailableCanonicalCollations( ).
+            "collation" => supported_values::available_collations(provider)
+                .iter()
+                .map(|co| js_string!(co.to_string()))
+                .collect(),
+            // 4. Else if key is "currency", then
+            //     a. Let list be AvailableCanonicalCurrencies( ).
+            "currency" => supported_values::available_currencies()
+                .map(|currency| js_string!(currency))
+                .collect(),
+            // 5. Else if key is "numberingSystem", then
+            //     a. Let list be AvailableCanonicalNumberingSystems( ).
+            "numberingSystem" => supported_values::available_numbering_systems(provider)
+                .iter()
+                .map(|nu| js_string!(nu.to_string()))
+                .collect(),
+            // 6. Else if key is "timeZone", then
+            //     a. Let list be AvailablePrimaryTimeZoneIdentifiers( ).
+            "timeZone" => supported_values::available_time_zones(None, provider)?
+                .into_iter()
+                .map(|tz| js_string!(tz))
+                .collect(),
+            // 7. Else if key is "unit", then
+            //     a. Let list be AvailableCanonicalUnits( ).
+            "unit" => number_format::SANCTIONED_UNITS
+                .into_iter()
+                .map(|unit| js_string!(unit))
+                .collect(),
+            // 8. Else,
+            //     a. Throw a RangeError exception.
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "`{}` is not a supported key for `Intl.supportedValuesOf`",
+                        key.to_std_string_escaped()
+                    ))
+                    .into())
+            }
+        };
+
+        // 9. Return CreateArrayFromList( list ).
+        Ok(JsValue::Object(Array::create_array_from_list(
+            list.into_iter().map(JsValue::from),
+            context,
+        )))
+    }
 }
 
 /// A service component that is part of the `Intl` API.
@@ -859,3 +859,28 @@ pub(crate) fn js_string_to_fixed_decimal(string: &JsString) -> Option<FixedDecim
 
     FixedDecimal::try_from(s.as_bytes()).ok()
 }
+
+/// Splits a formatted non-negative number into its integer, group, decimal and fraction parts.
+pub(crate) fn number_parts(
+    formatted: &str,
+    decimal: &str,
+    group: &str,
+) -> Vec<(&'static str, String)> {
+    let (integer, fraction) = match formatted.split_once(decimal) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted, None),
+    };
+
+    let mut parts = Vec::new();
+    for (i, digits) in integer.split(group).enumerate() {
+        if i > 0 {
+            parts.push(("group", group.to_owned()));
+        }
+        parts.push(("integer", digits.to_owned()));
+    }
+    if let Some(fraction) = fraction {
+        parts.push(("decimal", decimal.to_owned()));
+        parts.push(("fraction", fraction.to_owned()));
+    }
+    parts
+}
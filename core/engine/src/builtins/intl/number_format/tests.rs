@@ -1,4 +1,4 @@
-use crate::builtins::intl::number_format::RoundingIncrement;
+use crate::builtins::intl::number_format::{number_parts, RoundingIncrement};
 use fixed_decimal::RoundingIncrement::*;
 
 #[test]
@@ -39,3 +39,33 @@ fn u16_to_rounding_increment_rainy_day() {
         assert!(RoundingIncrement::from_u16(num).is_none());
     }
 }
+
+#[test]
+fn split_formatted_number_into_parts() {
+    assert_eq!(
+        number_parts("3", ".", ","),
+        vec![("integer", String::from("3"))]
+    );
+    assert_eq!(
+        number_parts("1,234,567.891", ".", ","),
+        vec![
+            ("integer", String::from("1")),
+            ("group", String::from(",")),
+            ("integer", String::from("234")),
+            ("group", String::from(",")),
+            ("integer", String::from("567")),
+            ("decimal", String::from(".")),
+            ("fraction", String::from("891")),
+        ]
+    );
+    assert_eq!(
+        number_parts("1.000,5", ",", "."),
+        vec![
+            ("integer", String::from("1")),
+            ("group", String::from(".")),
+            ("integer", String::from("000")),
+            ("decimal", String::from(",")),
+            ("fraction", String::from("5")),
+        ]
+    );
+}
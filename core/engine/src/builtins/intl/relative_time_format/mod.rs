@@ -0,0 +1,718 @@
+//! This module implements the global `Intl.RelativeTimeFormat` object.
+//!
+//! `Intl.RelativeTimeFormat` is a built-in object that enables language-sensitive relative time
+//! formatting.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#relativetimeformat-objects
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_experimental::relativetime::{
+    options::Numeric,
+    provider::{
+        LongDayRelativeTimeFormatDataV1Marker, LongHourRelativeTimeFormatDataV1Marker,
+        LongMinuteRelativeTimeFormatDataV1Marker, LongMonthRelativeTimeFormatDataV1Marker,
+        LongQuarterRelativeTimeFormatDataV1Marker, LongSecondRelativeTimeFormatDataV1Marker,
+        LongWeekRelativeTimeFormatDataV1Marker, LongYearRelativeTimeFormatDataV1Marker,
+        NarrowDayRelativeTimeFormatDataV1Marker, NarrowHourRelativeTimeFormatDataV1Marker,
+        NarrowMinuteRelativeTimeFormatDataV1Marker, NarrowMonthRelativeTimeFormatDataV1Marker,
+        NarrowQuarterRelativeTimeFormatDataV1Marker, NarrowSecondRelativeTimeFormatDataV1Marker,
+        NarrowWeekRelativeTimeFormatDataV1Marker, NarrowYearRelativeTimeFormatDataV1Marker,
+        RelativeTimePatternDataV1, ShortDayRelativeTimeFormatDataV1Marker,
+        ShortHourRelativeTimeFormatDataV1Marker, ShortMinuteRelativeTimeFormatDataV1Marker,
+        ShortMonthRelativeTimeFormatDataV1Marker, ShortQuarterRelativeTimeFormatDataV1Marker,
+        ShortSecondRelativeTimeFormatDataV1Marker, ShortWeekRelativeTimeFormatDataV1Marker,
+        ShortYearRelativeTimeFormatDataV1Marker,
+    },
+};
+use icu_locid::{
+    extensions::unicode::{key, Value},
+    Locale,
+};
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::{
+    DataLocale, DataMarker, DataPayload, DataProvider, DataRequest, DataRequestMetadata,
+    DataResponse, KeyedDataMarker,
+};
+
+use crate::{
+    builtins::{
+        options::get_option, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
+    number_format::{number_parts, DigitFormatOptions, NotationKind},
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Intl.RelativeTimeFormat` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `RelativeTimeFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: Style,
+    numeric: Numeric,
+    digit_options: DigitFormatOptions,
+    number_format: FixedDecimalFormatter,
+    decimal_symbols: DataPayload<DecimalSymbolsV1Marker>,
+    plural_rules: PluralRules,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct RelativeTimeFormatLocaleOptions {
+    numbering_system: Option<Value>,
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = LongDayRelativeTimeFormatDataV1Marker;
+
+    type LocaleOptions = RelativeTimeFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    provider,
+                )
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<DecimalSymbolsV1Marker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            provider,
+                        )
+                    })
+            });
+
+        locale.extensions.unicode.clear();
+
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.numbering_system = numbering_system;
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%",
+        //    « [[InitializedRelativeTimeFormat]], [[Locale]], [[LocaleData]], [[Style]], [[Numeric]],
+        //    [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 9. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_option(&options, js_string!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: RelativeTimeFormatLocaleOptions { numbering_system },
+        };
+
+        // 11. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], %Intl.RelativeTimeFormat%.[[LocaleData]]).
+        // 12. Let locale be r.[[Locale]].
+        // 13. Set relativeTimeFormat.[[Locale]] to locale.
+        // 14. Set relativeTimeFormat.[[LocaleData]] to r.[[LocaleData]].
+        // 15. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 16. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 17. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 18. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 19. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric = get_option(&options, js_string!("numeric"), context)?.unwrap_or_default();
+
+        // 20. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        // 21. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        // Both services are created with their default options, which always round to the same
+        // number of digits.
+        let digit_options = DigitFormatOptions::from_options(
+            &JsObject::with_null_proto(),
+            0,
+            3,
+            NotationKind::Standard,
+            context,
+        )?;
+
+        let data_locale = DataLocale::from(&locale);
+        let provider = context.intl_provider();
+        let number_format = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            &data_locale,
+            FixedDecimalFormatterOptions::default(),
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let decimal_symbols = load_data(provider, &data_locale)?;
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, &data_locale)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        // 22. Return relativeTimeFormat.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                numeric,
+                digit_options,
+                number_format,
+                decimal_symbols,
+                plural_rules,
+            },
+        )
+        .into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Formats a value and unit according to the locale and formatting options of this
+    /// `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on an `Intl.RelativeTimeFormat` object")
+        })?;
+        let rtf = rtf.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on an `Intl.RelativeTimeFormat` object")
+        })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+        // FormatRelativeTime ( relativeTimeFormat, value, unit )
+        // <https://tc39.es/ecma402/#sec-FormatRelativeTime>
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = rtf.partition_relative_time_pattern(value, &unit, context)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts.into_iter().map(|part| part.value).collect::<String>();
+
+        // 4. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`formatToParts` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+        let rtf = rtf.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`formatToParts` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+        // FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )
+        // <https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts>
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = rtf.partition_relative_time_pattern(value, &unit, context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            if let Some(unit) = part.unit {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(js_string!("unit"), unit.to_js_string(), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and relative time formatting
+    /// options computed during the construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+        let rtf = rtf.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 20, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(rtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                rtf.style.to_js_string(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numeric"),
+                match rtf.numeric {
+                    Numeric::Always => js_string!("always"),
+                    Numeric::Auto => js_string!("auto"),
+                },
+                Attribute::all(),
+            );
+        if let Some(nu) = &rtf.numbering_system {
+            options.property(
+                js_string!("numberingSystem"),
+                js_string!(nu.to_string()),
+                Attribute::all(),
+            );
+        }
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+
+    /// Abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition_relative_time_pattern(
+        &self,
+        value: f64,
+        unit: &JsString,
+        context: &Context,
+    ) -> JsResult<Vec<RelativeTimePart>> {
+        // 1. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a relative time that is not finite")
+                .into());
+        }
+
+        // 2. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = Unit::from_js_string(unit)?;
+
+        // 3. Let fields be relativeTimeFormat.[[LocaleData]].
+        // 4. Let style be relativeTimeFormat.[[Style]].
+        // 5. If style is equal to "short", then
+        //     a. Let entry be the string-concatenation of unit and "-short".
+        // 6. Else if style is equal to "narrow", then
+        //     a. Let entry be the string-concatenation of unit and "-narrow".
+        // 7. Else,
+        //     a. Let entry be unit.
+        // 8. If fields doesn't have a field [[<entry>]], then
+        //     a. Let entry be unit.
+        // 9. Let patterns be fields.[[<entry>]].
+        let patterns = load_patterns(
+            self.style,
+            unit,
+            &DataLocale::from(&self.locale),
+            context.intl_provider(),
+        )?;
+        let patterns = patterns.get();
+
+        // 10. Let numeric be relativeTimeFormat.[[Numeric]].
+        // 11. If numeric is equal to "auto", then
+        if self.numeric == Numeric::Auto {
+            // a. Let valueString be ToString(value).
+            // b. If patterns has a field [[<valueString>]], then
+            //     i. Let result be patterns.[[<valueString>]].
+            //     ii. Return a List containing the Record { [[Type]]: "literal", [[Value]]: result, [[Unit]]: empty }.
+            // The data only contains integers that fit in an `i8`, and `ToString(-0)` is "0".
+            if value.fract() == 0.0 && (f64::from(i8::MIN)..=f64::from(i8::MAX)).contains(&value) {
+                if let Some(result) = patterns.relatives.get(&(value as i8)) {
+                    return Ok(vec![RelativeTimePart::literal(result.into())]);
+                }
+            }
+        }
+
+        // 12. If value is -0𝔽 or if value is less than 0, then
+        //     a. Let tl be "past".
+        //     b. Set value to -value.
+        // 13. Else,
+        //     a. Let tl be "future".
+        // 14. Let po be patterns.[[<tl>]].
+        let po = if value.is_sign_negative() {
+            &patterns.past
+        } else {
+            &patterns.future
+        };
+        let value = value.abs();
+
+        // 15. Let fv be PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], value).
+        let fixed = self.digit_options.format_f64(value);
+        let formatted = self.number_format.format_to_string(&fixed);
+
+        // 16. Let pr be ResolvePlural(relativeTimeFormat.[[PluralRules]], value).[[PluralCategory]].
+        // 17. Let pattern be po.[[<pr>]].
+        let pattern = match self.plural_rules.category_for(&fixed) {
+            PluralCategory::Zero => po.zero.as_ref(),
+            PluralCategory::One => po.one.as_ref(),
+            PluralCategory::Two => po.two.as_ref(),
+            PluralCategory::Few => po.few.as_ref(),
+            PluralCategory::Many => po.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&po.other);
+
+        // 18. Return MakePartsList(pattern, unit, fv).
+        // MakePartsList ( pattern, unit, parts )
+        // <https://tc39.es/ecma402/#sec-makepartslist>
+
+        // The placeholder is already removed from the pattern, and its index is `u8::MAX` if the
+        // pattern doesn't have a placeholder.
+        if pattern.index == u8::MAX {
+            return Ok(vec![RelativeTimePart::literal(pattern.pattern.to_string())]);
+        }
+        let (prefix, suffix) = pattern
+            .pattern
+            .split_at_checked(usize::from(pattern.index))
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("invalid relative time pattern data")
+            })?;
+
+        let symbols = self.decimal_symbols.get();
+        let mut result = Vec::new();
+        if !prefix.is_empty() {
+            result.push(RelativeTimePart::literal(prefix.to_owned()));
+        }
+        result.extend(
+            number_parts(
+                &formatted,
+                &symbols.decimal_separator,
+                &symbols.grouping_separator,
+            )
+            .into_iter()
+            .map(|(typ, value)| RelativeTimePart {
+                typ,
+                value,
+                unit: Some(unit),
+            }),
+        );
+        if !suffix.is_empty() {
+            result.push(RelativeTimePart::literal(suffix.to_owned()));
+        }
+
+        Ok(result)
+    }
+}
+
+/// A part of a formatted relative time.
+#[derive(Debug)]
+struct RelativeTimePart {
+    typ: &'static str,
+    value: String,
+    unit: Option<Unit>,
+}
+
+impl RelativeTimePart {
+    fn literal(value: String) -> Self {
+        Self {
+            typ: "literal",
+            value,
+            unit: None,
+        }
+    }
+}
+
+/// Marker for the relative time data of any style and unit.
+struct ErasedRelativeTimePatternsV1Marker;
+
+impl DataMarker for ErasedRelativeTimePatternsV1Marker {
+    type Yokeable = RelativeTimePatternDataV1<'static>;
+}
+
+/// Loads the data of the marker `M` for `locale`.
+fn load_data<M>(provider: &IntlProvider, locale: &DataLocale) -> JsResult<DataPayload<M>>
+where
+    M: KeyedDataMarker,
+    IntlProvider: DataProvider<M>,
+{
+    provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+}
+
+/// Loads the relative time patterns of `unit` with the provided style.
+fn load_patterns(
+    style: Style,
+    unit: Unit,
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<DataPayload<ErasedRelativeTimePatternsV1Marker>> {
+    macro_rules! load {
+        ($($style:ident => [$($unit:ident => $marker:ty),* $(,)?]),* $(,)?) => {
+            match (style, unit) {
+                $($(
+                    (Style::$style, Unit::$unit) => load_data::<$marker>(provider, locale)
+                        .map(DataPayload::cast),
+                )*)*
+            }
+        };
+    }
+
+    load! {
+        Long => [
+            Second => LongSecondRelativeTimeFormatDataV1Marker,
+            Minute => LongMinuteRelativeTimeFormatDataV1Marker,
+            Hour => LongHourRelativeTimeFormatDataV1Marker,
+            Day => LongDayRelativeTimeFormatDataV1Marker,
+            Week => LongWeekRelativeTimeFormatDataV1Marker,
+            Month => LongMonthRelativeTimeFormatDataV1Marker,
+            Quarter => LongQuarterRelativeTimeFormatDataV1Marker,
+            Year => LongYearRelativeTimeFormatDataV1Marker,
+        ],
+        Short => [
+            Second => ShortSecondRelativeTimeFormatDataV1Marker,
+            Minute => ShortMinuteRelativeTimeFormatDataV1Marker,
+            Hour => ShortHourRelativeTimeFormatDataV1Marker,
+            Day => ShortDayRelativeTimeFormatDataV1Marker,
+            Week => ShortWeekRelativeTimeFormatDataV1Marker,
+            Month => ShortMonthRelativeTimeFormatDataV1Marker,
+            Quarter => ShortQuarterRelativeTimeFormatDataV1Marker,
+            Year => ShortYearRelativeTimeFormatDataV1Marker,
+        ],
+        Narrow => [
+            Second => NarrowSecondRelativeTimeFormatDataV1Marker,
+            Minute => NarrowMinuteRelativeTimeFormatDataV1Marker,
+            Hour => NarrowHourRelativeTimeFormatDataV1Marker,
+            Day => NarrowDayRelativeTimeFormatDataV1Marker,
+            Week => NarrowWeekRelativeTimeFormatDataV1Marker,
+            Month => NarrowMonthRelativeTimeFormatDataV1Marker,
+            Quarter => NarrowQuarterRelativeTimeFormatDataV1Marker,
+            Year => NarrowYearRelativeTimeFormatDataV1Marker,
+        ],
+    }
+}
@@ -0,0 +1,108 @@
+use icu_experimental::relativetime::options::Numeric;
+
+use crate::{
+    builtins::options::OptionType, js_string, Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// The `style` option of `Intl.RelativeTimeFormat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Style {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+impl Style {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+        }
+    }
+}
+
+impl OptionType for Style {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `long`, `short` or `narrow`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Numeric {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "always" => Ok(Self::Always),
+            "auto" => Ok(Self::Auto),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `always` or `auto`")
+                .into()),
+        }
+    }
+}
+
+/// The units accepted by the formatting methods of `Intl.RelativeTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Unit {
+    /// Abstract operation [`SingularRelativeTimeUnit ( unit )`][spec].
+    ///
+    /// Parses a unit, accepting both its singular and plural forms.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-singularrelativetimeunit
+    pub(crate) fn from_js_string(unit: &JsString) -> JsResult<Self> {
+        // 1. If unit is "seconds", return "second".
+        // 2. If unit is "minutes", return "minute".
+        // 3. If unit is "hours", return "hour".
+        // 4. If unit is "days", return "day".
+        // 5. If unit is "weeks", return "week".
+        // 6. If unit is "months", return "month".
+        // 7. If unit is "quarters", return "quarter".
+        // 8. If unit is "years", return "year".
+        // 9. If unit is not one of "second", "minute", "hour", "day", "week", "month", "quarter", or "year", throw a RangeError exception.
+        // 10. Return unit.
+        match unit.to_std_string_escaped().as_str() {
+            "second" | "seconds" => Ok(Self::Second),
+            "minute" | "minutes" => Ok(Self::Minute),
+            "hour" | "hours" => Ok(Self::Hour),
+            "day" | "days" => Ok(Self::Day),
+            "week" | "weeks" => Ok(Self::Week),
+            "month" | "months" => Ok(Self::Month),
+            "quarter" | "quarters" => Ok(Self::Quarter),
+            "year" | "years" => Ok(Self::Year),
+            unit => Err(JsNativeError::range()
+                .with_message(format!("invalid relative time unit `{unit}`"))
+                .into()),
+        }
+    }
+
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Second => js_string!("second"),
+            Self::Minute => js_string!("minute"),
+            Self::Hour => js_string!("hour"),
+            Self::Day => js_string!("day"),
+            Self::Week => js_string!("week"),
+            Self::Month => js_string!("month"),
+            Self::Quarter => js_string!("quarter"),
+            Self::Year => js_string!("year"),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::js_string;
+
+use super::Unit;
+
+#[test]
+fn singular_relative_time_unit() {
+    for (unit, expected) in [
+        ("second", Unit::Second),
+        ("seconds", Unit::Second),
+        ("minutes", Unit::Minute),
+        ("hour", Unit::Hour),
+        ("days", Unit::Day),
+        ("week", Unit::Week),
+        ("months", Unit::Month),
+        ("quarter", Unit::Quarter),
+        ("years", Unit::Year),
+    ] {
+        assert_eq!(Unit::from_js_string(&js_string!(unit)).unwrap(), expected);
+    }
+
+    for invalid in ["", "Day", "millisecond", "decade", "days "] {
+        assert!(
+            Unit::from_js_string(&js_string!(invalid)).is_err(),
+            "{invalid}"
+        );
+    }
+}
+
+#[cfg(feature = "intl_bundled")]
+mod data {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+    #[test]
+    fn format() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var en = new Intl.RelativeTimeFormat("en");
+                var auto = new Intl.RelativeTimeFormat("en", { numeric: "auto" });
+                var short = new Intl.RelativeTimeFormat("en", { style: "short" });
+                var de = new Intl.RelativeTimeFormat("de", { numeric: "auto" });
+            "#}),
+            TestAction::assert_eq("en.format(3, 'day')", js_string!("in 3 days")),
+            TestAction::assert_eq("en.format(-1, 'days')", js_string!("1 day ago")),
+            TestAction::assert_eq("en.format(-0, 'day')", js_string!("0 days ago")),
+            TestAction::assert_eq("en.format(1, 'day')", js_string!("in 1 day")),
+            TestAction::assert_eq("en.format(1234.5, 'year')", js_string!("in 1,234.5 years")),
+            TestAction::assert_eq("auto.format(1, 'day')", js_string!("tomorrow")),
+            TestAction::assert_eq("auto.format(-1, 'day')", js_string!("yesterday")),
+            TestAction::assert_eq("auto.format(-0, 'day')", js_string!("today")),
+            TestAction::assert_eq("auto.format(2, 'day')", js_string!("in 2 days")),
+            TestAction::assert_eq("auto.format(1.5, 'day')", js_string!("in 1.5 days")),
+            TestAction::assert_eq("short.format(-2, 'month')", js_string!("2 mo. ago")),
+            TestAction::assert_eq("de.format(2, 'day')", js_string!("übermorgen")),
+            TestAction::assert_eq("de.format(-3, 'day')", js_string!("vor 3 Tagen")),
+            TestAction::assert_native_error(
+                "en.format(1, 'decade')",
+                JsNativeErrorKind::Range,
+                "invalid relative time unit `decade`",
+            ),
+            TestAction::assert_native_error(
+                "en.format(Infinity, 'day')",
+                JsNativeErrorKind::Range,
+                "cannot format a relative time that is not finite",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn format_to_parts() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var en = new Intl.RelativeTimeFormat("en", { numeric: "auto" });
+                var parts = en.formatToParts(-1234.5, "seconds");
+                var named = en.formatToParts(1, "day");
+            "#}),
+            TestAction::assert_eq(
+                "parts.map(p => p.type).join()",
+                js_string!("integer,group,integer,decimal,fraction,literal"),
+            ),
+            TestAction::assert_eq(
+                "parts.map(p => p.value).join('|')",
+                js_string!("1|,|234|.|5| seconds ago"),
+            ),
+            TestAction::assert_eq(
+                "parts.map(p => p.unit).join()",
+                js_string!("second,second,second,second,second,"),
+            ),
+            TestAction::assert_eq("named.length", 1),
+            TestAction::assert_eq("named[0].type", js_string!("literal")),
+            TestAction::assert_eq("named[0].value", js_string!("tomorrow")),
+            TestAction::assert_eq("'unit' in named[0]", false),
+        ]);
+    }
+
+    #[test]
+    fn resolved_options() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var options = new Intl.RelativeTimeFormat("en-u-ca-gregory", {
+                    style: "narrow", numeric: "auto"
+                }).resolvedOptions();
+                var defaults = new Intl.RelativeTimeFormat("en").resolvedOptions();
+            "#}),
+            TestAction::assert_eq("options.locale", js_string!("en")),
+            TestAction::assert_eq("options.style", js_string!("narrow")),
+            TestAction::assert_eq("options.numeric", js_string!("auto")),
+            TestAction::assert_eq("defaults.style", js_string!("long")),
+            TestAction::assert_eq("defaults.numeric", js_string!("always")),
+            TestAction::assert_native_error(
+                "new Intl.RelativeTimeFormat('en', { style: 'tiny' })",
+                JsNativeErrorKind::Range,
+                "provided string was not `long`, `short` or `narrow`",
+            ),
+            TestAction::assert_native_error(
+                "Intl.RelativeTimeFormat()",
+                JsNativeErrorKind::Type,
+                "cannot call `Intl.RelativeTimeFormat` constructor without `new`",
+            ),
+        ]);
+    }
+}
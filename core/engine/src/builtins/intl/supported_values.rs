@@ -0,0 +1,326 @@
+//! The sets of values supported by the `Intl` services.
+//!
+//! These are used to implement [`Intl.supportedValuesOf`][super::Intl::supported_values_of] and
+//! the locale information methods of `Intl.Locale`.
+
+use icu_calendar::AnyCalendarKind;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::{key, value, Value},
+    langid,
+    subtags::Region,
+    LanguageIdentifier,
+};
+use icu_provider::{DataLocale, DataProvider, DataRequest, DataRequestMetadata, DataResponse};
+use icu_timezone::provider::names::{Bcp47ToIanaMapV1Marker, IanaToBcp47MapV2Marker};
+
+use crate::{context::icu::IntlProvider, JsNativeError, JsResult};
+
+use super::{
+    collator::Collator, date_time_format::is_supported_calendar, locale::validate_extension,
+    Service,
+};
+
+/// All the calendar types known to ICU4X.
+const CALENDARS: [AnyCalendarKind; 18] = [
+    AnyCalendarKind::Buddhist,
+    AnyCalendarKind::Chinese,
+    AnyCalendarKind::Coptic,
+    AnyCalendarKind::Dangi,
+    AnyCalendarKind::Ethiopian,
+    AnyCalendarKind::EthiopianAmeteAlem,
+    AnyCalendarKind::Gregorian,
+    AnyCalendarKind::Hebrew,
+    AnyCalendarKind::Indian,
+    AnyCalendarKind::IslamicCivil,
+    AnyCalendarKind::IslamicObservational,
+    AnyCalendarKind::IslamicTabular,
+    AnyCalendarKind::IslamicUmmAlQura,
+    AnyCalendarKind::Iso,
+    AnyCalendarKind::Japanese,
+    AnyCalendarKind::JapaneseExtended,
+    AnyCalendarKind::Persian,
+    AnyCalendarKind::Roc,
+];
+
+/// The collation types defined by CLDR, along with a language tailoring them.
+///
+/// `standard` and `search` are not included, since they cannot be used by `Intl.Collator`.
+const COLLATIONS: [(Value, LanguageIdentifier); 13] = [
+    (value!("big5han"), langid!("zh")),
+    (value!("compat"), langid!("ar")),
+    (value!("dict"), langid!("si")),
+    (value!("emoji"), langid!("und")),
+    (value!("eor"), langid!("und")),
+    (value!("gb2312"), langid!("zh")),
+    (value!("phonebk"), langid!("de")),
+    (value!("pinyin"), langid!("zh")),
+    (value!("searchjl"), langid!("ko")),
+    (value!("stroke"), langid!("zh")),
+    (value!("trad"), langid!("es")),
+    (value!("unihan"), langid!("ja")),
+    (value!("zhuyin"), langid!("zh")),
+];
+
+/// The numbering systems with simple digit mappings, along with their digit zero and a
+/// language using them.
+const NUMBERING_SYSTEMS: [(Value, char, LanguageIdentifier); 23] = [
+    (value!("adlm"), '\u{1E950}', langid!("ff-Adlm")),
+    (value!("arab"), '\u{660}', langid!("ar")),
+    (value!("arabext"), '\u{6F0}', langid!("fa")),
+    (value!("beng"), '\u{9E6}', langid!("bn")),
+    (value!("cakm"), '\u{11136}', langid!("ccp")),
+    (value!("deva"), '\u{966}', langid!("hi")),
+    (value!("gujr"), '\u{AE6}', langid!("gu")),
+    (value!("guru"), '\u{A66}', langid!("pa")),
+    (value!("hanidec"), '\u{3007}', langid!("zh")),
+    (value!("java"), '\u{A9D0}', langid!("jv")),
+    (value!("khmr"), '\u{17E0}', langid!("km")),
+    (value!("knda"), '\u{CE6}', langid!("kn")),
+    (value!("laoo"), '\u{ED0}', langid!("lo")),
+    (value!("latn"), '0', langid!("und")),
+    (value!("mlym"), '\u{D66}', langid!("ml")),
+    (value!("mtei"), '\u{ABF0}', langid!("mni")),
+    (value!("mymr"), '\u{1040}', langid!("my")),
+    (value!("olck"), '\u{1C50}', langid!("sat")),
+    (value!("orya"), '\u{B66}', langid!("or")),
+    (value!("tamldec"), '\u{BE6}', langid!("ta")),
+    (value!("telu"), '\u{C66}', langid!("te")),
+    (value!("thai"), '\u{E50}', langid!("th")),
+    (value!("tibt"), '\u{F20}', langid!("dz")),
+];
+
+/// The currencies in active use, as defined by ISO 4217.
+const CURRENCIES: [&str; 158] = [
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD",
+    "CDF", "CHF", "CLP", "CNY", "COP", "CRC", "CUC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP",
+    "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF",
+    "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD",
+    "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP",
+    "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR",
+    "MVR", "MWK", "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB",
+    "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD",
+    "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL", "SOS", "SRD", "SSP", "STN", "SVC", "SYP",
+    "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD",
+    "UYU", "UZS", "VED", "VES", "VND", "VUV", "WST", "XAF", "XCD", "XOF", "XPF", "YER", "ZAR",
+    "ZMW", "ZWL",
+];
+
+/// Abstract operation [`AvailableCalendars ( )`][spec].
+///
+/// Returns the calendar types supported by `Intl.DateTimeFormat`, sorted by identifier.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecalendars
+pub(in crate::builtins::intl) fn available_calendars() -> Vec<Value> {
+    let mut calendars = CALENDARS
+        .into_iter()
+        .map(AnyCalendarKind::as_bcp47_value)
+        .filter(is_supported_calendar)
+        .collect::<Vec<_>>();
+    calendars.sort_unstable();
+    calendars.dedup();
+    calendars
+}
+
+/// Abstract operation [`AvailableCanonicalCollations ( )`][spec].
+///
+/// Returns the collation types that `Intl.Collator` can use with the data of `provider`, sorted
+/// by identifier.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcollations
+pub(in crate::builtins::intl) fn available_collations(provider: &IntlProvider) -> Vec<Value> {
+    COLLATIONS
+        .iter()
+        .filter(|(co, language)| {
+            validate_extension::<<Collator as Service>::LangMarker>(
+                language.clone(),
+                key!("co"),
+                co,
+                provider,
+            )
+        })
+        .map(|(co, _)| co.clone())
+        .collect()
+}
+
+/// Returns the collation types tailored for the language of `id`, sorted by identifier.
+///
+/// The collation types of the root locale are available for all languages.
+pub(in crate::builtins::intl) fn collations_of(
+    id: &LanguageIdentifier,
+    provider: &IntlProvider,
+) -> Vec<Value> {
+    COLLATIONS
+        .iter()
+        .filter(|(co, _)| {
+            [
+                LanguageIdentifier::from(id.language),
+                LanguageIdentifier::UND,
+            ]
+            .into_iter()
+            .any(|language| {
+                validate_extension::<<Collator as Service>::LangMarker>(
+                    language,
+                    key!("co"),
+                    co,
+                    provider,
+                )
+            })
+        })
+        .map(|(co, _)| co.clone())
+        .collect()
+}
+
+/// Abstract operation [`AvailableCanonicalCurrencies ( )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcurrencies
+pub(in crate::builtins::intl) fn available_currencies() -> impl Iterator<Item = &'static str> {
+    CURRENCIES.into_iter()
+}
+
+/// Abstract operation [`AvailableCanonicalNumberingSystems ( )`][spec].
+///
+/// Returns the numbering systems for which `provider` has decimal data, sorted by identifier.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalnumberingsystems
+pub(in crate::builtins::intl) fn available_numbering_systems(
+    provider: &IntlProvider,
+) -> Vec<Value> {
+    NUMBERING_SYSTEMS
+        .iter()
+        .filter(|(nu, zero, language)| {
+            let mut locale = DataLocale::from(language);
+            locale.set_unicode_ext(key!("nu"), nu.clone());
+            digit_zero(&locale, provider) == Some(*zero)
+        })
+        .map(|(nu, ..)| nu.clone())
+        .collect()
+}
+
+/// Gets the default numbering system used by `language`, according to the data of `provider`.
+pub(in crate::builtins::intl) fn default_numbering_system(
+    language: &LanguageIdentifier,
+    provider: &IntlProvider,
+) -> Option<Value> {
+    let zero = digit_zero(&DataLocale::from(language), provider)?;
+    NUMBERING_SYSTEMS
+        .iter()
+        .find(|(_, z, _)| *z == zero)
+        .map(|(nu, ..)| nu.clone())
+}
+
+/// Gets the digit zero used to format numbers in `locale`.
+fn digit_zero(locale: &DataLocale, provider: &IntlProvider) -> Option<char> {
+    DataProvider::<DecimalSymbolsV1Marker>::load(
+        provider,
+        DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        },
+    )
+    .and_then(DataResponse::take_payload)
+    .ok()
+    .map(|symbols| symbols.get().digits[0])
+}
+
+/// Abstract operation [`AvailablePrimaryTimeZoneIdentifiers ( )`][spec], restricted to the time
+/// zones of `region` if provided.
+///
+/// Returns the IANA identifiers of the time zones in the data of `provider`, sorted by
+/// identifier.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availableprimarytimezoneidentifiers
+pub(in crate::builtins::intl) fn available_time_zones(
+    region: Option<Region>,
+    provider: &IntlProvider,
+) -> JsResult<Vec<String>> {
+    let ids = DataProvider::<IanaToBcp47MapV2Marker>::load(provider, DataRequest::default())
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+    let names = DataProvider::<Bcp47ToIanaMapV1Marker>::load(provider, DataRequest::default())
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+    // BCP 47 time zone identifiers start with the code of the region of the time zone, except
+    // for the identifiers of the `Etc` time zones.
+    let region = region.map(|region| region.as_str().to_ascii_lowercase());
+
+    let mut time_zones = ids
+        .get()
+        .bcp47_ids
+        .iter()
+        .zip(names.get().canonical_iana_ids.iter())
+        .filter_map(|(id, name)| match (&*id.0, &region) {
+            ("unk", _) => None,
+            ("utc", None) => Some("UTC".to_owned()),
+            (id, Some(region)) if !id.starts_with(region.as_str()) => None,
+            _ => Some(name.to_owned()),
+        })
+        .collect::<Vec<_>>();
+    time_zones.sort_unstable();
+    time_zones.dedup();
+
+    Ok(time_zones)
+}
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+    #[test]
+    fn supported_values_of() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                function isSorted(list) {
+                    return list.every((value, i) => i === 0 || list[i - 1] < value);
+                }
+            "#}),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('calendar').join()",
+                js_string!("gregory"),
+            ),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('collation').includes('phonebk')",
+                true,
+            ),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('collation').includes('standard')",
+                false,
+            ),
+            TestAction::assert_eq("Intl.supportedValuesOf('currency').includes('EUR')", true),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('numberingSystem').includes('latn')",
+                true,
+            ),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('numberingSystem').includes('deva')",
+                true,
+            ),
+            TestAction::assert_eq("Intl.supportedValuesOf('timeZone').includes('UTC')", true),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('timeZone').includes('Europe/Berlin')",
+                true,
+            ),
+            TestAction::assert_eq(
+                "Intl.supportedValuesOf('timeZone').includes('Etc/Unknown')",
+                false,
+            ),
+            TestAction::assert_eq("Intl.supportedValuesOf('unit').length", 45),
+            TestAction::assert_eq(
+                indoc! {r#"
+                    ["calendar", "collation", "currency", "numberingSystem", "timeZone", "unit"]
+                        .every(key => isSorted(Intl.supportedValuesOf(key)))
+                "#},
+                true,
+            ),
+            TestAction::assert_native_error(
+                "Intl.supportedValuesOf('calendars')",
+                JsNativeErrorKind::Range,
+                "`calendars` is not a supported key for `Intl.supportedValuesOf`",
+            ),
+        ]);
+    }
+}
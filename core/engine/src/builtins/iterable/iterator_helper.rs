@@ -0,0 +1,424 @@
+//! This module implements the `%IteratorHelperPrototype%` object and the iterator helper objects
+//! returned by the lazy methods of `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+
+use crate::{
+    builtins::{
+        iterable::{create_iter_result_object, IteratorRecord, PrimitiveHandling},
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The state of an iterator helper, mirroring the `[[GeneratorState]]` internal slot of the
+/// generator created by `CreateIteratorFromClosure`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The abstract closure captured by an iterator helper, along with its internal state.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(super) enum HelperKind {
+    /// The closure of `Iterator.prototype.map`.
+    Map { mapper: JsObject, counter: u64 },
+
+    /// The closure of `Iterator.prototype.filter`.
+    Filter { predicate: JsObject, counter: u64 },
+
+    /// The closure of `Iterator.prototype.take`.
+    ///
+    /// A `remaining` value of `None` represents `+∞`.
+    Take { remaining: Option<u64> },
+
+    /// The closure of `Iterator.prototype.drop`.
+    ///
+    /// A `remaining` value of `None` represents `+∞`.
+    Drop { remaining: Option<u64> },
+
+    /// The closure of `Iterator.prototype.flatMap`.
+    FlatMap {
+        mapper: JsObject,
+        counter: u64,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+impl HelperKind {
+    /// Runs the closure until its next `Yield`, returning `None` if the closure returned.
+    fn step(
+        &mut self,
+        iterated: &mut IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper, counter } => {
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = mapper
+                    .call(&JsValue::undefined(), &[value, (*counter).into()], context)
+                    .map_err(|err| iterated.close_with_error(err, context))?;
+
+                // vii. Set counter to counter + 1.
+                *counter += 1;
+
+                // v. Let completion be Completion(Yield(mapped)).
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate, counter } => loop {
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                // iv. IfAbruptCloseIterator(selected, iterated).
+                let selected = predicate
+                    .call(
+                        &JsValue::undefined(),
+                        &[value.clone(), (*counter).into()],
+                        context,
+                    )
+                    .map_err(|err| iterated.close_with_error(err, context))?;
+
+                // vi. Set counter to counter + 1.
+                *counter += 1;
+
+                // v. If ToBoolean(selected) is true, then
+                if selected.to_boolean() {
+                    // 1. Let completion be Completion(Yield(value)).
+                    return Ok(Some(value));
+                }
+            },
+            Self::Take { remaining } => {
+                // i. If remaining = 0, then
+                if *remaining == Some(0) {
+                    // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    iterated.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                // ii. If remaining ≠ +∞, then
+                if let Some(remaining) = remaining {
+                    // 1. Set remaining to remaining - 1.
+                    *remaining -= 1;
+                }
+
+                // iii. Let value be ? IteratorStepValue(iterated).
+                // iv. If value is done, return ReturnCompletion(undefined).
+                // v. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::Drop { remaining } => {
+                // b. Repeat, while remaining > 0,
+                while *remaining != Some(0) {
+                    // i. If remaining ≠ +∞, then
+                    if let Some(remaining) = remaining {
+                        // 1. Set remaining to remaining - 1.
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    let inner_value = inner_iterator
+                        .step_value(context)
+                        .map_err(|err| iterated.close_with_error(err, context))?;
+
+                    if let Some(inner_value) = inner_value {
+                        // 4. Else,
+                        //     a. Let completion be Completion(Yield(innerValue)).
+                        return Ok(Some(inner_value));
+                    }
+
+                    // 3. If innerValue is done, then
+                    //     a. Set innerAlive to false.
+                    *inner = None;
+
+                    // ix. Set counter to counter + 1.
+                    *counter += 1;
+                    continue;
+                }
+
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = mapper
+                    .call(&JsValue::undefined(), &[value, (*counter).into()], context)
+                    .map_err(|err| iterated.close_with_error(err, context))?;
+
+                // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                // vi. IfAbruptCloseIterator(innerIterator, iterated).
+                let inner_iterator = mapped
+                    .get_iterator_flattenable(PrimitiveHandling::RejectPrimitives, context)
+                    .map_err(|err| iterated.close_with_error(err, context))?;
+
+                // vii. Let innerAlive be true.
+                *inner = Some(inner_iterator);
+            },
+        }
+    }
+
+    /// Resumes the closure with a return completion after a `Yield`, closing the underlying
+    /// iterators.
+    fn close(&self, iterated: &IteratorRecord, context: &mut Context) -> JsResult<()> {
+        if let Self::FlatMap {
+            inner: Some(inner_iterator),
+            ..
+        } = self
+        {
+            // 4.b. If completion is an abrupt completion, then
+            //     i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            //     ii. IfAbruptCloseIterator(backupCompletion, iterated).
+            if let Err(err) = inner_iterator.close(Ok(JsValue::undefined()), context) {
+                return Err(iterated.close_with_error(err, context));
+            }
+        }
+
+        // IfAbruptCloseIterator(completion, iterated).
+        iterated.close(Ok(JsValue::undefined()), context)?;
+        Ok(())
+    }
+}
+
+/// An iterator helper object, created by the lazy methods of `%Iterator.prototype%`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct IteratorHelper {
+    /// `[[UnderlyingIterator]]`
+    underlying_iterator: IteratorRecord,
+
+    /// The closure captured by this helper.
+    kind: HelperKind,
+
+    /// `[[GeneratorState]]`
+    #[unsafe_ignore_trace]
+    state: HelperState,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// Creates a new iterator helper object, which is the result of
+    /// `CreateIteratorFromClosure ( closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] » )`.
+    pub(super) fn create(
+        underlying_iterator: IteratorRecord,
+        kind: HelperKind,
+        context: &mut Context,
+    ) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            Self {
+                underlying_iterator,
+                kind,
+                state: HelperState::SuspendedStart,
+            },
+        )
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let object = this
+            .as_object()
+            .filter(|object| object.is::<Self>())
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an Iterator Helper"))?;
+
+        let (mut iterated, mut kind) = {
+            let mut helper = object
+                .downcast_mut::<Self>()
+                .expect("must be an iterator helper");
+
+            // `GeneratorValidate ( generator, generatorBrand )`
+            match helper.state {
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Iterator Helper is already running")
+                        .into())
+                }
+                HelperState::Completed => {
+                    drop(helper);
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                HelperState::SuspendedStart | HelperState::SuspendedYield => {}
+            }
+
+            helper.state = HelperState::Executing;
+            (helper.underlying_iterator.clone(), helper.kind.clone())
+        };
+
+        let result = kind.step(&mut iterated, context);
+
+        {
+            let mut helper = object
+                .downcast_mut::<Self>()
+                .expect("must be an iterator helper");
+            helper.underlying_iterator = iterated;
+            helper.kind = kind;
+            helper.state = if matches!(result, Ok(Some(_))) {
+                HelperState::SuspendedYield
+            } else {
+                HelperState::Completed
+            };
+        }
+
+        match result? {
+            Some(value) => Ok(create_iter_result_object(value, false, context)),
+            None => Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            )),
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        let object = this
+            .as_object()
+            .filter(|object| object.is::<Self>())
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an Iterator Helper"))?;
+
+        let (iterated, kind, state) = {
+            let mut helper = object
+                .downcast_mut::<Self>()
+                .expect("must be an iterator helper");
+            let state = helper.state;
+            helper.state = match state {
+                // `GeneratorValidate ( generator, generatorBrand )`
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Iterator Helper is already running")
+                        .into())
+                }
+                // 4. If O.[[GeneratorState]] is suspended-start, then
+                //     a. Set O.[[GeneratorState]] to completed.
+                HelperState::SuspendedStart | HelperState::Completed => HelperState::Completed,
+                HelperState::SuspendedYield => HelperState::Executing,
+            };
+            (
+                helper.underlying_iterator.clone(),
+                helper.kind.clone(),
+                state,
+            )
+        };
+
+        match state {
+            HelperState::SuspendedStart => {
+                // b. NOTE: Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with O can be discarded at this point.
+                // c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+                iterated.close(Ok(JsValue::undefined()), context)?;
+            }
+            HelperState::SuspendedYield => {
+                // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+                // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+                let result = kind.close(&iterated, context);
+                object
+                    .downcast_mut::<Self>()
+                    .expect("must be an iterator helper")
+                    .state = HelperState::Completed;
+                result?;
+            }
+            HelperState::Completed | HelperState::Executing => {}
+        }
+
+        // 4.d. Return CreateIterResultObject(undefined, true).
+        Ok(create_iter_result_object(
+            JsValue::undefined(),
+            true,
+            context,
+        ))
+    }
+}
@@ -1,20 +1,36 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
-    context::intrinsics::Intrinsics,
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::JsObject,
+    object::{
+        internal_methods::{get_prototype_from_constructor, InternalMethodContext},
+        JsObject, CONSTRUCTOR,
+    },
+    property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
+    string::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsResult, JsValue,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsError, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 mod async_from_sync_iterator;
+mod iterator_helper;
+mod wrap_for_valid_iterator;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use iterator_helper::IteratorHelper;
+pub(crate) use wrap_for_valid_iterator::WrapForValidIterator;
+
+use iterator_helper::HelperKind;
+
+#[cfg(test)]
+mod tests;
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -71,6 +87,12 @@ pub struct IteratorPrototypes {
     /// The `ForInIteratorPrototype` prototype object.
     for_in: JsObject,
 
+    /// The `%IteratorHelperPrototype%` prototype object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` prototype object.
+    wrap_for_valid_iterator: JsObject,
+
     /// The `%SegmentIteratorPrototype%` prototype object.
     #[cfg(feature = "intl")]
     segment: JsObject,
@@ -140,6 +162,20 @@ impl IteratorPrototypes {
         self.for_in.clone()
     }
 
+    /// Returns the `%IteratorHelperPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
     /// Returns the `%SegmentIteratorPrototype%` object.
     #[inline]
     #[must_use]
@@ -149,28 +185,809 @@ impl IteratorPrototypes {
     }
 }
 
-/// `%IteratorPrototype%` object
+/// The `Iterator` constructor and the `%Iterator.prototype%` object.
 ///
 /// More information:
 ///  - [ECMA reference][spec]
+///  - [MDN documentation][mdn]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-objects
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator
 pub(crate) struct Iterator;
 
 impl IntrinsicObject for Iterator {
     fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("Iterator Prototype", "init");
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name(js_string!("get [Symbol.toStringTag]"))
             .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name(js_string!("set [Symbol.toStringTag]"))
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, js_string!("from"), 1)
+            .method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+            .method(Self::map, js_string!("map"), 1)
+            .method(Self::filter, js_string!("filter"), 1)
+            .method(Self::take, js_string!("take"), 1)
+            .method(Self::drop, js_string!("drop"), 1)
+            .method(Self::flat_map, js_string!("flatMap"), 1)
+            .method(Self::reduce, js_string!("reduce"), 1)
+            .method(Self::to_array, js_string!("toArray"), 0)
+            .method(Self::for_each, js_string!("forEach"), 1)
+            .method(Self::some, js_string!("some"), 1)
+            .method(Self::every, js_string!("every"), 1)
+            .method(Self::find, js_string!("find"), 1)
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        // `Iterator.prototype.constructor` is an accessor property instead of the usual data
+        // property, so it has to be redefined after building the constructor.
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name(js_string!("get constructor"))
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name(js_string!("set constructor"))
+            .length(1)
+            .build();
+
+        realm
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .prototype()
+            .insert(
+                CONSTRUCTOR,
+                PropertyDescriptor::builder()
+                    .get(get_constructor)
+                    .set(set_constructor)
+                    .enumerable(false)
+                    .configurable(true),
+            );
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Iterator {
+    const NAME: JsString = StaticJsStrings::ITERATOR;
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+    const P: usize = 14;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// `Iterator ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let is_active_function = new_target.as_object().is_some_and(|new_target| {
+            context
+                .active_function_object()
+                .is_some_and(|active| JsObject::equals(new_target, &active))
+        });
+        if new_target.is_undefined() || is_active_function {
+            return Err(JsNativeError::typ()
+                .with_message("the Iterator constructor is abstract and cannot be called directly")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            OrdinaryObject,
+        )
+        .into())
+    }
+}
+
+impl Iterator {
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = args
+            .get_or_undefined(0)
+            .get_iterator_flattenable(PrimitiveHandling::IterateStrings, context)?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .iterator()
+                .constructor()
+                .into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(WrapForValidIterator::create(iterator_record, context).into())
+    }
+
+    /// `get Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype-%symbol.tostringtag%
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(Self::NAME.into())
+    }
+
+    /// `set Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype-%symbol.tostringtag%
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, %Symbol.toStringTag%, v).
+        setter_that_ignores_prototype_properties(
+            this,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype.constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype.constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        setter_that_ignores_prototype_properties(
+            this,
+            CONSTRUCTOR.into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Gets the `this` object of an `%Iterator.prototype%` method, throwing a `TypeError` if
+    /// it is not an object.
+    fn this_iterator_object(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        this.as_object().cloned().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("Iterator.prototype.{method} called on non-object"))
+                .into()
+        })
+    }
+
+    /// Gets a callable argument of an `%Iterator.prototype%` method, closing the iterator with
+    /// a `TypeError` if it is not callable.
+    fn callable_argument(
+        object: &JsObject,
+        argument: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(argument) is false, then
+        if let Some(callable) = argument.as_callable() {
+            return Ok(callable.clone());
+        }
+
+        // a. Let error be ThrowCompletion(a newly created TypeError object).
+        let error = JsNativeError::typ()
+            .with_message(format!(
+                "Iterator.prototype.{method}: argument must be a function"
+            ))
+            .into();
+
+        // b. Return ? IteratorClose(iterated, error).
+        Err(IteratorRecord::new(object.clone(), JsValue::undefined())
+            .close_with_error(error, context))
+    }
+
+    /// Gets the limit argument of `Iterator.prototype.take` and `Iterator.prototype.drop`,
+    /// closing the iterator if it is not a valid limit.
+    ///
+    /// Returns `None` if the limit is `+∞`.
+    fn limit_argument(
+        object: &JsObject,
+        limit: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<u64>> {
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(object.clone(), JsValue::undefined());
+
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        let num_limit = match limit.to_number(context) {
+            Ok(num_limit) => num_limit,
+            Err(err) => return Err(iterated.close_with_error(err, context)),
+        };
+
+        // 6. If numLimit is NaN, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let limit = match IntegerOrInfinity::from(num_limit) {
+            _ if num_limit.is_nan() => None,
+            IntegerOrInfinity::PositiveInfinity => return Ok(None),
+            IntegerOrInfinity::Integer(limit) => u64::try_from(limit).ok(),
+            IntegerOrInfinity::NegativeInfinity => None,
+        };
+
+        if let Some(limit) = limit {
+            return Ok(Some(limit));
+        }
+
+        let error = JsNativeError::range()
+            .with_message(format!(
+                "Iterator.prototype.{method}: limit must be a positive number"
+            ))
+            .into();
+        Err(iterated.close_with_error(error, context))
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "map")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = Self::callable_argument(&object, args.get_or_undefined(0), "map", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper ...
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(
+            IteratorHelper::create(iterated, HelperKind::Map { mapper, counter: 0 }, context)
+                .into(),
+        )
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "filter")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_argument(&object, args.get_or_undefined(0), "filter", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate ...
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Filter {
+                predicate,
+                counter: 0,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "take")?;
+
+        // 3-8. Validate `limit`, closing the iterator on abrupt completions.
+        let remaining = Self::limit_argument(&object, args.get_or_undefined(0), "take", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit ...
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperKind::Take { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "drop")?;
+
+        // 3-8. Validate `limit`, closing the iterator on abrupt completions.
+        let remaining = Self::limit_argument(&object, args.get_or_undefined(0), "drop", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit ...
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperKind::Drop { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/flatMap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "flatMap")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper =
+            Self::callable_argument(&object, args.get_or_undefined(0), "flatMap", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper ...
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::FlatMap {
+                mapper,
+                counter: 0,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "reduce")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(reducer) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let reducer =
+            Self::callable_argument(&object, args.get_or_undefined(0), "reduce", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&object, context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 7. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0u64)
+        } else {
+            // 6. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            //     c. Let counter be 1.
+            let accumulator = iterated.step_value(context)?.ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("Iterator.prototype.reduce: empty iterator with no initial value")
+            })?;
+            (accumulator, 1)
+        };
+
+        // 8. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return accumulator.
+        Ok(accumulator)
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/toArray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&object, context)?;
+
+        // 4. Let items be a new empty List.
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        let items = iterated.into_list(context)?;
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( procedure )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/forEach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "forEach")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(procedure) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let procedure =
+            Self::callable_argument(&object, args.get_or_undefined(0), "forEach", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(procedure, undefined, « value, 𝔽(counter) »)).
+            let result = procedure.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "some")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_argument(&object, args.get_or_undefined(0), "some", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+            if result.to_boolean() {
+                return iterated.close(Ok(true.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return false.
+        Ok(false.into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "every")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_argument(&object, args.get_or_undefined(0), "every", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return true.
+        Ok(true.into())
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let object = Self::this_iterator_object(this, "find")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::callable_argument(&object, args.get_or_undefined(0), "find", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&object, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return undefined.
+        Ok(JsValue::undefined())
     }
 }
 
+/// `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    p: PropertyKey,
+    v: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If thisValue is not an Object, throw a TypeError exception.
+    let this = this.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("cannot set a property on a non-object iterator")
+    })?;
+
+    // 2. If SameValue(thisValue, home) is true, then
+    let home = context.intrinsics().constructors().iterator().prototype();
+    if JsObject::equals(this, &home) {
+        // a. NOTE: Throwing here emulates assignment to a non-writable data property on the home object in strict mode code.
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property on %Iterator.prototype%")
+            .into());
+    }
+
+    // 3. Let desc be ? thisValue.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&p, &mut InternalMethodContext::new(context))?;
+
+    if desc.is_none() {
+        // 4. If desc is undefined, then
+        //     a. Perform ? CreateDataPropertyOrThrow(thisValue, p, v).
+        this.create_data_property_or_throw(p, v, context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(thisValue, p, v, true).
+        this.set(p, v, true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
+}
+
 /// `%AsyncIteratorPrototype%` object
 ///
 /// More information:
@@ -223,6 +1040,34 @@ pub enum IteratorHint {
     Async,
 }
 
+/// Primitive handling for `GetIteratorFlattenable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    /// Strings are iterated, while any other primitive throws a `TypeError`.
+    IterateStrings,
+
+    /// Every primitive throws a `TypeError`.
+    RejectPrimitives,
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = object.get(js_string!("next"), context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(object.clone(), next_method))
+}
+
 impl JsValue {
     /// `GetIteratorFromMethod ( obj, method )`
     ///
@@ -302,6 +1147,51 @@ impl JsValue {
         // 4. Return ? GetIteratorFromMethod(obj, method).
         self.get_iterator_from_method(&method, context)
     }
+
+    /// `GetIteratorFlattenable ( obj, primitiveHandling )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+    pub(crate) fn get_iterator_flattenable(
+        &self,
+        primitive_handling: PrimitiveHandling,
+        context: &mut Context,
+    ) -> JsResult<IteratorRecord> {
+        // 1. If obj is not an Object, then
+        if !self.is_object() {
+            // a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+            // b. Assert: primitiveHandling is iterate-string-primitives.
+            // c. If obj is not a String, throw a TypeError exception.
+            if primitive_handling == PrimitiveHandling::RejectPrimitives || !self.is_string() {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "value with type `{}` is not an iterator or iterable",
+                        self.type_of()
+                    ))
+                    .into());
+            }
+        }
+
+        // 2. Let method be ? GetMethod(obj, %Symbol.iterator%).
+        let iterator = match self.get_method(JsSymbol::iterator(), context)? {
+            // 3. If method is undefined, then
+            //     a. Let iterator be obj.
+            None => self.clone(),
+            // 4. Else,
+            //     a. Let iterator be ? Call(method, obj).
+            Some(method) => method.call(self, &[], context)?,
+        };
+
+        // 5. If iterator is not an Object, throw a TypeError exception.
+        let iterator = iterator.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("returned iterator is not an object")
+        })?;
+
+        // 6. Return ? GetIteratorDirect(iterator).
+        get_iterator_direct(iterator, context)
+    }
 }
 
 /// The result of the iteration process.
@@ -629,6 +1519,17 @@ impl IteratorRecord {
         }
     }
 
+    /// `IteratorClose ( iteratorRecord, completion )` with a throw completion.
+    ///
+    /// Closing an iterator with a throw completion always results in a throw completion, so this
+    /// returns the error that must be propagated.
+    pub(crate) fn close_with_error(&self, error: JsError, context: &mut Context) -> JsError {
+        match self.close(Err(error), context) {
+            Err(err) => err,
+            Ok(_) => unreachable!("closing an iterator with a throw completion must throw"),
+        }
+    }
+
     /// `IteratorToList ( iteratorRecord )`
     ///
     /// More information:
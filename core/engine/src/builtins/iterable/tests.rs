@@ -0,0 +1,195 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_eq("typeof Iterator", js_str!("function")),
+        TestAction::assert("[].values() instanceof Iterator"),
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "the Iterator constructor is abstract and cannot be called directly",
+        ),
+        TestAction::assert_native_error(
+            "Iterator()",
+            JsNativeErrorKind::Type,
+            "the Iterator constructor is abstract and cannot be called directly",
+        ),
+        TestAction::assert(indoc! {r#"
+            class MyIterator extends Iterator {}
+            new MyIterator() instanceof Iterator
+        "#}),
+        TestAction::assert("Iterator.prototype.constructor === Iterator"),
+        TestAction::assert_eq(
+            "Iterator.prototype[Symbol.toStringTag]",
+            js_str!("Iterator"),
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_prototype_accessors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "Iterator.prototype.constructor = 1",
+            JsNativeErrorKind::Type,
+            "cannot set a property on %Iterator.prototype%",
+        ),
+        TestAction::assert(indoc! {r#"
+            const it = Object.create(Iterator.prototype);
+            it[Symbol.toStringTag] = "custom";
+            Object.hasOwn(it, Symbol.toStringTag) && it[Symbol.toStringTag] === "custom"
+        "#}),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::assert("arrayEquals(Iterator.from('abc').toArray(), ['a', 'b', 'c'])"),
+        TestAction::run(indoc! {r#"
+            let i = 0;
+            const plain = { next() { return { value: i++, done: i > 3 }; } };
+            const wrapped = Iterator.from(plain);
+        "#}),
+        TestAction::assert("wrapped instanceof Iterator"),
+        TestAction::assert("wrapped !== plain"),
+        TestAction::assert("arrayEquals(wrapped.toArray(), [0, 1, 2])"),
+        TestAction::assert_eq("Iterator.from({ next() {} }).return().done", true),
+        TestAction::assert(indoc! {r#"
+            const values = [1, 2].values();
+            Iterator.from(values) === values
+        "#}),
+        TestAction::assert_native_error(
+            "Iterator.from(5)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator or iterable",
+        ),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            function* naturals() {
+                let i = 0;
+                while (true) {
+                    yield i++;
+                }
+            }
+        "#}),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(
+                naturals()
+                    .filter((x) => x % 2 === 0)
+                    .map((x, i) => x * i)
+                    .drop(1)
+                    .take(3)
+                    .toArray(),
+                [2, 8, 18]
+            )
+        "#}),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(
+                naturals().take(3).flatMap((x) => [x, x]).toArray(),
+                [0, 0, 1, 1, 2, 2]
+            )
+        "#}),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(naturals().map(x => x))",
+            js_str!("[object Iterator Helper]"),
+        ),
+        TestAction::assert_native_error(
+            "naturals().take(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: limit must be a positive number",
+        ),
+        TestAction::assert_native_error(
+            "naturals().drop(NaN)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.drop: limit must be a positive number",
+        ),
+        TestAction::assert_native_error(
+            "naturals().flatMap(x => x).next()",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator or iterable",
+        ),
+    ]);
+}
+
+#[test]
+fn helper_closes_underlying_iterator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let closed = 0;
+            class Counter extends Iterator {
+                i = 0;
+                next() { return { value: this.i++, done: false }; }
+                return() { closed++; return {}; }
+            }
+        "#}),
+        TestAction::assert(indoc! {r#"
+            const helper = new Counter().map(x => x);
+            helper.next();
+            helper.return();
+            helper.return();
+            closed === 1 && helper.next().done
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                closed = 0;
+                const take = new Counter().take(2);
+                take.next();
+                take.next();
+                take.next();
+                closed
+            "#},
+            1,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                closed = 0;
+                try { new Counter().map(1); } catch {}
+                closed
+            "#},
+            1,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                closed = 0;
+                new Counter().find(x => x === 5);
+                closed
+            "#},
+            1,
+        ),
+    ]);
+}
+
+#[test]
+fn eager_helpers() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b)", 6),
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b, 10)", 16),
+        TestAction::assert_native_error(
+            "[].values().reduce((a, b) => a + b)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: empty iterator with no initial value",
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let sum = 0;
+                [1, 2, 3].values().forEach((x, i) => { sum += x * i; });
+                sum
+            "#},
+            8,
+        ),
+        TestAction::assert_eq("[1, 2, 3].values().some(x => x > 2)", true),
+        TestAction::assert_eq("[1, 2, 3].values().every(x => x > 2)", false),
+        TestAction::assert_eq("[1, 2, 3].values().find(x => x > 1)", 2),
+    ]);
+}
@@ -2,7 +2,7 @@
 //!
 //! The `JSON` object contains methods for parsing [JavaScript Object Notation (JSON)][spec]
 //! and converting values to JSON. It can't be called or constructed, and aside from its
-//! two method properties, it has no interesting functionality of its own.
+//! method properties, it has no interesting functionality of its own.
 //!
 //! More information:
 //!  - [ECMAScript reference][spec]
@@ -25,24 +25,28 @@ use crate::{
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
-    object::{internal_methods::InternalMethodContext, JsObject},
+    object::{internal_methods::InternalMethodContext, IntegrityLevel, JsObject},
     property::{Attribute, PropertyNameKind},
     realm::Realm,
     string::{CodePoint, StaticJsStrings},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
     vm::{CallFrame, CallFrameFlags},
-    Context, JsArgs, JsBigInt, JsResult, JsString, JsValue,
+    Context, JsArgs, JsBigInt, JsData, JsResult, JsString, JsValue,
 };
-use boa_gc::Gc;
+use boa_gc::{Finalize, Gc, Trace};
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
+mod parse_record;
 #[cfg(test)]
 mod tests;
 
+use parse_record::JsonParseRecord;
+
 /// JavaScript `JSON` global object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Json;
@@ -57,6 +61,8 @@ impl IntrinsicObject for Json {
         BuiltInBuilder::with_intrinsic::<Self>(realm)
             .static_method(Self::parse, js_string!("parse"), 2)
             .static_method(Self::stringify, js_string!("stringify"), 3)
+            .static_method(Self::raw_json, js_string!("rawJSON"), 1)
+            .static_method(Self::is_raw_json, js_string!("isRawJSON"), 1)
             .static_property(to_string_tag, Self::NAME, attribute)
             .build();
     }
@@ -70,6 +76,11 @@ impl BuiltInObject for Json {
     const NAME: JsString = StaticJsStrings::JSON;
 }
 
+/// The `[[IsRawJSON]]` internal slot of the objects created by `JSON.rawJSON`.
+#[derive(Debug, Clone, Copy, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+pub(crate) struct RawJson;
+
 impl Json {
     /// `JSON.parse( text[, reviver] )`
     ///
@@ -154,11 +165,14 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw(js_string!(), unfiltered, context)
+            root.create_data_property_or_throw(js_string!(), unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, js_string!(), obj, context)
+            // d. Let snapshot be CreateJSONParseRecord(parseResult.[[ParseNode]], rootName, unfiltered).
+            let snapshot = JsonParseRecord::new(&json_string, unfiltered, context)?;
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, js_string!(), obj, snapshot, context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -166,37 +180,71 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver, parseRecord )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [Proposal][proposal]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        parse_record: Option<JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
-        // 2. If Type(val) is Object, then
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        let reviver_context = JsObject::with_object_proto(context.intrinsics());
+
+        // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+        // 4. Else,
+        //     a. Let elementRecords be a new empty List.
+        //     b. Let entryRecords be a new empty List.
+        let (element_records, mut entry_records) = match parse_record {
+            Some(record) if JsValue::same_value(&record.value, &val) => {
+                // a. If val is not an Object, then
+                if let Some(source) = record.source {
+                    // i. Let parseNode be parseRecord.[[ParseNode]].
+                    // ii. Assert: parseNode is not an ArrayLiteral Parse Node and not an ObjectLiteral Parse Node.
+                    // iii. Let sourceText be the source text matched by parseNode.
+                    // iv. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(sourceText)).
+                    reviver_context
+                        .create_data_property_or_throw(js_string!("source"), source, context)
+                        .expect("CreateDataPropertyOrThrow should never throw here");
+                }
+
+                // b. Let elementRecords be parseRecord.[[Elements]].
+                // c. Let entryRecords be parseRecord.[[Entries]].
+                (record.elements, record.entries)
+            }
+            _ => (Vec::new(), FxHashMap::default()),
+        };
+
+        // 5. If Type(val) is Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
             if obj.is_array_abstract()? {
-                // i. Let I be 0.
+                // i. Let elementRecordsLen be the number of elements in elementRecords.
                 // ii. Let len be ? LengthOfArrayLike(val).
-                // iii. Repeat, while I < len,
+                // iii. Let I be 0.
+                // iv. Repeat, while I < len,
+                let mut element_records = element_records.into_iter();
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                    // 2. If I < elementRecordsLen, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
                     let new_element = Self::internalize_json_property(
                         obj,
                         i.to_string().into(),
                         reviver,
+                        element_records.next(),
                         context,
                     )?;
 
@@ -225,9 +273,16 @@ impl Json {
                         .expect("EnumerableOwnPropertyNames only returns strings")
                         .clone();
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P. If there is no such element, let entryRecord be empty.
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let entry_record = entry_records.remove(&p);
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
 
                     // 2. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -243,8 +298,96 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        reviver.call(
+            &holder.clone().into(),
+            &[name.into(), val, reviver_context.into()],
+            context,
+        )
+    }
+
+    /// `JSON.rawJSON ( text )`
+    ///
+    /// Creates a frozen object that `JSON.stringify` serializes as the given JSON text.
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/rawJSON
+    pub(crate) fn raw_json(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first
+        //    or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED),
+        //    0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+        let is_whitespace = |c: Option<u16>| matches!(c, Some(0x0009 | 0x000A | 0x000D | 0x0020));
+        if json_string.is_empty()
+            || is_whitespace(json_string.iter().next())
+            || is_whitespace(json_string.iter().last())
+        {
+            return Err(JsNativeError::syntax()
+                .with_message("raw JSON text cannot be empty or start or end with whitespace")
+                .into());
+        }
+
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a
+        //    SyntaxError exception if it is not a valid JSON text as defined in that specification,
+        //    or if its outermost value is an object or array as defined in that specification.
+        let text = json_string
+            .to_std_string()
+            .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
+            Ok(value) if value.is_object() || value.is_array() => {
+                return Err(JsNativeError::syntax()
+                    .with_message("raw JSON text cannot be an object or array")
+                    .into())
+            }
+            Ok(_) => {}
+        }
+
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        let obj = JsObject::from_proto_and_data(None, RawJson);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        obj.create_data_property_or_throw(js_string!("rawJSON"), json_string, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        obj.set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("SetIntegrityLevel should never throw here");
+
+        // 8. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `JSON.isRawJSON ( O )`
+    ///
+    /// Returns `true` if the value was created by `JSON.rawJSON`.
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/isRawJSON
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn is_raw_json(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(O) is Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .is_some_and(JsObject::is::<RawJson>)
+            .into())
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -510,6 +653,16 @@ impl Json {
         // 11. If Type(value) is Object and IsCallable(value) is false, then
         if let Some(obj) = value.as_object() {
             if !obj.is_callable() {
+                // a. If value has an [[IsRawJSON]] internal slot, then
+                if obj.is::<RawJson>() {
+                    // i. Return ! Get(value, "rawJSON").
+                    return Ok(obj
+                        .get(js_string!("rawJSON"), context)
+                        .expect("Get should never throw here")
+                        .as_string()
+                        .cloned());
+                }
+
                 // a. Let isArray be ? IsArray(value).
                 // b. If isArray is true, return ? SerializeJSONArray(state, value).
                 // c. Return ? SerializeJSONObject(state, value).
@@ -0,0 +1,214 @@
+//! This module implements the JSON Parse Records used by `JSON.parse` to give revivers access
+//! to the source text of primitive values.
+//!
+//! More information:
+//!  - [Proposal][proposal]
+//!
+//! [proposal]: https://tc39.es/proposal-json-parse-with-source/
+
+use rustc_hash::FxHashMap;
+
+use crate::{property::PropertyNameKind, Context, JsResult, JsString, JsValue};
+
+/// A value of a JSON text, keeping the source text of primitive values.
+#[derive(Debug)]
+enum JsonNode<'a> {
+    Primitive(&'a str),
+    Array(Vec<JsonNode<'a>>),
+    Object(Vec<(JsString, JsonNode<'a>)>),
+}
+
+/// Scans the structure of an already validated JSON text.
+struct JsonScanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> JsonScanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.skip_whitespace();
+        (self.peek()? == byte).then(|| self.pos += 1)
+    }
+
+    fn string(&mut self) -> Option<&'a str> {
+        let start = self.pos;
+        self.expect(b'"')?;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => break,
+                _ => self.pos += 1,
+            }
+        }
+        self.pos += 1;
+        Some(&self.text[start..self.pos])
+    }
+
+    fn node(&mut self) -> Option<JsonNode<'a>> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'[' => {
+                self.pos += 1;
+                let mut elements = Vec::new();
+                self.skip_whitespace();
+                if self.peek()? == b']' {
+                    self.pos += 1;
+                    return Some(JsonNode::Array(elements));
+                }
+                loop {
+                    elements.push(self.node()?);
+                    self.skip_whitespace();
+                    self.pos += 1;
+                    match self.text.as_bytes().get(self.pos - 1)? {
+                        b',' => {}
+                        b']' => return Some(JsonNode::Array(elements)),
+                        _ => return None,
+                    }
+                }
+            }
+            b'{' => {
+                self.pos += 1;
+                let mut entries = Vec::new();
+                self.skip_whitespace();
+                if self.peek()? == b'}' {
+                    self.pos += 1;
+                    return Some(JsonNode::Object(entries));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key = serde_json::from_str::<String>(self.string()?).ok()?;
+                    self.expect(b':')?;
+                    entries.push((key.as_str().into(), self.node()?));
+                    self.skip_whitespace();
+                    self.pos += 1;
+                    match self.text.as_bytes().get(self.pos - 1)? {
+                        b',' => {}
+                        b'}' => return Some(JsonNode::Object(entries)),
+                        _ => return None,
+                    }
+                }
+            }
+            b'"' => self.string().map(JsonNode::Primitive),
+            _ => {
+                let start = self.pos;
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+                Some(JsonNode::Primitive(&self.text[start..self.pos]))
+            }
+        }
+    }
+}
+
+/// A `JSON Parse Record`, associating a value created by `JSON.parse` with its source text.
+///
+/// More information:
+///  - [Proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    pub(super) value: JsValue,
+    pub(super) source: Option<JsString>,
+    pub(super) elements: Vec<JsonParseRecord>,
+    pub(super) entries: FxHashMap<JsString, JsonParseRecord>,
+}
+
+impl JsonParseRecord {
+    /// Creates the parse record of the JSON `text` that evaluated to `value`.
+    ///
+    /// Returns `None` if `text` is not a valid JSON text.
+    pub(super) fn new(text: &str, value: JsValue, context: &mut Context) -> JsResult<Option<Self>> {
+        let mut scanner = JsonScanner { text, pos: 0 };
+        let Some(node) = scanner.node() else {
+            return Ok(None);
+        };
+        Self::create(node, value, context).map(Some)
+    }
+
+    /// `CreateJSONParseRecord ( parseNode, key, val )`
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord
+    fn create(node: JsonNode<'_>, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let mut record = Self {
+            value,
+            source: None,
+            elements: Vec::new(),
+            entries: FxHashMap::default(),
+        };
+
+        match (node, record.value.as_object().cloned()) {
+            // 3. If val is not an Object, then
+            //     a. Let elements be a new empty List.
+            //     b. Let entries be a new empty List.
+            (JsonNode::Primitive(source), None) => {
+                record.source = Some(source.into());
+            }
+            // 4. Else,
+            //     a. If typedValNode is an ArrayLiteral Parse Node, then
+            (JsonNode::Array(nodes), Some(obj)) => {
+                // i. Let contentNodes be the ElementList of typedValNode.
+                // iii. Let elements be a new empty List.
+                // iv. For each Parse Node contentNode of contentNodes, do
+                for (index, node) in nodes.into_iter().enumerate() {
+                    // 1. Let propName be ! ToString(𝔽(index)).
+                    // 2. Let propVal be ! Get(val, propName).
+                    let value = obj.get(index, context)?;
+
+                    // 3. Let record be CreateJSONParseRecord(contentNode, propName, propVal).
+                    // 4. Append record to elements.
+                    record.elements.push(Self::create(node, value, context)?);
+                }
+            }
+            // b. Else,
+            (JsonNode::Object(nodes), Some(obj)) => {
+                // i. Let propertyNodes be the PropertyDefinitionList of typedValNode.
+                // NOTE: The value of duplicated property names is specified by the last pair
+                //       with that name.
+                let mut nodes = nodes.into_iter().collect::<FxHashMap<_, _>>();
+
+                // iii. Let keys be ! EnumerableOwnProperties(val, key).
+                let keys = obj.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+
+                // iv. For each String P of keys, do
+                for key in keys {
+                    let Some(key) = key.as_string() else {
+                        continue;
+                    };
+                    let Some(node) = nodes.remove(key) else {
+                        continue;
+                    };
+
+                    // 6. Let propertyValue be ! Get(val, P).
+                    let value = obj.get(key.clone(), context)?;
+
+                    // 7. Let entry be CreateJSONParseRecord(propertyValueNode, P, propertyValue).
+                    // 8. Append entry to entries.
+                    record
+                        .entries
+                        .insert(key.clone(), Self::create(node, value, context)?);
+                }
+            }
+            _ => {}
+        }
+
+        // 5. Return the JSON Parse Record { [[ParseNode]]: typedValNode, [[Key]]: key, [[Value]]: val, [[Elements]]: elements, [[Entries]]: entries }.
+        Ok(record)
+    }
+}
@@ -315,3 +315,60 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[test]
+fn json_parse_reviver_source() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var sources = [];
+                var result = JSON.parse(
+                    '{"id": 12345678901234567890, "list": [1.10, "a"], "dup": 1, "dup": 2.50}',
+                    function (key, value, context) {
+                        sources.push(key + "=" + context.source);
+                        return key === "id" ? BigInt(context.source) : value;
+                    }
+                );
+            "#}),
+        TestAction::assert_eq("result.id === 12345678901234567890n", true),
+        TestAction::assert_eq(
+            "sources.join()",
+            js_str!(r#"id=12345678901234567890,0=1.10,1="a",list=undefined,dup=2.50,=undefined"#),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var replaced;
+                JSON.parse('[1, 2]', function (key, value, context) {
+                    if (key === "0") this[1] = 3;
+                    if (key === "1") replaced = "source" in context;
+                    return value;
+                });
+                replaced
+            "#},
+            false,
+        ),
+    ]);
+}
+
+#[test]
+fn json_raw_json() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#"JSON.stringify({ id: JSON.rawJSON("12345678901234567890"), s: JSON.rawJSON('"x"') })"#,
+            js_str!(r#"{"id":12345678901234567890,"s":"x"}"#),
+        ),
+        TestAction::assert_eq("JSON.isRawJSON(JSON.rawJSON('null'))", true),
+        TestAction::assert_eq("JSON.isRawJSON({ rawJSON: '1' })", false),
+        TestAction::assert_eq("Object.isFrozen(JSON.rawJSON('1'))", true),
+        TestAction::assert_eq("Object.getPrototypeOf(JSON.rawJSON('1'))", JsValue::null()),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "raw JSON text cannot be empty or start or end with whitespace",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('{}')",
+            JsNativeErrorKind::Syntax,
+            "raw JSON text cannot be an object or array",
+        ),
+    ]);
+}
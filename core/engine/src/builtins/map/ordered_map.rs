@@ -1,7 +1,7 @@
 //! Implements a map type that preserves insertion order.
 
 use crate::{object::JsObject, JsData, JsValue};
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, ExternalMemory, Finalize, Trace};
 use indexmap::{Equivalent, IndexMap};
 use std::{
     fmt::Debug,
@@ -39,6 +39,9 @@ pub struct OrderedMap<V> {
     map: IndexMap<MapKey, Option<V>>,
     lock: u32,
     empty_count: usize,
+    /// The size of the entries, reported to the garbage collector to count it towards the heap
+    /// limit.
+    memory: ExternalMemory,
 }
 
 unsafe impl<V: Trace> Trace for OrderedMap<V> {
@@ -72,17 +75,28 @@ impl<V> OrderedMap<V> {
             map: IndexMap::new(),
             lock: 0,
             empty_count: 0,
+            memory: ExternalMemory::default(),
         }
     }
 
     /// Creates a new empty `OrderedMap` with the specified capacity.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
+        let mut map = Self {
             map: IndexMap::with_capacity(capacity),
             lock: 0,
             empty_count: 0,
-        }
+            memory: ExternalMemory::default(),
+        };
+        map.report_memory();
+        map
+    }
+
+    /// Reports the current size of the entries to the garbage collector.
+    fn report_memory(&mut self) {
+        // Each entry is stored with its hash, and indexed by the hash table.
+        let entry_size = size_of::<(MapKey, Option<V>)>() + 2 * size_of::<usize>();
+        self.memory.set(self.map.capacity() * entry_size);
     }
 
     /// Return the number of key-value pairs in the map, including empty values.
@@ -120,7 +134,9 @@ impl<V> OrderedMap<V> {
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn insert(&mut self, key: JsValue, value: V) -> Option<V> {
-        self.map.insert(MapKey::Key(key), Some(value)).flatten()
+        let old = self.map.insert(MapKey::Key(key), Some(value)).flatten();
+        self.report_memory();
+        old
     }
 
     /// Remove the key-value pair equivalent to `key` and return
@@ -139,7 +155,9 @@ impl<V> OrderedMap<V> {
         } else if self.map.contains_key(key) {
             self.map.insert(MapKey::Empty(self.empty_count), None);
             self.empty_count += 1;
-            self.map.swap_remove(key).flatten()
+            let removed = self.map.swap_remove(key).flatten();
+            self.report_memory();
+            removed
         } else {
             None
         }
@@ -151,6 +169,7 @@ impl<V> OrderedMap<V> {
         self.map.clear();
         self.map.shrink_to_fit();
         self.empty_count = 0;
+        self.report_memory();
     }
 
     /// Return a reference to the value stored for `key`, if it is present,
@@ -124,6 +124,7 @@ impl OptionType for f64 {
     }
 }
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
 #[derive(Debug, Copy, Clone, Default)]
 pub(crate) enum RoundingMode {
     Ceil,
@@ -138,6 +139,7 @@ pub(crate) enum RoundingMode {
     HalfEven,
 }
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
 impl RoundingMode {
     #[cfg(feature = "intl")]
     pub(crate) fn to_js_string(self) -> JsString {
@@ -156,15 +158,18 @@ impl RoundingMode {
     }
 }
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
 #[derive(Debug)]
 pub(crate) struct ParseRoundingModeError;
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
 impl fmt::Display for ParseRoundingModeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("provided string was not a valid rounding mode")
     }
 }
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
 impl FromStr for RoundingMode {
     type Err = ParseRoundingModeError;
 
@@ -184,6 +189,7 @@ impl FromStr for RoundingMode {
     }
 }
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
 impl ParsableOptionType for RoundingMode {}
 
 // TODO: remove once confirmed.
@@ -197,6 +203,7 @@ pub(crate) enum UnsignedRoundingMode {
     HalfEven,
 }
 
+#[cfg(feature = "temporal")]
 impl RoundingMode {
     // TODO: remove once confirmed.
     #[cfg(feature = "temporal")]
@@ -0,0 +1,234 @@
+//! This module implements the legacy static properties of the `RegExp` constructor.
+//!
+//! These are the `RegExp.$1`-`$9`, `RegExp.input` (`$_`), `RegExp.lastMatch` (`$&`),
+//! `RegExp.lastParen` (`$+`), `RegExp.leftContext` (`` $` ``) and `RegExp.rightContext` (`$'`)
+//! accessors, which expose the results of the last successful match in the realm.
+//!
+//! More information:
+//!  - [Proposal][proposal]
+//!
+//! [proposal]: https://github.com/tc39/proposal-regexp-legacy-features
+
+use crate::{
+    error::JsNativeError, js_str, js_string, native_function::NativeFunctionPointer,
+    object::JsObject, Context, JsArgs, JsResult, JsStr, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// The values of the legacy static properties of `%RegExp%`.
+///
+/// In the proposal these are internal slots of the `%RegExp%` intrinsic, which are stored in the
+/// realm instead. A value of `None` represents a slot that is `empty`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct LegacyRegExpStatics {
+    input: Option<JsString>,
+    last_match: Option<LegacyMatch>,
+}
+
+/// The match dependent slots of [`LegacyRegExpStatics`], which are always updated together.
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+struct LegacyMatch {
+    last_match: JsString,
+    last_paren: JsString,
+    left_context: JsString,
+    right_context: JsString,
+    parens: [JsString; 9],
+}
+
+impl Default for LegacyRegExpStatics {
+    fn default() -> Self {
+        // The initial value of all the internal slots is the empty String.
+        Self {
+            input: Some(js_string!()),
+            last_match: Some(LegacyMatch::default()),
+        }
+    }
+}
+
+impl LegacyRegExpStatics {
+    /// `UpdateLegacyRegExpStaticProperties ( C, S, startIndex, endIndex, capturedValues )`
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#updatelegacyregexpstaticproperties--c-s-startindex-endindex-capturedvalues-
+    pub(crate) fn update(&mut self, s: &JsString, match_value: &regress::Match) {
+        let captured_value = |i: usize| {
+            match_value
+                .group(i)
+                .map_or_else(JsString::default, |range| js_string!(s.get_expect(range)))
+        };
+
+        // 5. Let n be the number of elements in capturedValues.
+        let n = match_value.captures.len();
+
+        // 6. Set the value of C’s [[RegExpInput]] internal slot to S.
+        self.input = Some(s.clone());
+
+        self.last_match = Some(LegacyMatch {
+            // 7. Set the value of C’s [[RegExpLastMatch]] internal slot to a String whose length is endIndex - startIndex and containing the code units from S with indices startIndex through endIndex - 1, in ascending order.
+            last_match: js_string!(s.get_expect(match_value.range())),
+            // 8. If n > 0, set the value of C’s [[RegExpLastParen]] internal slot to the last element of capturedValues.
+            // 9. Else, set the value of C’s [[RegExpLastParen]] internal slot to the empty String.
+            last_paren: if n > 0 {
+                captured_value(n)
+            } else {
+                js_string!()
+            },
+            // 10. Set the value of C’s [[RegExpLeftContext]] internal slot to a String whose length is startIndex and containing the code units from S with indices 0 through startIndex - 1, in ascending order.
+            left_context: js_string!(s.get_expect(..match_value.start())),
+            // 11. Set the value of C’s [[RegExpRightContext]] internal slot to a String whose length is len - endIndex and containing the code units from S with indices endIndex through len - 1, in ascending order.
+            right_context: js_string!(s.get_expect(match_value.end()..)),
+            // 12. For each integer i such that 1 ≤ i ≤ 9
+            //     a. If i ≤ n, set the value of C’s [[RegExpPareni]] internal slot to the ith element of capturedValues.
+            //     b. Else, set the value of C’s [[RegExpPareni]] internal slot to the empty String.
+            parens: std::array::from_fn(|i| {
+                if i < n {
+                    captured_value(i + 1)
+                } else {
+                    js_string!()
+                }
+            }),
+        });
+    }
+
+    /// `InvalidateLegacyRegExpStaticProperties ( C )`
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#invalidatelegacyregexpstaticproperties--c
+    pub(crate) fn invalidate(&mut self) {
+        // 2. Set the value of the following internal slots of C to empty:
+        self.input = None;
+        self.last_match = None;
+    }
+}
+
+/// `GetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName )`
+///
+/// More information:
+///  - [Proposal][spec]
+///
+/// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#getlegacyregexpstaticproperty-c-thisvalue-internalslotname-
+fn get_legacy_static(
+    this: &JsValue,
+    context: &Context,
+    slot: impl FnOnce(&LegacyRegExpStatics) -> Option<JsString>,
+) -> JsResult<JsValue> {
+    // 1. Assert C is an object that has an internal slot named internalSlotName.
+    // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+    let regexp = context.intrinsics().constructors().regexp().constructor();
+    if !this
+        .as_object()
+        .is_some_and(|this| JsObject::equals(this, &regexp))
+    {
+        return Err(JsNativeError::typ()
+            .with_message("legacy RegExp static properties can only be read from %RegExp%")
+            .into());
+    }
+
+    // 3. Let value be the value of the internal slot of C named internalSlotName.
+    // 4. If value is empty, throw a TypeError exception.
+    // 5. Return value.
+    slot(&context.realm().legacy_regexp_statics().borrow())
+        .map(Into::into)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("legacy RegExp static properties were invalidated")
+                .into()
+        })
+}
+
+/// `SetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName, val )`
+///
+/// More information:
+///  - [Proposal][spec]
+///
+/// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#setlegacyregexpstaticproperty-c-thisvalue-internalslotname-val-
+fn set_input(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. Assert C is an object that has an internal slot named internalSlotName.
+    // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+    let regexp = context.intrinsics().constructors().regexp().constructor();
+    if !this
+        .as_object()
+        .is_some_and(|this| JsObject::equals(this, &regexp))
+    {
+        return Err(JsNativeError::typ()
+            .with_message("legacy RegExp static properties can only be set on %RegExp%")
+            .into());
+    }
+
+    // 3. Let strVal be ? ToString(val).
+    let value = args.get_or_undefined(0).to_string(context)?;
+
+    // 4. Set the value of the internal slot of C named internalSlotName to strVal.
+    context.realm().legacy_regexp_statics().borrow_mut().input = Some(value);
+
+    Ok(JsValue::undefined())
+}
+
+fn get_input(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    get_legacy_static(this, context, |statics| statics.input.clone())
+}
+
+fn get_last_match(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    get_legacy_static(this, context, |statics| {
+        statics.last_match.as_ref().map(|m| m.last_match.clone())
+    })
+}
+
+fn get_last_paren(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    get_legacy_static(this, context, |statics| {
+        statics.last_match.as_ref().map(|m| m.last_paren.clone())
+    })
+}
+
+fn get_left_context(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    get_legacy_static(this, context, |statics| {
+        statics.last_match.as_ref().map(|m| m.left_context.clone())
+    })
+}
+
+fn get_right_context(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    get_legacy_static(this, context, |statics| {
+        statics.last_match.as_ref().map(|m| m.right_context.clone())
+    })
+}
+
+fn get_paren<const N: usize>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    get_legacy_static(this, context, |statics| {
+        statics.last_match.as_ref().map(|m| m.parens[N - 1].clone())
+    })
+}
+
+/// The legacy static accessor properties of `%RegExp%`, with their getter and optional setter.
+pub(super) const STATIC_ACCESSORS: [(
+    JsStr<'static>,
+    NativeFunctionPointer,
+    Option<NativeFunctionPointer>,
+); 19] = [
+    (js_str!("input"), get_input, Some(set_input)),
+    (js_str!("$_"), get_input, Some(set_input)),
+    (js_str!("lastMatch"), get_last_match, None),
+    (js_str!("$&"), get_last_match, None),
+    (js_str!("lastParen"), get_last_paren, None),
+    (js_str!("$+"), get_last_paren, None),
+    (js_str!("leftContext"), get_left_context, None),
+    (js_str!("$`"), get_left_context, None),
+    (js_str!("rightContext"), get_right_context, None),
+    (js_str!("$'"), get_right_context, None),
+    (js_str!("$1"), get_paren::<1>, None),
+    (js_str!("$2"), get_paren::<2>, None),
+    (js_str!("$3"), get_paren::<3>, None),
+    (js_str!("$4"), get_paren::<4>, None),
+    (js_str!("$5"), get_paren::<5>, None),
+    (js_str!("$6"), get_paren::<6>, None),
+    (js_str!("$7"), get_paren::<7>, None),
+    (js_str!("$8"), get_paren::<8>, None),
+    (js_str!("$9"), get_paren::<9>, None),
+];
@@ -24,28 +24,42 @@ use crate::{
 };
 use boa_gc::{Finalize, Trace};
 use boa_macros::{js_str, utf16};
-use boa_parser::lexer::regex::RegExpFlags;
+use boa_parser::lexer::regex::{desugar_duplicate_named_groups, RegExpFlags};
 use boa_profiler::Profiler;
 use regress::{Flags, Range, Regex};
 use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+#[cfg(feature = "annex-b")]
+mod legacy;
 mod regexp_string_iterator;
+#[cfg(feature = "annex-b")]
+pub(crate) use legacy::LegacyRegExpStatics;
 pub(crate) use regexp_string_iterator::RegExpStringIterator;
 #[cfg(test)]
 mod tests;
 
 /// The internal representation of a `RegExp` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
-// Safety: `RegExp` does not contain any objects which needs to be traced, so this is safe.
-#[boa_gc(unsafe_empty_trace)]
 pub struct RegExp {
     /// Regex matcher.
+    // Safety: Nothing in `Regex` needs tracing.
+    #[unsafe_ignore_trace]
     matcher: Regex,
+    /// Internal names of renamed duplicate capture groups, mapped to their original name.
+    #[unsafe_ignore_trace]
+    group_aliases: Box<[(Box<str>, Box<str>)]>,
+    #[unsafe_ignore_trace]
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
+    /// The realm in which this `RegExp` was allocated.
+    #[cfg(feature = "annex-b")]
+    realm: Realm,
+    /// Whether matching this `RegExp` updates the legacy static properties of `%RegExp%`.
+    #[cfg(feature = "annex-b")]
+    legacy_features_enabled: bool,
 }
 
 impl IntrinsicObject for RegExp {
@@ -161,7 +175,26 @@ impl IntrinsicObject for RegExp {
             );
 
         #[cfg(feature = "annex-b")]
-        let regexp = regexp.method(Self::compile, js_string!("compile"), 2);
+        let regexp = {
+            let mut regexp = regexp.method(Self::compile, js_string!("compile"), 2);
+            for (name, getter, setter) in legacy::STATIC_ACCESSORS {
+                let getter = BuiltInBuilder::callable(realm, getter)
+                    .name(js_string!(js_str!("get "), name))
+                    .build();
+                let setter = setter.map(|setter| {
+                    BuiltInBuilder::callable(realm, setter)
+                        .name(js_string!(js_str!("set "), name))
+                        .build()
+                });
+                regexp = regexp.static_accessor(
+                    js_string!(name),
+                    Some(getter),
+                    setter,
+                    Attribute::CONFIGURABLE,
+                );
+            }
+            regexp
+        };
 
         regexp.build();
     }
@@ -178,7 +211,7 @@ impl BuiltInObject for RegExp {
 impl BuiltInConstructor for RegExp {
     const LENGTH: usize = 2;
     const P: usize = 19;
-    const SP: usize = 1;
+    const SP: usize = 20;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::regexp;
@@ -266,7 +299,29 @@ impl BuiltInConstructor for RegExp {
             get_prototype_from_constructor(new_target, StandardConstructors::regexp, context)?;
 
         // 8.Return ? RegExpInitialize(O, P, F).
-        Self::initialize(Some(proto), &p, &f, context)
+        let regexp = Self::initialize(Some(proto), &p, &f, context)?;
+
+        // Annex B: RegExpAlloc ( newTarget )
+        // 3. Let thisRealm be the current Realm Record.
+        // 4. Set the value of obj’s [[Realm]] internal slot to thisRealm.
+        // 5. If SameValue(newTarget, thisRealm.[[Intrinsics]].[[%RegExp%]]) is true, then
+        //     a. Set the value of obj’s [[LegacyFeaturesEnabled]] internal slot to true.
+        // 6. Else,
+        //     a. Set the value of obj’s [[LegacyFeaturesEnabled]] internal slot to false.
+        #[cfg(feature = "annex-b")]
+        if let Some(new_target) = new_target.as_object() {
+            let intrinsic = context.intrinsics().constructors().regexp().constructor();
+            if !JsObject::equals(new_target, &intrinsic) {
+                if let Some(mut regexp) = regexp
+                    .as_object()
+                    .and_then(JsObject::downcast_mut::<RegExp>)
+                {
+                    regexp.legacy_features_enabled = false;
+                }
+            }
+        }
+
+        Ok(regexp)
     }
 }
 
@@ -341,12 +396,21 @@ impl RegExp {
 
         // 13. Let parseResult be ParsePattern(patternText, u, v).
         // 14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
+        let pattern = p.code_points().map(CodePoint::as_u32).collect::<Vec<_>>();
+        let (pattern, group_aliases) = match desugar_duplicate_named_groups(&pattern, flags) {
+            Ok(Some(desugared)) => (desugared.pattern, desugared.group_aliases),
+            Ok(None) => (pattern, Box::default()),
+            Err(error) => {
+                return Err(JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {error}"))
+                    .into())
+            }
+        };
         let matcher =
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
-                .map_err(|error| {
-                    JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                })?;
+            Regex::from_unicode(pattern.into_iter(), Flags::from(flags)).map_err(|error| {
+                JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+            })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -357,12 +421,25 @@ impl RegExp {
         // 21. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult with argument rer.
         Ok(RegExp {
             matcher,
+            group_aliases,
             flags,
             original_source: p,
             original_flags: f,
+            #[cfg(feature = "annex-b")]
+            realm: context.realm().clone(),
+            #[cfg(feature = "annex-b")]
+            legacy_features_enabled: true,
         })
     }
 
+    /// Returns the name of a capture group as written in the original pattern.
+    fn original_group_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.group_aliases
+            .iter()
+            .find(|(alias, _)| &**alias == name)
+            .map_or(name, |(_, original)| original)
+    }
+
     /// `RegExpInitialize ( obj, pattern, flags )`
     ///
     /// If prototype is `None`, initializes the prototype to `%RegExp%.prototype`.
@@ -1072,9 +1149,20 @@ impl RegExp {
         a.create_data_property_or_throw(0, matched_substr, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        let mut named_groups = match_value
-            .named_groups()
-            .collect::<Vec<(&str, Option<Range>)>>();
+        // Duplicate named groups are merged back into a single group, which takes the value of
+        // whichever of them participated in the match.
+        let mut named_groups: Vec<(&str, Option<Range>)> = Vec::new();
+        for (name, range) in match_value.named_groups() {
+            let name = rx.original_group_name(name);
+            match named_groups.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, existing)) => {
+                    if existing.is_none() {
+                        *existing = range;
+                    }
+                }
+                None => named_groups.push((name, range)),
+            }
+        }
         // Strict mode requires groups to be created in a sorted order
         named_groups.sort_by(|(name_x, _), (name_y, _)| name_x.cmp(name_y));
 
@@ -1182,6 +1270,24 @@ impl RegExp {
             }
         }
 
+        // Annex B: RegExpBuiltinExec ( R, S )
+        // 1. Let thisRealm be the current Realm Record.
+        // 2. Let rRealm be the value of R's [[Realm]] internal slot.
+        // 3. If SameValue(thisRealm, rRealm) is true, then
+        //     i. If the value of R’s [[LegacyFeaturesEnabled]] internal slot is true, then
+        //         a. Perform UpdateLegacyRegExpStaticProperties(%RegExp%, S, lastIndex, e, capturedValues).
+        //     ii. Else,
+        //         a. Perform InvalidateLegacyRegExpStaticProperties(%RegExp%).
+        #[cfg(feature = "annex-b")]
+        if rx.realm == *context.realm() {
+            let mut statics = context.realm().legacy_regexp_statics().borrow_mut();
+            if rx.legacy_features_enabled {
+                statics.update(input, &match_value);
+            } else {
+                statics.invalidate();
+            }
+        }
+
         // 34. If hasIndices is true, then
         // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices, groupNames, hasGroups).
         // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
@@ -1894,6 +2000,34 @@ impl RegExp {
                 JsNativeError::typ()
                     .with_message("`RegExp.prototype.compile` cannot be called for a non-object")
             })?;
+
+        {
+            let rx = this
+                .downcast_ref::<RegExp>()
+                .expect("already checked that the object was a RegExp");
+
+            // Annex B: RegExp.prototype.compile ( pattern, flags )
+            // 3. Let thisRealm be the current Realm Record.
+            // 4. Let oRealm be the value of O’s [[Realm]] internal slot.
+            // 5. If SameValue(thisRealm, oRealm) is false, throw a TypeError exception.
+            if rx.realm != *context.realm() {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`RegExp.prototype.compile` cannot be called for a RegExp from another realm",
+                    )
+                    .into());
+            }
+
+            // 6. If the value of R’s [[LegacyFeaturesEnabled]] internal slot is false, throw a TypeError exception.
+            if !rx.legacy_features_enabled {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`RegExp.prototype.compile` cannot be called for a RegExp subclass instance",
+                    )
+                    .into());
+            }
+        }
+
         let pattern = args.get_or_undefined(0);
         let flags = args.get_or_undefined(1);
         // 3. If pattern is an Object and pattern has a [[RegExpMatcher]] internal slot, then
@@ -225,3 +225,69 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[test]
+fn duplicate_named_groups() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var re = /(?<year>\d{4})-(?<month>\d{2})|(?<month>\d{2})\/(?<year>\d{4})/d;
+                var iso = re.exec("2024-05");
+                var us = re.exec("05/2024");
+            "#}),
+        TestAction::assert_eq("iso.groups.year", js_str!("2024")),
+        TestAction::assert_eq("us.groups.year", js_str!("2024")),
+        TestAction::assert_eq("us.groups.month", js_str!("05")),
+        TestAction::assert_eq("Object.keys(us.groups).length", 2),
+        TestAction::assert_eq("us.indices.groups.year[0]", 3),
+        TestAction::assert_eq(
+            "'05/2024'.replace(re, '$<year>-$<month>')",
+            js_str!("2024-05"),
+        ),
+        TestAction::assert_eq(r"/(?:(?<a>x)|(?<a>y))\k<a>/.exec('yy')[0]", js_str!("yy")),
+        TestAction::assert_eq(r"/(?:(?<a>x)|(?<a>y))\k<a>/.test('xy')", false),
+        TestAction::assert_native_error(
+            r#"new RegExp("(?<a>x)(?:(?<a>y)|z)")"#,
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: duplicate capture group name `a`",
+        ),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_static_properties() {
+    run_test_actions([
+        TestAction::assert_eq("RegExp.$1", js_str!("")),
+        TestAction::run(r"/(\w)(\d)?-/.exec('ab-cd')"),
+        TestAction::assert_eq("RegExp.$1", js_str!("b")),
+        TestAction::assert_eq("RegExp.$2", js_str!("")),
+        TestAction::assert_eq("RegExp.lastMatch", js_str!("b-")),
+        TestAction::assert_eq("RegExp['$&']", js_str!("b-")),
+        TestAction::assert_eq("RegExp.lastParen", js_str!("")),
+        TestAction::assert_eq("RegExp.leftContext", js_str!("a")),
+        TestAction::assert_eq("RegExp.rightContext", js_str!("cd")),
+        TestAction::assert_eq("RegExp.input", js_str!("ab-cd")),
+        TestAction::run("RegExp.$_ = 'other'"),
+        TestAction::assert_eq("RegExp.input", js_str!("other")),
+        TestAction::run("'xyz'.replace(/y/, '')"),
+        TestAction::assert_eq("RegExp['$`'] + RegExp[\"$'\"]", js_str!("xz")),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(RegExp, '$1').get.call({})",
+            JsNativeErrorKind::Type,
+            "legacy RegExp static properties can only be read from %RegExp%",
+        ),
+        TestAction::run("class Sub extends RegExp {}; new Sub('a').exec('a')"),
+        TestAction::assert_native_error(
+            "RegExp.lastMatch",
+            JsNativeErrorKind::Type,
+            "legacy RegExp static properties were invalidated",
+        ),
+        TestAction::assert_native_error(
+            "new Sub('a').compile('b')",
+            JsNativeErrorKind::Type,
+            "`RegExp.prototype.compile` cannot be called for a RegExp subclass instance",
+        ),
+        TestAction::run("/c/.exec('abc')"),
+        TestAction::assert_eq("RegExp.leftContext", js_str!("ab")),
+    ]);
+}
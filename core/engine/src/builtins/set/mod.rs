@@ -11,13 +11,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
 
 mod set_iterator;
+mod set_record;
 
 #[cfg(test)]
 mod tests;
 
 pub mod ordered_set;
 
-use self::ordered_set::OrderedSet;
+use self::{ordered_set::OrderedSet, set_record::SetRecord};
 use crate::{
     builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
@@ -72,6 +73,17 @@ impl IntrinsicObject for Set {
             .method(Self::entries, js_string!("entries"), 0)
             .method(Self::for_each, js_string!("forEach"), 1)
             .method(Self::has, js_string!("has"), 1)
+            .method(Self::union, js_string!("union"), 1)
+            .method(Self::intersection, js_string!("intersection"), 1)
+            .method(Self::difference, js_string!("difference"), 1)
+            .method(
+                Self::symmetric_difference,
+                js_string!("symmetricDifference"),
+                1,
+            )
+            .method(Self::is_subset_of, js_string!("isSubsetOf"), 1)
+            .method(Self::is_superset_of, js_string!("isSupersetOf"), 1)
+            .method(Self::is_disjoint_from, js_string!("isDisjointFrom"), 1)
             .property(
                 js_string!("keys"),
                 values_function.clone(),
@@ -108,7 +120,7 @@ impl BuiltInObject for Set {
 
 impl BuiltInConstructor for Set {
     const LENGTH: usize = 0;
-    const P: usize = 11;
+    const P: usize = 18;
     const SP: usize = 1;
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::set;
@@ -497,6 +509,407 @@ impl Set {
         ))
     }
 
+    /// `Set.prototype.union ( other )`
+    ///
+    /// Returns a new set containing the elements of both this set and `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.union
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/union
+    pub(crate) fn union(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "union")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "union", context)?;
+
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys("union", context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result = Self::copy_set_data(&set);
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        while let Some(next) = keys_iter.step_value(context)? {
+            // b. If next is not done, then
+            //     i. Set next to CanonicalizeKeyedCollectionKey(next).
+            //     ii. If SetDataHas(resultSetData, next) is false, then
+            //         1. Append next to resultSetData.
+            result.add(canonicalize_keyed_collection_key(next));
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_set_data(result, context).into())
+    }
+
+    /// `Set.prototype.intersection ( other )`
+    ///
+    /// Returns a new set containing the elements that are in both this set and `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.intersection
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/intersection
+    pub(crate) fn intersection(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "intersection")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "intersection", context)?;
+
+        // 4. Let resultSetData be a new empty List.
+        let mut result = OrderedSet::new();
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(&set) <= other.size() {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            Self::for_each_set_data(&set, context, |e, context| {
+                // i. Let e be O.[[SetData]][index].
+                // ii. Set index to index + 1.
+                // iii. If e is not empty, then
+                //     1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                //     2. If inOther is true, then
+                if other.has(e, context)? {
+                    // a. NOTE: It is possible for earlier calls to otherRec.[[Has]] to remove and
+                    //    re-add an element of O.[[SetData]], which can cause elements to be visited
+                    //    more than once during this iteration.
+                    // b. If SetDataHas(resultSetData, e) is false, then
+                    //     i. Append e to resultSetData.
+                    result.add(e.clone());
+                }
+
+                // 3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+                // 4. Set thisSize to the number of elements in O.[[SetData]].
+                Ok(true)
+            })?;
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other.keys("intersection", context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            while let Some(next) = keys_iter.step_value(context)? {
+                // ii. If next is not done, then
+                //     1. Set next to CanonicalizeKeyedCollectionKey(next).
+                let next = canonicalize_keyed_collection_key(next);
+
+                //     2. Let inThis be SetDataHas(O.[[SetData]], next).
+                //     3. Let alreadyInResult be SetDataHas(resultSetData, next).
+                //     4. If inThis is true and alreadyInResult is false, then
+                //         a. Append next to resultSetData.
+                if Self::set_data_has(&set, &next) {
+                    result.add(next);
+                }
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_set_data(result, context).into())
+    }
+
+    /// `Set.prototype.difference ( other )`
+    ///
+    /// Returns a new set containing the elements of this set that are not in `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.difference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/difference
+    pub(crate) fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "difference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "difference", context)?;
+
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let mut result = Self::copy_set_data(&set);
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(&set) <= other.size() {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be resultSetData[index].
+            //     ii. If e is not empty, then
+            let elements = result.iter().cloned().collect::<Vec<_>>();
+            for e in elements {
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, then
+                if other.has(&e, context)? {
+                    // a. Set resultSetData[index] to empty.
+                    result.delete(&e);
+                }
+                // iii. Set index to index + 1.
+            }
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other.keys("difference", context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            while let Some(next) = keys_iter.step_value(context)? {
+                // ii. If next is not done, then
+                //     1. Set next to CanonicalizeKeyedCollectionKey(next).
+                //     2. Let valueIndex be SetDataIndex(resultSetData, next).
+                //     3. If valueIndex is not not-found, then
+                //         a. Set resultSetData[valueIndex] to empty.
+                result.delete(&canonicalize_keyed_collection_key(next));
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_set_data(result, context).into())
+    }
+
+    /// `Set.prototype.symmetricDifference ( other )`
+    ///
+    /// Returns a new set containing the elements which are in either this set or `other`, but
+    /// not in both.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.symmetricdifference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/symmetricDifference
+    pub(crate) fn symmetric_difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "symmetricDifference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "symmetricDifference", context)?;
+
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys("symmetricDifference", context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result = Self::copy_set_data(&set);
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        while let Some(next) = keys_iter.step_value(context)? {
+            // b. If next is not done, then
+            //     i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(next);
+
+            //     ii. Let resultIndex be SetDataIndex(resultSetData, next).
+            //     iii. If resultIndex is not-found, let alreadyInResult be false. Otherwise let alreadyInResult be true.
+            //     iv. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::set_data_has(&set, &next) {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to empty.
+                result.delete(&next);
+            } else {
+                // v. Else,
+                //     1. If alreadyInResult is false, append next to resultSetData.
+                result.add(next);
+            }
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_set_data(result, context).into())
+    }
+
+    /// `Set.prototype.isSubsetOf ( other )`
+    ///
+    /// Returns `true` if all the elements of this set are in `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.issubsetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSubsetOf
+    pub(crate) fn is_subset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "isSubsetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "isSubsetOf", context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if Self::set_data_size(&set) > other.size() {
+            return Ok(false.into());
+        }
+
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        // 6. Let index be 0.
+        // 7. Repeat, while index < thisSize,
+        //     a. Let e be O.[[SetData]][index].
+        //     b. Set index to index + 1.
+        //     c. If e is not empty, then
+        //         i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+        //         ii. If inOther is false, return false.
+        //         iii. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+        //         iv. Set thisSize to the number of elements in O.[[SetData]].
+        // 8. Return true.
+        Self::for_each_set_data(&set, context, |e, context| other.has(e, context))
+            .map(JsValue::from)
+    }
+
+    /// `Set.prototype.isSupersetOf ( other )`
+    ///
+    /// Returns `true` if all the elements of `other` are in this set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.issupersetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSupersetOf
+    pub(crate) fn is_superset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "isSupersetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "isSupersetOf", context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if Self::set_data_size(&set) < other.size() {
+            return Ok(false.into());
+        }
+
+        // 5. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys("isSupersetOf", context)?;
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        while let Some(next) = keys_iter.step_value(context)? {
+            // b. If next is not done, then
+            //     i. If SetDataHas(O.[[SetData]], next) is false, then
+            if !Self::set_data_has(&set, &next) {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                // 2. Return false.
+                return Ok(false.into());
+            }
+        }
+
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isDisjointFrom ( other )`
+    ///
+    /// Returns `true` if this set has no elements in common with `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.isdisjointfrom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isDisjointFrom
+    pub(crate) fn is_disjoint_from(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "isDisjointFrom")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::new(args.get_or_undefined(0), "isDisjointFrom", context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(&set) <= other.size() {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            //         1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            //         2. If inOther is true, return false.
+            //         3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+            //         4. Set thisSize to the number of elements in O.[[SetData]].
+            return Self::for_each_set_data(
+                &set,
+                context,
+                |e, context| Ok(!other.has(e, context)?),
+            )
+            .map(JsValue::from);
+        }
+
+        // 5. Else,
+        //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys("isDisjointFrom", context)?;
+
+        //     b. Let next be not-started.
+        //     c. Repeat, while next is not done,
+        //         i. Set next to ? IteratorStepValue(keysIter).
+        while let Some(next) = keys_iter.step_value(context)? {
+            // ii. If next is not done, then
+            //     1. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::set_data_has(&set, &next) {
+                // a. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                // b. Return false.
+                return Ok(false.into());
+            }
+        }
+
+        // 6. Return true.
+        Ok(true.into())
+    }
+
     fn size_getter(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         Self::get_size(this).map(JsValue::from)
     }
@@ -530,4 +943,108 @@ impl Set {
                     .into()
             })
     }
+
+    /// Gets the `this` set of a `Set.prototype` method, throwing a `TypeError` if it doesn't have
+    /// a `[[SetData]]` internal slot.
+    fn this_set(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.is::<OrderedSet>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Method Set.prototype.{method} called on incompatible receiver"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Creates a new `Set` object with `%Set.prototype%` as its prototype and `data` as its
+    /// `[[SetData]]`.
+    fn create_from_set_data(data: OrderedSet, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context.intrinsics().constructors().set().prototype(),
+            data,
+        )
+    }
+
+    /// Returns a copy of the `[[SetData]]` of `set`, without its empty elements.
+    fn copy_set_data(set: &JsObject) -> OrderedSet {
+        let set = set
+            .downcast_ref::<OrderedSet>()
+            .expect("`set` must be a `Set` object");
+        let mut copy = OrderedSet::with_capacity(set.len());
+        for value in set.iter() {
+            copy.add(value.clone());
+        }
+        copy
+    }
+
+    /// Abstract operation [`SetDataSize ( setData )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setdatasize
+    fn set_data_size(set: &JsObject) -> i64 {
+        let size = set
+            .downcast_ref::<OrderedSet>()
+            .expect("`set` must be a `Set` object")
+            .len();
+        i64::try_from(size).unwrap_or(i64::MAX)
+    }
+
+    /// Abstract operation [`SetDataHas ( setData, value )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setdatahas
+    fn set_data_has(set: &JsObject, value: &JsValue) -> bool {
+        set.downcast_ref::<OrderedSet>()
+            .expect("`set` must be a `Set` object")
+            .contains(&canonicalize_keyed_collection_key(value.clone()))
+    }
+
+    /// Calls `f` for each element of the `[[SetData]]` of `set`, visiting the elements added by
+    /// `f` itself, and stopping at the first call returning `false`.
+    ///
+    /// Returns `false` if the iteration was stopped early, or `true` otherwise.
+    fn for_each_set_data<F>(set: &JsObject, context: &mut Context, mut f: F) -> JsResult<bool>
+    where
+        F: FnMut(&JsValue, &mut Context) -> JsResult<bool>,
+    {
+        // Lock the set so that elements removed by `f` don't shift the indices of the remaining ones.
+        let _lock = set
+            .downcast_mut::<OrderedSet>()
+            .expect("`set` must be a `Set` object")
+            .lock(set.clone());
+
+        let mut index = 0;
+        loop {
+            let e = {
+                let data = set
+                    .downcast_ref::<OrderedSet>()
+                    .expect("`set` must be a `Set` object");
+                if index >= data.full_len() {
+                    return Ok(true);
+                }
+                data.get_index(index).cloned()
+            };
+            index += 1;
+
+            if let Some(e) = e {
+                if !f(&e, context)? {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+}
+
+/// Abstract operation [`CanonicalizeKeyedCollectionKey ( key )`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalizekeyedcollectionkey
+fn canonicalize_keyed_collection_key(key: JsValue) -> JsValue {
+    // 1. If key is -0𝔽, return +0𝔽.
+    match key.as_number() {
+        Some(n) if n.is_zero() => JsValue::Integer(0),
+        // 2. Return key.
+        _ => key,
+    }
 }
@@ -1,7 +1,7 @@
 //! Implements a set type that preserves insertion order.
 
 use crate::{builtins::map::ordered_map::MapKey, object::JsObject, JsData, JsValue};
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, ExternalMemory, Finalize, Trace};
 use indexmap::IndexSet;
 use std::fmt::Debug;
 
@@ -11,6 +11,9 @@ pub struct OrderedSet {
     inner: IndexSet<MapKey>,
     lock: u32,
     empty_count: usize,
+    /// The size of the entries, reported to the garbage collector to count it towards the heap
+    /// limit.
+    memory: ExternalMemory,
 }
 
 unsafe impl Trace for OrderedSet {
@@ -43,17 +46,28 @@ impl OrderedSet {
             inner: IndexSet::new(),
             lock: 0,
             empty_count: 0,
+            memory: ExternalMemory::default(),
         }
     }
 
     /// Creates a new empty `OrderedSet` with the specified capacity.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
+        let mut set = Self {
             inner: IndexSet::with_capacity(capacity),
             lock: 0,
             empty_count: 0,
-        }
+            memory: ExternalMemory::default(),
+        };
+        set.report_memory();
+        set
+    }
+
+    /// Reports the current size of the entries to the garbage collector.
+    fn report_memory(&mut self) {
+        // Each entry is stored with its hash, and indexed by the hash table.
+        let entry_size = size_of::<MapKey>() + 2 * size_of::<usize>();
+        self.memory.set(self.inner.capacity() * entry_size);
     }
 
     /// Return the number of elements in the set, including empty elements.
@@ -89,7 +103,9 @@ impl OrderedSet {
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn add(&mut self, value: JsValue) -> bool {
-        self.inner.insert(MapKey::Key(value))
+        let inserted = self.inner.insert(MapKey::Key(value));
+        self.report_memory();
+        inserted
     }
 
     /// Delete the `value` from the set and return true if successful
@@ -103,7 +119,9 @@ impl OrderedSet {
         } else if self.inner.contains(value) {
             self.inner.insert(MapKey::Empty(self.empty_count));
             self.empty_count += 1;
-            self.inner.swap_remove(value)
+            let removed = self.inner.swap_remove(value);
+            self.report_memory();
+            removed
         } else {
             false
         }
@@ -115,6 +133,7 @@ impl OrderedSet {
         self.inner.clear();
         self.inner.shrink_to_fit();
         self.empty_count = 0;
+        self.report_memory();
     }
 
     /// Checks if a given value is present in the set
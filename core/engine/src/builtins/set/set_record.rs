@@ -0,0 +1,154 @@
+//! Implements the `Set Record` specification type, used by the `Set` methods to operate on
+//! set-like objects.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-set-records
+
+use crate::{
+    builtins::iterable::IteratorRecord, error::JsNativeError, js_string, object::JsObject,
+    value::IntegerOrInfinity, Context, JsResult, JsValue,
+};
+
+/// A `Set Record`, encapsulating the set-like interface of an object.
+#[derive(Debug)]
+pub(crate) struct SetRecord {
+    /// `[[SetObject]]`
+    object: JsObject,
+    /// `[[Size]]`
+    size: IntegerOrInfinity,
+    /// `[[Has]]`
+    has: JsObject,
+    /// `[[Keys]]`
+    keys: JsObject,
+}
+
+impl SetRecord {
+    /// Abstract operation [`GetSetRecord ( obj )`][spec].
+    ///
+    /// `method` is the name of the `Set` method calling this operation, used for error messages.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsetrecord
+    pub(crate) fn new(obj: &JsValue, method: &str, context: &mut Context) -> JsResult<Self> {
+        // 1. If obj is not an Object, throw a TypeError exception.
+        let Some(object) = obj.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Set.prototype.{method}: expected a set-like object, got a value of type `{}`",
+                    obj.type_of()
+                ))
+                .into());
+        };
+
+        // 2. Let rawSize be ? Get(obj, "size").
+        let raw_size = object.get(js_string!("size"), context)?;
+
+        // 3. Let numSize be ? ToNumber(rawSize).
+        // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+        let num_size = raw_size.to_number(context)?;
+
+        // 5. If numSize is NaN, throw a TypeError exception.
+        if num_size.is_nan() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Set.prototype.{method}: the `size` property of a set-like object must be a number"
+                ))
+                .into());
+        }
+
+        // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+        let size = IntegerOrInfinity::from(num_size);
+
+        // 7. If intSize < 0, throw a RangeError exception.
+        if size < 0 {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "Set.prototype.{method}: the `size` property of a set-like object cannot be negative"
+                ))
+                .into());
+        }
+
+        // 8. Let has be ? Get(obj, "has").
+        // 9. If IsCallable(has) is false, throw a TypeError exception.
+        let has = object
+            .get(js_string!("has"), context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "Set.prototype.{method}: the `has` property of a set-like object must be a function"
+                ))
+            })?;
+
+        // 10. Let keys be ? Get(obj, "keys").
+        // 11. If IsCallable(keys) is false, throw a TypeError exception.
+        let keys = object
+            .get(js_string!("keys"), context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "Set.prototype.{method}: the `keys` property of a set-like object must be a function"
+                ))
+            })?;
+
+        // 12. Return a new Set Record { [[SetObject]]: obj, [[Size]]: intSize, [[Has]]: has, [[Keys]]: keys }.
+        Ok(Self {
+            object: object.clone(),
+            size,
+            has,
+            keys,
+        })
+    }
+
+    /// Gets the `[[Size]]` of this record.
+    pub(crate) const fn size(&self) -> IntegerOrInfinity {
+        self.size
+    }
+
+    /// Calls the `[[Has]]` method of the set-like object with `value`, and converts the result to
+    /// a boolean.
+    pub(crate) fn has(&self, value: &JsValue, context: &mut Context) -> JsResult<bool> {
+        Ok(self
+            .has
+            .call(
+                &self.object.clone().into(),
+                std::slice::from_ref(value),
+                context,
+            )?
+            .to_boolean())
+    }
+
+    /// Abstract operation [`GetKeysIterator ( setRec )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-getkeysiterator
+    pub(crate) fn keys(&self, method: &str, context: &mut Context) -> JsResult<IteratorRecord> {
+        // 1. Let keysIter be ? Call(setRec.[[Keys]], setRec.[[SetObject]]).
+        let keys_iter = self.keys.call(&self.object.clone().into(), &[], context)?;
+
+        // 2. If keysIter is not an Object, throw a TypeError exception.
+        let Some(keys_iter) = keys_iter.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Set.prototype.{method}: the `keys` method of a set-like object must return an object"
+                ))
+                .into());
+        };
+
+        // 3. Let nextMethod be ? Get(keysIter, "next").
+        let next_method = keys_iter.get(js_string!("next"), context)?;
+
+        // 4. If IsCallable(nextMethod) is false, throw a TypeError exception.
+        if !next_method.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "Set.prototype.{method}: the `next` method of a keys iterator must be a function"
+                ))
+                .into());
+        }
+
+        // 5. Return a new Iterator Record { [[Iterator]]: keysIter, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        Ok(IteratorRecord::new(keys_iter.clone(), next_method))
+    }
+}
@@ -174,3 +174,87 @@ fn not_a_function() {
         "calling a builtin Set constructor without new is forbidden",
     )]);
 }
+
+#[test]
+fn set_methods() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const a = new Set([1, 2, 3]);
+            const b = new Set([2, 3, 4]);
+        "#}),
+        TestAction::assert("arrayEquals([...a.union(b)], [1, 2, 3, 4])"),
+        TestAction::assert("arrayEquals([...a.intersection(b)], [2, 3])"),
+        TestAction::assert("arrayEquals([...a.difference(b)], [1])"),
+        TestAction::assert("arrayEquals([...a.symmetricDifference(b)], [1, 4])"),
+        TestAction::assert("new Set([2]).isSubsetOf(a)"),
+        TestAction::assert("!a.isSubsetOf(b)"),
+        TestAction::assert("a.isSupersetOf(new Set([1, 3]))"),
+        TestAction::assert("!a.isSupersetOf(b)"),
+        TestAction::assert("a.isDisjointFrom(new Set([5, 6]))"),
+        TestAction::assert("!a.isDisjointFrom(b)"),
+        TestAction::assert("a.union(b) instanceof Set"),
+    ]);
+}
+
+#[test]
+fn set_methods_set_like() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            const a = new Set([1, 2, 3]);
+            const map = new Map([[3, "three"], [4, "four"]]);
+            class SetLike {
+                size = 2;
+                has(value) { return value === 1 || value === 2; }
+                *keys() { yield 1; yield 2; }
+            }
+        "#}),
+        TestAction::assert("arrayEquals([...a.union(map)], [1, 2, 3, 4])"),
+        TestAction::assert("arrayEquals([...a.intersection(new SetLike())], [1, 2])"),
+        TestAction::assert("arrayEquals([...a.difference(new SetLike())], [3])"),
+        TestAction::assert("a.isSupersetOf(new SetLike())"),
+        TestAction::assert_native_error(
+            "a.union([1, 2])",
+            JsNativeErrorKind::Type,
+            "Set.prototype.union: the `size` property of a set-like object must be a number",
+        ),
+        TestAction::assert_native_error(
+            "a.union({ size: -1, has() {}, keys() {} })",
+            JsNativeErrorKind::Range,
+            "Set.prototype.union: the `size` property of a set-like object cannot be negative",
+        ),
+        TestAction::assert_native_error(
+            "a.isSubsetOf({ size: 1, keys() {} })",
+            JsNativeErrorKind::Type,
+            "Set.prototype.isSubsetOf: the `has` property of a set-like object must be a function",
+        ),
+        TestAction::assert_native_error(
+            "a.union(1)",
+            JsNativeErrorKind::Type,
+            "Set.prototype.union: expected a set-like object, got a value of type `number`",
+        ),
+    ]);
+}
+
+#[test]
+fn set_methods_close_iterator() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            let closed = 0;
+            const setLike = {
+                size: 1,
+                has() { return false; },
+                keys() {
+                    return {
+                        next() { return { value: 5, done: false }; },
+                        return() { closed++; return {}; },
+                    };
+                },
+            };
+            new Set([1, 2]).isSupersetOf(setLike);
+            closed
+        "#},
+        1,
+    )]);
+}
@@ -0,0 +1,394 @@
+//! Boa's implementation of ECMAScript's `ShadowRealm` builtin object.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object containing its
+//! own intrinsics and built-ins, that shares the same [`Context`] as its creator. The only values
+//! that can cross the boundary between both realms are primitives and callable objects, which get
+//! wrapped by a [`WrappedFunction`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{eval::Eval, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    module::{ModuleRequest, Referrer},
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, JsPromise},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+mod wrapped;
+
+pub(crate) use wrapped::get_wrapped_value;
+pub use wrapped::WrappedFunction;
+
+#[cfg(test)]
+mod tests;
+
+/// Boa's implementation of ECMAScript's `ShadowRealm` builtin object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ShadowRealm {
+    /// `[[ShadowRealm]]`
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .build();
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    /// The amount of arguments the `ShadowRealm` constructor takes.
+    const LENGTH: usize = 0;
+    const P: usize = 3;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let realmRec be CreateRealm().
+        // 4. Set O.[[ShadowRealm]] to realmRec.
+        // 5-9. Create and push a new execution context for realmRec.
+        // 10. Perform ? SetDefaultGlobalBindings(O.[[ShadowRealm]]).
+        let realm = context.create_realm()?;
+
+        // 11. Perform ? HostInitializeShadowRealm(O.[[ShadowRealm]]).
+        let old_realm = context.enter_realm(realm.clone());
+        let result = context
+            .host_hooks()
+            .initialize_shadow_realm(&realm, context);
+        context.enter_realm(old_realm);
+        result?;
+
+        // 12. Return O.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        )
+        .into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation `ValidateShadowRealmObject ( O )`, returning the `[[ShadowRealm]]` of
+    /// the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn validate(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(|o| o.downcast_ref::<Self>().map(|shadow| shadow.realm.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: expected `this` to be a `ShadowRealm` object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// Synchronously evaluates `sourceText` as a script in the global environment of the shadow
+    /// realm, returning its completion value wrapped for the caller realm.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "evaluate")?;
+
+        // 3. If Type(sourceText) is not String, throw a TypeError exception.
+        let Some(source_text) = args.get_or_undefined(0).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: source text must be a string")
+                .into());
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+        context.host_hooks().ensure_can_compile_strings(
+            eval_realm.clone(),
+            &[],
+            source_text,
+            false,
+            context,
+        )?;
+
+        // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        //     c. If script Contains ScriptBody is false, return undefined.
+        //     d. Let body be the ScriptBody of script.
+        //     e. If body Contains NewTarget is true, throw a SyntaxError exception.
+        //     f. If body Contains SuperProperty is true, throw a SyntaxError exception.
+        //     g. If body Contains SuperCall is true, throw a SyntaxError exception.
+        let body = Eval::parse_eval_body(source_text, false, false, context)?;
+
+        // 3-17. Create and push a new execution context for evalRealm, then evaluate body as an
+        //       indirect eval in it.
+        let old_realm = context.enter_realm(eval_realm);
+        let result = Eval::evaluate_eval_body(body, false, None, false, context);
+        context.enter_realm(old_realm);
+
+        // 18. If result.[[Type]] is throw, throw a TypeError exception.
+        let result = result.map_err(|err| {
+            if err.is_catchable() {
+                JsNativeError::typ()
+                    .with_message("ShadowRealm.prototype.evaluate: evaluation threw an error")
+                    .with_realm(caller_realm.clone())
+                    .into()
+            } else {
+                err
+            }
+        })?;
+
+        // 19. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        get_wrapped_value(caller_realm, result, context)
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// Imports the module `specifier` into the shadow realm, returning a promise for the export
+    /// `exportName` of the module, wrapped for the caller realm.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If Type(exportName) is not String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string().cloned() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: export name must be a string")
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportNameString, callerRealm, evalRealm).
+        Ok(Self::shadow_realm_import_value(
+            specifier,
+            export_name,
+            &caller_realm,
+            eval_realm,
+            context,
+        )
+        .into())
+    }
+
+    /// Abstract operation `ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn shadow_realm_import_value(
+        specifier: JsString,
+        export_name: JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsPromise {
+        // 1. Let evalContext be GetShadowRealmContext(evalRealm, true).
+        // 2. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let (inner_promise, resolvers) = JsPromise::new_pending(context);
+
+        // 3. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let old_realm = context.enter_realm(eval_realm.clone());
+
+        // 4. Perform HostLoadImportedModule(evalRealm, specifierString, empty, innerCapability).
+        let request = ModuleRequest::from_specifier(specifier);
+        context.module_loader().load_imported_module(
+            Referrer::Realm(eval_realm.clone()),
+            request.clone(),
+            Box::new(move |completion, context| {
+                // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
+                let module = match completion {
+                    Ok(module) => {
+                        eval_realm
+                            .loaded_modules()
+                            .borrow_mut()
+                            .entry(request)
+                            .or_insert_with(|| module.clone());
+                        module
+                    }
+                    Err(err) => {
+                        let err = err.to_opaque(context);
+                        resolvers
+                            .reject
+                            .call(&JsValue::undefined(), &[err], context)
+                            .expect("default `reject` function cannot throw");
+                        return;
+                    }
+                };
+
+                // Load, link and evaluate the module, resolving the inner capability with the
+                // module's namespace object.
+                let namespace = module.load_link_evaluate(context).then(
+                    Some(
+                        NativeFunction::from_copy_closure_with_captures(
+                            |_, _, module, context| Ok(module.namespace(context).into()),
+                            module.clone(),
+                        )
+                        .to_js_function(context.realm()),
+                    ),
+                    None,
+                    context,
+                );
+                resolvers
+                    .resolve
+                    .call(&JsValue::undefined(), &[namespace.into()], context)
+                    .expect("default `resolve` function cannot throw");
+            }),
+            context,
+        );
+
+        // 5. Pop evalContext from the execution context stack.
+        context.enter_realm(old_realm);
+
+        // 6. Let steps be the steps of an ExportGetter function as described below.
+        // 7. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+        // 8. Set onFulfilled.[[ExportNameString]] to exportNameString.
+        let on_fulfilled = NativeFunction::from_copy_closure_with_captures(
+            |_, args, (export_name, caller_realm), context| {
+                // 1. Assert: exports is a module namespace exotic object.
+                let exports = args
+                    .get_or_undefined(0)
+                    .as_object()
+                    .expect("the inner promise must be fulfilled with a module namespace");
+
+                // 2. Let f be the active function object.
+                // 3. Let string be f.[[ExportNameString]].
+                // 4. Assert: Type(string) is String.
+                // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                // 6. If hasOwn is false, throw a TypeError exception.
+                if !exports.has_own_property(export_name.clone(), context)? {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "ShadowRealm.prototype.importValue: module does not export `{}`",
+                            export_name.to_std_string_escaped()
+                        ))
+                        .into());
+                }
+
+                // 7. Let value be ? Get(exports, string).
+                let value = exports.get(export_name.clone(), context)?;
+
+                // 8. Let realm be f.[[Realm]].
+                // 9. Return ? GetWrappedValue(realm, value).
+                get_wrapped_value(caller_realm, value, context)
+            },
+            (export_name, caller_realm.clone()),
+        )
+        .to_js_function(caller_realm);
+
+        // 10. Let errorSteps be the steps of an ImportValueError function, which throw a TypeError
+        //     exception on the callerRealm.
+        let on_rejected = NativeFunction::from_fn_ptr(|_, _, _| {
+            Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: could not import the module")
+                .into())
+        })
+        .to_js_function(caller_realm);
+
+        // 11. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 12. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, onRejected, promiseCapability).
+        inner_promise.then(Some(on_fulfilled), Some(on_rejected), context)
+    }
+}
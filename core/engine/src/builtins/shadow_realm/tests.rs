@@ -0,0 +1,206 @@
+use std::rc::Rc;
+
+use crate::{
+    module::{ModuleLoader, ModuleRequest, Referrer},
+    run_test_actions, run_test_actions_with, Context, JsNativeError, JsNativeErrorKind, JsResult,
+    Module, Source, TestAction,
+};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+        TestAction::assert("Object.getPrototypeOf(new ShadowRealm()) === ShadowRealm.prototype"),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new ShadowRealm())",
+            js_str!("[object ShadowRealm]"),
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm.prototype.evaluate.call({}, '1')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: expected `this` to be a `ShadowRealm` object",
+        ),
+    ]);
+}
+
+#[test]
+fn evaluate() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var x = "outer";
+            realm.evaluate("var x = 'inner';");
+        "#}),
+        TestAction::assert_eq("realm.evaluate('1 + 2')", 3),
+        TestAction::assert_eq("realm.evaluate('x')", js_str!("inner")),
+        TestAction::assert_eq("x", js_str!("outer")),
+        TestAction::assert("realm.evaluate('Array') !== Array"),
+        TestAction::assert_eq("realm.evaluate('typeof ShadowRealm')", js_str!("function")),
+        TestAction::assert_native_error(
+            "realm.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: source text must be a string",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('globalThis')",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across a ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw new Error(\"boom\")')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: evaluation threw an error",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('let y = 1; let y = 2;')",
+            JsNativeErrorKind::Syntax,
+            "lexical name declared multiple times at line 1, col 1",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('new.target')",
+            JsNativeErrorKind::Syntax,
+            "invalid new.target usage at line 1, col 1",
+        ),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var double = realm.evaluate("(function double(a, b) { return a * 2; })");
+            var apply = realm.evaluate("(f, value) => f(value)");
+        "#}),
+        TestAction::assert_eq("double(21)", 42),
+        TestAction::assert_eq("double.name", js_str!("double")),
+        TestAction::assert_eq("double.length", 2),
+        TestAction::assert("Object.getPrototypeOf(double) === Function.prototype"),
+        TestAction::assert("!('prototype' in double)"),
+        TestAction::assert_eq("apply((x) => x + 1, 1)", 2),
+        TestAction::assert_eq("apply((f) => typeof f, apply)", js_str!("function")),
+        TestAction::assert_native_error(
+            "double({})",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across a ShadowRealm boundary",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                double({});
+                false
+            } catch (e) {
+                e.constructor === TypeError
+            }
+        "#}),
+        TestAction::assert(indoc! {r#"
+            try {
+                double.call({}, 1);
+                false
+            } catch (e) {
+                e.constructor === TypeError
+            }
+        "#}),
+        TestAction::assert_native_error(
+            "apply(() => { throw new RangeError('boom') }, 1)",
+            JsNativeErrorKind::Type,
+            "a function wrapped by a ShadowRealm threw an error",
+        ),
+        TestAction::assert_native_error(
+            "new double(1)",
+            JsNativeErrorKind::Type,
+            "not a constructor",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                apply(() => { throw 1 }, 1);
+                false
+            } catch (e) {
+                e.constructor === TypeError
+            }
+        "#}),
+    ]);
+}
+
+/// Loads the module `plugin` from a static source, and fails for any other specifier.
+#[derive(Debug)]
+struct PluginLoader;
+
+impl ModuleLoader for PluginLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: Referrer,
+        request: ModuleRequest,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+        context: &mut Context,
+    ) {
+        if request.specifier() != &js_str!("plugin") {
+            finish_load(
+                Err(JsNativeError::typ().with_message("module not found").into()),
+                context,
+            );
+            return;
+        }
+
+        let module = Module::parse(
+            Source::from_bytes(indoc! {r#"
+                export const answer = 42;
+                export const object = {};
+                export function identity(x) { return x; }
+                export function realmGlobal() { return typeof globalThis.marker; }
+            "#}),
+            None,
+            context,
+        );
+        finish_load(module, context);
+    }
+}
+
+#[test]
+fn import_value() {
+    let context = &mut Context::builder()
+        .module_loader(Rc::new(PluginLoader))
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var marker = true;
+                var realm = new ShadowRealm();
+                var results = {};
+                function record(name) {
+                    return [
+                        (value) => { results[name] = value; },
+                        (error) => { results[name] = error; },
+                    ];
+                }
+
+                realm.importValue("plugin", "answer").then(...record("answer"));
+                realm.importValue("plugin", "identity").then(...record("identity"));
+                realm.importValue("plugin", "realmGlobal").then(...record("realmGlobal"));
+                realm.importValue("plugin", "object").then(...record("object"));
+                realm.importValue("plugin", "missing").then(...record("missing"));
+                realm.importValue("unknown", "answer").then(...record("unknown"));
+            "#}),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert_eq("results.answer", 42),
+            TestAction::assert_eq("(0, results.identity)(5)", 5),
+            TestAction::assert_eq("(0, results.realmGlobal)()", js_str!("undefined")),
+            TestAction::assert("results.object instanceof TypeError"),
+            TestAction::assert("results.missing instanceof TypeError"),
+            TestAction::assert("results.unknown instanceof TypeError"),
+            TestAction::assert_native_error(
+                "realm.importValue('plugin', 1)",
+                JsNativeErrorKind::Type,
+                "ShadowRealm.prototype.importValue: export name must be a string",
+            ),
+        ],
+        context,
+    );
+}
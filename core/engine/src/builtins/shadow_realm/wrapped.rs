@@ -0,0 +1,247 @@
+//! Boa's implementation of the wrapped function exotic objects of `ShadowRealm`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::function::copy_name_and_length,
+    object::{
+        internal_methods::{CallValue, InternalObjectMethods, ORDINARY_INTERNAL_METHODS},
+        JsData,
+    },
+    realm::Realm,
+    Context, JsError, JsNativeError, JsObject, JsResult, JsValue,
+};
+
+/// A callable object that forwards calls to a function living in another realm.
+///
+/// Wrapped functions are the only kind of object that can cross the boundary of a
+/// `ShadowRealm`: every argument, `this` value and return value that passes through one is either
+/// a primitive or gets wrapped again, so objects from one realm are never directly reachable from
+/// the other.
+#[derive(Debug, Trace, Finalize)]
+pub struct WrappedFunction {
+    /// `[[WrappedTargetFunction]]`
+    target_function: JsObject,
+    /// `[[Realm]]`
+    realm: Realm,
+}
+
+impl JsData for WrappedFunction {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __call__: wrapped_function_exotic_call,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl WrappedFunction {
+    /// Abstract operation `WrappedFunctionCreate ( callerRealm, Target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: Realm,
+        target_function: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let prototype = caller_realm
+            .intrinsics()
+            .constructors()
+            .function()
+            .prototype();
+        let wrapped = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                target_function: target_function.clone(),
+                realm: caller_realm.clone(),
+            },
+        );
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        // 8. If result is an abrupt completion, throw a TypeError exception.
+        if let Err(err) = copy_name_and_length(&wrapped, target_function, None, 0, context) {
+            if !err.is_catchable() {
+                return Err(err);
+            }
+            return Err(JsNativeError::typ()
+                .with_message("could not copy the name and length of the wrapped function")
+                .with_realm(caller_realm)
+                .into());
+        }
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// Get a reference to the wrapped function's target function.
+    #[must_use]
+    pub const fn target_function(&self) -> &JsObject {
+        &self.target_function
+    }
+
+    /// Get a reference to the realm in which the wrapped function was created.
+    #[must_use]
+    pub const fn realm(&self) -> &Realm {
+        &self.realm
+    }
+}
+
+/// Abstract operation `GetWrappedValue ( callerRealm, value )`
+///
+/// Prepares `value` to be passed into `caller_realm`, wrapping callable objects and throwing for
+/// any other kind of object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(value) is Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot pass a non-callable object across a ShadowRealm boundary")
+                .with_realm(caller_realm.clone())
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm.clone(), object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value)
+}
+
+/// Internal method `[[Call]]` for Wrapped Function Exotic Objects
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    argument_count: usize,
+    context: &mut Context,
+) -> JsResult<CallValue> {
+    let args = context.vm.pop_n_values(argument_count);
+    let _func = context.vm.pop();
+    let this = context.vm.pop();
+
+    let (target, caller_realm) = {
+        let function = obj.downcast_ref::<WrappedFunction>().expect(
+            "wrapped function exotic method should only be callable from wrapped functions",
+        );
+        (function.target_function.clone(), function.realm.clone())
+    };
+
+    // 1. Let callerContext be the running execution context.
+    // 2. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    // 3. Assert: calleeContext is now the running execution context.
+    // 4. Let result be OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList).
+    // 5. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+    // 6. Return ? result.
+    let result = ordinary_wrapped_function_call(&target, &caller_realm, &this, &args, context)
+        .map_err(|err| err.inject_realm(caller_realm))?;
+
+    context.vm.push(result);
+
+    Ok(CallValue::Complete)
+}
+
+/// Abstract operation `OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`
+///
+/// Any native error returned by this function must be associated with `caller_realm`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    target: &JsObject,
+    caller_realm: &Realm,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| {
+            get_wrapped_value(&target_realm, arg.clone(), context)
+                .map_err(|err| caller_realm_error(err, caller_realm))
+        })
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument be ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this.clone(), context)
+        .map_err(|err| caller_realm_error(err, caller_realm))?;
+
+    // 9. Let result be Completion(Call(target, wrappedThisArgument, wrappedArgs)).
+    // 10. If result is a normal completion or a throw completion, then
+    //     a. If result is a throw completion, throw a TypeError exception.
+    let result = target
+        .call(&wrapped_this, &wrapped_args, context)
+        .map_err(|err| {
+            if err.is_catchable() {
+                JsNativeError::typ()
+                    .with_message("a function wrapped by a ShadowRealm threw an error")
+                    .into()
+            } else {
+                err
+            }
+        })?;
+
+    //     b. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+    get_wrapped_value(caller_realm, result, context)
+}
+
+/// Recreates a catchable error thrown while wrapping a value for `targetRealm` as a `TypeError`
+/// of `caller_realm`, which is still the running realm when the arguments are wrapped.
+fn caller_realm_error(err: JsError, caller_realm: &Realm) -> JsError {
+    if !err.is_catchable() {
+        return err;
+    }
+
+    let message = err
+        .as_native()
+        .map(|err| err.message().to_string())
+        .unwrap_or_else(|| "cannot pass a value across a ShadowRealm boundary".to_string());
+    JsNativeError::typ()
+        .with_message(message)
+        .with_realm(caller_realm.clone())
+        .into()
+}
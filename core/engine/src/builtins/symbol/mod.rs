@@ -89,6 +89,12 @@ impl GlobalSymbolRegistry {
     }
 }
 
+/// Returns `true` if `sym` is in the `GlobalSymbolRegistry`, i.e. if `KeyForSymbol(sym)` is not
+/// undefined.
+pub(crate) fn is_registered_symbol(sym: &JsSymbol) -> bool {
+    GLOBAL_SYMBOL_REGISTRY.symbols.contains_key(sym)
+}
+
 /// The internal representation of a `Symbol` object.
 #[derive(Debug, Clone, Copy)]
 pub struct Symbol;
@@ -97,12 +103,15 @@ impl IntrinsicObject for Symbol {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let symbol_async_dispose = JsSymbol::async_dispose();
         let symbol_async_iterator = JsSymbol::async_iterator();
+        let symbol_dispose = JsSymbol::dispose();
         let symbol_has_instance = JsSymbol::has_instance();
         let symbol_is_concat_spreadable = JsSymbol::is_concat_spreadable();
         let symbol_iterator = JsSymbol::iterator();
         let symbol_match = JsSymbol::r#match();
         let symbol_match_all = JsSymbol::match_all();
+        let symbol_metadata = JsSymbol::metadata();
         let symbol_replace = JsSymbol::replace();
         let symbol_search = JsSymbol::search();
         let symbol_species = JsSymbol::species();
@@ -125,11 +134,13 @@ impl IntrinsicObject for Symbol {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
+            .static_property(js_string!("asyncDispose"), symbol_async_dispose, attribute)
             .static_property(
                 js_string!("asyncIterator"),
                 symbol_async_iterator,
                 attribute,
             )
+            .static_property(js_string!("dispose"), symbol_dispose, attribute)
             .static_property(js_string!("hasInstance"), symbol_has_instance, attribute)
             .static_property(
                 js_string!("isConcatSpreadable"),
@@ -139,6 +150,7 @@ impl IntrinsicObject for Symbol {
             .static_property(js_string!("iterator"), symbol_iterator, attribute)
             .static_property(js_string!("match"), symbol_match, attribute)
             .static_property(js_string!("matchAll"), symbol_match_all, attribute)
+            .static_property(js_string!("metadata"), symbol_metadata, attribute)
             .static_property(js_string!("replace"), symbol_replace, attribute)
             .static_property(js_string!("search"), symbol_search, attribute)
             .static_property(js_string!("species"), symbol_species, attribute)
@@ -190,6 +190,7 @@ impl IntrinsicObject for Duration {
             .method(Self::total, js_string!("total"), 1)
             .method(Self::to_string, js_string!("toString"), 1)
             .method(Self::to_json, js_string!("toJSON"), 0)
+            .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .build();
     }
 
@@ -803,6 +804,57 @@ impl Duration {
             .with_message("not yet implemented.")
             .into())
     }
+
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("this value must be a Duration object.")
+            })?
+            .inner;
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::duration_format::{DurationFormat, DurationRecord};
+
+            // 3. Let formatter be ? Construct(%Intl.DurationFormat%, « locales, options »).
+            let formatter = DurationFormat::try_new(
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                context,
+            )?;
+
+            // 4. Return FormatDurationFormatPattern(formatter, duration).
+            let record = DurationRecord {
+                years: duration.years().as_inner(),
+                months: duration.months().as_inner(),
+                weeks: duration.weeks().as_inner(),
+                days: duration.days().as_inner(),
+                hours: duration.hours().as_inner(),
+                minutes: duration.minutes().as_inner(),
+                seconds: duration.seconds().as_inner(),
+                milliseconds: duration.milliseconds().as_inner(),
+                microseconds: duration.microseconds().as_inner(),
+                nanoseconds: duration.nanoseconds().as_inner(),
+            };
+            formatter.format_to_string(&record).map(Into::into)
+        }
+
+        // Without ECMA-402 support, return TemporalDurationToString(duration, "auto").
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (duration, args);
+            Self::to_string(this, &[], context)
+        }
+    }
 }
 
 // -- Duration Abstract Operations --
@@ -0,0 +1,152 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn uint8array_from_base64() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64('SGVsbG8sIFdvcmxkIQ==').join()",
+            js_str!("72,101,108,108,111,44,32,87,111,114,108,100,33"),
+        ),
+        TestAction::assert_eq("Uint8Array.fromBase64(' +/ 8 ').join()", js_str!("251,255")),
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64('-_8', { alphabet: 'base64url' }).join()",
+            js_str!("251,255"),
+        ),
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64('AAEC/w', { lastChunkHandling: 'stop-before-partial' }).join()",
+            js_str!("0,1,2"),
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zh', { lastChunkHandling: 'strict' })",
+            JsNativeErrorKind::Syntax,
+            "base64 string ends with an incomplete chunk",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zh==', { lastChunkHandling: 'strict' })",
+            JsNativeErrorKind::Syntax,
+            "base64 string has non-zero padding bits",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('+/8=', { alphabet: 'base64url' })",
+            JsNativeErrorKind::Syntax,
+            "invalid character in base64url string",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('AAAA', { alphabet: 'base32' })",
+            JsNativeErrorKind::Type,
+            "alphabet must be either \"base64\" or \"base64url\"",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64(1)",
+            JsNativeErrorKind::Type,
+            "Uint8Array.fromBase64: input must be a string",
+        ),
+    ]);
+}
+
+#[test]
+fn uint8array_to_base64() {
+    run_test_actions([
+        TestAction::run("var array = new Uint8Array([72, 101, 108, 108, 111, 251, 255]);"),
+        TestAction::assert_eq("array.toBase64()", js_str!("SGVsbG/7/w==")),
+        TestAction::assert_eq(
+            "array.toBase64({ alphabet: 'base64url', omitPadding: true })",
+            js_str!("SGVsbG_7_w"),
+        ),
+        TestAction::assert_eq("array.subarray(1, 3).toBase64()", js_str!("ZWw=")),
+        TestAction::assert_eq("new Uint8Array().toBase64()", js_str!("")),
+        TestAction::assert_native_error(
+            "Uint8Array.prototype.toBase64.call(new Int8Array(1))",
+            JsNativeErrorKind::Type,
+            "`this` is not a Uint8Array object",
+        ),
+    ]);
+}
+
+#[test]
+fn uint8array_hex() {
+    run_test_actions([
+        TestAction::assert_eq("Uint8Array.fromHex('00fFa0').join()", js_str!("0,255,160")),
+        TestAction::assert_eq("new Uint8Array([0, 15, 255]).toHex()", js_str!("000fff")),
+        TestAction::assert_native_error(
+            "Uint8Array.fromHex('abc')",
+            JsNativeErrorKind::Syntax,
+            "hex string must have an even length",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromHex('0g')",
+            JsNativeErrorKind::Syntax,
+            "invalid character in hex string",
+        ),
+    ]);
+}
+
+#[test]
+fn uint8array_set_from() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var target = new Uint8Array(4);
+            var base64 = target.setFromBase64("AQIDBAUG");
+            var hex = new Uint8Array(8).subarray(2).setFromHex("aabbcc");
+
+            var partial = new Uint8Array(4);
+            var error;
+            try {
+                partial.setFromHex("0102zz");
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert_eq("target.join()", js_str!("1,2,3,0")),
+        TestAction::assert_eq("base64.read", 4),
+        TestAction::assert_eq("base64.written", 3),
+        TestAction::assert_eq("hex.read", 6),
+        TestAction::assert_eq("hex.written", 3),
+        TestAction::assert("error instanceof SyntaxError"),
+        TestAction::assert_eq("partial.join()", js_str!("1,2,0,0")),
+    ]);
+}
+
+#[test]
+fn float16array() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var array = new Float16Array([1.5, 5.05, 65520, -0]);
+            var bytes = new Uint8Array(array.buffer);
+        "#}),
+        TestAction::assert_eq("Float16Array.BYTES_PER_ELEMENT", 2),
+        TestAction::assert_eq("array.byteLength", 8),
+        TestAction::assert_eq("array[0]", 1.5),
+        TestAction::assert_eq("array[1]", 5.050_781_25),
+        TestAction::assert_eq("array[2]", f64::INFINITY),
+        TestAction::assert("Object.is(array[3], -0)"),
+        TestAction::assert_eq("bytes[0] | bytes[1] << 8", 0x3E00),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(array)",
+            js_str!("[object Float16Array]"),
+        ),
+        TestAction::assert_native_error(
+            "Atomics.load(array, 0)",
+            JsNativeErrorKind::Type,
+            "platform doesn't support atomic operations on the provided `TypedArray`",
+        ),
+    ]);
+}
+
+#[test]
+fn dataview_float16() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var view = new DataView(new ArrayBuffer(4));
+            view.setFloat16(0, 1.5);
+            view.setFloat16(2, -2, true);
+        "#}),
+        TestAction::assert_eq("view.getUint16(0)", 0x3E00),
+        TestAction::assert_eq("view.getUint16(2, true)", 0xC000),
+        TestAction::assert_eq("view.getFloat16(0)", 1.5),
+        TestAction::assert_eq("view.getFloat16(2, true)", -2.0),
+        TestAction::assert_eq("view.getFloat16(2)", 0.000_011_444_091_796_875),
+    ]);
+}
@@ -0,0 +1,930 @@
+//! Boa's implementation of the base64 and hex methods of `Uint8Array`.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/
+
+use std::sync::atomic::Ordering;
+
+use crate::{
+    builtins::{
+        array_buffer::utils::{memcpy, SliceRef, SliceRefMut},
+        options::{get_option, get_options_object, OptionType},
+    },
+    js_string,
+    object::JsObject,
+    string::JsStr,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{BuiltinTypedArray, TypedArray, TypedArrayKind, Uint8Array};
+
+/// The alphabet used to encode and decode base64 strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard base64 alphabet, using `+` and `/`.
+    #[default]
+    Base64,
+    /// The URL and filename safe base64 alphabet, using `-` and `_`.
+    Base64Url,
+}
+
+impl OptionType for Base64Alphabet {
+    fn from_value(value: JsValue, _: &mut Context) -> JsResult<Self> {
+        match value.as_string() {
+            Some(alphabet) if alphabet == &js_string!("base64") => Ok(Self::Base64),
+            Some(alphabet) if alphabet == &js_string!("base64url") => Ok(Self::Base64Url),
+            _ => Err(JsNativeError::typ()
+                .with_message("alphabet must be either \"base64\" or \"base64url\"")
+                .into()),
+        }
+    }
+}
+
+/// How the last chunk of a base64 string is handled when decoding it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LastChunkHandling {
+    /// Decodes a partial last chunk, and doesn't check its padding bits.
+    #[default]
+    Loose,
+    /// Requires the last chunk to be complete and padded, with padding bits set to zero.
+    Strict,
+    /// Stops decoding before a partial last chunk.
+    StopBeforePartial,
+}
+
+impl OptionType for LastChunkHandling {
+    fn from_value(value: JsValue, _: &mut Context) -> JsResult<Self> {
+        match value.as_string() {
+            Some(handling) if handling == &js_string!("loose") => Ok(Self::Loose),
+            Some(handling) if handling == &js_string!("strict") => Ok(Self::Strict),
+            Some(handling) if handling == &js_string!("stop-before-partial") => {
+                Ok(Self::StopBeforePartial)
+            }
+            _ => Err(JsNativeError::typ()
+                .with_message(
+                    "lastChunkHandling must be either \"loose\", \"strict\" or \"stop-before-partial\"",
+                )
+                .into()),
+        }
+    }
+}
+
+/// The result of decoding a base64 or hex string.
+#[derive(Debug)]
+pub(crate) struct DecodeResult {
+    /// The number of code units of the string that were read.
+    pub(crate) read: usize,
+    /// The decoded bytes.
+    pub(crate) bytes: Vec<u8>,
+    /// The error found while decoding, if any.
+    pub(crate) error: Option<JsError>,
+}
+
+impl DecodeResult {
+    fn error(read: usize, bytes: Vec<u8>, message: &'static str) -> Self {
+        Self {
+            read,
+            bytes,
+            error: Some(JsNativeError::syntax().with_message(message).into()),
+        }
+    }
+
+    /// Returns the decoded bytes, or the error found while decoding.
+    pub(crate) fn into_bytes(self) -> JsResult<Vec<u8>> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.bytes),
+        }
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Returns the index of the first code unit of `string` at or after `index` that is not ASCII
+/// whitespace.
+fn skip_ascii_whitespace(string: JsStr<'_>, mut index: usize) -> usize {
+    while let Some(0x09 | 0x0A | 0x0C | 0x0D | 0x20) = string.get(index) {
+        index += 1;
+    }
+    index
+}
+
+/// Gets the value of a standard base64 character.
+fn base64_value(char: u16) -> Option<u8> {
+    let value = match u8::try_from(char).ok()? {
+        c @ b'A'..=b'Z' => c - b'A',
+        c @ b'a'..=b'z' => c - b'a' + 26,
+        c @ b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Abstract operation [`DecodeBase64Chunk ( chunk [ , throwOnExtraBits ] )`][spec].
+///
+/// Returns `None` if `throw_on_extra_bits` is `true` and the padding bits of a partial chunk
+/// are not zero.
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-decodebase64chunk
+fn decode_base64_chunk(chunk: &[u8], throw_on_extra_bits: bool, bytes: &mut Vec<u8>) -> Option<()> {
+    // 1. Let chunkLength be the length of chunk.
+    // 2. If chunkLength is 2, then
+    //     a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength is 3, then
+    //     a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //     a. Assert: chunkLength is 4.
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from decoding chunk as
+    //    base64 (such that applying the base64 encoding specified in section 4 of RFC 4648 to
+    //    byteSequence would result in chunk).
+    let sextets = chunk
+        .iter()
+        .fold(0u32, |acc, &value| (acc << 6) | u32::from(value))
+        << (6 * (4 - chunk.len()));
+    let [_, first, second, third] = sextets.to_be_bytes();
+
+    match chunk.len() {
+        // 6. If chunkLength is 2, then
+        2 => {
+            // a. Assert: throwOnExtraBits is present.
+            // b. If throwOnExtraBits is true and byteSequence[1] ≠ 0, then
+            if throw_on_extra_bits && second != 0 {
+                // i. Throw a SyntaxError exception.
+                return None;
+            }
+            // c. Return « byteSequence[0] ».
+            bytes.push(first);
+        }
+        // 7. Else if chunkLength is 3, then
+        3 => {
+            // a. Assert: throwOnExtraBits is present.
+            // b. If throwOnExtraBits is true and byteSequence[2] ≠ 0, then
+            if throw_on_extra_bits && third != 0 {
+                // i. Throw a SyntaxError exception.
+                return None;
+            }
+            // c. Return « byteSequence[0], byteSequence[1] ».
+            bytes.extend_from_slice(&[first, second]);
+        }
+        // 8. Else,
+        //     a. Return byteSequence.
+        _ => bytes.extend_from_slice(&[first, second, third]),
+    }
+
+    Some(())
+}
+
+/// Abstract operation [`FromBase64 ( string, alphabet, lastChunkHandling [ , maxLength ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-frombase64
+pub(crate) fn from_base64(
+    string: JsStr<'_>,
+    alphabet: Base64Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    max_length: usize,
+) -> DecodeResult {
+    // 1. If maxLength is not present, then
+    //     a. Let maxLength be 2**53 - 1.
+    //     b. NOTE: Because the input is a string, the length of strings is limited to
+    //        2**53 - 1 characters, and the output requires no more bytes than the input has
+    //        characters, this limit can never be reached. However, it is editorially convenient
+    //        to use a finite value here.
+    // 2. NOTE: The order of validation and decoding in the algorithm below is not observable.
+    //    Implementations are encouraged to perform them in whatever order is most efficient,
+    //    possibly interleaving validation with decoding, as long as the behaviour is observably
+    //    equivalent.
+    // 3. If maxLength = 0, then
+    if max_length == 0 {
+        // a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+        return DecodeResult {
+            read: 0,
+            bytes: Vec::new(),
+            error: None,
+        };
+    }
+
+    // 4. Let read be 0.
+    let mut read = 0;
+    // 5. Let bytes be « ».
+    let mut bytes = Vec::with_capacity((string.len() / 4 * 3).min(max_length));
+    // 6. Let chunk be the empty String.
+    // 7. Let chunkLength be 0.
+    let mut chunk = [0u8; 4];
+    let mut chunk_length = 0;
+    // 8. Let index be 0.
+    let mut index = 0;
+    // 9. Let length be the length of string.
+    let length = string.len();
+
+    // 10. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+
+        // b. If index = length, then
+        if index == length {
+            // i. If chunkLength > 0, then
+            if chunk_length > 0 {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    LastChunkHandling::StopBeforePartial => {
+                        // a. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult {
+                            read,
+                            bytes,
+                            error: None,
+                        };
+                    }
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk_length == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                            return DecodeResult::error(
+                                read,
+                                bytes,
+                                "base64 string ends with a single character",
+                            );
+                        }
+                        // b. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk, false).
+                        decode_base64_chunk(&chunk[..chunk_length], false, &mut bytes);
+                    }
+                    // 3. Else,
+                    LastChunkHandling::Strict => {
+                        // a. Assert: lastChunkHandling is "strict".
+                        // b. Let error be a new SyntaxError exception.
+                        // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                        return DecodeResult::error(
+                            read,
+                            bytes,
+                            "base64 string ends with an incomplete chunk",
+                        );
+                    }
+                }
+            }
+
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult {
+                read: length,
+                bytes,
+                error: None,
+            };
+        }
+
+        // c. Let char be the substring of string from index to index + 1.
+        let mut char = string.get(index).expect("index must be in bounds");
+
+        // d. Set index to index + 1.
+        index += 1;
+
+        // e. If char is "=", then
+        if char == u16::from(b'=') {
+            // i. If chunkLength < 2, then
+            if chunk_length < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, "unexpected padding in base64 string");
+            }
+
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+
+            // iii. If chunkLength = 2, then
+            if chunk_length == 2 {
+                // 1. If index = length, then
+                if index == length {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult {
+                            read,
+                            bytes,
+                            error: None,
+                        };
+                    }
+
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, "missing padding in base64 string");
+                }
+
+                // 2. Set char to the substring of string from index to index + 1.
+                // 3. If char is "=", then
+                if string.get(index) == Some(u16::from(b'=')) {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(
+                    read,
+                    bytes,
+                    "unexpected character after padding in base64 string",
+                );
+            }
+
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+
+            // vii. Let decodeResult be Completion(DecodeBase64Chunk(chunk, throwOnExtraBits)).
+            // viii. If decodeResult is an abrupt completion, then
+            if decode_base64_chunk(&chunk[..chunk_length], throw_on_extra_bits, &mut bytes)
+                .is_none()
+            {
+                // 1. Let error be decodeResult.[[Value]].
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, "base64 string has non-zero padding bits");
+            }
+
+            // ix. Set bytes to the list-concatenation of bytes and ! decodeResult.
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult {
+                read: length,
+                bytes,
+                error: None,
+            };
+        }
+
+        // f. If alphabet is "base64url", then
+        if alphabet == Base64Alphabet::Base64Url {
+            // i. If char is either "+" or "/", then
+            if char == u16::from(b'+') || char == u16::from(b'/') {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, "invalid character in base64url string");
+            }
+            // ii. Else if char is "-", then
+            else if char == u16::from(b'-') {
+                // 1. Set char to "+".
+                char = u16::from(b'+');
+            }
+            // iii. Else if char is "_", then
+            else if char == u16::from(b'_') {
+                // 1. Set char to "/".
+                char = u16::from(b'/');
+            }
+        }
+
+        // g. If the sole code unit of char is not an element of the standard base64 alphabet, then
+        let Some(value) = base64_value(char) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, "invalid character in base64 string");
+        };
+
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - bytes.len();
+
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and chunkLength = 3, then
+        if (remaining == 1 && chunk_length == 2) || (remaining == 2 && chunk_length == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult {
+                read,
+                bytes,
+                error: None,
+            };
+        }
+
+        // j. Set chunk to the string-concatenation of chunk and char.
+        // k. Set chunkLength to the length of chunk.
+        chunk[chunk_length] = value;
+        chunk_length += 1;
+
+        // l. If chunkLength = 4, then
+        if chunk_length == 4 {
+            // i. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk).
+            decode_base64_chunk(&chunk, false, &mut bytes);
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk_length = 0;
+            // iv. Set read to index.
+            read = index;
+            // v. If the length of bytes = maxLength, then
+            if bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                return DecodeResult {
+                    read,
+                    bytes,
+                    error: None,
+                };
+            }
+        }
+    }
+}
+
+/// Abstract operation [`FromHex ( string [ , maxLength ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-fromhex
+pub(crate) fn from_hex(string: JsStr<'_>, max_length: usize) -> DecodeResult {
+    fn hex_value(char: u16) -> Option<u8> {
+        char::from_u32(u32::from(char))?
+            .to_digit(16)
+            .and_then(|digit| u8::try_from(digit).ok())
+    }
+
+    // 1. If maxLength is not present, let maxLength be 2**53 - 1.
+    // 2. Let length be the length of string.
+    let length = string.len();
+    // 3. Let bytes be « ».
+    let mut bytes = Vec::with_capacity((length / 2).min(max_length));
+    // 4. Let read be 0.
+    let mut read = 0;
+
+    // 5. If length modulo 2 is not 0, then
+    if length % 2 != 0 {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        return DecodeResult::error(read, bytes, "hex string must have an even length");
+    }
+
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    while read < length && bytes.len() < max_length {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits does not consist exclusively of hexadecimal digits, then
+        let (Some(high), Some(low)) = (
+            string.get(read).and_then(hex_value),
+            string.get(read + 1).and_then(hex_value),
+        ) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, "invalid character in hex string");
+        };
+
+        // c. Set read to read + 2.
+        read += 2;
+
+        // d. Let byte be the integer value represented by hexits in base-16 notation, using the
+        //    letters A-F and a-f for digits with values 10 through 15.
+        // e. Append byte to bytes.
+        bytes.push((high << 4) | low);
+    }
+
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+    DecodeResult {
+        read,
+        bytes,
+        error: None,
+    }
+}
+
+/// Encodes `bytes` as a base64 string, using the provided `alphabet`.
+pub(crate) fn to_base64(bytes: &[u8], alphabet: Base64Alphabet, omit_padding: bool) -> JsString {
+    let table = match alphabet {
+        Base64Alphabet::Base64 => BASE64_ALPHABET,
+        Base64Alphabet::Base64Url => BASE64URL_ALPHABET,
+    };
+
+    let mut output = Vec::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let mut triplet = [0u8; 3];
+        triplet[..chunk.len()].copy_from_slice(chunk);
+        let bits = u32::from_be_bytes([0, triplet[0], triplet[1], triplet[2]]);
+
+        // A chunk of `n` bytes is encoded by `n + 1` characters.
+        for i in 0..=chunk.len() {
+            output.push(table[(bits >> (18 - 6 * i)) as usize & 0x3F]);
+        }
+        if !omit_padding {
+            output.extend_from_slice(&b"=="[..3 - chunk.len()]);
+        }
+    }
+
+    JsString::from(JsStr::latin1(&output))
+}
+
+/// Encodes `bytes` as a lowercase hex string.
+pub(crate) fn to_hex(bytes: &[u8]) -> JsString {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut output = Vec::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        output.push(DIGITS[usize::from(byte >> 4)]);
+        output.push(DIGITS[usize::from(byte & 0xF)]);
+    }
+
+    JsString::from(JsStr::latin1(&output))
+}
+
+impl Uint8Array {
+    /// Abstract operation [`ValidateUint8Array ( ta )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-validateuint8array
+    pub(crate) fn validate(ta: &JsValue) -> JsResult<JsObject<TypedArray>> {
+        // 1. Perform ? RequireInternalSlot(ta, [[TypedArrayName]]).
+        // 2. If ta.[[TypedArrayName]] is not "Uint8Array", throw a TypeError exception.
+        ta.as_object()
+            .and_then(|o| o.clone().downcast::<TypedArray>().ok())
+            .filter(|o| o.borrow().data.kind() == TypedArrayKind::Uint8)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not a Uint8Array object")
+                    .into()
+            })
+    }
+
+    /// Creates a new `Uint8Array` containing `bytes`.
+    pub(crate) fn from_bytes(
+        bytes: &[u8],
+        context: &mut Context,
+    ) -> JsResult<JsObject<TypedArray>> {
+        // 8. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        let new_target = context
+            .intrinsics()
+            .constructors()
+            .typed_uint8_array()
+            .constructor()
+            .into();
+        let ta = BuiltinTypedArray::allocate::<Self>(&new_target, bytes.len() as u64, context)?
+            .downcast::<TypedArray>()
+            .expect("must be a typed array");
+
+        // 9. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the
+        //    value at the corresponding index of result.[[Bytes]].
+        Self::set_bytes(&ta, bytes);
+
+        // 10. Return ta.
+        Ok(ta)
+    }
+
+    /// Abstract operation [`GetUint8ArrayBytes ( ta )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-getuint8arraybytes
+    pub(crate) fn bytes(ta: &JsObject<TypedArray>) -> JsResult<Vec<u8>> {
+        let ta = ta.borrow();
+
+        // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+        let buffer = ta.data.viewed_array_buffer().as_buffer();
+
+        // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+        // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        let Some(buffer) = buffer
+            .bytes(Ordering::SeqCst)
+            .filter(|buf| !ta.data.is_out_of_bounds(buf.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("typed array is outside the bounds of its inner buffer")
+                .into());
+        };
+
+        // 4. Let len be TypedArrayLength(taRecord).
+        let len = ta.data.array_length(buffer.len()) as usize;
+
+        // 5. Let byteOffset be ta.[[ByteOffset]].
+        let byte_offset = ta.data.byte_offset() as usize;
+
+        // 6. Let bytes be a new empty List.
+        // 7. Let index be 0.
+        // 8. Repeat, while index < len,
+        //     a. Let byteIndex be byteOffset + index.
+        //     b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true, unordered)).
+        //     c. Append byte to bytes.
+        //     d. Set index to index + 1.
+        let mut bytes = vec![0; len];
+        let src = buffer.subslice(byte_offset..byte_offset + len);
+
+        // SAFETY: `src` and `bytes` both have a length of `len`, and `bytes` was just allocated,
+        // so they cannot overlap.
+        unsafe {
+            memcpy(src.as_ptr(), SliceRefMut::Slice(&mut bytes).as_ptr(), len);
+        }
+
+        // 9. Return bytes.
+        Ok(bytes)
+    }
+
+    /// Abstract operation [`SetUint8ArrayBytes ( into, bytes )`][spec].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `into` is out of bounds or is not long enough to hold all of `bytes`.
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-setuint8arraybytes
+    pub(crate) fn set_bytes(into: &JsObject<TypedArray>, bytes: &[u8]) {
+        let into = into.borrow();
+
+        // 1. Let offset be into.[[ByteOffset]].
+        let offset = into.data.byte_offset() as usize;
+
+        // 2. Let len be the length of bytes.
+        // 3. Let index be 0.
+        // 4. Repeat, while index < len,
+        //     a. Let byte be bytes[index].
+        //     b. Let byteIndexInBuffer be index + offset.
+        //     c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]], byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+        //     d. Set index to index + 1.
+        let mut buffer = into.data.viewed_array_buffer().as_buffer_mut();
+        let mut buffer = buffer
+            .bytes(Ordering::SeqCst)
+            .expect("the buffer of the typed array cannot be detached");
+        let mut dest = buffer.subslice_mut(offset..offset + bytes.len());
+
+        // SAFETY: `dest` has a length equal to the length of `bytes`, and `bytes` cannot overlap
+        // with the buffer of a typed array.
+        unsafe {
+            memcpy(SliceRef::Slice(bytes).as_ptr(), dest.as_ptr(), bytes.len());
+        }
+    }
+
+    /// Sets the contents of `into` from the result of decoding a string.
+    ///
+    /// `decode` receives the length of `into`, which is the maximum number of bytes that can be
+    /// decoded.
+    pub(crate) fn set_from<F>(into: &JsObject<TypedArray>, decode: F) -> JsResult<(usize, usize)>
+    where
+        F: FnOnce(usize) -> DecodeResult,
+    {
+        let byte_length = {
+            let ta = into.borrow();
+
+            // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+            // 2. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            let buffer = ta.data.viewed_array_buffer().as_buffer();
+            let Some(buffer) = buffer
+                .bytes(Ordering::SeqCst)
+                .filter(|buf| !ta.data.is_out_of_bounds(buf.len()))
+            else {
+                return Err(JsNativeError::typ()
+                    .with_message("typed array is outside the bounds of its inner buffer")
+                    .into());
+            };
+
+            // 3. Let byteLength be TypedArrayLength(taRecord).
+            ta.data.array_length(buffer.len()) as usize
+        };
+
+        // 4. Let result be FromBase64(string, alphabet, lastChunkHandling, byteLength).
+        let result = decode(byte_length);
+
+        // 5. Let bytes be result.[[Bytes]].
+        // 6. Let written be the length of bytes.
+        let written = result.bytes.len();
+
+        // 7. NOTE: FromBase64 does not invoke any user code, so the ArrayBuffer backing into
+        //    cannot have been detached or shrunk.
+        // 8. Assert: written ≤ byteLength.
+        debug_assert!(written <= byte_length);
+
+        // 9. Perform SetUint8ArrayBytes(into, bytes).
+        Self::set_bytes(into, &result.bytes);
+
+        // 10. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(error);
+        }
+
+        Ok((result.read, written))
+    }
+
+    /// Creates the object returned by the `setFromBase64` and `setFromHex` methods.
+    fn read_written_object(read: usize, written: usize, context: &mut Context) -> JsValue {
+        // 13. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        let result = JsObject::with_object_proto(context.intrinsics());
+
+        // 14. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        result
+            .create_data_property_or_throw(js_string!("read"), read, context)
+            .expect("cannot fail for a newly created object");
+
+        // 15. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        result
+            .create_data_property_or_throw(js_string!("written"), written, context)
+            .expect("cannot fail for a newly created object");
+
+        // 16. Return resultObject.
+        result.into()
+    }
+
+    /// `Uint8Array.fromBase64 ( string [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.frombase64
+    pub(crate) fn from_base64(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = args
+            .get_or_undefined(0)
+            .as_string()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("Uint8Array.fromBase64: input must be a string")
+            })?;
+
+        // 2. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 3. Let alphabet be ? Get(opts, "alphabet").
+        // 4. If alphabet is undefined, set alphabet to "base64".
+        // 5. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_option(&options, js_string!("alphabet"), context)?.unwrap_or_default();
+
+        // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 7. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 8. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling =
+            get_option(&options, js_string!("lastChunkHandling"), context)?.unwrap_or_default();
+
+        // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+        // 10. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        // 11. Let resultLength be the length of result.[[Bytes]].
+        let bytes =
+            from_base64(string.as_str(), alphabet, last_chunk_handling, usize::MAX).into_bytes()?;
+
+        // 12. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 13. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 14. Return ta.
+        Self::from_bytes(&bytes, context).map(|ta| ta.upcast().into())
+    }
+
+    /// `Uint8Array.fromHex ( string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.fromhex
+    pub(crate) fn from_hex(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = args.get_or_undefined(0).as_string().ok_or_else(|| {
+            JsNativeError::typ().with_message("Uint8Array.fromHex: input must be a string")
+        })?;
+
+        // 2. Let result be FromHex(string).
+        // 3. If result.[[Error]] is not none, then
+        //     a. Throw result.[[Error]].
+        // 4. Let resultLength be the length of result.[[Bytes]].
+        let bytes = from_hex(string.as_str(), usize::MAX).into_bytes()?;
+
+        // 5. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 6. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 7. Return ta.
+        Self::from_bytes(&bytes, context).map(|ta| ta.upcast().into())
+    }
+
+    /// `Uint8Array.prototype.setFromBase64 ( string [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfrombase64
+    pub(crate) fn set_from_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = Self::validate(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = args
+            .get_or_undefined(0)
+            .as_string()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("Uint8Array.prototype.setFromBase64: input must be a string")
+            })?;
+
+        // 4. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Let alphabet be ? Get(opts, "alphabet").
+        // 6. If alphabet is undefined, set alphabet to "base64".
+        // 7. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_option(&options, js_string!("alphabet"), context)?.unwrap_or_default();
+
+        // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 9. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 10. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling =
+            get_option(&options, js_string!("lastChunkHandling"), context)?.unwrap_or_default();
+
+        // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // ...
+        let (read, written) = Self::set_from(&into, |max_length| {
+            from_base64(string.as_str(), alphabet, last_chunk_handling, max_length)
+        })?;
+
+        Ok(Self::read_written_object(read, written, context))
+    }
+
+    /// `Uint8Array.prototype.setFromHex ( string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfromhex
+    pub(crate) fn set_from_hex(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = Self::validate(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = args.get_or_undefined(0).as_string().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("Uint8Array.prototype.setFromHex: input must be a string")
+        })?;
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // ...
+        let (read, written) =
+            Self::set_from(&into, |max_length| from_hex(string.as_str(), max_length))?;
+
+        Ok(Self::read_written_object(read, written, context))
+    }
+
+    /// `Uint8Array.prototype.toBase64 ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tobase64
+    pub(crate) fn to_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = Self::validate(this)?;
+
+        // 3. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. Let alphabet be ? Get(opts, "alphabet").
+        // 5. If alphabet is undefined, set alphabet to "base64".
+        // 6. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_option(&options, js_string!("alphabet"), context)?.unwrap_or_default();
+
+        // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+        let omit_padding =
+            get_option::<bool>(&options, js_string!("omitPadding"), context)?.unwrap_or_default();
+
+        // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+        let to_encode = Self::bytes(&o)?;
+
+        // 9. If alphabet is "base64", then
+        //     a. Let outAscii be the sequence of code points which results from encoding toEncode
+        //        according to the base64 encoding specified in section 4 of RFC 4648. Padding is
+        //        included if and only if omitPadding is false.
+        // 10. Else,
+        //     a. Assert: alphabet is "base64url".
+        //     b. Let outAscii be the sequence of code points which results from encoding toEncode
+        //        according to the base64url encoding specified in section 5 of RFC 4648. Padding
+        //        is included if and only if omitPadding is false.
+        // 11. Return CodePointsToString(outAscii).
+        Ok(to_base64(&to_encode, alphabet, omit_padding).into())
+    }
+
+    /// `Uint8Array.prototype.toHex ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tohex
+    pub(crate) fn to_hex(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = Self::validate(this)?;
+
+        // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+        let to_encode = Self::bytes(&o)?;
+
+        // 4. Let out be the empty String.
+        // 5. For each byte byte of toEncode, do
+        //     a. Let hex be Number::toString(𝔽(byte), 16).
+        //     b. Set hex to StringPad(hex, 2, "0", start).
+        //     c. Set out to the string-concatenation of out and hex.
+        // 6. Return out.
+        Ok(to_hex(&to_encode).into())
+    }
+}
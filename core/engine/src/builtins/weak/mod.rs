@@ -1,5 +1,8 @@
-//! Boa's implementation of ECMAScript's `WeakRef` object.
+//! Boa's implementation of ECMAScript's `WeakRef` object, and of the weak keys shared by all
+//! the objects that hold values weakly.
 
+mod weak_key;
 mod weak_ref;
 
+pub(crate) use weak_key::{can_be_held_weakly, WeakKeyEphemeron, WeakKeyMap, WeakKeyRef};
 pub(crate) use weak_ref::WeakRef;
@@ -0,0 +1,232 @@
+//! This module implements the weak keys used to hold values weakly.
+//!
+//! Objects are garbage collected, so they can be used directly as the keys of the ephemerons
+//! backing `WeakMap`, `WeakSet`, `WeakRef` and `FinalizationRegistry`. Symbols, on the other
+//! hand, are reference counted and shared between threads, so they are held through weak
+//! references to the symbol instead, which the garbage collector checks on every collection to
+//! clear the entries of the symbols that were dropped.
+//!
+//! Note that a symbol referenced by a value that is only reachable through the symbol itself
+//! (e.g. a `WeakMap` entry whose value references its own key) is never released, since the
+//! reference count of the symbol cannot observe that cycle.
+
+use boa_gc::{Ephemeron, ExternalEphemeron, ExternalWeakMap, Finalize, Trace, WeakGc};
+
+use crate::{
+    builtins::symbol::is_registered_symbol,
+    object::{ErasedVTableObject, JsObject},
+    symbol::{JsSymbol, WeakJsSymbol},
+    JsData, JsValue,
+};
+
+/// A value that can be held weakly.
+#[derive(Debug, Clone)]
+pub(crate) enum WeakKey {
+    Object(JsObject),
+    Symbol(JsSymbol),
+}
+
+/// Abstract operation [`CanBeHeldWeakly ( v )`][spec].
+///
+/// Returns the key used to hold `value` weakly, or `None` if `value` cannot be held weakly.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canbeheldweakly
+pub(crate) fn can_be_held_weakly(value: &JsValue) -> Option<WeakKey> {
+    match value {
+        // 1. If v is an Object, return true.
+        JsValue::Object(object) => Some(WeakKey::Object(object.clone())),
+        // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+        JsValue::Symbol(symbol) if !is_registered_symbol(symbol) => {
+            Some(WeakKey::Symbol(symbol.clone()))
+        }
+        // 3. Return false.
+        _ => None,
+    }
+}
+
+/// A weak reference to a [`WeakKey`].
+#[derive(Trace, Finalize, JsData)]
+pub(crate) enum WeakKeyRef {
+    Object(WeakGc<ErasedVTableObject>),
+    Symbol(WeakJsSymbol),
+}
+
+impl WeakKeyRef {
+    /// Creates a new weak reference to `key`.
+    pub(crate) fn new(key: &WeakKey) -> Self {
+        match key {
+            WeakKey::Object(object) => Self::Object(WeakGc::new(object.inner())),
+            WeakKey::Symbol(symbol) => Self::Symbol(symbol.downgrade()),
+        }
+    }
+
+    /// Gets the value referenced by this weak reference, or `None` if it was already dropped.
+    pub(crate) fn upgrade(&self) -> Option<JsValue> {
+        match self {
+            Self::Object(object) => object.upgrade().map(|object| JsObject::from(object).into()),
+            Self::Symbol(symbol) => symbol.upgrade().map(Into::into),
+        }
+    }
+}
+
+/// An ephemeron whose key is a [`WeakKey`].
+#[derive(Trace, Finalize)]
+pub(crate) enum WeakKeyEphemeron<V: Trace + 'static> {
+    Object(Ephemeron<ErasedVTableObject, V>),
+    Symbol(ExternalEphemeron<WeakJsSymbol, V>),
+}
+
+impl<V: Trace + Clone> WeakKeyEphemeron<V> {
+    /// Creates a new ephemeron that holds `value` for as long as `key` is alive.
+    pub(crate) fn new(key: &WeakKey, value: V) -> Self {
+        match key {
+            WeakKey::Object(object) => Self::Object(Ephemeron::new(object.inner(), value)),
+            WeakKey::Symbol(symbol) => {
+                Self::Symbol(ExternalEphemeron::new(symbol.downgrade(), value))
+            }
+        }
+    }
+
+    /// Returns `true` if the key of this ephemeron wasn't collected yet.
+    pub(crate) fn has_value(&self) -> bool {
+        match self {
+            Self::Object(eph) => eph.has_value(),
+            Self::Symbol(eph) => eph.has_value(),
+        }
+    }
+}
+
+/// A map whose keys are held weakly, backing the `WeakMap` and `WeakSet` builtins.
+#[derive(Trace, Finalize)]
+pub(crate) struct WeakKeyMap<V: Trace + Clone + 'static> {
+    objects: boa_gc::WeakMap<ErasedVTableObject, V>,
+    symbols: ExternalWeakMap<WeakJsSymbol, V>,
+}
+
+impl<V: Trace + Clone + 'static> JsData for WeakKeyMap<V> {}
+
+impl<V: Trace + Clone + 'static> WeakKeyMap<V> {
+    /// Creates a new, empty `WeakKeyMap`.
+    pub(crate) fn new() -> Self {
+        Self {
+            objects: boa_gc::WeakMap::new(),
+            symbols: ExternalWeakMap::new(),
+        }
+    }
+
+    /// Inserts a key-value pair into the map, replacing the previous value of the key.
+    pub(crate) fn insert(&mut self, key: &WeakKey, value: V) {
+        match key {
+            WeakKey::Object(object) => self.objects.insert(object.inner(), value),
+            WeakKey::Symbol(symbol) => self.symbols.insert(symbol.downgrade(), value),
+        }
+    }
+
+    /// Removes a key from the map, returning its value if it was in the map.
+    pub(crate) fn remove(&mut self, key: &WeakKey) -> Option<V> {
+        match key {
+            WeakKey::Object(object) => self.objects.remove(object.inner()),
+            WeakKey::Symbol(symbol) => self.symbols.remove(&symbol.downgrade()),
+        }
+    }
+
+    /// Returns `true` if the map contains a value for `key`.
+    pub(crate) fn contains_key(&self, key: &WeakKey) -> bool {
+        match key {
+            WeakKey::Object(object) => self.objects.contains_key(object.inner()),
+            WeakKey::Symbol(symbol) => self.symbols.contains_key(&symbol.downgrade()),
+        }
+    }
+
+    /// Returns the value corresponding to `key`.
+    pub(crate) fn get(&self, key: &WeakKey) -> Option<V> {
+        match key {
+            WeakKey::Object(object) => self.objects.get(object.inner()),
+            WeakKey::Symbol(symbol) => self.symbols.get(&symbol.downgrade()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{js_str, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+    #[test]
+    fn symbols_as_weak_keys() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var set = new WeakSet();
+                var key = Symbol("key");
+                map.set(key, 1).set(Symbol.iterator, 2);
+                set.add(key);
+            "#}),
+            TestAction::assert_eq("map.get(key)", 1),
+            TestAction::assert_eq("map.get(Symbol.iterator)", 2),
+            TestAction::assert("!map.has(Symbol('key'))"),
+            TestAction::assert("set.has(key)"),
+            TestAction::assert("!set.has(Symbol.for('key'))"),
+            TestAction::assert("map.delete(key) && !map.has(key)"),
+            TestAction::assert("set.delete(key) && !set.has(key)"),
+            TestAction::assert_native_error(
+                "map.set(Symbol.for('key'), 1)",
+                JsNativeErrorKind::Type,
+                "WeakMap.set: expected target argument of type `object` or `symbol`, got target of type `symbol`",
+            ),
+            TestAction::assert_native_error(
+                "set.add(Symbol.for('key'))",
+                JsNativeErrorKind::Type,
+                "WeakSet.add: expected target argument of type `object` or `symbol`, got target of type `symbol`",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn symbol_weak_ref_collected() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var wellKnown = new WeakRef(Symbol.iterator);
+                var ptr, description;
+                {
+                    let sym = Symbol("sym");
+                    ptr = new WeakRef(sym);
+                }
+                description = ptr.deref().description;
+            "#}),
+            TestAction::assert_eq("description", js_str!("sym")),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
+            TestAction::assert("wellKnown.deref() === Symbol.iterator"),
+        ]);
+    }
+
+    #[test]
+    fn symbol_key_entries_cleared() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var held = Symbol("held");
+                var ptr;
+                {
+                    let sym = Symbol("sym");
+                    let value = {};
+                    map.set(sym, value).set(held, 1);
+                    ptr = new WeakRef(value);
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                // The first collection drops the symbol, and the second one clears its entry.
+                boa_gc::force_collect();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
+            TestAction::assert_eq("map.get(held)", 1),
+        ]);
+    }
+}
@@ -1,11 +1,12 @@
-use boa_gc::{Finalize, Trace, WeakGc};
+use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
+use super::{can_be_held_weakly, WeakKeyRef};
 use crate::{
     builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsObject},
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
     realm::Realm,
     string::StaticJsStrings,
@@ -74,11 +75,12 @@ impl BuiltInConstructor for WeakRef {
                 .into());
         }
 
-        // 2. If target is not an Object, throw a TypeError exception.
-        let target = args.first().and_then(JsValue::as_object).ok_or_else(|| {
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let key = can_be_held_weakly(target).ok_or_else(|| {
             JsNativeError::typ().with_message(format!(
-                "WeakRef: expected target argument of type `object`, got target of type `{}`",
-                args.get_or_undefined(0).type_of()
+                "WeakRef: expected target argument of type `object` or `symbol`, got target of type `{}`",
+                target.type_of()
             ))
         })?;
 
@@ -89,7 +91,7 @@ impl BuiltInConstructor for WeakRef {
         let weak_ref = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            WeakGc::new(target.inner()),
+            WeakKeyRef::new(&key),
         );
 
         // 4. Perform AddToKeptObjects(target).
@@ -112,7 +114,7 @@ impl WeakRef {
         // 2. Perform ? RequireInternalSlot(weakRef, [[WeakRefTarget]]).
         let weak_ref = this
             .as_object()
-            .and_then(JsObject::downcast_ref::<WeakGc<ErasedVTableObject>>)
+            .and_then(JsObject::downcast_ref::<WeakKeyRef>)
             .ok_or_else(|| {
                 JsNativeError::typ().with_message(
                     "WeakRef.prototype.deref: expected `this` to be a `WeakRef` object",
@@ -125,14 +127,12 @@ impl WeakRef {
         // https://tc39.es/ecma262/multipage/managing-memory.html#sec-weakrefderef
         // 1. Let target be weakRef.[[WeakRefTarget]].
         // 2. If target is not empty, then
-        if let Some(object) = weak_ref.upgrade() {
-            let object = JsObject::from(object);
-
+        if let Some(target) = weak_ref.upgrade() {
             // a. Perform AddToKeptObjects(target).
-            context.kept_alive.push(object.clone());
+            context.kept_alive.push(target.clone());
 
             // b. Return target.
-            Ok(object.into())
+            Ok(target)
         } else {
             // 3. Return undefined.
             Ok(JsValue::undefined())
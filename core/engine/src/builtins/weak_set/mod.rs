@@ -8,10 +8,13 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        weak::{can_be_held_weakly, WeakKeyMap},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsObject},
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
     realm::Realm,
     string::StaticJsStrings,
@@ -23,7 +26,7 @@ use boa_profiler::Profiler;
 
 use super::iterable::IteratorHint;
 
-type NativeWeakSet = boa_gc::WeakMap<ErasedVTableObject, ()>;
+type NativeWeakSet = WeakKeyMap<()>;
 
 #[derive(Debug, Trace, Finalize)]
 pub(crate) struct WeakSet;
@@ -150,26 +153,26 @@ impl WeakSet {
                 JsNativeError::typ().with_message("WeakSet.add: called with non-object value")
             })?;
 
-        // 3. If Type(value) is not Object, throw a TypeError exception.
+        // 3. If CanBeHeldWeakly(value) is false, throw a TypeError exception.
         let value = args.get_or_undefined(0);
-        let Some(value) = value.as_object() else {
+        let Some(value) = can_be_held_weakly(value) else {
             return Err(JsNativeError::typ()
                 .with_message(format!(
-                    "WeakSet.add: expected target argument of type `object`, got target of type `{}`",
+                    "WeakSet.add: expected target argument of type `object` or `symbol`, got target of type `{}`",
                     value.type_of()
                 )).into());
         };
 
         // 4. Let entries be the List that is S.[[WeakSetData]].
         // 5. For each element e of entries, do
-        if set.contains_key(value.inner()) {
+        if set.contains_key(&value) {
             // a. If e is not empty and SameValue(e, value) is true, then
             // i. Return S.
             return Ok(this.clone());
         }
 
         // 6. Append value as the last element of entries.
-        set.insert(value.inner(), ());
+        set.insert(&value, ());
 
         // 7. Return S.
         Ok(this.clone())
@@ -199,9 +202,8 @@ impl WeakSet {
                 JsNativeError::typ().with_message("WeakSet.delete: called with non-object value")
             })?;
 
-        // 3. If Type(value) is not Object, return false.
-        let value = args.get_or_undefined(0);
-        let Some(value) = value.as_object() else {
+        // 3. If CanBeHeldWeakly(value) is false, return false.
+        let Some(value) = can_be_held_weakly(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
@@ -211,7 +213,7 @@ impl WeakSet {
         // i. Replace the element of entries whose value is e with an element whose value is empty.
         // ii. Return true.
         // 6. Return false.
-        Ok(set.remove(value.inner()).is_some().into())
+        Ok(set.remove(&value).is_some().into())
     }
 
     /// `WeakSet.prototype.has( value )`
@@ -239,15 +241,14 @@ impl WeakSet {
             })?;
 
         // 3. Let entries be the List that is S.[[WeakSetData]].
-        // 4. If Type(value) is not Object, return false.
-        let value = args.get_or_undefined(0);
-        let Some(value) = value.as_object() else {
+        // 4. If CanBeHeldWeakly(value) is false, return false.
+        let Some(value) = can_be_held_weakly(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
         // 5. For each element e of entries, do
         // a. If e is not empty and SameValue(e, value) is true, return true.
         // 6. Return false.
-        Ok(set.contains_key(value.inner()).into())
+        Ok(set.contains_key(&value).into())
     }
 }
@@ -1,13 +1,14 @@
-use super::{ByteCompiler, Literal, Operand, ToJsString};
+use super::{ByteCompiler, Literal, Operand, Register, ToJsString};
 use crate::{
     js_string,
-    vm::{BindingOpcode, CodeBlock, CodeBlockFlags, Opcode},
+    vm::{BindingOpcode, CodeBlock, CodeBlockFlags, DecoratedElementFlags, Opcode},
+    JsString,
 };
 use boa_ast::{
     expression::Identifier,
     function::{
-        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, FormalParameterList,
-        FunctionExpression,
+        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, Decorator,
+        FormalParameterList, FunctionExpression,
     },
     property::{MethodDefinitionKind, PropertyName},
     scope::Scope,
@@ -23,6 +24,9 @@ enum StaticElement {
 
     // A static class field with it's function code, an optional name index and the information if the function is an anonymous function.
     StaticField((Gc<CodeBlock>, Option<u32>, bool)),
+
+    // A decorated static class field or a static auto-accessor with it's index in the class decoration state.
+    DecoratedField(u32),
 }
 
 /// Describes the complete specification of a class.
@@ -32,6 +36,7 @@ pub(crate) struct ClassSpec<'a> {
     super_ref: Option<&'a Expression>,
     constructor: Option<&'a FunctionExpression>,
     elements: &'a [ClassElement],
+    decorators: &'a [Decorator],
     has_binding_identifier: bool,
     name_scope: Option<&'a Scope>,
 }
@@ -43,6 +48,7 @@ impl<'a> From<&'a ClassDeclaration> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: true,
             name_scope: Some(class.name_scope()),
         }
@@ -56,6 +62,7 @@ impl<'a> From<&'a ClassExpression> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: class.name().is_some(),
             name_scope: class.name_scope(),
         }
@@ -79,6 +86,20 @@ impl ByteCompiler<'_> {
             .map_or(Sym::EMPTY_STRING, Identifier::sym)
             .to_js_string(self.interner());
 
+        // Classes with decorators or auto-accessors are compiled with the help of a decoration
+        // state, which keeps track of the decorated elements and their extra initializers.
+        let decorated = !class.decorators.is_empty()
+            || class.elements.iter().any(|element| {
+                !element.decorators().is_empty()
+                    || matches!(element, ClassElement::AccessorDefinition(_))
+            });
+        let class_decorators = decorated.then(|| {
+            self.compile_decorators(class.decorators);
+            let register = self.register_allocator.alloc();
+            self.pop_into_register(&register);
+            register
+        });
+
         let outer_scope = self.push_declarative_scope(class.name_scope);
 
         let mut compiler = ByteCompiler::new(
@@ -160,21 +181,34 @@ impl ByteCompiler<'_> {
                         self.emit_u32(index);
                     }
                 }
-                ClassElement::PrivateFieldDefinition(field) => {
+                ClassElement::PrivateFieldDefinition(field)
+                | ClassElement::PrivateStaticFieldDefinition(field) => {
                     count += 1;
                     let index = self.get_or_insert_private_name(*field.name());
                     self.emit_u32(index);
                 }
-                ClassElement::PrivateStaticFieldDefinition(name, _) => {
-                    count += 1;
-                    let index = self.get_or_insert_private_name(*name);
-                    self.emit_u32(index);
+                ClassElement::AccessorDefinition(accessor) => {
+                    if let ClassElementName::PrivateName(name) = accessor.name() {
+                        count += 1;
+                        let index = self.get_or_insert_private_name(*name);
+                        self.emit_u32(index);
+                    }
                 }
                 _ => {}
             }
         }
         self.patch_jump_with_target(count_label, count);
 
+        let state = decorated.then(|| {
+            self.emit_opcode(Opcode::Dup);
+            self.emit_push_literal(Literal::String(class_name.clone()));
+            self.emit_opcode(Opcode::CreateClassDecorationState);
+            let register = self.register_allocator.alloc();
+            self.pop_into_register(&register);
+            register
+        });
+        let mut decorated_element_count = 0;
+
         let mut static_elements = Vec::new();
         let mut static_field_name_count = 0;
 
@@ -187,6 +221,27 @@ impl ByteCompiler<'_> {
 
         for element in class.elements {
             match element {
+                ClassElement::MethodDefinition(m) if decorated => {
+                    let mut flags = match m.kind() {
+                        MethodDefinitionKind::Get => DecoratedElementFlags::GETTER,
+                        MethodDefinitionKind::Set => DecoratedElementFlags::SETTER,
+                        _ => DecoratedElementFlags::empty(),
+                    };
+                    flags.set(DecoratedElementFlags::STATIC, m.is_static());
+                    flags.set(DecoratedElementFlags::PRIVATE, m.is_private());
+
+                    self.push_decorated_class_element_key(
+                        state.as_ref().expect("decorated classes must have a state"),
+                        m.decorators(),
+                        m.name(),
+                    );
+                    self.method(m.into());
+                    self.emit(
+                        Opcode::PushDecoratedClassElement,
+                        &[Operand::U8(flags.bits())],
+                    );
+                    decorated_element_count += 1;
+                }
                 ClassElement::MethodDefinition(m) => {
                     if !m.is_static() && !m.is_private() {
                         self.emit_opcode(Opcode::Swap);
@@ -267,6 +322,27 @@ impl ByteCompiler<'_> {
                         self.emit_opcode(Opcode::Swap);
                     }
                 }
+                ClassElement::FieldDefinition(field) if !field.decorators().is_empty() => {
+                    self.push_decorated_class_element_key(
+                        state.as_ref().expect("decorated classes must have a state"),
+                        field.decorators(),
+                        &ClassElementName::PropertyName(field.name().clone()),
+                    );
+                    let (code, is_anonymous_function) =
+                        self.class_field_initializer(js_string!(), field.field(), field.scope());
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    let mut flags = DecoratedElementFlags::FIELD;
+                    flags.set(
+                        DecoratedElementFlags::ANONYMOUS_FUNCTION,
+                        is_anonymous_function,
+                    );
+                    self.emit(
+                        Opcode::PushDecoratedClassElement,
+                        &[Operand::U8(flags.bits())],
+                    );
+                    decorated_element_count += 1;
+                }
                 ClassElement::FieldDefinition(field) => {
                     self.emit_opcode(Opcode::Dup);
                     match field.name() {
@@ -313,6 +389,26 @@ impl ByteCompiler<'_> {
                         &[Operand::Bool(is_anonymous_function)],
                     );
                 }
+                ClassElement::PrivateFieldDefinition(field) if !field.decorators().is_empty() => {
+                    self.push_decorated_class_element_key(
+                        state.as_ref().expect("decorated classes must have a state"),
+                        field.decorators(),
+                        &ClassElementName::PrivateName(*field.name()),
+                    );
+                    let (code, _) = self.class_field_initializer(
+                        class_name.clone(),
+                        field.field(),
+                        field.scope(),
+                    );
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    let flags = DecoratedElementFlags::FIELD | DecoratedElementFlags::PRIVATE;
+                    self.emit(
+                        Opcode::PushDecoratedClassElement,
+                        &[Operand::U8(flags.bits())],
+                    );
+                    decorated_element_count += 1;
+                }
                 ClassElement::PrivateFieldDefinition(field) => {
                     self.emit_opcode(Opcode::Dup);
                     let name_index = self.get_or_insert_private_name(*field.name());
@@ -343,6 +439,31 @@ impl ByteCompiler<'_> {
                     self.emit_with_varying_operand(Opcode::GetFunction, index);
                     self.emit_with_varying_operand(Opcode::PushClassFieldPrivate, name_index);
                 }
+                ClassElement::StaticFieldDefinition(field) if !field.decorators().is_empty() => {
+                    self.push_decorated_class_element_key(
+                        state.as_ref().expect("decorated classes must have a state"),
+                        field.decorators(),
+                        &ClassElementName::PropertyName(field.name().clone()),
+                    );
+                    let (code, is_anonymous_function) = self.class_field_initializer(
+                        class_name.clone(),
+                        field.field(),
+                        field.scope(),
+                    );
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    let mut flags = DecoratedElementFlags::FIELD | DecoratedElementFlags::STATIC;
+                    flags.set(
+                        DecoratedElementFlags::ANONYMOUS_FUNCTION,
+                        is_anonymous_function,
+                    );
+                    self.emit(
+                        Opcode::PushDecoratedClassElement,
+                        &[Operand::U8(flags.bits())],
+                    );
+                    static_elements.push(StaticElement::DecoratedField(decorated_element_count));
+                    decorated_element_count += 1;
+                }
                 ClassElement::StaticFieldDefinition(field) => {
                     let name_index = match field.name() {
                         PropertyName::Literal(name) => {
@@ -391,16 +512,79 @@ impl ByteCompiler<'_> {
                         is_anonymous_function,
                     )));
                 }
-                ClassElement::PrivateStaticFieldDefinition(name, field) => {
+                // Static private methods are only defined once the element decorators are applied,
+                // so static private fields of decorated classes are always defined afterwards.
+                ClassElement::PrivateStaticFieldDefinition(field) if decorated => {
+                    self.push_decorated_class_element_key(
+                        state.as_ref().expect("decorated classes must have a state"),
+                        field.decorators(),
+                        &ClassElementName::PrivateName(*field.name()),
+                    );
+                    let (code, _) = self.class_field_initializer(
+                        class_name.clone(),
+                        field.field(),
+                        field.scope(),
+                    );
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    let flags = DecoratedElementFlags::FIELD
+                        | DecoratedElementFlags::STATIC
+                        | DecoratedElementFlags::PRIVATE;
+                    self.emit(
+                        Opcode::PushDecoratedClassElement,
+                        &[Operand::U8(flags.bits())],
+                    );
+                    static_elements.push(StaticElement::DecoratedField(decorated_element_count));
+                    decorated_element_count += 1;
+                }
+                ClassElement::PrivateStaticFieldDefinition(field) => {
                     self.emit_opcode(Opcode::Dup);
-                    if let Some(node) = field {
-                        self.compile_expr(node, true);
-                    } else {
-                        self.emit_opcode(Opcode::PushUndefined);
-                    }
-                    let index = self.get_or_insert_private_name(*name);
+                    self.emit_opcode(Opcode::Dup);
+                    let (code, _) = self.class_field_initializer(
+                        class_name.clone(),
+                        field.field(),
+                        field.scope(),
+                    );
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    self.emit_opcode(Opcode::SetHomeObject);
+                    self.emit_with_varying_operand(Opcode::Call, 0);
+                    let index = self.get_or_insert_private_name(*field.name());
                     self.emit_with_varying_operand(Opcode::DefinePrivateField, index);
                 }
+                ClassElement::AccessorDefinition(accessor) => {
+                    self.push_decorated_class_element_key(
+                        state.as_ref().expect("decorated classes must have a state"),
+                        accessor.decorators(),
+                        accessor.name(),
+                    );
+                    let (code, is_anonymous_function) = self.class_field_initializer(
+                        class_name.clone(),
+                        accessor.field(),
+                        accessor.scope(),
+                    );
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    let mut flags = DecoratedElementFlags::ACCESSOR;
+                    flags.set(DecoratedElementFlags::STATIC, accessor.is_static());
+                    flags.set(
+                        DecoratedElementFlags::PRIVATE,
+                        matches!(accessor.name(), ClassElementName::PrivateName(_)),
+                    );
+                    flags.set(
+                        DecoratedElementFlags::ANONYMOUS_FUNCTION,
+                        is_anonymous_function,
+                    );
+                    self.emit(
+                        Opcode::PushDecoratedClassElement,
+                        &[Operand::U8(flags.bits())],
+                    );
+                    if accessor.is_static() {
+                        static_elements
+                            .push(StaticElement::DecoratedField(decorated_element_count));
+                    }
+                    decorated_element_count += 1;
+                }
                 ClassElement::StaticBlock(block) => {
                     let mut compiler = ByteCompiler::new(
                         Sym::EMPTY_STRING.to_js_string(self.interner()),
@@ -437,6 +621,13 @@ impl ByteCompiler<'_> {
             }
         }
 
+        if let Some(state) = &state {
+            self.push_from_register(state);
+            self.emit_opcode(Opcode::ApplyElementDecorators);
+            self.push_from_register(state);
+            self.emit(Opcode::RunDecoratorInitializers, &[Operand::Bool(false)]);
+        }
+
         for element in static_elements {
             match element {
                 StaticElement::StaticBlock(code) => {
@@ -471,7 +662,36 @@ impl ByteCompiler<'_> {
                         self.emit_opcode(Opcode::DefineOwnPropertyByValue);
                     }
                 }
+                StaticElement::DecoratedField(index) => {
+                    self.push_from_register(
+                        state.as_ref().expect("decorated classes must have a state"),
+                    );
+                    self.emit_with_varying_operand(Opcode::DefineDecoratedStaticField, index);
+                }
+            }
+        }
+
+        if let (Some(state), Some(class_decorators)) = (state, class_decorators) {
+            self.push_from_register(&state);
+            self.push_from_register(&class_decorators);
+            self.emit_opcode(Opcode::ApplyClassDecorators);
+
+            if let Some(scope) = class.name_scope {
+                let binding = scope.get_identifier_reference(class_name.clone());
+                let index = self.get_or_insert_binding(binding);
+                self.emit_opcode(Opcode::Dup);
+                self.emit_binding_access(Opcode::PutLexicalValue, &index);
             }
+
+            self.push_from_register(&state);
+            self.emit(Opcode::RunDecoratorInitializers, &[Operand::Bool(true)]);
+
+            // Replace the undecorated class with the result of the class decorators.
+            self.emit_opcode(Opcode::Swap);
+            self.emit_opcode(Opcode::Pop);
+
+            self.register_allocator.dealloc(state);
+            self.register_allocator.dealloc(class_decorators);
         }
 
         self.emit_opcode(Opcode::Swap);
@@ -487,4 +707,81 @@ impl ByteCompiler<'_> {
         // NOTE: Reset strict mode to before class declaration/expression evalutation.
         self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
     }
+
+    /// Compiles a list of decorators into an array containing the evaluated decorators.
+    fn compile_decorators(&mut self, decorators: &[Decorator]) {
+        self.emit_opcode(Opcode::PushNewArray);
+        for decorator in decorators {
+            self.compile_expr(decorator.expression(), true);
+            self.emit_opcode(Opcode::PushValueToArray);
+        }
+    }
+
+    /// Pushes the decoration state, the decorators and the key of a decorated class element.
+    ///
+    /// Private names are pushed as their description, which the VM turns into the private name
+    /// of the class.
+    fn push_decorated_class_element_key(
+        &mut self,
+        state: &Register,
+        decorators: &[Decorator],
+        name: &ClassElementName,
+    ) {
+        self.push_from_register(state);
+        self.compile_decorators(decorators);
+        match name {
+            ClassElementName::PropertyName(PropertyName::Literal(name)) => {
+                self.emit_push_literal(Literal::String(
+                    self.interner().resolve_expect(*name).into_common(false),
+                ));
+            }
+            ClassElementName::PropertyName(PropertyName::Computed(name)) => {
+                self.compile_expr(name, true);
+                self.emit_opcode(Opcode::ToPropertyKey);
+            }
+            ClassElementName::PrivateName(name) => {
+                self.emit_push_literal(Literal::String(
+                    self.interner()
+                        .resolve_expect(name.description())
+                        .into_common(false),
+                ));
+            }
+        }
+    }
+
+    /// Compiles the initializer function of a class field or an auto-accessor.
+    ///
+    /// Returns the code of the function and whether the initializer is an anonymous function definition.
+    fn class_field_initializer(
+        &mut self,
+        name: JsString,
+        field: Option<&Expression>,
+        scope: &Scope,
+    ) -> (Gc<CodeBlock>, bool) {
+        let mut field_compiler = ByteCompiler::new(
+            name,
+            true,
+            self.json_parse,
+            self.variable_scope.clone(),
+            self.lexical_scope.clone(),
+            false,
+            false,
+            self.interner,
+            self.in_with,
+        );
+        field_compiler.code_block_flags |= CodeBlockFlags::HAS_FUNCTION_SCOPE;
+        let _ = field_compiler.push_scope(scope);
+        let is_anonymous_function = if let Some(node) = field {
+            field_compiler.compile_expr(node, true);
+            node.is_anonymous_function_definition()
+        } else {
+            field_compiler.emit_opcode(Opcode::PushUndefined);
+            false
+        };
+        field_compiler.emit_opcode(Opcode::SetReturnValue);
+
+        field_compiler.code_block_flags |= CodeBlockFlags::IN_CLASS_FIELD_INITIALIZER;
+
+        (Gc::new(field_compiler.finish()), is_anonymous_function)
+    }
 }
@@ -0,0 +1,141 @@
+use crate::{
+    bytecompiler::{ByteCompiler, Operand},
+    vm::Opcode,
+};
+use boa_ast::{Declaration, StatementListItem};
+
+/// Returns `Some(is_async)` if the items directly contain a `using` or an `await using`
+/// declaration, where `is_async` is `true` if any of them is an `await using` declaration.
+pub(crate) fn using_declarations<'a, I>(items: I) -> Option<bool>
+where
+    I: IntoIterator<Item = &'a StatementListItem>,
+{
+    items
+        .into_iter()
+        .filter_map(|item| match item {
+            StatementListItem::Declaration(Declaration::Lexical(decl)) if decl.is_using() => {
+                Some(decl.is_await_using())
+            }
+            _ => None,
+        })
+        .reduce(|a, b| a || b)
+}
+
+impl ByteCompiler<'_> {
+    /// Compiles a scope whose `using` and `await using` declarations are disposed when it's
+    /// exited, by a normal completion, a `break`, `continue` or `return`, or a thrown error.
+    ///
+    /// This works like a `try` statement with a `finally` block, where the `finally` block runs
+    /// the [`DisposeResources`][spec] operation on the resources added by `body`.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn compile_dispose_scope<F>(&mut self, r#async: bool, use_expr: bool, body: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.emit_opcode(Opcode::CreateDisposeCapability);
+        self.push_try_with_finally_control_info(use_expr);
+
+        let handler = self.push_handler();
+        body(self);
+
+        // stack: 0, false
+        self.emit_opcode(Opcode::PushZero);
+        self.emit_opcode(Opcode::PushFalse);
+        let finally = self.jump();
+
+        self.patch_handler(handler);
+
+        // Thrown error, which is recorded by the dispose capability and thrown after the resources
+        // are disposed.
+        //
+        // stack: undefined, true, undefined, error, true
+        self.emit_opcode(Opcode::PushUndefined);
+        self.emit_opcode(Opcode::PushTrue);
+        self.emit_opcode(Opcode::PushUndefined);
+
+        self.current_stack_value_count += 3;
+        let generator_handler = self.is_generator().then(|| self.push_handler());
+        self.emit_opcode(Opcode::Exception);
+        self.emit_opcode(Opcode::PushTrue);
+        let throw = self.jump();
+        self.current_stack_value_count -= 3;
+
+        // `return()` was called on the generator, the empty exception is rethrown after the
+        // resources are disposed.
+        //
+        // stack: undefined, true, return_value, undefined, false
+        let generator_return = generator_handler.map(|handler| {
+            self.patch_handler(handler);
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::GetReturnValue);
+            self.emit_opcode(Opcode::PushUndefined);
+            self.emit_opcode(Opcode::PushFalse);
+            self.jump()
+        });
+
+        self.patch_jump(finally);
+
+        // Normal completion, or `break`, `continue` and `return` with the index of their jump.
+        //
+        // stack: index, false, return_value, undefined, false
+        let finally_start = self.next_opcode_location();
+        self.emit_opcode(Opcode::GetReturnValue);
+        self.emit_opcode(Opcode::PushUndefined);
+        self.emit_opcode(Opcode::PushFalse);
+
+        self.patch_jump(throw);
+        if let Some(generator_return) = generator_return {
+            self.patch_jump(generator_return);
+        }
+
+        self.current_stack_value_count += 3;
+        let dispose = self.next_opcode_location();
+        self.emit_opcode(Opcode::DisposeResources);
+        if r#async {
+            let done = self.jump_if_false();
+
+            let handler = self.push_handler();
+            self.emit_opcode(Opcode::Await);
+            self.emit_opcode(Opcode::GeneratorNext);
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::PushUndefined);
+            self.emit_opcode(Opcode::PushFalse);
+            self.emit(Opcode::Jump, &[Operand::U32(dispose)]);
+            self.patch_handler(handler);
+
+            // The awaited value was rejected.
+            self.emit_opcode(Opcode::Exception);
+            self.emit_opcode(Opcode::PushTrue);
+            self.emit(Opcode::Jump, &[Operand::U32(dispose)]);
+
+            self.patch_jump(done);
+        } else {
+            self.emit_opcode(Opcode::Pop);
+        }
+        self.current_stack_value_count -= 3;
+
+        // stack: index, is_generator_return
+        self.emit_opcode(Opcode::SetReturnValue);
+        if self.is_generator() {
+            let not_generator_return = self.jump_if_false();
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::ReThrow);
+            self.patch_jump(not_generator_return);
+        } else {
+            self.emit_opcode(Opcode::Pop);
+        }
+
+        // stack: index
+        let has_jumps = !self
+            .jump_info
+            .last()
+            .expect("there should be a dispose scope")
+            .jumps
+            .is_empty();
+        self.pop_try_with_finally_control_info(finally_start);
+        if !has_jumps {
+            self.emit_opcode(Opcode::Pop);
+        }
+    }
+}
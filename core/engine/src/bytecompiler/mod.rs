@@ -3,6 +3,7 @@
 mod class;
 mod declaration;
 mod declarations;
+mod dispose;
 mod env;
 mod expression;
 mod function;
@@ -41,12 +42,13 @@ use boa_ast::{
     pattern::Pattern,
     property::MethodDefinitionKind,
     scope::{BindingLocator, BindingLocatorError, FunctionScopes, IdentifierReference, Scope},
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Statement, StatementList, StatementListItem,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
 use boa_macros::js_str;
 use class::ClassSpec;
+use dispose::using_declarations;
 use rustc_hash::FxHashMap;
 use thin_vec::ThinVec;
 
@@ -425,6 +427,7 @@ pub struct ByteCompiler<'ctx> {
     code_block_flags: CodeBlockFlags,
     handlers: ThinVec<Handler>,
     pub(crate) ic: Vec<InlineCache>,
+    source_positions: Vec<(u32, Position)>,
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
     bindings_map: FxHashMap<BindingLocator, u32>,
@@ -524,6 +527,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             code_block_flags,
             handlers: ThinVec::default(),
             ic: Vec::default(),
+            source_positions: Vec::default(),
 
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
@@ -676,6 +680,14 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.bytecode.len() as u32
     }
 
+    /// Records `position` as the source position of the last emitted instruction.
+    pub(crate) fn emit_source_position(&mut self, position: Option<Position>) {
+        if let Some(position) = position {
+            let pc = self.next_opcode_location();
+            self.source_positions.push((pc, position));
+        }
+    }
+
     pub(crate) fn emit(&mut self, opcode: Opcode, operands: &[Operand]) {
         let mut varying_kind = VaryingOperandKind::U8;
         for operand in operands {
@@ -1170,6 +1182,23 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        if let Some(r#async) = using_declarations(list.statements()) {
+            self.compile_dispose_scope(r#async, use_expr, |compiler| {
+                compiler.compile_statement_list_items(list, use_expr, block);
+            });
+        } else {
+            self.compile_statement_list_items(list, use_expr, block);
+        }
+    }
+
+    /// Compile the items of a [`StatementList`], without disposing the resources of its `using`
+    /// declarations.
+    pub(crate) fn compile_statement_list_items(
+        &mut self,
+        list: &StatementList,
+        use_expr: bool,
+        block: bool,
+    ) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -1451,6 +1480,26 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                let is_async = decl.is_await_using();
+                for variable in decls.as_ref() {
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
+                    self.compile_expr(init, true);
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit(Opcode::AddDisposableResource, &[Operand::Bool(is_async)]);
+                    match variable.binding() {
+                        Binding::Identifier(ident) => {
+                            let ident = ident.to_js_string(self.interner());
+                            self.emit_binding(BindingOpcode::InitLexical, ident);
+                        }
+                        Binding::Pattern(pattern) => {
+                            self.compile_declaration_pattern(pattern, BindingOpcode::InitLexical);
+                        }
+                    }
+                }
+            }
         };
     }
 
@@ -1758,6 +1807,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             CallKind::New => self.emit_with_varying_operand(Opcode::New, call.args().len() as u32),
         }
 
+        self.emit_source_position(call.position());
+
         if !use_expr {
             self.emit_opcode(Opcode::Pop);
         }
@@ -1808,6 +1859,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             handlers: self.handlers,
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
+            source_positions: self.source_positions.into_boxed_slice(),
         }
     }
 
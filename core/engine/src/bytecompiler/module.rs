@@ -1,6 +1,6 @@
 use crate::vm::{BindingOpcode, Opcode};
 
-use super::{ByteCompiler, Literal, Operand, ToJsString};
+use super::{using_declarations, ByteCompiler, Literal, Operand, ToJsString};
 use boa_ast::{declaration::ExportDeclaration, ModuleItem, ModuleItemList};
 use boa_interner::Sym;
 
@@ -8,8 +8,21 @@ impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
-        for node in list.items() {
-            self.compile_module_item(node);
+        let statements = list.items().iter().filter_map(|item| match item {
+            ModuleItem::StatementListItem(stmt) => Some(stmt),
+            _ => None,
+        });
+
+        if let Some(r#async) = using_declarations(statements) {
+            self.compile_dispose_scope(r#async, false, |compiler| {
+                for node in list.items() {
+                    compiler.compile_module_item(node);
+                }
+            });
+        } else {
+            for node in list.items() {
+                self.compile_module_item(node);
+            }
         }
     }
 
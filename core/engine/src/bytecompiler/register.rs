@@ -74,9 +74,8 @@ impl RegisterAllocator {
         if let Some((i, register)) = self
             .registers
             .iter_mut()
-            .filter(|reg| !reg.flags.is_used())
             .enumerate()
-            .next()
+            .find(|(_, reg)| !reg.flags.is_used())
         {
             assert!(!register.flags.is_persistent());
 
@@ -138,3 +137,25 @@ impl RegisterAllocator {
         self.registers.len() as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RegisterAllocator;
+
+    #[test]
+    fn alloc_reuses_the_freed_register() {
+        let mut allocator = RegisterAllocator::default();
+        let first = allocator.alloc();
+        let second = allocator.alloc();
+        let third = allocator.alloc();
+
+        allocator.dealloc(second);
+        let reused = allocator.alloc();
+        assert_eq!(reused.index(), 1);
+
+        for register in [first, reused, third] {
+            allocator.dealloc(register);
+        }
+        assert_eq!(allocator.finish(), 3);
+    }
+}
@@ -21,6 +21,22 @@ impl ByteCompiler<'_> {
         label: Option<Sym>,
         use_expr: bool,
     ) {
+        // The resources of a `using` declaration in the initializer are disposed when the loop
+        // is exited.
+        if let Some(ForLoopInitializer::Lexical(decl)) = for_loop.init() {
+            if decl.declaration().is_using() {
+                let r#async = decl.declaration().is_await_using();
+                self.compile_dispose_scope(r#async, use_expr, |compiler| {
+                    compiler.compile_for_loop_inner(for_loop, label, use_expr);
+                });
+                return;
+            }
+        }
+
+        self.compile_for_loop_inner(for_loop, label, use_expr);
+    }
+
+    fn compile_for_loop_inner(&mut self, for_loop: &ForLoop, label: Option<Sym>, use_expr: bool) {
         let mut let_binding_indices = None;
         let mut outer_scope_local = None;
         let mut outer_scope = None;
@@ -190,7 +206,9 @@ impl ByteCompiler<'_> {
                 }
             },
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => match declaration {
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => match declaration {
                 Binding::Identifier(ident) => {
                     let ident = ident.to_js_string(self.interner());
                     self.emit_binding(BindingOpcode::InitLexical, ident);
@@ -308,9 +326,37 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
+            IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
+                // The resource of each iteration is disposed at the end of the iteration.
+                let r#async = matches!(
+                    for_of_loop.initializer(),
+                    IterableLoopInitializer::AwaitUsing(_)
+                );
+                self.compile_dispose_scope(r#async, use_expr, |compiler| {
+                    compiler.emit_opcode(Opcode::Dup);
+                    compiler.emit(Opcode::AddDisposableResource, &[Operand::Bool(r#async)]);
+                    match declaration {
+                        Binding::Identifier(ident) => {
+                            let ident = ident.to_js_string(compiler.interner());
+                            compiler.emit_binding(BindingOpcode::InitLexical, ident);
+                        }
+                        Binding::Pattern(pattern) => {
+                            compiler
+                                .compile_declaration_pattern(pattern, BindingOpcode::InitLexical);
+                        }
+                    }
+                    compiler.compile_stmt(for_of_loop.body(), use_expr, true);
+                });
+            }
         }
 
-        self.compile_stmt(for_of_loop.body(), use_expr, true);
+        if !matches!(
+            for_of_loop.initializer(),
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_)
+        ) {
+            self.compile_stmt(for_of_loop.body(), use_expr, true);
+        }
 
         {
             let exit = self.jump();
@@ -58,6 +58,7 @@ impl ByteCompiler<'_> {
             Statement::Throw(throw) => {
                 self.compile_expr(throw.target(), true);
                 self.emit(Opcode::Throw, &[]);
+                self.emit_source_position(throw.position());
             }
             Statement::Switch(switch) => {
                 self.compile_switch(switch, use_expr);
@@ -1,4 +1,7 @@
-use crate::{bytecompiler::ByteCompiler, vm::Opcode};
+use crate::{
+    bytecompiler::{using_declarations, ByteCompiler},
+    vm::Opcode,
+};
 use boa_ast::statement::Switch;
 
 impl ByteCompiler<'_> {
@@ -12,6 +15,28 @@ impl ByteCompiler<'_> {
         let start_address = self.next_opcode_location();
         self.push_switch_control_info(None, start_address, use_expr);
 
+        let using = using_declarations(
+            switch
+                .cases()
+                .iter()
+                .flat_map(|case| case.body().statements()),
+        );
+        if let Some(r#async) = using {
+            self.compile_dispose_scope(r#async, use_expr, |compiler| {
+                compiler.compile_switch_cases(switch, use_expr);
+            });
+        } else {
+            self.compile_switch_cases(switch, use_expr);
+        }
+
+        self.pop_switch_control_info();
+        self.pop_declarative_scope(outer_scope);
+    }
+
+    /// Compile the cases of a [`Switch`] `boa_ast` node.
+    ///
+    /// The `using` declarations of the cases are disposed at the end of the whole case block.
+    fn compile_switch_cases(&mut self, switch: &Switch, use_expr: bool) {
         let mut labels = Vec::with_capacity(switch.cases().len());
         for case in switch.cases() {
             // If it does not have a condition it is the default case.
@@ -39,14 +64,11 @@ impl ByteCompiler<'_> {
             };
             self.patch_jump(label);
 
-            self.compile_statement_list(case.body(), use_expr, true);
+            self.compile_statement_list_items(case.body(), use_expr, true);
         }
 
         if !default_label_set {
             self.patch_jump(default_label);
         }
-
-        self.pop_switch_control_info();
-        self.pop_declarative_scope(outer_scope);
     }
 }
@@ -109,6 +109,7 @@ use crate::{
     native_function::NativeFunction,
     object::{ConstructorBuilder, FunctionBinding, JsFunction, JsObject, NativeObject, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    symbol::JsSymbol,
     Context, JsResult, JsValue,
 };
 
@@ -272,6 +273,86 @@ impl<'ctx> ClassBuilder<'ctx> {
         self
     }
 
+    /// Add a `[Symbol.dispose]` method to the class.
+    ///
+    /// It is added to `prototype`, and is called with the instance as its `this` value when a
+    /// `using` declaration holding the instance goes out of scope, or when the instance is
+    /// disposed by a `DisposableStack`. This can be used to release the resources held by the
+    /// native data of the instance, like file handles or locks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    class::{Class, ClassBuilder},
+    /// #    js_string, Context, JsData, JsObject, JsResult, JsValue, NativeFunction, Source,
+    /// # };
+    /// # use boa_gc::{Finalize, Trace};
+    /// #[derive(Debug, Trace, Finalize, JsData)]
+    /// struct Lock {
+    ///     held: bool,
+    /// }
+    ///
+    /// impl Class for Lock {
+    ///     const NAME: &'static str = "Lock";
+    ///
+    ///     fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+    ///         Ok(Self { held: true })
+    ///     }
+    ///
+    ///     fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+    ///         class.dispose(NativeFunction::from_fn_ptr(|this, _, _| {
+    ///             if let Some(mut lock) = this.as_object().and_then(JsObject::downcast_mut::<Lock>) {
+    ///                 lock.held = false;
+    ///             }
+    ///             Ok(JsValue::undefined())
+    ///         }));
+    ///         class.method(
+    ///             js_string!("isHeld"),
+    ///             0,
+    ///             NativeFunction::from_fn_ptr(|this, _, _| {
+    ///                 Ok(this
+    ///                     .as_object()
+    ///                     .and_then(JsObject::downcast_ref::<Lock>)
+    ///                     .is_some_and(|lock| lock.held)
+    ///                     .into())
+    ///             }),
+    ///         );
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let context = &mut Context::default();
+    /// context.register_global_class::<Lock>().unwrap();
+    ///
+    /// let result = context
+    ///     .eval(Source::from_bytes(
+    ///         r#"
+    ///         let lock;
+    ///         {
+    ///             using l = new Lock();
+    ///             lock = l;
+    ///         }
+    ///         lock.isHeld()
+    ///     "#,
+    ///     ))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, JsValue::from(false));
+    /// ```
+    pub fn dispose(&mut self, function: NativeFunction) -> &mut Self {
+        self.method(JsSymbol::dispose(), 0, function)
+    }
+
+    /// Add a `[Symbol.asyncDispose]` method to the class.
+    ///
+    /// It is added to `prototype`, and is called with the instance as its `this` value when an
+    /// `await using` declaration holding the instance goes out of scope, or when the instance is
+    /// disposed by an `AsyncDisposableStack`. The result of the function is awaited.
+    pub fn async_dispose(&mut self, function: NativeFunction) -> &mut Self {
+        self.method(JsSymbol::async_dispose(), 0, function)
+    }
+
     /// Add a static method to the class.
     ///
     /// It is added to class object itself.
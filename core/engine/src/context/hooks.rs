@@ -157,6 +157,23 @@ pub trait HostHooks {
         Ok(())
     }
 
+    /// [`HostInitializeShadowRealm ( realm )`][spec]
+    ///
+    /// Called right after the global bindings of a new `ShadowRealm` are created, with `realm` as
+    /// the current realm of `context`. This allows hosts to install additional host-defined
+    /// properties on its global object.
+    ///
+    /// # Requirements
+    ///
+    /// - If the returned Completion Record is a normal completion, it must be a normal completion
+    ///   containing unused. This is already ensured by the return type.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-hostinitializeshadowrealm
+    fn initialize_shadow_realm(&self, _realm: &Realm, _context: &mut Context) -> JsResult<()> {
+        // The default implementation of HostInitializeShadowRealm is to return NormalCompletion(unused).
+        Ok(())
+    }
+
     /// Creates the global object of a new [`Context`] from the initial intrinsics.
     ///
     /// Equivalent to the step 7 of [`InitializeHostDefinedRealm ( )`][ihdr].
@@ -3,6 +3,7 @@
 use std::{cell::Cell, path::Path, rc::Rc};
 
 use boa_ast::StatementList;
+use boa_gc::WeakGc;
 use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 use boa_profiler::Profiler;
@@ -11,15 +12,15 @@ pub use hooks::{DefaultHooks, HostHooks};
 pub use icu::IcuError;
 use intrinsics::Intrinsics;
 
-use crate::vm::RuntimeLimits;
+use crate::vm::{InterruptHandle, RuntimeLimits};
 use crate::{
-    builtins,
+    builtins::{self, finalization_registry::FinalizationRegistry},
     class::{Class, ClassBuilder},
     job::{JobQueue, NativeJob, SimpleJobQueue},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, ErasedVTableObject, FunctionObjectBuilder, JsObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
@@ -96,7 +97,10 @@ pub struct Context {
 
     pub(crate) vm: Vm,
 
-    pub(crate) kept_alive: Vec<JsObject>,
+    pub(crate) kept_alive: Vec<JsValue>,
+
+    /// The `FinalizationRegistry` objects created in this context.
+    pub(crate) finalization_registries: Vec<WeakGc<ErasedVTableObject>>,
 
     can_block: bool,
 
@@ -471,8 +475,11 @@ impl Context {
     /// Runs all the jobs in the job queue.
     #[inline]
     pub fn run_jobs(&mut self) {
-        self.job_queue().run_jobs(self);
-        self.clear_kept_objects();
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
+        // All the jobs share the instruction budget and the deadline of a single execution.
+        let context = &mut self.enter_execution();
+        context.job_queue().run_jobs(context);
+        context.clear_kept_objects();
     }
 
     /// Asynchronously runs all the jobs in the job queue.
@@ -484,14 +491,17 @@ impl Context {
     /// provide a custom implementor of `JobQueue` to the context.
     #[allow(clippy::future_not_send)]
     pub async fn run_jobs_async(&mut self) {
-        self.job_queue().run_jobs_async(self).await;
-        self.clear_kept_objects();
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
+        // All the jobs share the instruction budget and the deadline of a single execution.
+        let context = &mut self.enter_execution();
+        context.job_queue().run_jobs_async(context).await;
+        context.clear_kept_objects();
     }
 
     /// Abstract operation [`ClearKeptObjects`][clear].
     ///
-    /// Clears all objects maintained alive by calls to the [`AddToKeptObjects`][add] abstract
-    /// operation, used within the [`WeakRef`][weak] constructor.
+    /// Clears all objects and symbols maintained alive by calls to the [`AddToKeptObjects`][add]
+    /// abstract operation, used within the [`WeakRef`][weak] constructor.
     ///
     /// [clear]: https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-clear-kept-objects
     /// [add]: https://tc39.es/ecma262/multipage/executable-code-and-execution-contexts.html#sec-addtokeptobjects
@@ -573,6 +583,14 @@ impl Context {
         &mut self.vm.runtime_limits
     }
 
+    /// Returns an [`InterruptHandle`] that can interrupt the execution of this context from
+    /// another thread.
+    #[inline]
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.execution.interrupt.clone()
+    }
+
     /// Returns `true` if this context can be suspended by an `Atomics.wait` call.
     #[inline]
     #[must_use]
@@ -855,6 +873,17 @@ impl Context {
         ContextCleanupGuard::new(self, cleanup)
     }
 
+    /// Enters an execution tracked against the runtime limits, returning a guard that exits it
+    /// when dropped.
+    ///
+    /// Nested executions share the instruction budget and the deadline of the outermost one.
+    pub(crate) fn enter_execution(
+        &mut self,
+    ) -> ContextCleanupGuard<'_, impl FnOnce(&mut Context) + 'static> {
+        self.vm.execution.enter(&self.vm.runtime_limits);
+        self.guard(|context| context.vm.execution.exit())
+    }
+
     /// Get the Intl data provider.
     #[cfg(feature = "intl")]
     pub(crate) const fn intl_provider(&self) -> &icu::IntlProvider {
@@ -1053,6 +1082,11 @@ impl ContextBuilder {
     // require having a `Context` in the first place.
     pub fn build(self) -> JsResult<Context> {
         let _timer = Profiler::global().start_event("Ctx::build", "context");
+
+        // Strings aren't allocated by the garbage collector, so their memory is reported to it to
+        // count towards the heap limit.
+        boa_string::set_allocation_hook(boa_gc::adjust_external_memory);
+
         if self.can_block {
             if CANNOT_BLOCK_COUNTER.get() > 0 {
                 return Err(JsNativeError::typ()
@@ -1105,6 +1139,7 @@ impl ContextBuilder {
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
             host_hooks,
             job_queue,
             module_loader,
@@ -1,15 +1,26 @@
 //! Error-related types and conversions.
 
 use crate::{
-    builtins::{error::ErrorObject, Array},
+    builtins::{
+        error::{Error, ErrorObject},
+        Array,
+    },
     js_string,
     object::JsObject,
     property::PropertyDescriptor,
     realm::Realm,
+    vm::{ActiveRunnable, CallFrame},
     Context, JsString, JsValue,
 };
+use boa_ast::Position;
 use boa_gc::{custom_trace, Finalize, Trace};
-use std::{borrow::Cow, error, fmt};
+use boa_macros::js_str;
+use std::{
+    borrow::Cow,
+    error, fmt, iter,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 use thiserror::Error;
 
 /// Create an error object from a value or string literal. Optionally the
@@ -187,12 +198,23 @@ macro_rules! js_error {
 /// let kind = &native_error.as_native().unwrap().kind;
 /// assert!(matches!(kind, JsNativeErrorKind::Type));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+#[derive(Debug, Clone, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
 pub struct JsError {
     inner: Repr,
+    #[unsafe_ignore_trace]
+    backtrace: Option<Backtrace>,
 }
 
+// The backtrace only describes where the error was thrown, so it doesn't take part in comparisons.
+impl PartialEq for JsError {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for JsError {}
+
 /// Internal representation of a [`JsError`].
 ///
 /// `JsError` is represented by an opaque enum because it restricts
@@ -281,6 +303,7 @@ impl JsError {
     pub const fn from_native(err: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(err),
+            backtrace: None,
         }
     }
 
@@ -321,6 +344,7 @@ impl JsError {
     pub const fn from_opaque(value: JsValue) -> Self {
         Self {
             inner: Repr::Opaque(value),
+            backtrace: None,
         }
     }
 
@@ -341,7 +365,9 @@ impl JsError {
     /// ```
     pub fn to_opaque(&self, context: &mut Context) -> JsValue {
         match &self.inner {
-            Repr::Native(e) => e.to_opaque(context).into(),
+            Repr::Native(e) => e
+                .to_opaque_with_backtrace(self.backtrace.as_ref(), context)
+                .into(),
             Repr::Opaque(v) => v.clone(),
         }
     }
@@ -459,6 +485,26 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    ErrorObject::Suppressed => {
+                        let error = obj.get(js_string!("error"), context).map_err(|e| {
+                            TryNativeError::InaccessibleProperty {
+                                property: "error",
+                                source: e,
+                            }
+                        })?;
+                        let suppressed =
+                            obj.get(js_string!("suppressed"), context).map_err(|e| {
+                                TryNativeError::InaccessibleProperty {
+                                    property: "suppressed",
+                                    source: e,
+                                }
+                            })?;
+
+                        JsNativeErrorKind::Suppressed {
+                            error: Box::new(Self::from_opaque(error)),
+                            suppressed: Box::new(Self::from_opaque(suppressed)),
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -526,6 +572,37 @@ impl JsError {
         }
     }
 
+    /// Gets the JavaScript backtrace captured when this error was thrown by the engine, or
+    /// `None` if the error was never thrown from script code.
+    ///
+    /// The backtrace lists the script frames that were active at the point of the throw, from
+    /// the innermost to the outermost one, and is limited by `Error.stackTraceLimit`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{js_string, Context, JsError, JsNativeError, Source};
+    /// let context = &mut Context::default();
+    ///
+    /// let error = context
+    ///     .eval(Source::from_bytes("function f() {\n  throw new Error('oops');\n}\nf();"))
+    ///     .unwrap_err();
+    /// let frames = error.backtrace().unwrap().frames();
+    ///
+    /// assert_eq!(frames.len(), 2);
+    /// assert_eq!(frames[0].function_name(), &js_string!("f"));
+    /// assert_eq!(frames[0].position().unwrap().line_number(), 2);
+    /// assert_eq!(frames[1].position().unwrap().line_number(), 4);
+    ///
+    /// let error: JsError = JsNativeError::typ().into();
+    ///
+    /// assert!(error.backtrace().is_none());
+    /// ```
+    #[must_use]
+    pub const fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_ref()
+    }
+
     /// Converts this error into its thread-safe, erased version.
     ///
     /// Even though this operation is lossy, converting into a `JsErasedError`
@@ -579,6 +656,12 @@ impl JsError {
                     .map(|err| err.into_erased(context))
                     .collect(),
             ),
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Error => JsErasedNativeErrorKind::Error,
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
@@ -616,6 +699,19 @@ impl JsError {
         self
     }
 
+    /// Captures the currently active script frames on the `backtrace` field of this error.
+    ///
+    /// This is a no-op if the error already has a backtrace, or if `Error.stackTraceLimit` is
+    /// not a number.
+    pub(crate) fn inject_backtrace(mut self, context: &Context) -> Self {
+        if self.backtrace.is_none() {
+            if let Some(limit) = Error::stack_trace_limit(context) {
+                self.backtrace = Some(Backtrace::capture(context, None, limit));
+            }
+        }
+        self
+    }
+
     /// Is the [`JsError`] catchable in JavaScript.
     #[inline]
     pub(crate) fn is_catchable(&self) -> bool {
@@ -633,6 +729,7 @@ impl From<JsNativeError> for JsError {
     fn from(error: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(error),
+            backtrace: None,
         }
     }
 }
@@ -646,6 +743,153 @@ impl fmt::Display for JsError {
     }
 }
 
+/// A JavaScript backtrace, listing the script frames that were active at some point of the
+/// execution, from the innermost to the outermost one.
+///
+/// Backtraces are captured by the engine when an error is thrown (see [`JsError::backtrace`]),
+/// and their [`Display`][fmt::Display] implementation mirrors the format of the `stack`
+/// property of `Error` objects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Backtrace {
+    frames: Rc<[StackFrame]>,
+}
+
+impl Backtrace {
+    /// Captures up to `limit` of the currently active script frames.
+    ///
+    /// If `skip_until` is provided, all frames above the topmost call to that function,
+    /// including the call itself, are left out of the backtrace.
+    pub(crate) fn capture(context: &Context, skip_until: Option<&JsObject>, limit: usize) -> Self {
+        let vm = &context.vm;
+
+        // The bottom frame of the VM is a placeholder that doesn't belong to any script.
+        let frames = iter::once(&vm.frame)
+            .chain(vm.frames.iter().rev())
+            .take(vm.frames.len());
+
+        let skip = skip_until.map_or(0, |function| {
+            frames
+                .clone()
+                .position(|frame| {
+                    frame
+                        .function(vm)
+                        .is_some_and(|f| JsObject::equals(&f, function))
+                })
+                .map_or(usize::MAX, |index| index + 1)
+        });
+
+        Self {
+            frames: frames
+                .skip(skip)
+                .take(limit)
+                .map(StackFrame::from_call_frame)
+                .collect(),
+        }
+    }
+
+    /// Gets the frames of this backtrace, from the innermost to the outermost one.
+    #[inline]
+    #[must_use]
+    pub fn frames(&self) -> &[StackFrame] {
+        &self.frames
+    }
+}
+
+impl fmt::Display for Backtrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "    at {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single frame of a [`Backtrace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    function_name: JsString,
+    path: Option<PathBuf>,
+    position: Option<Position>,
+}
+
+impl StackFrame {
+    fn from_call_frame(frame: &CallFrame) -> Self {
+        let code_block = frame.code_block();
+
+        let function_name = if code_block.name() == &js_str!("<main>") {
+            JsString::default()
+        } else {
+            code_block.name().clone()
+        };
+
+        let path = frame
+            .active_runnable
+            .as_ref()
+            .and_then(|runnable| match runnable {
+                ActiveRunnable::Script(script) => script.path(),
+                ActiveRunnable::Module(module) => module.path(),
+            })
+            .map(Path::to_path_buf);
+
+        Self {
+            function_name,
+            path,
+            position: code_block.find_source_position(frame.pc),
+        }
+    }
+
+    /// Gets the name of the function of this frame.
+    ///
+    /// The name is empty for anonymous functions and for top-level script or module code.
+    #[inline]
+    #[must_use]
+    pub const fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the path of the script or module of this frame, if it has one.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Gets the source position of the call or `throw` that was being executed in this frame,
+    /// if it is known.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let has_name = !self.function_name.is_empty();
+        if has_name {
+            write!(f, "{} (", self.function_name.to_std_string_escaped())?;
+        }
+
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => f.write_str("<anonymous>")?,
+        }
+
+        if let Some(position) = self.position {
+            write!(f, ":{position}")?;
+        }
+
+        if has_name {
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Native representation of an ideal `Error` object from Javascript.
 ///
 /// This representation is more space efficient than its [`JsObject`] equivalent,
@@ -942,6 +1186,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Uri)
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the error that was thrown
+    /// last and the error it suppressed, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::range().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Cow::Borrowed(""),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` that indicates that the context hit its execution limit. This
     /// is only used in a fuzzing context.
     #[cfg(feature = "fuzz")]
@@ -1084,6 +1362,16 @@ impl JsNativeError {
     /// If converting a [`JsNativeErrorKind::RuntimeLimit`] to an opaque object.
     #[inline]
     pub fn to_opaque(&self, context: &mut Context) -> JsObject {
+        self.to_opaque_with_backtrace(None, context)
+    }
+
+    /// Converts this native error to its opaque representation, using `backtrace` as the
+    /// source of its `stack` property, or the currently active frames if `backtrace` is `None`.
+    fn to_opaque_with_backtrace(
+        &self,
+        backtrace: Option<&Backtrace>,
+        context: &mut Context,
+    ) -> JsObject {
         let Self {
             kind,
             message,
@@ -1113,6 +1401,10 @@ impl JsNativeError {
             }
             JsNativeErrorKind::Type => (constructors.type_error().prototype(), ErrorObject::Type),
             JsNativeErrorKind::Uri => (constructors.uri_error().prototype(), ErrorObject::Uri),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                ErrorObject::Suppressed,
+            ),
             #[cfg(feature = "fuzz")]
             JsNativeErrorKind::NoInstructionsRemain => {
                 unreachable!(
@@ -1158,6 +1450,20 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(js_string!("error"), error, context);
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
+
+        Error::install_error_stack(&o, backtrace, context);
+
         o
     }
 
@@ -1267,6 +1573,18 @@ pub enum JsNativeErrorKind {
     /// [e_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
+    /// An error thrown while disposing a resource, which suppressed a previously thrown error.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsError>,
+    },
 
     /// Error thrown when no instructions remain. Only used in a fuzzing context; not a valid JS
     /// error variant.
@@ -1284,6 +1602,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1310,7 +1632,8 @@ impl JsNativeErrorKind {
             | Self::Reference
             | Self::Syntax
             | Self::Type
-            | Self::Uri => true,
+            | Self::Uri
+            | Self::Suppressed { .. } => true,
             Self::RuntimeLimit => false,
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => false,
@@ -1330,6 +1653,7 @@ impl PartialEq<ErrorObject> for JsNativeErrorKind {
                 | (Self::Syntax, ErrorObject::Syntax)
                 | (Self::Type, ErrorObject::Type)
                 | (Self::Uri, ErrorObject::Uri)
+                | (Self::Suppressed { .. }, ErrorObject::Suppressed)
         )
     }
 }
@@ -1345,6 +1669,7 @@ impl fmt::Display for JsNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::RuntimeLimit => "RuntimeLimit",
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => "NoInstructionsRemain",
@@ -1516,6 +1841,18 @@ pub enum JsErasedNativeErrorKind {
     /// [e_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
+    /// An error thrown while disposing a resource, which suppressed a previously thrown error.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsErasedError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsErasedError>,
+    },
 
     /// Error thrown when a runtime limit is exceeded. It's not a valid JS error variant.
     RuntimeLimit,
@@ -1534,6 +1871,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::RuntimeLimit => "RuntimeLimit",
         }
         .fmt(f)
@@ -17,7 +17,15 @@
 //! [Job]: https://tc39.es/ecma262/#sec-jobs
 //! [JobCallback]: https://tc39.es/ecma262/#sec-jobcallback-records
 
-use std::{cell::RefCell, collections::VecDeque, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{self, Poll, Wake, Waker},
+};
 
 use crate::{
     object::{JsFunction, NativeObject},
@@ -279,9 +287,17 @@ impl JobQueue for IdleJobQueue {
 /// This is the default job queue for the [`Context`], but it is mostly pretty limited for
 /// custom event queues.
 ///
+/// Future jobs are not polled until the queue is run. Once all pending promise jobs have run, the
+/// queue polls each of its futures once, running the jobs of the ones that are ready. The queue
+/// never blocks on a future; the ones that aren't ready are polled again the next time the queue
+/// is run.
+///
 /// To disable running promise jobs on the engine, see [`IdleJobQueue`].
 #[derive(Default)]
-pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: RefCell<VecDeque<FutureJob>>,
+}
 
 impl Debug for SimpleJobQueue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -299,24 +315,60 @@ impl SimpleJobQueue {
 
 impl JobQueue for SimpleJobQueue {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
-        // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
-        // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
+        loop {
+            // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
+            // dropped after calling `pop_front`.
+            let next_job = self.jobs.borrow_mut().pop_front();
+            if let Some(job) = next_job {
+                if job.call(context).is_err() {
+                    self.jobs.borrow_mut().clear();
+                    self.futures.borrow_mut().clear();
+                    return;
+                };
+                continue;
+            }
+
+            if self.futures.borrow().is_empty() {
                 return;
-            };
-            next_job = self.0.borrow_mut().pop_front();
+            }
+
+            let ready = poll_futures_once(&mut self.futures.borrow_mut());
+            if ready.is_empty() {
+                return;
+            }
+            self.jobs.borrow_mut().extend(ready);
         }
     }
 
-    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context) {
-        let job = pollster::block_on(future);
-        self.enqueue_promise_job(job, context);
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.futures.borrow_mut().push_back(future);
     }
 }
+
+/// A waker that does nothing, used to poll futures without blocking on them.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Polls each future of `futures` once, removing and returning the jobs of the ones that are
+/// ready.
+fn poll_futures_once(futures: &mut VecDeque<FutureJob>) -> Vec<NativeJob> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let cx = &mut task::Context::from_waker(&waker);
+
+    let mut ready = Vec::new();
+    futures.retain_mut(|future| match future.as_mut().poll(cx) {
+        Poll::Ready(job) => {
+            ready.push(job);
+            false
+        }
+        Poll::Pending => true,
+    });
+    ready
+}
@@ -3,9 +3,11 @@ use std::path::{Component, Path, PathBuf};
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
 
-use boa_gc::GcRefCell;
+use boa_gc::{Finalize, GcRefCell, Trace};
+use boa_interner::Interner;
 use boa_parser::Source;
 
+use crate::bytecompiler::ToJsString;
 use crate::script::Script;
 use crate::{
     js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context, JsError, JsNativeError,
@@ -142,27 +144,189 @@ impl From<ActiveRunnable> for Referrer {
     }
 }
 
+/// An import attribute of a [`ModuleRequest`] (`with { key: "value" }`).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#importattribute-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+pub struct ImportAttribute {
+    key: JsString,
+    value: JsString,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[must_use]
+    pub const fn new(key: JsString, value: JsString) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the attribute.
+    #[must_use]
+    pub const fn key(&self) -> &JsString {
+        &self.key
+    }
+
+    /// Gets the value of the attribute.
+    #[must_use]
+    pub const fn value(&self) -> &JsString {
+        &self.value
+    }
+}
+
+/// The type of a module, as requested by the `type` import attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleType {
+    /// A JavaScript module, requested without a `type` attribute.
+    JavaScript,
+    /// A JSON module, requested with `type: "json"`.
+    Json,
+}
+
+/// A [**`ModuleRequest` Record**][spec].
+///
+/// Represents a request to load a module, composed by the module specifier and the import
+/// attributes that were used to import it. The attributes are kept sorted by key, meaning two
+/// requests compare equal if they have the same specifier and the same set of attributes.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+pub struct ModuleRequest {
+    specifier: JsString,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and a list of import attributes.
+    #[must_use]
+    pub fn new<I>(specifier: JsString, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = ImportAttribute>,
+    {
+        let mut attributes = attributes.into_iter().collect::<Vec<_>>();
+        attributes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+        }
+    }
+
+    /// Creates a new `ModuleRequest` from a specifier without any import attributes.
+    #[must_use]
+    pub fn from_specifier(specifier: JsString) -> Self {
+        Self {
+            specifier,
+            attributes: Box::default(),
+        }
+    }
+
+    /// Creates a new `ModuleRequest` from its AST representation.
+    pub(crate) fn from_ast(
+        request: &boa_ast::declaration::ModuleRequest,
+        interner: &Interner,
+    ) -> Self {
+        Self::new(
+            request.specifier().to_js_string(interner),
+            request.attributes().iter().map(|attribute| {
+                ImportAttribute::new(
+                    attribute.key().to_js_string(interner),
+                    attribute.value().to_js_string(interner),
+                )
+            }),
+        )
+    }
+
+    /// Gets the module specifier of this request.
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the import attributes of this request, sorted by key.
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the value of the import attribute with the provided key, if it exists.
+    #[must_use]
+    pub fn get_attribute(&self, key: &str) -> Option<&JsString> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(ImportAttribute::value)
+    }
+
+    /// Gets the [`ModuleType`] requested by the `type` import attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the `type` attribute has a value that is not supported by
+    /// the engine.
+    pub fn module_type(&self) -> JsResult<ModuleType> {
+        match self.get_attribute("type") {
+            None => Ok(ModuleType::JavaScript),
+            Some(ty) if ty == "json" => Ok(ModuleType::Json),
+            Some(ty) => Err(JsNativeError::typ()
+                .with_message(format!(
+                    "unsupported module type `{}` for module `{}`",
+                    ty.to_std_string_escaped(),
+                    self.specifier.to_std_string_escaped()
+                ))
+                .into()),
+        }
+    }
+}
+
+/// Abstract operation [`AllImportAttributesSupported ( attributes )`][spec].
+///
+/// The only import attribute supported by the engine is `type`.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-AllImportAttributesSupported
+pub(crate) fn validate_import_attributes(request: &ModuleRequest) -> JsResult<()> {
+    // 1. Let supported be HostGetSupportedImportAttributes().
+    // 2. For each ImportAttribute Record attribute of attributes, do
+    for attribute in request.attributes() {
+        // a. If supported does not contain attribute.[[Key]], return false.
+        if attribute.key() != "type" {
+            return Err(JsNativeError::syntax()
+                .with_message(format!(
+                    "unsupported import attribute `{}`",
+                    attribute.key().to_std_string_escaped()
+                ))
+                .into());
+        }
+    }
+    // 3. Return true.
+    Ok(())
+}
+
 /// Module loading related host hooks.
 ///
 /// This trait allows to customize the behaviour of the engine on module load requests and
 /// `import.meta` requests.
 pub trait ModuleLoader {
-    /// Host hook [`HostLoadImportedModule ( referrer, specifier, hostDefined, payload )`][spec].
+    /// Host hook [`HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )`][spec].
     ///
-    /// This hook allows to customize the module loading functionality of the engine. Technically,
+    /// This hook allows to customize the module loading functionality of the engine. The
+    /// [`ModuleRequest`] contains the module specifier and the import attributes of the request,
+    /// which can be used to pick how to load the module (e.g. [`Module::parse_json`] for
+    /// `type: "json"`), or to reject unsupported module types. Technically,
     /// this should call the [`FinishLoadingImportedModule`][finish] operation, but this simpler API just provides
     /// a closure that replaces `FinishLoadingImportedModule`.
     ///
     /// # Requirements
     ///
-    /// - The host environment must perform `FinishLoadingImportedModule(referrer, specifier, payload, result)`,
+    /// - The host environment must perform `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)`,
     ///   where result is either a normal completion containing the loaded Module Record or a throw
     ///   completion, either synchronously or asynchronously. This is equivalent to calling the `finish_load`
     ///   callback.
-    /// - If this operation is called multiple times with the same `(referrer, specifier)` pair and
-    ///   it performs FinishLoadingImportedModule(referrer, specifier, payload, result) where result
+    /// - If this operation is called multiple times with the same `(referrer, moduleRequest)` pair and
+    ///   it performs FinishLoadingImportedModule(referrer, moduleRequest, payload, result) where result
     ///   is a normal completion, then it must perform
-    ///   `FinishLoadingImportedModule(referrer, specifier, payload, result)` with the same result each
+    ///   `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)` with the same result each
     ///   time.
     /// - The operation must treat payload as an opaque value to be passed through to
     ///   `FinishLoadingImportedModule`. (can be ignored)
@@ -173,7 +337,7 @@ pub trait ModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     );
@@ -223,7 +387,7 @@ impl ModuleLoader for IdleModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        _specifier: JsString,
+        _request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
@@ -238,6 +402,9 @@ impl ModuleLoader for IdleModuleLoader {
 
 /// A simple module loader that loads modules relative to a root path.
 ///
+/// Modules imported with the `type: "json"` import attribute are loaded as JSON modules using
+/// [`Module::parse_json`].
+///
 /// # Note
 ///
 /// This loader only works by using the type methods [`SimpleModuleLoader::insert`] and
@@ -247,6 +414,7 @@ impl ModuleLoader for IdleModuleLoader {
 pub struct SimpleModuleLoader {
     root: PathBuf,
     module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    json_module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
 }
 
 impl SimpleModuleLoader {
@@ -267,6 +435,7 @@ impl SimpleModuleLoader {
         Ok(Self {
             root: absolute,
             module_map: GcRefCell::default(),
+            json_module_map: GcRefCell::default(),
         })
     }
 
@@ -287,14 +456,38 @@ impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         let result = (|| {
+            let specifier = request.specifier();
             let short_path = specifier.to_std_string_escaped();
+            let module_type = request.module_type()?;
             let path =
-                resolve_module_specifier(Some(&self.root), &specifier, referrer.path(), context)?;
+                resolve_module_specifier(Some(&self.root), specifier, referrer.path(), context)?;
+
+            if module_type == ModuleType::Json {
+                if let Some(module) = self.json_module_map.borrow().get(&path) {
+                    return Ok(module.clone());
+                }
+
+                let json = std::fs::read_to_string(&path).map_err(|err| {
+                    JsNativeError::typ()
+                        .with_message(format!("could not open file `{short_path}`"))
+                        .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                })?;
+                let module = Module::parse_json(js_string!(json), context).map_err(|err| {
+                    JsNativeError::syntax()
+                        .with_message(format!("could not parse JSON module `{short_path}`"))
+                        .with_cause(err)
+                })?;
+                self.json_module_map
+                    .borrow_mut()
+                    .insert(path, module.clone());
+                return Ok(module);
+            }
+
             if let Some(module) = self.get(&path) {
                 return Ok(module);
             }
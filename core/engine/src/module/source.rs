@@ -33,7 +33,8 @@ use crate::{
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
+    loader::validate_import_attributes, BindingName, GraphLoadingState, Module, ModuleRequest,
+    Referrer, ResolveExportError, ResolvedBinding,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -209,7 +210,7 @@ impl std::fmt::Debug for SourceTextContext {
 #[derive(Trace, Finalize)]
 pub(crate) struct SourceTextModule {
     status: GcRefCell<ModuleStatus>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     async_parent_modules: GcRefCell<Vec<Module>>,
     import_meta: GcRefCell<Option<JsObject>>,
     #[unsafe_ignore_trace]
@@ -230,12 +231,12 @@ impl std::fmt::Debug for SourceTextModule {
 #[derive(Debug)]
 struct ModuleCode {
     has_tla: bool,
-    requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    requested_modules: IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
-    star_export_entries: Vec<JsString>,
+    star_export_entries: Vec<ModuleRequest>,
 }
 
 impl SourceTextModule {
@@ -250,7 +251,7 @@ impl SourceTextModule {
             .items()
             .requests()
             .iter()
-            .map(|name| name.to_js_string(interner))
+            .map(|request| ModuleRequest::from_ast(request, interner))
             .collect();
         // 4. Let importEntries be ImportEntries of body.
         let import_entries = code.items().import_entries();
@@ -275,7 +276,7 @@ impl SourceTextModule {
                     if let Some((module, import)) =
                         import_entries.iter().find_map(|ie| match ie.import_name() {
                             ImportName::Name(name) if ie.local_name() == entry.local_name() => {
-                                Some((ie.module_request(), name))
+                                Some((ie.module_request().clone(), name))
                             }
                             _ => None,
                         })
@@ -304,7 +305,7 @@ impl SourceTextModule {
                 ExportEntry::StarReExport { module_request } => {
                     // i. Assert: ee.[[ExportName]] is null.
                     // ii. Append ee to starExportEntries.
-                    star_export_entries.push(module_request.to_js_string(interner));
+                    star_export_entries.push(ModuleRequest::from_ast(&module_request, interner));
                 }
                 // c. Else,
                 //    i. Append ee to indirectExportEntries.
@@ -366,86 +367,96 @@ impl SourceTextModule {
             state
                 .pending_modules
                 .set(state.pending_modules.get() + requested.len());
-            // d. For each String required of module.[[RequestedModules]], do
+            // d. For each ModuleRequest Record required of module.[[RequestedModules]], do
             for required in requested.iter().cloned() {
-                // i. If module.[[LoadedModules]] contains a Record whose [[Specifier]] is required, then
+                // i. If module.[[LoadedModules]] contains a LoadedModuleRequest Record record such that
+                //    ModuleRequestsEqual(record, required) is true, then
                 let loaded = self.loaded_modules.borrow().get(&required).cloned();
                 if let Some(loaded) = loaded {
-                    // 1. Let record be that Record.
-                    // 2. Perform InnerModuleLoading(state, record.[[Module]]).
+                    // 1. Perform InnerModuleLoading(state, record.[[Module]]).
                     loaded.inner_load(state, context);
                 } else {
                     //    ii. Else,
                     //       1. Perform HostLoadImportedModule(module, required, state.[[HostDefined]], state).
                     //       2. NOTE: HostLoadImportedModule will call FinishLoadingImportedModule, which re-enters
                     //          the graph loading process through ContinueModuleLoading.
-                    let name_specifier = required.clone();
+                    let request = required.clone();
                     let src = module_self.clone();
                     let state = state.clone();
-                    context.module_loader().load_imported_module(
-                        Referrer::Module(module_self.clone()),
-                        name_specifier,
-                        Box::new(move |completion, context| {
-                            // FinishLoadingImportedModule ( referrer, specifier, payload, result )
-                            // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
-
-                            // 1. If result is a normal completion, then
-                            if let Ok(loaded) = &completion {
-                                let ModuleKind::SourceText(src) = src.kind() else {
-                                    unreachable!("captured src must be a source text module");
-                                };
-                                // a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
-                                // b. Else,
-                                //    i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
-                                let mut loaded_modules = src.loaded_modules.borrow_mut();
-                                let entry = loaded_modules
-                                    .entry(required)
-                                    .or_insert_with(|| loaded.clone());
-
-                                //    i. Assert: That Record's [[Module]] is result.[[Value]].
-                                assert_eq!(entry, loaded);
-                            }
+                    #[allow(clippy::type_complexity)]
+                    let finish_load: Box<
+                        dyn FnOnce(JsResult<Module>, &mut Context),
+                    > = Box::new(move |completion, context| {
+                        // FinishLoadingImportedModule ( referrer, specifier, payload, result )
+                        // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
+
+                        // 1. If result is a normal completion, then
+                        if let Ok(loaded) = &completion {
+                            let ModuleKind::SourceText(src) = src.kind() else {
+                                unreachable!("captured src must be a source text module");
+                            };
+                            // a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
+                            // b. Else,
+                            //    i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
+                            let mut loaded_modules = src.loaded_modules.borrow_mut();
+                            let entry = loaded_modules
+                                .entry(required)
+                                .or_insert_with(|| loaded.clone());
+
+                            //    i. Assert: That Record's [[Module]] is result.[[Value]].
+                            assert_eq!(entry, loaded);
+                        }
 
-                            // 2. If payload is a GraphLoadingState Record, then
-                            //    a. Perform ContinueModuleLoading(payload, result).
+                        // 2. If payload is a GraphLoadingState Record, then
+                        //    a. Perform ContinueModuleLoading(payload, result).
 
-                            // Abstract operation `ContinueModuleLoading ( state, moduleCompletion )`.
-                            //
-                            // https://tc39.es/ecma262/#sec-ContinueModuleLoading
+                        // Abstract operation `ContinueModuleLoading ( state, moduleCompletion )`.
+                        //
+                        // https://tc39.es/ecma262/#sec-ContinueModuleLoading
 
-                            // 1. If state.[[IsLoading]] is false, return unused.
-                            if !state.loading.get() {
-                                return;
-                            }
+                        // 1. If state.[[IsLoading]] is false, return unused.
+                        if !state.loading.get() {
+                            return;
+                        }
 
-                            // 2. If moduleCompletion is a normal completion, then
-                            match completion {
-                                Ok(m) => {
-                                    // a. Perform InnerModuleLoading(state, moduleCompletion.[[Value]]).
-                                    m.inner_load(&state, context);
-                                }
-                                // 3. Else,
-                                Err(err) => {
-                                    // a. Set state.[[IsLoading]] to false.
-                                    state.loading.set(false);
-
-                                    // b. Perform ! Call(state.[[PromiseCapability]].[[Reject]], undefined, « moduleCompletion.[[Value]] »).
-                                    state
-                                        .capability
-                                        .reject()
-                                        .call(
-                                            &JsValue::undefined(),
-                                            &[err.to_opaque(context)],
-                                            context,
-                                        )
-                                        .expect("cannot fail for the default reject function");
-                                }
+                        // 2. If moduleCompletion is a normal completion, then
+                        match completion {
+                            Ok(m) => {
+                                // a. Perform InnerModuleLoading(state, moduleCompletion.[[Value]]).
+                                m.inner_load(&state, context);
                             }
+                            // 3. Else,
+                            Err(err) => {
+                                // a. Set state.[[IsLoading]] to false.
+                                state.loading.set(false);
+
+                                // b. Perform ! Call(state.[[PromiseCapability]].[[Reject]], undefined, « moduleCompletion.[[Value]] »).
+                                state
+                                    .capability
+                                    .reject()
+                                    .call(&JsValue::undefined(), &[err.to_opaque(context)], context)
+                                    .expect("cannot fail for the default reject function");
+                            }
+                        }
 
-                            // 4. Return unused.
-                        }),
-                        context,
-                    );
+                        // 4. Return unused.
+                    });
+
+                    // Note: the check for unsupported attributes is done before loading, since
+                    //       the loaded modules can never contain an unsupported request.
+                    // If AllImportAttributesSupported(required.[[Attributes]]) is false, then
+                    if let Err(err) = validate_import_attributes(&request) {
+                        // 1. Let error be ThrowCompletion(a newly created SyntaxError object).
+                        // 2. Perform ContinueModuleLoading(state, error).
+                        finish_load(Err(err), context);
+                    } else {
+                        context.module_loader().load_imported_module(
+                            Referrer::Module(module_self.clone()),
+                            request,
+                            finish_load,
+                            context,
+                        );
+                    }
                 }
                 // iii. If state.[[IsLoading]] is false, return unused.
                 if !state.loading.get() {
@@ -560,7 +571,7 @@ impl SourceTextModule {
             // a. If SameValue(exportName, e.[[ExportName]]) is true, then
             if export_name == &e.export_name().to_js_string(interner) {
                 // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
-                let module_request = e.module_request().to_js_string(interner);
+                let module_request = ModuleRequest::from_ast(e.module_request(), interner);
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
                 return match e.import_name() {
                     // ii. If e.[[ImportName]] is all, then
@@ -1444,7 +1455,8 @@ impl SourceTextModule {
             // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
             for entry in &self.code.import_entries {
                 // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
-                let module_request = entry.module_request().to_js_string(compiler.interner());
+                let module_request =
+                    ModuleRequest::from_ast(entry.module_request(), compiler.interner());
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
 
                 if let ImportName::Name(name) = entry.import_name() {
@@ -1769,7 +1781,7 @@ impl SourceTextModule {
     }
 
     /// Gets the loaded modules of this module.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.loaded_modules
     }
 
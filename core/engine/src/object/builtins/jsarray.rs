@@ -2,7 +2,7 @@
 use crate::{
     builtins::Array,
     error::JsNativeError,
-    object::{JsFunction, JsObject},
+    object::{JsFunction, JsObject, JsPromise},
     value::{IntoOrUndefined, TryFromJs},
     Context, JsResult, JsString, JsValue,
 };
@@ -35,6 +35,54 @@ impl JsArray {
         }
     }
 
+    /// Creates an array from the values of an async iterable, an iterable or an array-like
+    /// object, returning a promise that resolves to the new array.
+    ///
+    /// Same as `Array.fromAsync(items)` in JavaScript. The promise is settled once the pending
+    /// jobs of the context are run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #     builtins::promise::PromiseState, js_string, object::builtins::JsArray, Context,
+    /// #     JsResult, Source,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// let context = &mut Context::default();
+    /// let iterable = context.eval(Source::from_bytes(
+    ///     "(async function* () { yield 1; yield 2; yield 3; })()",
+    /// ))?;
+    ///
+    /// let promise = JsArray::from_async_iterable(&iterable, context)?;
+    /// context.run_jobs();
+    ///
+    /// let PromiseState::Fulfilled(array) = promise.state() else {
+    ///     panic!("promise should be fulfilled");
+    /// };
+    /// let array = JsArray::from_object(array.as_object().unwrap().clone())?;
+    /// assert_eq!(array.join(None, context)?, js_string!("1,2,3"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_async_iterable(items: &JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .array()
+            .constructor()
+            .into();
+        let promise = Array::from_async(&constructor, std::slice::from_ref(items), context)?;
+
+        Ok(JsPromise::from_object(
+            promise
+                .as_object()
+                .expect("`Array.fromAsync` must return an object")
+                .clone(),
+        )
+        .expect("`Array.fromAsync` must return a promise"))
+    }
+
     /// Create a [`JsArray`] from a [`JsObject`], if the object is not an array throw a `TypeError`.
     ///
     /// This does not clone the fields of the array, it only does a shallow clone of the object.
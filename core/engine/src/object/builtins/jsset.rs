@@ -141,6 +141,95 @@ impl JsSet {
         )
     }
 
+    /// Returns a new set with the elements of both this set and the set-like `other`.
+    ///
+    /// Same as JavaScript's `set.union(other)`.
+    pub fn union<T>(&self, other: T, context: &mut Context) -> JsResult<Self>
+    where
+        T: Into<JsValue>,
+    {
+        Set::union(&self.inner.clone().into(), &[other.into()], context)
+            .map(Self::from_set_method_result)
+    }
+
+    /// Returns a new set with the elements that are both in this set and in the set-like `other`.
+    ///
+    /// Same as JavaScript's `set.intersection(other)`.
+    pub fn intersection<T>(&self, other: T, context: &mut Context) -> JsResult<Self>
+    where
+        T: Into<JsValue>,
+    {
+        Set::intersection(&self.inner.clone().into(), &[other.into()], context)
+            .map(Self::from_set_method_result)
+    }
+
+    /// Returns a new set with the elements of this set that are not in the set-like `other`.
+    ///
+    /// Same as JavaScript's `set.difference(other)`.
+    pub fn difference<T>(&self, other: T, context: &mut Context) -> JsResult<Self>
+    where
+        T: Into<JsValue>,
+    {
+        Set::difference(&self.inner.clone().into(), &[other.into()], context)
+            .map(Self::from_set_method_result)
+    }
+
+    /// Returns a new set with the elements that are either in this set or in the set-like
+    /// `other`, but not in both.
+    ///
+    /// Same as JavaScript's `set.symmetricDifference(other)`.
+    pub fn symmetric_difference<T>(&self, other: T, context: &mut Context) -> JsResult<Self>
+    where
+        T: Into<JsValue>,
+    {
+        Set::symmetric_difference(&self.inner.clone().into(), &[other.into()], context)
+            .map(Self::from_set_method_result)
+    }
+
+    /// Returns a boolean asserting whether all the elements of this set are in the set-like
+    /// `other`.
+    ///
+    /// Same as JavaScript's `set.isSubsetOf(other)`.
+    pub fn is_subset_of<T>(&self, other: T, context: &mut Context) -> JsResult<bool>
+    where
+        T: Into<JsValue>,
+    {
+        Set::is_subset_of(&self.inner.clone().into(), &[other.into()], context)
+            .map(|result| result.to_boolean())
+    }
+
+    /// Returns a boolean asserting whether all the elements of the set-like `other` are in this
+    /// set.
+    ///
+    /// Same as JavaScript's `set.isSupersetOf(other)`.
+    pub fn is_superset_of<T>(&self, other: T, context: &mut Context) -> JsResult<bool>
+    where
+        T: Into<JsValue>,
+    {
+        Set::is_superset_of(&self.inner.clone().into(), &[other.into()], context)
+            .map(|result| result.to_boolean())
+    }
+
+    /// Returns a boolean asserting whether this set has no elements in common with the set-like
+    /// `other`.
+    ///
+    /// Same as JavaScript's `set.isDisjointFrom(other)`.
+    pub fn is_disjoint_from<T>(&self, other: T, context: &mut Context) -> JsResult<bool>
+    where
+        T: Into<JsValue>,
+    {
+        Set::is_disjoint_from(&self.inner.clone().into(), &[other.into()], context)
+            .map(|result| result.to_boolean())
+    }
+
+    /// Wraps the `Set` object returned by one of the set methods.
+    fn from_set_method_result(result: JsValue) -> Self {
+        let JsValue::Object(inner) = result else {
+            unreachable!("set methods must always return a `Set` object");
+        };
+        Self { inner }
+    }
+
     /// Utility: Creates `JsSet` from `JsObject`, if not a Set throw `TypeError`.
     #[inline]
     pub fn from_object(object: JsObject) -> JsResult<Self> {
@@ -48,7 +48,9 @@ impl JsSharedArrayBuffer {
 
     /// Creates a [`JsSharedArrayBuffer`] from a shared raw buffer.
     #[inline]
-    pub fn from_buffer(buffer: SharedArrayBuffer, context: &mut Context) -> Self {
+    pub fn from_buffer(mut buffer: SharedArrayBuffer, context: &mut Context) -> Self {
+        buffer.report_memory();
+
         let proto = context
             .intrinsics()
             .constructors()
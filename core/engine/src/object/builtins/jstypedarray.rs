@@ -1,6 +1,8 @@
 //! Rust API wrappers for the `TypedArray` Builtin ECMAScript Objects
 use crate::{
-    builtins::typed_array::BuiltinTypedArray,
+    builtins::typed_array::{
+        self, Base64Alphabet, BuiltinTypedArray, LastChunkHandling, Uint8Array,
+    },
     builtins::{typed_array::TypedArray, BuiltInConstructor},
     error::JsNativeError,
     object::{JsArrayBuffer, JsFunction, JsObject},
@@ -1085,6 +1087,136 @@ JsTypedArrayType!(
     to_uint8,
     u8
 );
+impl JsUint8Array {
+    /// Creates a `Uint8Array` from a slice of bytes.
+    pub fn from_bytes(bytes: &[u8], context: &mut Context) -> JsResult<Self> {
+        let object = Uint8Array::from_bytes(bytes, context)?.upcast();
+        Ok(Self {
+            inner: JsTypedArray { inner: object },
+        })
+    }
+
+    /// Creates a `Uint8Array` by decoding a base64 string.
+    ///
+    /// Same as `Uint8Array.fromBase64(string, { alphabet, lastChunkHandling })` in JavaScript.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #     builtins::typed_array::{Base64Alphabet, LastChunkHandling},
+    /// #     js_string, object::builtins::JsUint8Array, Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// let context = &mut Context::default();
+    /// let array = JsUint8Array::from_base64(
+    ///     &js_string!("SGVsbG8="),
+    ///     Base64Alphabet::Base64,
+    ///     LastChunkHandling::Strict,
+    ///     context,
+    /// )?;
+    /// assert_eq!(array.to_vec()?, b"Hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base64(
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let bytes =
+            typed_array::from_base64(string.as_str(), alphabet, last_chunk_handling, usize::MAX)
+                .into_bytes()?;
+        Self::from_bytes(&bytes, context)
+    }
+
+    /// Creates a `Uint8Array` by decoding a hex string.
+    ///
+    /// Same as `Uint8Array.fromHex(string)` in JavaScript.
+    pub fn from_hex(string: &JsString, context: &mut Context) -> JsResult<Self> {
+        let bytes = typed_array::from_hex(string.as_str(), usize::MAX).into_bytes()?;
+        Self::from_bytes(&bytes, context)
+    }
+
+    /// Copies the bytes of the array into a `Vec`.
+    ///
+    /// Throws a `TypeError` if the array is out of bounds of its buffer.
+    pub fn to_vec(&self) -> JsResult<Vec<u8>> {
+        Uint8Array::bytes(&self.typed_array())
+    }
+
+    /// Encodes the bytes of the array as a base64 string.
+    ///
+    /// Same as `array.toBase64({ alphabet, omitPadding })` in JavaScript.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #     builtins::typed_array::Base64Alphabet, js_string, object::builtins::JsUint8Array,
+    /// #     Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// let context = &mut Context::default();
+    /// let array = JsUint8Array::from_bytes(&[0xFB, 0xFF], context)?;
+    /// assert_eq!(array.to_base64(Base64Alphabet::Base64, false)?, js_string!("+/8="));
+    /// assert_eq!(array.to_base64(Base64Alphabet::Base64Url, true)?, js_string!("-_8"));
+    /// assert_eq!(array.to_hex()?, js_string!("fbff"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_base64(&self, alphabet: Base64Alphabet, omit_padding: bool) -> JsResult<JsString> {
+        Ok(typed_array::to_base64(
+            &self.to_vec()?,
+            alphabet,
+            omit_padding,
+        ))
+    }
+
+    /// Encodes the bytes of the array as a lowercase hex string.
+    ///
+    /// Same as `array.toHex()` in JavaScript.
+    pub fn to_hex(&self) -> JsResult<JsString> {
+        Ok(typed_array::to_hex(&self.to_vec()?))
+    }
+
+    /// Decodes a base64 string into the array, returning the number of characters read and the
+    /// number of bytes written.
+    ///
+    /// Same as `array.setFromBase64(string, { alphabet, lastChunkHandling })` in JavaScript.
+    /// On a decoding error, the bytes decoded before the error are still written to the array.
+    pub fn set_from_base64(
+        &self,
+        string: &JsString,
+        alphabet: Base64Alphabet,
+        last_chunk_handling: LastChunkHandling,
+    ) -> JsResult<(usize, usize)> {
+        Uint8Array::set_from(&self.typed_array(), |max_length| {
+            typed_array::from_base64(string.as_str(), alphabet, last_chunk_handling, max_length)
+        })
+    }
+
+    /// Decodes a hex string into the array, returning the number of characters read and the
+    /// number of bytes written.
+    ///
+    /// Same as `array.setFromHex(string)` in JavaScript.
+    /// On a decoding error, the bytes decoded before the error are still written to the array.
+    pub fn set_from_hex(&self, string: &JsString) -> JsResult<(usize, usize)> {
+        Uint8Array::set_from(&self.typed_array(), |max_length| {
+            typed_array::from_hex(string.as_str(), max_length)
+        })
+    }
+
+    fn typed_array(&self) -> JsObject<TypedArray> {
+        self.inner
+            .inner
+            .clone()
+            .downcast::<TypedArray>()
+            .expect("`JsUint8Array` must contain a typed array")
+    }
+}
+
 JsTypedArrayType!(
     JsInt8Array,
     Int8Array,
@@ -1125,6 +1257,14 @@ JsTypedArrayType!(
     to_i32,
     i32
 );
+JsTypedArrayType!(
+    JsFloat16Array,
+    Float16Array,
+    is_typed_float16_array,
+    typed_float16_array,
+    to_f16,
+    half::f16
+);
 JsTypedArrayType!(
     JsFloat32Array,
     Float32Array,
@@ -1162,6 +1302,18 @@ fn typed_iterators_uint32() {
     assert_eq!(vec, vec2);
 }
 
+#[test]
+fn typed_iterators_f16() {
+    let context = &mut Context::default();
+    let vec = [0.1f32, 0.2, 0.3, 0.4, 1.1, 9.99999]
+        .map(half::f16::from_f32)
+        .to_vec();
+
+    let array = JsFloat16Array::from_iter(vec.clone(), context).unwrap();
+    let vec2 = array.iter(context).collect::<Vec<_>>();
+    assert_eq!(vec, vec2);
+}
+
 #[test]
 fn typed_iterators_f32() {
     let context = &mut Context::default();
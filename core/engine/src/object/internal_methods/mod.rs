@@ -16,6 +16,7 @@ use crate::{
     object::JsObject,
     property::{DescriptorKind, PropertyDescriptor, PropertyKey},
     value::JsValue,
+    vm::check_heap_limit,
     Context, JsNativeError, JsResult,
 };
 use boa_profiler::Profiler;
@@ -159,7 +160,13 @@ impl JsObject {
         context: &mut InternalMethodContext<'_>,
     ) -> JsResult<bool> {
         let _timer = Profiler::global().start_event("Object::__define_own_property__", "object");
-        (self.vtable().__define_own_property__)(self, key, desc, context)
+        let result = (self.vtable().__define_own_property__)(self, key, desc, context)?;
+
+        // Defining properties is how native loops grow objects, so the heap limit is checked
+        // here instead of waiting for the next instruction.
+        check_heap_limit()?;
+
+        Ok(result)
     }
 
     /// Internal method `[[hasProperty]]`.
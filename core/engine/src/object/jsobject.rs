@@ -12,6 +12,7 @@ use crate::{
         array::ARRAY_EXOTIC_INTERNAL_METHODS,
         array_buffer::{ArrayBuffer, BufferObject, SharedArrayBuffer},
         object::OrdinaryObject,
+        RegExp,
     },
     context::intrinsics::Intrinsics,
     error::JsNativeError,
@@ -20,7 +21,10 @@ use crate::{
     value::PreferredType,
     Context, JsResult, JsString, JsValue,
 };
-use boa_gc::{self, Finalize, Gc, GcBox, GcRefCell, Trace};
+use boa_gc::{
+    self, Finalize, Gc, GcBox, GcRefCell, HeapEdgeName, HeapNodeDescription, HeapNodeKind, Trace,
+    Tracer,
+};
 use std::{
     cell::RefCell,
     collections::HashMap,
@@ -63,13 +67,120 @@ impl<T: NativeObject + ?Sized> Clone for JsObject<T> {
 // implementation of `Debug` for `JsObject` could easily cause stack overflows,
 // so we have to force our users to debug the `JsObject` instead.
 #[allow(missing_debug_implementations)]
-#[derive(Trace, Finalize)]
+#[derive(Finalize)]
 pub(crate) struct VTableObject<T: NativeObject + ?Sized> {
-    #[unsafe_ignore_trace]
     vtable: &'static InternalObjectMethods,
     object: GcRefCell<Object<T>>,
 }
 
+// SAFETY: `object` is the only field containing garbage collected values, and it is traced.
+unsafe impl<T: NativeObject + ?Sized> Trace for VTableObject<T> {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe {
+            Trace::trace(&self.object, tracer);
+        }
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: The implementor must ensure that `trace_non_roots` is correctly implemented.
+        unsafe {
+            Trace::trace_non_roots(&self.object);
+        }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+        Trace::run_finalizer(&self.object);
+    }
+
+    fn describe(&self, node: &mut HeapNodeDescription) {
+        // Objects that are being mutated are left undescribed.
+        let Ok(object) = self.object.try_borrow() else {
+            return;
+        };
+
+        if self.vtable.__call__ == ORDINARY_INTERNAL_METHODS.__call__ {
+            let kind = if object.data.as_any().is::<RegExp>() {
+                HeapNodeKind::RegExp
+            } else {
+                HeapNodeKind::Object
+            };
+            node.set_kind(kind);
+            node.set_name(constructor_name(&object).map_or_else(
+                || {
+                    // Fall back to the name of the native type, without its path or generics.
+                    let name = object.data.type_name_of_value();
+                    let name = name.split('<').next().unwrap_or(name);
+                    name.rsplit("::").next().unwrap_or(name).to_string()
+                },
+                |name| name.to_std_string_escaped(),
+            ));
+        } else {
+            node.set_kind(HeapNodeKind::Closure);
+            node.set_name(
+                string_property(&object, js_string!("name"))
+                    .map(|name| name.to_std_string_escaped())
+                    .unwrap_or_default(),
+            );
+        }
+
+        if let Some(prototype) = object.prototype() {
+            node.add_edge(
+                HeapEdgeName::Property("__proto__".to_string()),
+                prototype.inner(),
+            );
+        }
+
+        let properties = object.properties();
+        properties.shape.add_heap_snapshot_edge(node);
+        for key in properties.shape.keys() {
+            let Some(property) = properties.get(&key) else {
+                continue;
+            };
+            if let Some(value) = property.value().and_then(JsValue::as_object) {
+                node.add_edge(HeapEdgeName::Property(key.to_string()), value.inner());
+            }
+            if let Some(get) = property.get().and_then(JsValue::as_object) {
+                node.add_edge(HeapEdgeName::Internal(format!("get {key}")), get.inner());
+            }
+            if let Some(set) = property.set().and_then(JsValue::as_object) {
+                node.add_edge(HeapEdgeName::Internal(format!("set {key}")), set.inner());
+            }
+        }
+        for (index, property) in properties.index_properties() {
+            if let Some(value) = property.value().and_then(JsValue::as_object) {
+                node.add_edge(HeapEdgeName::Element(index), value.inner());
+            }
+        }
+    }
+}
+
+/// Returns the value of the own string data property `key` of `object`.
+fn string_property<T: ?Sized>(object: &Object<T>, key: JsString) -> Option<JsString> {
+    object
+        .properties()
+        .get(&PropertyKey::String(key))?
+        .value()?
+        .as_string()
+        .cloned()
+}
+
+/// Returns the name of the constructor of the prototype of `object`, like heap snapshots of
+/// other engines name objects.
+fn constructor_name<T: ?Sized>(object: &Object<T>) -> Option<JsString> {
+    let prototype = object.prototype()?;
+    let prototype = prototype.try_borrow().ok()?;
+    let constructor = prototype
+        .properties()
+        .get(&PropertyKey::String(js_string!("constructor")))?
+        .value()?
+        .as_object()?
+        .clone();
+    let constructor = constructor.try_borrow().ok()?;
+    string_property(&constructor, js_string!("name"))
+}
+
 impl Default for JsObject {
     fn default() -> Self {
         Self::from_proto_and_data(None, OrdinaryObject)
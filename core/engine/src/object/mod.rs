@@ -209,6 +209,11 @@ impl PrivateName {
     pub(crate) const fn new(description: JsString, id: usize) -> Self {
         Self { description, id }
     }
+
+    /// Returns the `[[Description]]` internal slot of the private name.
+    pub(crate) const fn description(&self) -> &JsString {
+        &self.description
+    }
 }
 
 /// The representation of private object elements.
@@ -425,6 +430,17 @@ impl Object<dyn NativeObject> {
         }
     }
 
+    /// Checks if it a `Float16Array` object.
+    #[inline]
+    #[must_use]
+    pub fn is_typed_float16_array(&self) -> bool {
+        if let Some(int) = self.downcast_ref::<TypedArray>() {
+            matches!(int.kind(), TypedArrayKind::Float16)
+        } else {
+            false
+        }
+    }
+
     /// Checks if it a `Float32Array` object.
     #[inline]
     #[must_use]
@@ -1,6 +1,7 @@
 use crate::{
     builtins::{
         function::{set_function_name, BoundFunction, ClassFieldDefinition, OrdinaryFunction},
+        shadow_realm::WrappedFunction,
         Array, Proxy,
     },
     context::intrinsics::{StandardConstructor, StandardConstructors},
@@ -829,6 +830,10 @@ impl JsObject {
             return fun.get_function_realm(context);
         }
 
+        if let Some(wrapped) = constructor.downcast_ref::<WrappedFunction>() {
+            return Ok(wrapped.realm().clone());
+        }
+
         Ok(context.realm().clone())
     }
 
@@ -1076,44 +1081,45 @@ impl JsObject {
         context: &mut Context,
     ) -> JsResult<()> {
         // 2. Let initializer be fieldRecord.[[Initializer]].
-        let initializer = match field_record {
-            ClassFieldDefinition::Public(_, function, _)
-            | ClassFieldDefinition::Private(_, function) => function,
+        let (initializer, initializers) = match field_record {
+            ClassFieldDefinition::Public(_, function, _, initializers)
+            | ClassFieldDefinition::Private(_, function, initializers) => (function, initializers),
         };
 
         // 3. If initializer is not empty, then
-        // a. Let initValue be ? Call(initializer, receiver).
+        // a. Let initValue be ? Call(initializer, receiver).
         // 4. Else, let initValue be undefined.
-        let init_value = initializer.call(&self.clone().into(), &[], context)?;
+        let mut init_value = initializer.call(&self.clone().into(), &[], context)?;
+
+        if let ClassFieldDefinition::Public(_, _, Some(function_name), _) = field_record {
+            if let Some(init_value) = init_value.as_object() {
+                set_function_name(init_value, function_name, None, context);
+            }
+        }
+
+        // 5. For each element initializer of fieldRecord.[[Initializers]], do
+        for initializer in initializers {
+            // a. Set initValue to ? Call(initializer, receiver, « initValue »).
+            init_value = initializer.call(&self.clone().into(), &[init_value], context)?;
+        }
 
         match field_record {
             // 1. Let fieldName be fieldRecord.[[Name]].
-            // 5. If fieldName is a Private Name, then
-            ClassFieldDefinition::Private(field_name, _) => {
-                // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
+            // 6. If fieldName is a Private Name, then
+            ClassFieldDefinition::Private(field_name, _, _) => {
+                // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
                 self.private_field_add(field_name, init_value, context)?;
             }
             // 1. Let fieldName be fieldRecord.[[Name]].
-            // 6. Else,
-            ClassFieldDefinition::Public(field_name, _, function_name) => {
-                if let Some(function_name) = function_name {
-                    set_function_name(
-                        init_value
-                            .as_object()
-                            .expect("init value must be a function object"),
-                        function_name,
-                        None,
-                        context,
-                    );
-                }
-
+            // 7. Else,
+            ClassFieldDefinition::Public(field_name, _, _, _) => {
                 // a. Assert: IsPropertyKey(fieldName) is true.
                 // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
                 self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
             }
         }
 
-        // 7. Return unused.
+        // 8. Return unused.
         Ok(())
     }
 
@@ -1141,14 +1147,21 @@ impl JsObject {
             self.private_method_or_accessor_add(name, method, context)?;
         }
 
-        // 3. Let fields be the value of constructor.[[Fields]].
-        // 4. For each element fieldRecord of fields, do
+        // 3. Let initializers be the value of constructor.[[Initializers]].
+        // 4. For each element initializer of initializers, do
+        for initializer in constructor_function.get_initializers() {
+            // a. Perform ? Call(initializer, O).
+            initializer.call(&self.clone().into(), &[], context)?;
+        }
+
+        // 5. Let fields be the value of constructor.[[Fields]].
+        // 6. For each element fieldRecord of fields, do
         for field_record in constructor_function.get_fields() {
             // a. Perform ? DefineField(O, fieldRecord).
             self.define_field(field_record, context)?;
         }
 
-        // 5. Return unused.
+        // 7. Return unused.
         Ok(())
     }
 
@@ -8,7 +8,7 @@ use super::{
     JsPrototype, ObjectStorage, PropertyDescriptor, PropertyKey,
 };
 use crate::{property::PropertyDescriptorBuilder, JsValue};
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, ExternalMemory, Finalize, Trace};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
 use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
@@ -82,6 +82,16 @@ impl IndexedProperties {
         Self::DenseElement(elements)
     }
 
+    /// Returns the number of bytes allocated by the storage of the indexed properties.
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::DenseI32(vec) => vec.capacity() * size_of::<i32>(),
+            Self::DenseF64(vec) => vec.capacity() * size_of::<f64>(),
+            Self::DenseElement(vec) => vec.capacity() * size_of::<JsValue>(),
+            Self::Sparse(map) => map.capacity() * size_of::<(u32, PropertyDescriptor)>(),
+        }
+    }
+
     /// Get a property descriptor if it exists.
     fn get(&self, key: u32) -> Option<PropertyDescriptor> {
         let value = match self {
@@ -367,6 +377,10 @@ pub struct PropertyMap {
     /// Properties stored with integers as keys.
     pub(crate) indexed_properties: IndexedProperties,
 
+    /// The size of the indexed and named properties storage, reported to the garbage collector
+    /// to count it towards the heap limit.
+    memory: ExternalMemory,
+
     pub(crate) shape: Shape,
     pub(crate) storage: ObjectStorage,
 }
@@ -376,11 +390,24 @@ impl PropertyMap {
     #[must_use]
     #[inline]
     pub fn new(shape: Shape, indexed_properties: IndexedProperties) -> Self {
-        Self {
+        let mut map = Self {
             indexed_properties,
+            memory: ExternalMemory::default(),
             shape,
             storage: Vec::default(),
-        }
+        };
+        map.report_heap_size();
+        map
+    }
+
+    /// Reports the current size of the indexed and named properties storage to the garbage
+    /// collector.
+    ///
+    /// This must be called after replacing the `storage` of the map.
+    pub(crate) fn report_heap_size(&mut self) {
+        let size = self.indexed_properties.heap_size()
+            + self.storage.capacity() * size_of::<JsValue>();
+        self.memory.set(size);
     }
 
     /// Construct a [`PropertyMap`] from with the given prototype with an unique [`Shape`].
@@ -389,6 +416,7 @@ impl PropertyMap {
     pub fn from_prototype_unique_shape(prototype: JsPrototype) -> Self {
         Self {
             indexed_properties: IndexedProperties::default(),
+            memory: ExternalMemory::default(),
             shape: UniqueShape::new(prototype, PropertyTableInner::default()).into(),
             storage: Vec::default(),
         }
@@ -404,6 +432,7 @@ impl PropertyMap {
         let shape = root_shape.shape().change_prototype_transition(prototype);
         Self {
             indexed_properties: IndexedProperties::default(),
+            memory: ExternalMemory::default(),
             shape: shape.into(),
             storage: Vec::default(),
         }
@@ -480,7 +509,9 @@ impl PropertyMap {
         out_slot: &mut Slot,
     ) -> bool {
         if let PropertyKey::Index(index) = key {
-            return self.indexed_properties.insert(index.get(), property);
+            let existed = self.indexed_properties.insert(index.get(), property);
+            self.report_heap_size();
+            return existed;
         }
 
         let attributes = property.to_slot_attributes();
@@ -505,6 +536,7 @@ impl PropertyMap {
                         self.storage.insert(index, JsValue::undefined());
                     }
                 }
+                self.report_heap_size();
             }
 
             if attributes.is_accessor_descriptor() {
@@ -569,6 +601,7 @@ impl PropertyMap {
             self.storage
                 .push(property.value().cloned().unwrap_or_default());
         }
+        self.report_heap_size();
 
         false
     }
@@ -576,7 +609,9 @@ impl PropertyMap {
     /// Remove the property with the given key from the [`PropertyMap`].
     pub fn remove(&mut self, key: &PropertyKey) -> bool {
         if let PropertyKey::Index(index) = key {
-            return self.indexed_properties.remove(index.get());
+            let removed = self.indexed_properties.remove(index.get());
+            self.report_heap_size();
+            return removed;
         }
         if let Some(slot) = self.shape.lookup(key) {
             // shift all elements when removing.
@@ -584,6 +619,7 @@ impl PropertyMap {
                 self.storage.remove(slot.index as usize + 1);
             }
             self.storage.remove(slot.index as usize);
+            self.report_heap_size();
 
             self.shape = self.shape.remove_property_transition(key);
             return true;
@@ -595,6 +631,7 @@ impl PropertyMap {
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: ThinVec<JsValue>) {
         self.indexed_properties = IndexedProperties::DenseElement(properties);
+        self.report_heap_size();
     }
 
     pub(crate) fn get_dense_property(&self, index: u32) -> Option<JsValue> {
@@ -635,6 +672,7 @@ impl PropertyMap {
                             .collect::<ThinVec<_>>();
                         properties[index] = *value;
                         self.indexed_properties = IndexedProperties::DenseF64(properties);
+                        self.report_heap_size();
 
                         return true;
                     }
@@ -646,6 +684,7 @@ impl PropertyMap {
                             .collect::<ThinVec<_>>();
                         properties[index] = value.clone();
                         self.indexed_properties = IndexedProperties::DenseElement(properties);
+                        self.report_heap_size();
 
                         return true;
                     }
@@ -667,6 +706,7 @@ impl PropertyMap {
                         .collect::<ThinVec<_>>();
                     properties[index] = value.clone();
                     self.indexed_properties = IndexedProperties::DenseElement(properties);
+                    self.report_heap_size();
                     return true;
                 };
 
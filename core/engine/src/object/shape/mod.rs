@@ -12,7 +12,7 @@ pub(crate) use unique_shape::UniqueShape;
 
 use std::fmt::Debug;
 
-use boa_gc::{Finalize, Trace};
+use boa_gc::{Finalize, HeapNodeDescription, Trace};
 
 use crate::property::PropertyKey;
 
@@ -212,6 +212,20 @@ impl Shape {
             Inner::Unique(shape) => shape.to_addr_usize(),
         }
     }
+
+    /// Adds an edge from a heap snapshot node to the [`Shape`].
+    pub(crate) fn add_heap_snapshot_edge(&self, node: &mut HeapNodeDescription) {
+        match &self.inner {
+            Inner::Shared(shape) => shape.add_heap_snapshot_edge(node),
+            Inner::Unique(shape) => shape.add_heap_snapshot_edge(node),
+        }
+    }
+}
+
+/// Returns the name of a shape with the given keys in heap snapshots.
+fn heap_snapshot_name(keys: &[PropertyKey]) -> String {
+    let keys = keys.iter().map(ToString::to_string).collect::<Vec<_>>();
+    format!("(object shape) {{{}}}", keys.join(", "))
 }
 
 impl From<UniqueShape> for Shape {
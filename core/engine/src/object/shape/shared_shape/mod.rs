@@ -7,7 +7,10 @@ mod tests;
 use std::{collections::hash_map::RandomState, hash::Hash};
 
 use bitflags::bitflags;
-use boa_gc::{empty_trace, Finalize, Gc, Trace, WeakGc};
+use boa_gc::{
+    custom_trace, empty_trace, Finalize, Gc, HeapEdgeName, HeapNodeDescription, HeapNodeKind,
+    Trace, WeakGc,
+};
 use indexmap::IndexMap;
 
 use crate::{object::JsPrototype, property::PropertyKey, JsObject};
@@ -85,7 +88,7 @@ unsafe impl Trace for ShapeFlags {
 }
 
 /// The internal representation of a [`SharedShape`].
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Finalize)]
 struct Inner {
     /// See [`ForwardTransition`].
     forward_transitions: ForwardTransition,
@@ -96,9 +99,6 @@ struct Inner {
     /// Instance prototype `__proto__`.
     prototype: JsPrototype,
 
-    // SAFETY: This is safe because nothing in [`PropertyTable`]
-    //         needs tracing
-    #[unsafe_ignore_trace]
     property_table: PropertyTable,
 
     /// The previous shape in the transition chain.
@@ -113,6 +113,37 @@ struct Inner {
     flags: ShapeFlags,
 }
 
+// SAFETY: The fields that aren't traced don't contain garbage collected values.
+unsafe impl Trace for Inner {
+    custom_trace!(this, mark, {
+        mark(&this.forward_transitions);
+        mark(&this.prototype);
+        mark(&this.previous);
+        mark(&this.flags);
+    });
+
+    fn describe(&self, node: &mut HeapNodeDescription) {
+        node.set_kind(HeapNodeKind::ObjectShape);
+        if let Ok(property_table) = self.property_table.inner().try_borrow() {
+            node.set_name(super::heap_snapshot_name(
+                &property_table.keys_cloned_n(self.property_count),
+            ));
+        }
+        if let Some(prototype) = &self.prototype {
+            node.add_edge(
+                HeapEdgeName::Internal("prototype".to_string()),
+                prototype.inner(),
+            );
+        }
+        if let Some(previous) = &self.previous {
+            node.add_edge(
+                HeapEdgeName::Internal("previous".to_string()),
+                &previous.inner,
+            );
+        }
+    }
+}
+
 /// Represents a shared object shape.
 #[derive(Debug, Trace, Finalize, Clone)]
 pub struct SharedShape {
@@ -480,6 +511,11 @@ impl SharedShape {
         let ptr: *const _ = self.inner.as_ref();
         ptr as usize
     }
+
+    /// Adds an edge from a heap snapshot node to the [`SharedShape`].
+    pub(crate) fn add_heap_snapshot_edge(&self, node: &mut HeapNodeDescription) {
+        node.add_edge(HeapEdgeName::Internal("shape".to_string()), &self.inner);
+    }
 }
 
 /// Represents a weak reference to [`SharedShape`].
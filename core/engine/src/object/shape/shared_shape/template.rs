@@ -117,6 +117,7 @@ impl ObjectTemplate {
         };
 
         object.properties.storage = storage;
+        object.properties.report_heap_size();
 
         JsObject::from_object_and_vtable(object, internal_methods)
     }
@@ -140,6 +141,7 @@ impl ObjectTemplate {
         };
 
         object.properties.storage = storage;
+        object.properties.report_heap_size();
 
         JsObject::from_object_and_vtable(object, internal_methods)
     }
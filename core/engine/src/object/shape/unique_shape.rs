@@ -1,6 +1,9 @@
 use std::{cell::RefCell, fmt::Debug};
 
-use boa_gc::{Finalize, Gc, GcRefCell, Trace, WeakGc};
+use boa_gc::{
+    custom_trace, Finalize, Gc, GcRefCell, HeapEdgeName, HeapNodeDescription, HeapNodeKind, Trace,
+    WeakGc,
+};
 
 use crate::property::PropertyKey;
 
@@ -10,18 +13,35 @@ use super::{
 };
 
 /// The internal representation of [`UniqueShape`].
-#[derive(Default, Debug, Trace, Finalize)]
+#[derive(Default, Debug, Finalize)]
 struct Inner {
     /// The property table that maps a [`PropertyKey`] to a slot in the objects storage.
-    //
-    // SAFETY: This is safe becasue nothing in this field needs tracing.
-    #[unsafe_ignore_trace]
     property_table: RefCell<PropertyTableInner>,
 
     /// The prototype of the shape.
     prototype: GcRefCell<JsPrototype>,
 }
 
+// SAFETY: This is safe becasue nothing in `property_table` needs tracing.
+unsafe impl Trace for Inner {
+    custom_trace!(this, mark, mark(&this.prototype));
+
+    fn describe(&self, node: &mut HeapNodeDescription) {
+        node.set_kind(HeapNodeKind::ObjectShape);
+        if let Ok(property_table) = self.property_table.try_borrow() {
+            node.set_name(super::heap_snapshot_name(&property_table.keys()));
+        }
+        if let Ok(prototype) = self.prototype.try_borrow() {
+            if let Some(prototype) = &*prototype {
+                node.add_edge(
+                    HeapEdgeName::Internal("prototype".to_string()),
+                    prototype.inner(),
+                );
+            }
+        }
+    }
+}
+
 /// Represents a [`Shape`] that is not shared with any other object.
 ///
 /// This is useful for objects that are inherently unique like,
@@ -238,6 +258,11 @@ impl UniqueShape {
         let ptr: *const _ = self.inner.as_ref();
         ptr as usize
     }
+
+    /// Adds an edge from a heap snapshot node to the [`UniqueShape`].
+    pub(crate) fn add_heap_snapshot_edge(&self, node: &mut HeapNodeDescription) {
+        node.add_edge(HeapEdgeName::Internal("shape".to_string()), &self.inner);
+    }
 }
 
 /// Represents a weak reference to [`UniqueShape`].
@@ -1,4 +1,5 @@
 use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_gc::{HeapEdgeName, HeapNodeKind, HeapSnapshotNode};
 use indoc::indoc;
 
 #[test]
@@ -38,3 +39,55 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn heap_snapshot_describes_objects() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class Point {
+                constructor() {
+                    this.x = {};
+                    this.y = [1];
+                }
+            }
+            var point = new Point();
+        "#}),
+        TestAction::inspect_context(|_| {
+            let snapshot = boa_gc::heap_snapshot();
+            let nodes = snapshot.nodes();
+            let edge = |node: &HeapSnapshotNode, name: &HeapEdgeName| {
+                let edge = node
+                    .edges()
+                    .iter()
+                    .find(|edge| edge.name() == name)
+                    .expect("edge should exist");
+                &nodes[edge.to()]
+            };
+
+            let point = nodes
+                .iter()
+                .find(|node| node.name() == "Point" && node.kind() == HeapNodeKind::Object)
+                .expect("the instance should be in the snapshot");
+            assert_eq!(
+                edge(point, &HeapEdgeName::Property("x".to_string())).name(),
+                "Object"
+            );
+            assert_eq!(
+                edge(point, &HeapEdgeName::Property("y".to_string())).name(),
+                "Array"
+            );
+
+            let shape = edge(point, &HeapEdgeName::Internal("shape".to_string()));
+            assert_eq!(shape.kind(), HeapNodeKind::ObjectShape);
+            assert_eq!(shape.name(), "(object shape) {x, y}");
+
+            let prototype = edge(point, &HeapEdgeName::Property("__proto__".to_string()));
+            let constructor = edge(
+                prototype,
+                &HeapEdgeName::Property("constructor".to_string()),
+            );
+            assert_eq!(constructor.kind(), HeapNodeKind::Closure);
+            assert_eq!(constructor.name(), "Point");
+        }),
+    ]);
+}
@@ -11,6 +11,8 @@ use std::any::TypeId;
 use boa_ast::scope::Scope;
 use rustc_hash::FxHashMap;
 
+#[cfg(feature = "annex-b")]
+use crate::builtins::regexp::LegacyRegExpStatics;
 use crate::{
     class::Class,
     context::{
@@ -18,9 +20,9 @@ use crate::{
         HostHooks,
     },
     environments::DeclarativeEnvironment,
-    module::Module,
+    module::{Module, ModuleRequest},
     object::shape::RootShape,
-    HostDefined, JsNativeError, JsObject, JsResult, JsString,
+    HostDefined, JsNativeError, JsObject, JsResult,
 };
 use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
 use boa_profiler::Profiler;
@@ -68,9 +70,12 @@ struct Inner {
     global_object: JsObject,
     global_this: JsObject,
     template_map: GcRefCell<FxHashMap<u64, JsObject>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_classes: GcRefCell<FxHashMap<TypeId, StandardConstructor>>,
 
+    #[cfg(feature = "annex-b")]
+    legacy_regexp_statics: GcRefCell<LegacyRegExpStatics>,
+
     host_defined: GcRefCell<HostDefined>,
 }
 
@@ -101,6 +106,8 @@ impl Realm {
                 template_map: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_classes: GcRefCell::default(),
+                #[cfg(feature = "annex-b")]
+                legacy_regexp_statics: GcRefCell::default(),
                 host_defined: GcRefCell::default(),
             }),
         };
@@ -182,10 +189,16 @@ impl Realm {
         &self.inner.global_this
     }
 
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
+    /// Returns the legacy static properties of this realm's `%RegExp%`.
+    #[cfg(feature = "annex-b")]
+    pub(crate) fn legacy_regexp_statics(&self) -> &GcRefCell<LegacyRegExpStatics> {
+        &self.inner.legacy_regexp_statics
+    }
+
     /// Resizes the number of bindings on the global environment.
     pub(crate) fn resize_global_env(&self) {
         let binding_number = self.scope().num_bindings();
@@ -19,9 +19,10 @@ use boa_profiler::Profiler;
 use crate::{
     bytecompiler::{global_declaration_instantiation_context, ByteCompiler},
     js_string,
+    module::ModuleRequest,
     realm::Realm,
     vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
-    Context, HostDefined, JsResult, JsString, JsValue, Module,
+    Context, HostDefined, JsResult, JsValue, Module,
 };
 
 /// ECMAScript's [**Script Record**][spec].
@@ -48,7 +49,7 @@ struct Inner {
     #[unsafe_ignore_trace]
     source: boa_ast::Script,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_defined: HostDefined,
     path: Option<PathBuf>,
 }
@@ -69,7 +70,7 @@ impl Script {
     }
 
     /// Gets the loaded modules of this script.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
@@ -26,14 +26,15 @@ use crate::{
     string::{JsString, StaticJsStrings},
     tagged::{Tagged, UnwrappedTagged},
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{ExternalKey, Finalize, Trace};
 
 use boa_macros::{js_str, JsData};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use std::{
     hash::{Hash, Hasher},
-    sync::{atomic::Ordering, Arc},
+    mem::ManuallyDrop,
+    sync::{atomic::Ordering, Arc, Weak},
 };
 
 use portable_atomic::AtomicU64;
@@ -61,12 +62,15 @@ fn get_id() -> Option<u64> {
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 enum WellKnown {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
     Match,
     MatchAll,
+    Metadata,
     Replace,
     Search,
     Species,
@@ -79,12 +83,15 @@ enum WellKnown {
 impl WellKnown {
     const fn description(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::SYMBOL_ITERATOR,
             Self::Match => StaticJsStrings::SYMBOL_MATCH,
             Self::MatchAll => StaticJsStrings::SYMBOL_MATCH_ALL,
+            Self::Metadata => StaticJsStrings::SYMBOL_METADATA,
             Self::Replace => StaticJsStrings::SYMBOL_REPLACE,
             Self::Search => StaticJsStrings::SYMBOL_SEARCH,
             Self::Species => StaticJsStrings::SYMBOL_SPECIES,
@@ -97,12 +104,15 @@ impl WellKnown {
 
     const fn fn_name(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::FN_SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::FN_SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::FN_SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::FN_SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::FN_SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::FN_SYMBOL_ITERATOR,
             Self::Match => StaticJsStrings::FN_SYMBOL_MATCH,
             Self::MatchAll => StaticJsStrings::FN_SYMBOL_MATCH_ALL,
+            Self::Metadata => StaticJsStrings::FN_SYMBOL_METADATA,
             Self::Replace => StaticJsStrings::FN_SYMBOL_REPLACE,
             Self::Search => StaticJsStrings::FN_SYMBOL_SEARCH,
             Self::Species => StaticJsStrings::FN_SYMBOL_SPECIES,
@@ -249,8 +259,12 @@ impl JsSymbol {
     }
 
     well_known_symbols! {
+        /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
+        (async_dispose, WellKnown::AsyncDispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
+        /// Gets the static `JsSymbol` for `"Symbol.dispose"`.
+        (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
         (has_instance, WellKnown::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
@@ -261,6 +275,8 @@ impl JsSymbol {
         (r#match, WellKnown::Match),
         /// Gets the static `JsSymbol` for `"Symbol.matchAll"`.
         (match_all, WellKnown::MatchAll),
+        /// Gets the static `JsSymbol` for `"Symbol.metadata"`.
+        (metadata, WellKnown::Metadata),
         /// Gets the static `JsSymbol` for `"Symbol.replace"`.
         (replace, WellKnown::Replace),
         /// Gets the static `JsSymbol` for `"Symbol.search"`.
@@ -278,6 +294,108 @@ impl JsSymbol {
     }
 }
 
+/// A weak reference to a [`JsSymbol`], which doesn't keep the symbol alive.
+///
+/// Well-known symbols are never dropped, so weak references to them can always be upgraded.
+#[derive(Trace, Finalize)]
+// SAFETY: A weak reference to a symbol does not contain any objects which need to be traced.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct WeakJsSymbol {
+    repr: Tagged<Inner>,
+}
+
+impl JsSymbol {
+    /// Creates a weak reference to this symbol.
+    pub(crate) fn downgrade(&self) -> WeakJsSymbol {
+        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
+            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+            // that came from an `Arc::into_raw` call.
+            unsafe {
+                let arc = ManuallyDrop::new(Arc::from_raw(ptr.as_ptr().cast_const()));
+                // Only need to increment the weak count, since the pointer returned by
+                // `Weak::into_raw` is the same one returned by `Arc::into_raw`.
+                std::mem::forget(Arc::downgrade(&arc));
+            }
+        }
+        WeakJsSymbol { repr: self.repr }
+    }
+}
+
+impl WeakJsSymbol {
+    /// Gets the symbol referenced by this weak reference, or `None` if it was already dropped.
+    pub(crate) fn upgrade(&self) -> Option<JsSymbol> {
+        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
+            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+            // that came from a `Weak::into_raw` call.
+            let weak = ManuallyDrop::new(unsafe { Weak::from_raw(ptr.as_ptr().cast_const()) });
+            let arc = weak.upgrade()?;
+            return Some(JsSymbol {
+                // SAFETY: Pointers returned by `Arc::into_raw` must be non-null.
+                repr: unsafe { Tagged::from_ptr(Arc::into_raw(arc).cast_mut()) },
+            });
+        }
+        Some(JsSymbol { repr: self.repr })
+    }
+}
+
+impl ExternalKey for WeakJsSymbol {
+    fn is_alive(&self) -> bool {
+        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
+            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+            // that came from a `Weak::into_raw` call.
+            let weak = ManuallyDrop::new(unsafe { Weak::from_raw(ptr.as_ptr().cast_const()) });
+            return weak.strong_count() > 0;
+        }
+        true
+    }
+}
+
+impl Clone for WeakJsSymbol {
+    fn clone(&self) -> Self {
+        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
+            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+            // that came from a `Weak::into_raw` call.
+            unsafe {
+                let weak = ManuallyDrop::new(Weak::from_raw(ptr.as_ptr().cast_const()));
+                std::mem::forget(Weak::clone(&weak));
+            }
+        }
+        Self { repr: self.repr }
+    }
+}
+
+impl Drop for WeakJsSymbol {
+    fn drop(&mut self) {
+        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
+            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+            // that came from a `Weak::into_raw` call.
+            unsafe { drop(Weak::from_raw(ptr.as_ptr().cast_const())) }
+        }
+    }
+}
+
+// The allocation of a symbol outlives its weak references, so its address cannot be reused by
+// another symbol while they are compared.
+impl Eq for WeakJsSymbol {}
+
+impl PartialEq for WeakJsSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr.addr() == other.repr.addr()
+    }
+}
+
+impl Hash for WeakJsSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.addr().hash(state);
+    }
+}
+
+impl std::fmt::Debug for WeakJsSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeakJsSymbol").finish_non_exhaustive()
+    }
+}
+
 impl Clone for JsSymbol {
     fn clone(&self) -> Self {
         if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
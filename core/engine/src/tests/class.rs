@@ -1,4 +1,4 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -36,3 +36,140 @@ fn class_field_initializer_name() {
         TestAction::assert_eq("c.c.name", js_str!("#c")),
     ]);
 }
+
+#[test]
+fn class_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function logged(value, { kind, name, addInitializer }) {
+                log.push(`${kind} ${name}`);
+                addInitializer(function () { log.push(`init ${name}`); });
+                if (kind === "class") {
+                    return class extends value { decorated = true };
+                }
+            }
+
+            @logged
+            class C {
+                @logged m() {}
+                @logged static s() {}
+            }
+            let c = new C();
+        "#}),
+        TestAction::assert_eq(
+            "log.join()",
+            js_str!("method s,method m,init s,class C,init C,init m"),
+        ),
+        TestAction::assert("c.decorated"),
+        TestAction::assert_eq("Object.getPrototypeOf(C).name", js_str!("C")),
+    ]);
+}
+
+#[test]
+fn class_element_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function twice(value, { kind }) {
+                switch (kind) {
+                    case "method":
+                        return function () { return value.call(this) * 2; };
+                    case "field":
+                        return (v) => v * 2;
+                    case "accessor":
+                        return { init: (v) => v * 2 };
+                }
+            }
+
+            class C {
+                @twice m() { return 1; }
+                @twice x = 2;
+                @twice #y = 3;
+                @twice accessor z = 4;
+                @twice static w = 5;
+                get y() { return this.#y; }
+            }
+            let c = new C();
+        "#}),
+        TestAction::assert_eq("c.m()", 2),
+        TestAction::assert_eq("c.x", 4),
+        TestAction::assert_eq("c.y", 6),
+        TestAction::assert_eq("c.z", 8),
+        TestAction::assert_eq("C.w", 10),
+    ]);
+}
+
+#[test]
+fn class_auto_accessors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class C {
+                accessor a = 1;
+                static accessor #b = 2;
+                static b() { return C.#b; }
+            }
+            let c = new C();
+            c.a = 3;
+            let desc = Object.getOwnPropertyDescriptor(C.prototype, "a");
+        "#}),
+        TestAction::assert_eq("c.a", 3),
+        TestAction::assert_eq("C.b()", 2),
+        TestAction::assert("!Object.hasOwn(c, 'a')"),
+        TestAction::assert_eq("desc.get.name", js_str!("get a")),
+        TestAction::assert_eq("desc.set.name", js_str!("set a")),
+    ]);
+}
+
+#[test]
+fn class_decorator_metadata_and_access() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let access;
+            function track(value, context) {
+                context.metadata[context.name] = context.kind;
+                if (context.private) {
+                    access = context.access;
+                }
+            }
+
+            @track
+            class A {
+                @track #x = 1;
+            }
+
+            @track
+            class B extends A {}
+        "#}),
+        TestAction::assert_eq("A[Symbol.metadata]['#x']", js_str!("field")),
+        TestAction::assert_eq("A[Symbol.metadata].A", js_str!("class")),
+        TestAction::assert("Object.getPrototypeOf(B[Symbol.metadata]) === A[Symbol.metadata]"),
+        TestAction::assert_eq("access.get(new A())", 1),
+        TestAction::assert("access.has(new A()) && !access.has({})"),
+    ]);
+}
+
+#[test]
+fn class_decorator_errors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "(class { @(1) m() {} })",
+            JsNativeErrorKind::Type,
+            "decorators must be callable functions",
+        ),
+        TestAction::assert_native_error(
+            "(class { @(() => 1) m() {} })",
+            JsNativeErrorKind::Type,
+            "method decorators must return a function or undefined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                let context;
+                @((_, ctx) => { context = ctx; })
+                class D {}
+                context.addInitializer(() => {});
+            "#},
+            JsNativeErrorKind::Type,
+            "cannot add an initializer after decoration has finished",
+        ),
+    ]);
+}
@@ -953,6 +953,11 @@ impl JsValue {
         self.to_number(context).map(|n| n as f32)
     }
 
+    /// Converts a value to a 16 bit floating point.
+    pub fn to_f16(&self, context: &mut Context) -> JsResult<half::f16> {
+        self.to_number(context).map(half::f16::from_f64)
+    }
+
     /// This is a more specialized version of `to_numeric`, including `BigInt`.
     ///
     /// This function is equivalent to `Number(value)` in JavaScript
@@ -11,7 +11,10 @@ use crate::{
     Context, JsBigInt, JsString, JsValue,
 };
 use bitflags::bitflags;
-use boa_ast::scope::{BindingLocator, Scope};
+use boa_ast::{
+    scope::{BindingLocator, Scope},
+    Position,
+};
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, mem::size_of};
@@ -166,6 +169,11 @@ pub struct CodeBlock {
 
     /// inline caching
     pub(crate) ic: Box<[InlineCache]>,
+
+    /// Source positions of the instructions that can call other functions or throw, keyed by
+    /// the program counter right after the instruction and sorted by it.
+    #[unsafe_ignore_trace]
+    pub(crate) source_positions: Box<[(u32, Position)]>,
 }
 
 /// ---- `CodeBlock` public API ----
@@ -189,6 +197,7 @@ impl CodeBlock {
             parameter_length: 0,
             handlers: ThinVec::default(),
             ic: Box::default(),
+            source_positions: Box::default(),
         }
     }
 
@@ -291,6 +300,16 @@ impl CodeBlock {
             .find(|(_, handler)| handler.contains(pc))
     }
 
+    /// Find the source position of the instruction that ends right before the given program
+    /// counter (`pc`), if it was recorded.
+    #[inline]
+    pub(crate) fn find_source_position(&self, pc: u32) -> Option<Position> {
+        self.source_positions
+            .binary_search_by_key(&pc, |(end, _)| *end)
+            .ok()
+            .map(|index| self.source_positions[index].1)
+    }
+
     /// Get the [`JsString`] constant from the [`CodeBlock`].
     ///
     /// # Panics
@@ -399,7 +418,8 @@ impl CodeBlock {
             | Instruction::ThrowNewSyntaxError { message: index }
             | Instruction::HasRestrictedGlobalProperty { index }
             | Instruction::CanDeclareGlobalFunction { index }
-            | Instruction::CanDeclareGlobalVar { index } => index.value().to_string(),
+            | Instruction::CanDeclareGlobalVar { index }
+            | Instruction::DefineDecoratedStaticField { index } => index.value().to_string(),
             Instruction::PushRegExp {
                 pattern_index: source_index,
                 flags_index: flag_index,
@@ -560,6 +580,15 @@ impl CodeBlock {
             } => {
                 format!("is_anonymous_function: {is_anonymous_function}")
             }
+            Instruction::PushDecoratedClassElement { flags } => {
+                format!("flags: {flags:#b}")
+            }
+            Instruction::RunDecoratorInitializers { is_class } => {
+                format!("is_class: {is_class}")
+            }
+            Instruction::AddDisposableResource { is_async } => {
+                format!("is_async: {is_async}")
+            }
             Instruction::PopIntoRegister { dst } | Instruction::PopIntoLocal { dst } => {
                 format!("dst:reg{}", dst.value())
             }
@@ -567,6 +596,11 @@ impl CodeBlock {
                 format!("src:reg{}", src.value())
             }
             Instruction::Pop
+            | Instruction::CreateClassDecorationState
+            | Instruction::ApplyElementDecorators
+            | Instruction::ApplyClassDecorators
+            | Instruction::CreateDisposeCapability
+            | Instruction::DisposeResources
             | Instruction::Dup
             | Instruction::Swap
             | Instruction::PushZero
@@ -729,16 +763,7 @@ impl CodeBlock {
             | Instruction::Reserved37
             | Instruction::Reserved38
             | Instruction::Reserved39
-            | Instruction::Reserved40
-            | Instruction::Reserved41
-            | Instruction::Reserved42
-            | Instruction::Reserved43
-            | Instruction::Reserved44
-            | Instruction::Reserved45
-            | Instruction::Reserved46
-            | Instruction::Reserved47
-            | Instruction::Reserved48
-            | Instruction::Reserved49 => unreachable!("Reserved opcodes are unrechable"),
+            | Instruction::Reserved40 => unreachable!("Reserved opcodes are unrechable"),
         }
     }
 }
@@ -430,6 +430,15 @@ impl CodeBlock {
                 | Instruction::CompletePromiseCapability
                 | Instruction::GeneratorNext
                 | Instruction::PushClassField { .. }
+                | Instruction::CreateClassDecorationState
+                | Instruction::PushDecoratedClassElement { .. }
+                | Instruction::ApplyElementDecorators
+                | Instruction::ApplyClassDecorators
+                | Instruction::DefineDecoratedStaticField { .. }
+                | Instruction::RunDecoratorInitializers { .. }
+                | Instruction::CreateDisposeCapability
+                | Instruction::AddDisposableResource { .. }
+                | Instruction::DisposeResources
                 | Instruction::SuperCallDerived
                 | Instruction::Await
                 | Instruction::NewTarget
@@ -507,16 +516,7 @@ impl CodeBlock {
                 | Instruction::Reserved37
                 | Instruction::Reserved38
                 | Instruction::Reserved39
-                | Instruction::Reserved40
-                | Instruction::Reserved41
-                | Instruction::Reserved42
-                | Instruction::Reserved43
-                | Instruction::Reserved44
-                | Instruction::Reserved45
-                | Instruction::Reserved46
-                | Instruction::Reserved47
-                | Instruction::Reserved48
-                | Instruction::Reserved49 => unreachable!("Reserved opcodes are unrechable"),
+                | Instruction::Reserved40 => unreachable!("Reserved opcodes are unrechable"),
             }
         }
 
@@ -30,8 +30,11 @@ pub(crate) use inline_cache::InlineCache;
 
 // TODO: see if this can be exposed on all features.
 #[allow(unused_imports)]
-pub(crate) use opcode::{Instruction, InstructionIterator, Opcode, VaryingOperandKind};
-pub use runtime_limits::RuntimeLimits;
+pub(crate) use opcode::{
+    DecoratedElementFlags, Instruction, InstructionIterator, Opcode, VaryingOperandKind,
+};
+pub(crate) use runtime_limits::{check_heap_allocation, check_heap_limit, ExecutionTracker};
+pub use runtime_limits::{InterruptHandle, RuntimeLimits};
 pub use {
     call_frame::{CallFrame, GeneratorResumeKind},
     code_block::CodeBlock,
@@ -79,6 +82,9 @@ pub struct Vm {
     pub(crate) environments: EnvironmentStack,
     pub(crate) runtime_limits: RuntimeLimits,
 
+    /// Tracks the instruction and time limits of the current execution.
+    pub(crate) execution: ExecutionTracker,
+
     /// This is used to assign a native (rust) function as the active function,
     /// because we don't push a frame for them.
     pub(crate) native_active_function: Option<JsObject>,
@@ -123,6 +129,7 @@ impl Vm {
             environments: EnvironmentStack::new(realm.environment().clone()),
             pending_exception: None,
             runtime_limits: RuntimeLimits::default(),
+            execution: ExecutionTracker::default(),
             native_active_function: None,
             realm,
             #[cfg(feature = "trace")]
@@ -410,6 +417,10 @@ impl Context {
             opcode
         };
 
+        self.vm
+            .execution
+            .spend(opcode.cost(), &self.vm.runtime_limits)?;
+
         let _timer = Profiler::global().start_event(opcode.as_instruction_str(), "vm");
 
         f(opcode, self)
@@ -463,6 +474,8 @@ impl Context {
                     return ControlFlow::Break(CompletionRecord::Throw(err));
                 }
 
+                let err = err.inject_backtrace(self);
+
                 // Note: -1 because we increment after fetching the opcode.
                 let pc = self.vm.frame().pc.saturating_sub(1);
                 if self.vm.handle_exception_at(pc) {
@@ -565,8 +578,9 @@ impl Context {
 
         let mut runtime_budget: u32 = budget;
 
+        let context = &mut self.enter_execution();
         loop {
-            match self.execute_one(|opcode, context| {
+            match context.execute_one(|opcode, context| {
                 opcode.spend_budget_and_execute(context, &mut runtime_budget)
             }) {
                 ControlFlow::Continue(()) => {}
@@ -588,8 +602,9 @@ impl Context {
             self.trace_call_frame();
         }
 
+        let context = &mut self.enter_execution();
         loop {
-            match self.execute_one(Opcode::execute) {
+            match context.execute_one(Opcode::execute) {
                 ControlFlow::Continue(()) => {}
                 ControlFlow::Break(value) => return value,
             }
@@ -610,6 +625,10 @@ impl Context {
                 .with_message("exceeded maximum call stack length")
                 .into());
         }
+        // Must throw if the execution was interrupted.
+        self.vm.execution.interrupt.take()?;
+        // Must throw if the heap grew past its limit.
+        check_heap_limit()?;
 
         Ok(())
     }
@@ -1,10 +1,12 @@
 use crate::{
     builtins::{promise::PromiseCapability, Promise},
     error::JsNativeError,
-    module::{ModuleKind, Referrer},
+    js_string,
+    module::{validate_import_attributes, ImportAttribute, ModuleKind, ModuleRequest, Referrer},
     object::FunctionObjectBuilder,
+    property::PropertyNameKind,
     vm::{opcode::Operation, CompletionType},
-    Context, JsObject, JsResult, JsValue, NativeFunction,
+    Context, JsObject, JsResult, JsString, JsValue, NativeFunction,
 };
 
 /// `CallEval` implements the Opcode Operation for `Opcode::CallEval`
@@ -293,11 +295,17 @@ impl Operation for ImportCall {
             .get_active_script_or_module()
             .map_or_else(|| Referrer::Realm(context.realm().clone()), Into::into);
 
-        // 3. Let argRef be ? Evaluation of AssignmentExpression.
-        // 4. Let specifier be ? GetValue(argRef).
-        let arg = context.vm.pop();
-
-        // 5. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 3. Let specifierRef be ? Evaluation of the first AssignmentExpression.
+        // 4. Let specifier be ? GetValue(specifierRef).
+        // 5. If the second AssignmentExpression is present, then
+        //     a. Let optionsRef be ? Evaluation of the second AssignmentExpression.
+        //     b. Let options be ? GetValue(optionsRef).
+        // 6. Else,
+        //     a. Let options be undefined.
+        let options = context.vm.pop();
+        let specifier = context.vm.pop();
+
+        // 7. Let promiseCapability be ! NewPromiseCapability(%Promise%).
         let cap = PromiseCapability::new(
             &context.intrinsics().constructors().promise().constructor(),
             context,
@@ -305,17 +313,25 @@ impl Operation for ImportCall {
         .expect("operation cannot fail for the %Promise% intrinsic");
         let promise = cap.promise().clone();
 
-        // 6. Let specifierString be Completion(ToString(specifier)).
-        match arg.to_string(context) {
-            // 7. IfAbruptRejectPromise(specifierString, promiseCapability).
+        // 8. Let specifierString be Completion(ToString(specifier)).
+        // 9. IfAbruptRejectPromise(specifierString, promiseCapability).
+        // 10. Let attributes be a new empty List.
+        // 11. If options is not undefined, then
+        //     ...
+        // 12. Let moduleRequest be a new ModuleRequest Record { [[Specifier]]: specifierString, [[Attributes]]: attributes }.
+        let request = specifier
+            .to_string(context)
+            .and_then(|specifier| Self::module_request(specifier, &options, context));
+
+        match request {
             Err(err) => {
                 let err = err.to_opaque(context);
                 cap.reject().call(&JsValue::undefined(), &[err], context)?;
             }
-            // 8. Perform HostLoadImportedModule(referrer, specifierString, empty, promiseCapability).
-            Ok(specifier) => context.module_loader().load_imported_module(
+            // 13. Perform HostLoadImportedModule(referrer, moduleRequest, empty, promiseCapability).
+            Ok(request) => context.module_loader().load_imported_module(
                 referrer.clone(),
-                specifier.clone(),
+                request.clone(),
                 Box::new(move |completion, context| {
                     // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
                     // https://tc39.es/ecma262/#sec-ContinueDynamicImport
@@ -336,9 +352,8 @@ impl Operation for ImportCall {
                                     //     a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
                                     //     b. Else,
                                     //         i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
+                                    let entry =
+                                        loaded_modules.entry(request).or_insert_with(|| m.clone());
 
                                     //         i. Assert: That Record's [[Module]] is result.[[Value]].
                                     debug_assert_eq!(&m, entry);
@@ -347,16 +362,14 @@ impl Operation for ImportCall {
                                 }
                                 Referrer::Realm(realm) => {
                                     let mut loaded_modules = realm.loaded_modules().borrow_mut();
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
+                                    let entry =
+                                        loaded_modules.entry(request).or_insert_with(|| m.clone());
                                     debug_assert_eq!(&m, entry);
                                 }
                                 Referrer::Script(script) => {
                                     let mut loaded_modules = script.loaded_modules().borrow_mut();
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
+                                    let entry =
+                                        loaded_modules.entry(request).or_insert_with(|| m.clone());
                                     debug_assert_eq!(&m, entry);
                                 }
                             }
@@ -478,9 +491,96 @@ impl Operation for ImportCall {
             ),
         };
 
-        // 9. Return promiseCapability.[[Promise]].
+        // 14. Return promiseCapability.[[Promise]].
         context.vm.push(promise);
 
         Ok(CompletionType::Normal)
     }
 }
+
+impl ImportCall {
+    /// Steps 10 to 12 of [`EvaluateImportCall`][spec], which build the `ModuleRequest` of a
+    /// dynamic import from the `options` argument.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-evaluate-import-call
+    fn module_request(
+        specifier: JsString,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<ModuleRequest> {
+        // 10. Let attributes be a new empty List.
+        let mut attributes = Vec::new();
+
+        // 11. If options is not undefined, then
+        if !options.is_undefined() {
+            // a. If options is not an Object, then
+            let Some(options) = options.as_object() else {
+                // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                // ii. Return promiseCapability.[[Promise]].
+                return Err(JsNativeError::typ()
+                    .with_message("the options of a dynamic import must be an object")
+                    .into());
+            };
+
+            // b. Let attributesObj be Completion(Get(options, "with")).
+            // c. IfAbruptRejectPromise(attributesObj, promiseCapability).
+            let attributes_obj = options.get(js_string!("with"), context)?;
+
+            // d. If attributesObj is not undefined, then
+            if !attributes_obj.is_undefined() {
+                // i. If attributesObj is not an Object, then
+                let Some(attributes_obj) = attributes_obj.as_object() else {
+                    // 1. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                    // 2. Return promiseCapability.[[Promise]].
+                    return Err(JsNativeError::typ()
+                        .with_message("the `with` option of a dynamic import must be an object")
+                        .into());
+                };
+
+                // ii. Let entries be Completion(EnumerableOwnProperties(attributesObj, key+value)).
+                // iii. IfAbruptRejectPromise(entries, promiseCapability).
+                let entries = attributes_obj
+                    .enumerable_own_property_names(PropertyNameKind::KeyAndValue, context)?;
+
+                // iv. For each element entry of entries, do
+                for entry in entries {
+                    let entry = entry
+                        .as_object()
+                        .expect("entries returned by `EnumerableOwnProperties` must be arrays");
+                    // 1. Let key be ! Get(entry, "0").
+                    // 2. Let value be ! Get(entry, "1").
+                    let key = entry.get(0, context)?;
+                    let value = entry.get(1, context)?;
+
+                    // 3. If key is a String, then
+                    if let Some(key) = key.as_string() {
+                        // a. If value is not a String, then
+                        let Some(value) = value.as_string() else {
+                            // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                            // ii. Return promiseCapability.[[Promise]].
+                            return Err(JsNativeError::typ()
+                                .with_message(format!(
+                                    "the value of the import attribute `{}` must be a string",
+                                    key.to_std_string_escaped()
+                                ))
+                                .into());
+                        };
+
+                        // b. Append the ImportAttribute Record { [[Key]]: key, [[Value]]: value } to attributes.
+                        attributes.push(ImportAttribute::new(key.clone(), value.clone()));
+                    }
+                }
+            }
+        }
+
+        // 11.e. If AllImportAttributesSupported(attributes) is false, then
+        //     i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created SyntaxError object »).
+        //     ii. Return promiseCapability.[[Promise]].
+        // 11.f. Sort attributes according to the lexicographic order of their [[Key]] field,
+        //    treating the value of each such field as a sequence of UTF-16 code unit values.
+        let request = ModuleRequest::new(specifier, attributes);
+        validate_import_attributes(&request)?;
+
+        Ok(request)
+    }
+}
@@ -0,0 +1,1086 @@
+//! Runtime support for the [decorators proposal][proposal].
+//!
+//! Decorated classes are evaluated with the help of a [`ClassDecorationState`] object that keeps
+//! track of the decorated class elements, their decorators and the extra initializers added with
+//! `context.addInitializer`.
+//!
+//! [proposal]: https://github.com/tc39/proposal-decorators
+
+use std::{cell::Cell, rc::Rc};
+
+use crate::{
+    builtins::{
+        function::{set_function_name, OrdinaryFunction},
+        OrdinaryObject,
+    },
+    js_str, js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsFunction, JsObject, PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    symbol::JsSymbol,
+    vm::{opcode::Operation, CompletionType},
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+use bitflags::bitflags;
+use boa_gc::{empty_trace, Finalize, Trace};
+
+bitflags! {
+    /// Flags describing a decorated class element.
+    ///
+    /// An element without any of the `GETTER`, `SETTER`, `FIELD` or `ACCESSOR` flags is a method.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) struct DecoratedElementFlags: u8 {
+        /// The element is a getter.
+        const GETTER = 0b0000_0001;
+
+        /// The element is a setter.
+        const SETTER = 0b0000_0010;
+
+        /// The element is a field.
+        const FIELD = 0b0000_0100;
+
+        /// The element is an auto-accessor declared with the `accessor` keyword.
+        const ACCESSOR = 0b0000_1000;
+
+        /// The element is static.
+        const STATIC = 0b0001_0000;
+
+        /// The element has a private name.
+        const PRIVATE = 0b0010_0000;
+
+        /// The initializer of the field is an anonymous function definition.
+        const ANONYMOUS_FUNCTION = 0b0100_0000;
+    }
+}
+
+/// The kind of a decorated class element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    Method,
+    Getter,
+    Setter,
+    Field,
+    Accessor,
+}
+
+impl ElementKind {
+    const fn from_flags(flags: DecoratedElementFlags) -> Self {
+        if flags.contains(DecoratedElementFlags::GETTER) {
+            Self::Getter
+        } else if flags.contains(DecoratedElementFlags::SETTER) {
+            Self::Setter
+        } else if flags.contains(DecoratedElementFlags::FIELD) {
+            Self::Field
+        } else if flags.contains(DecoratedElementFlags::ACCESSOR) {
+            Self::Accessor
+        } else {
+            Self::Method
+        }
+    }
+
+    fn name(self) -> JsString {
+        match self {
+            Self::Method => js_string!("method"),
+            Self::Getter => js_string!("getter"),
+            Self::Setter => js_string!("setter"),
+            Self::Field => js_string!("field"),
+            Self::Accessor => js_string!("accessor"),
+        }
+    }
+}
+
+/// Where the initializers added by `context.addInitializer` are run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placement {
+    Static,
+    Instance,
+    Class,
+}
+
+/// The key of a decorated class element.
+#[derive(Debug, Clone, Finalize)]
+enum ElementKey {
+    Public(PropertyKey),
+    Private(PrivateName),
+}
+
+// SAFETY: Neither property keys nor private names contain traceable data.
+unsafe impl Trace for ElementKey {
+    empty_trace!();
+}
+
+impl ElementKey {
+    /// Returns the name of the element, as exposed by the `name` property of the decorator context.
+    fn name(&self) -> JsValue {
+        match self {
+            Self::Public(key) => key.clone().into(),
+            Self::Private(name) => js_string!(js_str!("#"), name.description()).into(),
+        }
+    }
+
+    /// Returns the name used for `SetFunctionName` on the functions of the element.
+    fn function_name(&self) -> PropertyKey {
+        match self {
+            Self::Public(key) => key.clone(),
+            Self::Private(name) => js_string!(js_str!("#"), name.description()).into(),
+        }
+    }
+}
+
+/// A class element that has to go through decorator application.
+#[derive(Debug, Trace, Finalize)]
+struct DecoratedElement {
+    #[unsafe_ignore_trace]
+    kind: ElementKind,
+    is_static: bool,
+    anonymous_function: bool,
+    key: ElementKey,
+
+    /// The method, getter or setter function, or the initializer function of a field or accessor.
+    value: JsValue,
+    decorators: Vec<JsValue>,
+
+    /// The index of the element in the `[[Fields]]` of the class, for non-static fields and
+    /// accessors.
+    field_index: Option<usize>,
+
+    /// The private name of the backing storage of an accessor.
+    storage: Option<PrivateName>,
+
+    /// The initializers returned by the decorators of static fields and accessors.
+    initializers: Vec<JsFunction>,
+}
+
+/// The state of a class definition that contains decorators or auto-accessors.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ClassDecorationState {
+    class: JsObject,
+    prototype: JsObject,
+    class_name: JsString,
+    metadata: JsObject,
+    elements: Vec<DecoratedElement>,
+    static_initializers: Vec<JsFunction>,
+    instance_initializers: Vec<JsFunction>,
+    class_initializers: Vec<JsFunction>,
+    decorated_class: Option<JsObject>,
+}
+
+impl ClassDecorationState {
+    fn push_initializer(&mut self, placement: Placement, initializer: JsFunction) {
+        match placement {
+            Placement::Static => self.static_initializers.push(initializer),
+            Placement::Instance => self.instance_initializers.push(initializer),
+            Placement::Class => self.class_initializers.push(initializer),
+        }
+    }
+}
+
+/// Collects the values of the decorators array created by the bytecompiler.
+fn decorator_list(decorators: &JsValue) -> Vec<JsValue> {
+    decorators
+        .as_object()
+        .expect("decorators must be an array")
+        .borrow()
+        .properties()
+        .to_dense_indexed_properties()
+        .expect("decorators array must be dense")
+        .into_iter()
+        .collect()
+}
+
+/// Calls `decorator` with the value being decorated and its context object.
+///
+/// The decoration is finished as soon as the decorator returns, which disallows any further
+/// calls to `context.addInitializer`.
+fn call_decorator(
+    decorator: &JsValue,
+    value: JsValue,
+    context_object: JsObject,
+    finished: &Rc<Cell<bool>>,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let decorator = decorator.as_callable().ok_or_else(|| {
+        JsNativeError::typ().with_message("decorators must be callable functions")
+    })?;
+    let result = decorator.call(
+        &JsValue::undefined(),
+        &[value, context_object.into()],
+        context,
+    );
+    finished.set(true);
+    result
+}
+
+/// Creates the `addInitializer` function of a decorator context object.
+fn create_add_initializer(
+    state: &JsObject,
+    placement: Placement,
+    finished: &Rc<Cell<bool>>,
+    context: &mut Context,
+) -> JsFunction {
+    #[derive(Debug, Trace, Finalize)]
+    struct AddInitializerCaptures {
+        state: JsObject,
+        #[unsafe_ignore_trace]
+        placement: Placement,
+        #[unsafe_ignore_trace]
+        finished: Rc<Cell<bool>>,
+    }
+
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, captures, _| {
+                // 1. If decorationState.[[Finished]] is true, throw a TypeError exception.
+                if captures.finished.get() {
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot add an initializer after decoration has finished")
+                        .into());
+                }
+
+                // 2. If IsCallable(initializer) is false, throw a TypeError exception.
+                let initializer = args.first().and_then(JsValue::as_callable).ok_or_else(|| {
+                    JsNativeError::typ().with_message("initializer must be a function")
+                })?;
+
+                // 3. Append initializer to extraInitializers.
+                captures
+                    .state
+                    .downcast_mut::<ClassDecorationState>()
+                    .expect("must be a class decoration state")
+                    .push_initializer(
+                        captures.placement,
+                        JsFunction::from_object_unchecked(initializer.clone()),
+                    );
+
+                // 4. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            AddInitializerCaptures {
+                state: state.clone(),
+                placement,
+                finished: finished.clone(),
+            },
+        ),
+    )
+    .name(js_string!("addInitializer"))
+    .length(1)
+    .build()
+}
+
+/// Creates the `access` object of a decorator context object.
+fn create_access_object(kind: ElementKind, key: &ElementKey, context: &mut Context) -> JsObject {
+    fn target(value: &JsValue) -> JsResult<&JsObject> {
+        value.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("decorator access target must be an object")
+                .into()
+        })
+    }
+
+    let access = JsObject::with_object_proto(context.intrinsics());
+
+    if kind != ElementKind::Setter {
+        let get = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, key, context| {
+                    let object = target(args.get_or_undefined(0))?;
+                    match key {
+                        ElementKey::Public(key) => object.get(key.clone(), context),
+                        ElementKey::Private(name) => object.private_get(name, context),
+                    }
+                },
+                key.clone(),
+            ),
+        )
+        .name(js_string!("get"))
+        .length(1)
+        .build();
+        access
+            .create_data_property_or_throw(js_string!("get"), get, context)
+            .expect("creating a property on a new object cannot fail");
+    }
+
+    if matches!(
+        kind,
+        ElementKind::Setter | ElementKind::Field | ElementKind::Accessor
+    ) {
+        let set = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, key, context| {
+                    let object = target(args.get_or_undefined(0))?;
+                    let value = args.get_or_undefined(1).clone();
+                    match key {
+                        ElementKey::Public(key) => {
+                            object.set(key.clone(), value, true, context)?;
+                        }
+                        ElementKey::Private(name) => object.private_set(name, value, context)?,
+                    }
+                    Ok(JsValue::undefined())
+                },
+                key.clone(),
+            ),
+        )
+        .name(js_string!("set"))
+        .length(2)
+        .build();
+        access
+            .create_data_property_or_throw(js_string!("set"), set, context)
+            .expect("creating a property on a new object cannot fail");
+    }
+
+    let has = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, key, context| {
+                let object = target(args.get_or_undefined(0))?;
+                match key {
+                    ElementKey::Public(key) => object.has_property(key.clone(), context),
+                    ElementKey::Private(name) => {
+                        Ok(object.private_element_find(name, true, true).is_some())
+                    }
+                }
+                .map(JsValue::from)
+            },
+            key.clone(),
+        ),
+    )
+    .name(js_string!("has"))
+    .length(1)
+    .build();
+    access
+        .create_data_property_or_throw(js_string!("has"), has, context)
+        .expect("creating a property on a new object cannot fail");
+
+    access
+}
+
+/// Abstract operation `CreateDecoratorContextObject ( kind, key, extraInitializers, decorationState [ , isStatic ] )`
+/// for class elements.
+fn create_element_context(
+    state: &JsObject,
+    element: &DecoratedElement,
+    finished: &Rc<Cell<bool>>,
+    context: &mut Context,
+) -> JsObject {
+    let metadata = state
+        .downcast_ref::<ClassDecorationState>()
+        .expect("must be a class decoration state")
+        .metadata
+        .clone();
+    let placement = if element.is_static {
+        Placement::Static
+    } else {
+        Placement::Instance
+    };
+
+    // 1. Let contextObj be OrdinaryObjectCreate(%Object.prototype%).
+    let context_object = JsObject::with_object_proto(context.intrinsics());
+
+    // 2. If kind is method, let kindStr be "method".
+    // ...
+    // 8. Perform ! CreateDataPropertyOrThrow(contextObj, "kind", kindStr).
+    // 9. Let access be CreateDecoratorAccessObject(kind, name).
+    // 10. Perform ! CreateDataPropertyOrThrow(contextObj, "access", access).
+    // 11. If isStatic is present, perform ! CreateDataPropertyOrThrow(contextObj, "static", isStatic).
+    // 12. If key is a Private Name, then
+    //     a. Perform ! CreateDataPropertyOrThrow(contextObj, "private", true).
+    // 13. Else,
+    //     a. Perform ! CreateDataPropertyOrThrow(contextObj, "private", false).
+    // 14. Perform ! CreateDataPropertyOrThrow(contextObj, "name", name).
+    let access = create_access_object(element.kind, &element.key, context);
+    let add_initializer = create_add_initializer(state, placement, finished, context);
+    let properties: [(JsString, JsValue); 7] = [
+        (js_string!("kind"), element.kind.name().into()),
+        (js_string!("access"), access.into()),
+        (js_string!("static"), element.is_static.into()),
+        (
+            js_string!("private"),
+            matches!(element.key, ElementKey::Private(_)).into(),
+        ),
+        (js_string!("name"), element.key.name()),
+        // 15. Let addInitializer be a new built-in function that adds to extraInitializers.
+        // 16. Perform ! CreateDataPropertyOrThrow(contextObj, "addInitializer", addInitializer).
+        (js_string!("addInitializer"), add_initializer.into()),
+        (js_string!("metadata"), metadata.into()),
+    ];
+    for (key, value) in properties {
+        context_object
+            .create_data_property_or_throw(key, value, context)
+            .expect("creating a property on a new object cannot fail");
+    }
+
+    // 17. Return contextObj.
+    context_object
+}
+
+/// Creates the getter or setter of an auto-accessor, which reads or writes its private storage.
+fn create_accessor_function(
+    storage: &PrivateName,
+    name: &PropertyKey,
+    is_getter: bool,
+    context: &mut Context,
+) -> JsObject {
+    fn receiver(this: &JsValue) -> JsResult<&JsObject> {
+        this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("accessor receiver must be an object")
+                .into()
+        })
+    }
+
+    let function = if is_getter {
+        // MakeAutoAccessorGetter ( homeObject, name, privateStateName )
+        NativeFunction::from_copy_closure_with_captures(
+            |this, _, storage, context| receiver(this)?.private_get(storage, context),
+            storage.clone(),
+        )
+    } else {
+        // MakeAutoAccessorSetter ( homeObject, name, privateStateName )
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, storage, context| {
+                receiver(this)?.private_set(storage, args.get_or_undefined(0).clone(), context)?;
+                Ok(JsValue::undefined())
+            },
+            storage.clone(),
+        )
+    };
+
+    let function = FunctionObjectBuilder::new(context.realm(), function)
+        .length(usize::from(!is_getter))
+        .build();
+    set_function_name(
+        &function,
+        name,
+        Some(if is_getter {
+            js_str!("get")
+        } else {
+            js_str!("set")
+        }),
+        context,
+    );
+    function.into()
+}
+
+/// Returns `value` if it is a valid replacement for a decorated function.
+fn decorated_function(value: &JsValue, kind: &str) -> JsResult<Option<JsObject>> {
+    if let Some(function) = value.as_callable() {
+        return Ok(Some(function.clone()));
+    }
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    Err(JsNativeError::typ()
+        .with_message(format!(
+            "{kind} decorators must return a function or undefined"
+        ))
+        .into())
+}
+
+/// Abstract operations `ApplyDecoratorsToElementDefinition` and `ApplyDecoratorsAndDefineMethod`.
+fn apply_element_decorators(
+    state: &JsObject,
+    class: &JsObject,
+    prototype: &JsObject,
+    element: &mut DecoratedElement,
+    context: &mut Context,
+) -> JsResult<()> {
+    let home = if element.is_static { class } else { prototype };
+
+    let mut initializers = Vec::new();
+    match element.kind {
+        ElementKind::Method | ElementKind::Getter | ElementKind::Setter => {
+            let mut function = element
+                .value
+                .as_object()
+                .expect("method must be a function object")
+                .clone();
+            let prefix = match element.kind {
+                ElementKind::Getter => Some(js_str!("get")),
+                ElementKind::Setter => Some(js_str!("set")),
+                _ => None,
+            };
+            set_function_name(&function, &element.key.function_name(), prefix, context);
+            function
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("method must be a function object")
+                .set_home_object(home.clone());
+
+            for decorator in element.decorators.iter().rev() {
+                let finished = Rc::new(Cell::new(false));
+                let context_object = create_element_context(state, element, &finished, context);
+                let result = call_decorator(
+                    decorator,
+                    function.clone().into(),
+                    context_object,
+                    &finished,
+                    context,
+                )?;
+                if let Some(result) = decorated_function(&result, "method")? {
+                    function = result;
+                }
+            }
+
+            match &element.key {
+                ElementKey::Public(key) => {
+                    let descriptor = match element.kind {
+                        ElementKind::Getter => PropertyDescriptor::builder().get(function),
+                        ElementKind::Setter => PropertyDescriptor::builder().set(function),
+                        _ => PropertyDescriptor::builder().value(function).writable(true),
+                    };
+                    home.define_property_or_throw(
+                        key.clone(),
+                        descriptor.enumerable(false).configurable(true),
+                        context,
+                    )?;
+                }
+                ElementKey::Private(name) => {
+                    let private_element = match element.kind {
+                        ElementKind::Getter => PrivateElement::Accessor {
+                            getter: Some(function),
+                            setter: None,
+                        },
+                        ElementKind::Setter => PrivateElement::Accessor {
+                            getter: None,
+                            setter: Some(function),
+                        },
+                        _ => PrivateElement::Method(function),
+                    };
+                    if element.is_static {
+                        class
+                            .borrow_mut()
+                            .append_private_element(name.clone(), private_element);
+                    } else {
+                        class
+                            .downcast_mut::<OrdinaryFunction>()
+                            .expect("class must be function object")
+                            .push_private_method(name.clone(), private_element);
+                    }
+                }
+            }
+        }
+        ElementKind::Field => {
+            for decorator in element.decorators.iter().rev() {
+                let finished = Rc::new(Cell::new(false));
+                let context_object = create_element_context(state, element, &finished, context);
+                let result = call_decorator(
+                    decorator,
+                    JsValue::undefined(),
+                    context_object,
+                    &finished,
+                    context,
+                )?;
+                if let Some(initializer) = decorated_function(&result, "field")? {
+                    initializers.push(JsFunction::from_object_unchecked(initializer));
+                }
+            }
+        }
+        ElementKind::Accessor => {
+            let storage = element
+                .storage
+                .clone()
+                .expect("accessor must have a private storage");
+            let name = element.key.function_name();
+            let mut getter = create_accessor_function(&storage, &name, true, context);
+            let mut setter = create_accessor_function(&storage, &name, false, context);
+
+            for decorator in element.decorators.iter().rev() {
+                let value = JsObject::with_object_proto(context.intrinsics());
+                value
+                    .create_data_property_or_throw(js_string!("get"), getter.clone(), context)
+                    .expect("creating a property on a new object cannot fail");
+                value
+                    .create_data_property_or_throw(js_string!("set"), setter.clone(), context)
+                    .expect("creating a property on a new object cannot fail");
+
+                let finished = Rc::new(Cell::new(false));
+                let context_object = create_element_context(state, element, &finished, context);
+                let result =
+                    call_decorator(decorator, value.into(), context_object, &finished, context)?;
+
+                if let Some(result) = result.as_object() {
+                    if let Some(new_getter) =
+                        decorated_function(&result.get(js_string!("get"), context)?, "accessor")?
+                    {
+                        getter = new_getter;
+                    }
+                    if let Some(new_setter) =
+                        decorated_function(&result.get(js_string!("set"), context)?, "accessor")?
+                    {
+                        setter = new_setter;
+                    }
+                    if let Some(initializer) =
+                        decorated_function(&result.get(js_string!("init"), context)?, "accessor")?
+                    {
+                        initializers.push(JsFunction::from_object_unchecked(initializer));
+                    }
+                } else if !result.is_undefined() {
+                    return Err(JsNativeError::typ()
+                        .with_message("accessor decorators must return an object or undefined")
+                        .into());
+                }
+            }
+
+            match &element.key {
+                ElementKey::Public(key) => {
+                    home.define_property_or_throw(
+                        key.clone(),
+                        PropertyDescriptor::builder()
+                            .get(getter)
+                            .set(setter)
+                            .enumerable(false)
+                            .configurable(true),
+                        context,
+                    )?;
+                }
+                ElementKey::Private(name) => {
+                    let private_element = PrivateElement::Accessor {
+                        getter: Some(getter),
+                        setter: Some(setter),
+                    };
+                    if element.is_static {
+                        class
+                            .borrow_mut()
+                            .append_private_element(name.clone(), private_element);
+                    } else {
+                        class
+                            .downcast_mut::<OrdinaryFunction>()
+                            .expect("class must be function object")
+                            .push_private_method(name.clone(), private_element);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(index) = element.field_index {
+        let mut class = class
+            .downcast_mut::<OrdinaryFunction>()
+            .expect("class must be function object");
+        for initializer in initializers {
+            class.push_field_initializer(index, initializer);
+        }
+    } else {
+        element.initializers = initializers;
+    }
+
+    Ok(())
+}
+
+/// `CreateClassDecorationState` implements the Opcode Operation for `Opcode::CreateClassDecorationState`
+///
+/// Operation:
+///  - Create the decoration state of a class that contains decorators or auto-accessors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateClassDecorationState;
+
+impl Operation for CreateClassDecorationState {
+    const NAME: &'static str = "CreateClassDecorationState";
+    const INSTRUCTION: &'static str = "INST - CreateClassDecorationState";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let class_name = context.vm.pop();
+        let class = context.vm.pop();
+        let class_name = class_name
+            .as_string()
+            .expect("class name must be a string")
+            .clone();
+        let class = class.as_object().expect("class must be object").clone();
+        let prototype = class
+            .get(js_string!("prototype"), context)?
+            .as_object()
+            .expect("class prototype must be an object")
+            .clone();
+
+        // Let parentMetadata be ? Get(constructorParent, @@metadata).
+        // If parentMetadata is not an Object, set parentMetadata to null.
+        // Let metadataObj be OrdinaryObjectCreate(parentMetadata).
+        let parent = class
+            .prototype()
+            .filter(|parent| *parent != context.intrinsics().constructors().function().prototype());
+        let parent_metadata = match parent {
+            Some(parent) => parent
+                .get(JsSymbol::metadata(), context)?
+                .as_object()
+                .cloned(),
+            None => None,
+        };
+        let metadata = JsObject::from_proto_and_data(parent_metadata, OrdinaryObject);
+
+        let state = JsObject::from_proto_and_data(
+            None,
+            ClassDecorationState {
+                class,
+                prototype,
+                class_name,
+                metadata,
+                elements: Vec::new(),
+                static_initializers: Vec::new(),
+                instance_initializers: Vec::new(),
+                class_initializers: Vec::new(),
+                decorated_class: None,
+            },
+        );
+        context.vm.push(state);
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `PushDecoratedClassElement` implements the Opcode Operation for `Opcode::PushDecoratedClassElement`
+///
+/// Operation:
+///  - Push a class element, with its decorators, to the decoration state of the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushDecoratedClassElement;
+
+impl Operation for PushDecoratedClassElement {
+    const NAME: &'static str = "PushDecoratedClassElement";
+    const INSTRUCTION: &'static str = "INST - PushDecoratedClassElement";
+    const COST: u8 = 6;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let flags = DecoratedElementFlags::from_bits_truncate(context.vm.read::<u8>());
+        let value = context.vm.pop();
+        let key = context.vm.pop();
+        let decorators = decorator_list(&context.vm.pop());
+        let state = context.vm.pop();
+        let state = state.as_object().expect("state must be an object");
+
+        let (class, index) = {
+            let state = state
+                .downcast_ref::<ClassDecorationState>()
+                .expect("must be a class decoration state");
+            (state.class.clone(), state.elements.len())
+        };
+
+        let key = if flags.contains(DecoratedElementFlags::PRIVATE) {
+            let description = key.as_string().expect("private name must be a string");
+            ElementKey::Private(class.private_name(description.clone()))
+        } else {
+            ElementKey::Public(key.to_property_key(context)?)
+        };
+        let kind = ElementKind::from_flags(flags);
+        let is_static = flags.contains(DecoratedElementFlags::STATIC);
+        let anonymous_function = flags.contains(DecoratedElementFlags::ANONYMOUS_FUNCTION);
+
+        let mut storage = None;
+        let mut field_index = None;
+        if matches!(kind, ElementKind::Field | ElementKind::Accessor) {
+            let initializer = value
+                .as_object()
+                .expect("field initializer must be function object");
+            initializer
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("field initializer must be function object")
+                .set_home_object(class.clone());
+            let initializer = JsFunction::from_object_unchecked(initializer.clone());
+
+            if kind == ElementKind::Accessor {
+                storage = Some(class.private_name(js_string!(
+                    js_str!("accessor storage "),
+                    &JsString::from(index.to_string())
+                )));
+            }
+
+            if !is_static {
+                let mut class = class
+                    .downcast_mut::<OrdinaryFunction>()
+                    .expect("class must be function object");
+                match (&key, &storage) {
+                    (_, Some(storage)) | (ElementKey::Private(storage), None) => {
+                        class.push_field_private(storage.clone(), initializer);
+                    }
+                    (ElementKey::Public(key), None) => {
+                        class.push_field(
+                            key.clone(),
+                            initializer,
+                            anonymous_function.then(|| key.clone()),
+                        );
+                    }
+                }
+                field_index = Some(class.fields_len() - 1);
+            }
+        }
+
+        state
+            .downcast_mut::<ClassDecorationState>()
+            .expect("must be a class decoration state")
+            .elements
+            .push(DecoratedElement {
+                kind,
+                is_static,
+                anonymous_function,
+                key,
+                value,
+                decorators,
+                field_index,
+                storage,
+                initializers: Vec::new(),
+            });
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `ApplyElementDecorators` implements the Opcode Operation for `Opcode::ApplyElementDecorators`
+///
+/// Operation:
+///  - Apply the decorators of the class elements, define the methods and accessors and define the
+///    metadata of the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyElementDecorators;
+
+impl Operation for ApplyElementDecorators {
+    const NAME: &'static str = "ApplyElementDecorators";
+    const INSTRUCTION: &'static str = "INST - ApplyElementDecorators";
+    const COST: u8 = 8;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let state = context.vm.pop();
+        let state = state.as_object().expect("state must be an object");
+
+        let (class, prototype, metadata, mut elements) = {
+            let mut state = state
+                .downcast_mut::<ClassDecorationState>()
+                .expect("must be a class decoration state");
+            (
+                state.class.clone(),
+                state.prototype.clone(),
+                state.metadata.clone(),
+                std::mem::take(&mut state.elements),
+            )
+        };
+
+        // Static elements are decorated before the non-static ones.
+        for is_static in [true, false] {
+            for element in elements.iter_mut().filter(|e| e.is_static == is_static) {
+                apply_element_decorators(state, &class, &prototype, element, context)?;
+            }
+        }
+
+        let instance_initializers = {
+            let mut state = state
+                .downcast_mut::<ClassDecorationState>()
+                .expect("must be a class decoration state");
+            state.elements = elements;
+            std::mem::take(&mut state.instance_initializers)
+        };
+
+        // Set F.[[Initializers]] to instanceExtraInitializers.
+        {
+            let mut class = class
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("class must be function object");
+            for initializer in instance_initializers {
+                class.push_initializer(initializer);
+            }
+        }
+
+        // Perform ? DefinePropertyOrThrow(F, @@metadata, PropertyDescriptor {
+        //     [[Value]]: metadataObj, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }).
+        class.define_property_or_throw(
+            JsSymbol::metadata(),
+            PropertyDescriptor::builder()
+                .value(metadata)
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `ApplyClassDecorators` implements the Opcode Operation for `Opcode::ApplyClassDecorators`
+///
+/// Operation:
+///  - Apply the class decorators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyClassDecorators;
+
+impl Operation for ApplyClassDecorators {
+    const NAME: &'static str = "ApplyClassDecorators";
+    const INSTRUCTION: &'static str = "INST - ApplyClassDecorators";
+    const COST: u8 = 8;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let decorators = decorator_list(&context.vm.pop());
+        let state = context.vm.pop();
+        let state = state.as_object().expect("state must be an object");
+
+        let (class, class_name, metadata) = {
+            let state = state
+                .downcast_ref::<ClassDecorationState>()
+                .expect("must be a class decoration state");
+            (
+                state.class.clone(),
+                state.class_name.clone(),
+                state.metadata.clone(),
+            )
+        };
+
+        // ApplyDecoratorsToClassDefinition ( classDef, decorators, className, extraInitializers )
+        let mut decorated = class;
+        for decorator in decorators.iter().rev() {
+            let finished = Rc::new(Cell::new(false));
+            let add_initializer =
+                create_add_initializer(state, Placement::Class, &finished, context);
+            let context_object = JsObject::with_object_proto(context.intrinsics());
+            let properties: [(JsString, JsValue); 4] = [
+                (js_string!("kind"), js_string!("class").into()),
+                (js_string!("name"), class_name.clone().into()),
+                (js_string!("addInitializer"), add_initializer.into()),
+                (js_string!("metadata"), metadata.clone().into()),
+            ];
+            for (key, value) in properties {
+                context_object
+                    .create_data_property_or_throw(key, value, context)
+                    .expect("creating a property on a new object cannot fail");
+            }
+
+            let result = call_decorator(
+                decorator,
+                decorated.clone().into(),
+                context_object,
+                &finished,
+                context,
+            )?;
+            if let Some(result) = decorated_function(&result, "class")? {
+                decorated = result;
+            }
+        }
+
+        state
+            .downcast_mut::<ClassDecorationState>()
+            .expect("must be a class decoration state")
+            .decorated_class = Some(decorated.clone());
+        context.vm.push(decorated);
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DefineDecoratedStaticField` implements the Opcode Operation for `Opcode::DefineDecoratedStaticField`
+///
+/// Operation:
+///  - Define a decorated static field or the storage of a static accessor on the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DefineDecoratedStaticField;
+
+impl DefineDecoratedStaticField {
+    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+        let state = context.vm.pop();
+        let state = state.as_object().expect("state must be an object");
+
+        let (class, initializer, key, storage, anonymous_function, initializers) = {
+            let state = state
+                .downcast_ref::<ClassDecorationState>()
+                .expect("must be a class decoration state");
+            let element = &state.elements[index];
+            (
+                state.class.clone(),
+                element.value.clone(),
+                element.key.clone(),
+                element.storage.clone(),
+                element.anonymous_function,
+                element.initializers.clone(),
+            )
+        };
+        let receiver: JsValue = class.clone().into();
+
+        // DefineField ( receiver, fieldRecord )
+        let initializer = initializer
+            .as_callable()
+            .expect("field initializer must be callable");
+        let mut value = initializer.call(&receiver, &[], context)?;
+        if let (ElementKey::Public(key), true) = (&key, anonymous_function) {
+            if let Some(function) = value.as_object() {
+                set_function_name(function, key, None, context);
+            }
+        }
+        for initializer in initializers {
+            value = initializer.call(&receiver, &[value], context)?;
+        }
+
+        match (key, storage) {
+            (_, Some(name)) | (ElementKey::Private(name), None) => {
+                class.private_field_add(&name, value, context)?;
+            }
+            (ElementKey::Public(key), None) => {
+                class.create_data_property_or_throw(key, value, context)?;
+            }
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+impl Operation for DefineDecoratedStaticField {
+    const NAME: &'static str = "DefineDecoratedStaticField";
+    const INSTRUCTION: &'static str = "INST - DefineDecoratedStaticField";
+    const COST: u8 = 6;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u8>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>() as usize;
+        Self::operation(context, index)
+    }
+}
+
+/// `RunDecoratorInitializers` implements the Opcode Operation for `Opcode::RunDecoratorInitializers`
+///
+/// Operation:
+///  - Call the static or class extra initializers added by decorators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RunDecoratorInitializers;
+
+impl Operation for RunDecoratorInitializers {
+    const NAME: &'static str = "RunDecoratorInitializers";
+    const INSTRUCTION: &'static str = "INST - RunDecoratorInitializers";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let is_class = context.vm.read::<u8>() != 0;
+        let state = context.vm.pop();
+        let state = state.as_object().expect("state must be an object");
+
+        let (receiver, initializers) = {
+            let mut state = state
+                .downcast_mut::<ClassDecorationState>()
+                .expect("must be a class decoration state");
+            if is_class {
+                (
+                    state
+                        .decorated_class
+                        .clone()
+                        .unwrap_or_else(|| state.class.clone()),
+                    std::mem::take(&mut state.class_initializers),
+                )
+            } else {
+                (
+                    state.class.clone(),
+                    std::mem::take(&mut state.static_initializers),
+                )
+            }
+        };
+
+        let receiver = receiver.into();
+        for initializer in initializers {
+            initializer.call(&receiver, &[], context)?;
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
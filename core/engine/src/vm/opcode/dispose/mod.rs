@@ -0,0 +1,105 @@
+use crate::{
+    builtins::disposable_stack::{DisposeCapability, DisposeHint},
+    vm::{opcode::Operation, CompletionType},
+    Context, JsError, JsResult,
+};
+
+/// `CreateDisposeCapability` implements the Opcode Operation for `Opcode::CreateDisposeCapability`
+///
+/// Operation:
+///  - Push a new dispose capability for the resources of a `using` declarations scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateDisposeCapability;
+
+impl Operation for CreateDisposeCapability {
+    const NAME: &'static str = "CreateDisposeCapability";
+    const INSTRUCTION: &'static str = "INST - CreateDisposeCapability";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .push(DisposeCapability::default());
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Add the value of a `using` or `await using` declaration to the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let is_async = context.vm.read::<u8>() != 0;
+        let value = context.vm.pop();
+
+        let hint = if is_async {
+            DisposeHint::Async
+        } else {
+            DisposeHint::Sync
+        };
+
+        // Getting the dispose method can call user code, so the capability is taken out of the
+        // frame while it's being updated.
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .pop()
+            .expect("there should be a dispose capability");
+        let result = capability.add_resource(value, hint, None, context);
+        context.vm.frame_mut().dispose_capabilities.push(capability);
+
+        result?;
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Dispose the resources of the current dispose capability, until a value has to be awaited.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 5;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let is_error = context.vm.pop().to_boolean();
+        let error = context.vm.pop();
+
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .pop()
+            .expect("there should be a dispose capability");
+
+        if is_error {
+            capability.record_error(JsError::from_opaque(error), context);
+        }
+
+        if let Some(value) = capability.dispose_step(context)? {
+            context.vm.frame_mut().dispose_capabilities.push(capability);
+            context.vm.push(value);
+            context.vm.push(true);
+            return Ok(CompletionType::Normal);
+        }
+
+        capability.finish()?;
+        context.vm.push(false);
+        Ok(CompletionType::Normal)
+    }
+}
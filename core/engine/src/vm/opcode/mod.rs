@@ -11,8 +11,10 @@ mod call;
 mod concat;
 mod control_flow;
 mod copy;
+mod decorator;
 mod define;
 mod delete;
+mod dispose;
 mod dup;
 mod environment;
 mod generator;
@@ -50,10 +52,14 @@ pub(crate) use control_flow::*;
 #[doc(inline)]
 pub(crate) use copy::*;
 #[doc(inline)]
+pub(crate) use decorator::*;
+#[doc(inline)]
 pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use dup::*;
 #[doc(inline)]
 pub(crate) use environment::*;
@@ -1674,7 +1680,7 @@ generate_opcodes! {
     ///
     /// Operands:
     ///
-    /// Stack: specifier **=>** promise
+    /// Stack: specifier, options **=>** promise
     ImportCall,
 
     /// Pop the two values of the stack, strict equal compares the two values,
@@ -2163,6 +2169,76 @@ generate_opcodes! {
     /// [spec]: https://tc39.es/ecma262/#sec-createglobalvarbinding
     CreateGlobalVarBinding { configurable: bool, index: VaryingOperand },
 
+    /// Create the decoration state of a class that contains decorators or auto-accessors.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, name **=>** state
+    CreateClassDecorationState,
+
+    /// Push a class element that has to go through decorator application to the decoration state.
+    ///
+    /// Operands: flags: `u8`
+    ///
+    /// Stack: state, decorators, key, value **=>**
+    PushDecoratedClassElement { flags: u8 },
+
+    /// Apply the decorators of the class elements, define the methods and accessors and define the
+    /// metadata of the class.
+    ///
+    /// Operands:
+    ///
+    /// Stack: state **=>**
+    ApplyElementDecorators,
+
+    /// Apply the class decorators.
+    ///
+    /// Operands:
+    ///
+    /// Stack: state, decorators **=>** class
+    ApplyClassDecorators,
+
+    /// Define a decorated static field or the storage of a static accessor on the class.
+    ///
+    /// Operands: index: `VaryingOperand`
+    ///
+    /// Stack: state **=>**
+    DefineDecoratedStaticField { index: VaryingOperand },
+
+    /// Call the static or class extra initializers added by decorators.
+    ///
+    /// Operands: `is_class`: `bool`
+    ///
+    /// Stack: state **=>**
+    RunDecoratorInitializers { is_class: bool },
+
+    /// Push a new dispose capability for the resources of a `using` declarations scope.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    CreateDisposeCapability,
+
+    /// Add the value of a `using` or `await using` declaration to the current dispose capability.
+    ///
+    /// Operands: `is_async`: `bool`
+    ///
+    /// Stack: value **=>**
+    AddDisposableResource { is_async: bool },
+
+    /// Dispose the resources of the current dispose capability, until a value has to be awaited.
+    ///
+    /// If `is_error` is `true`, the error is recorded as the completion of the disposal.
+    ///
+    /// When a value has to be awaited, it's pushed along with `true` and the dispose capability
+    /// is kept, otherwise the dispose capability is popped, its error thrown if there is one and
+    /// `false` is pushed.
+    ///
+    /// Operands:
+    ///
+    /// Stack: error, `is_error` **=>** (value, true) or false
+    DisposeResources,
+
     /// No-operation instruction, does nothing.
     ///
     /// Operands:
@@ -2264,24 +2340,6 @@ generate_opcodes! {
     Reserved39 => Reserved,
     /// Reserved [`Opcode`].
     Reserved40 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved41 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved42 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved43 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved44 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved45 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved46 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved47 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved48 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved49 => Reserved,
 }
 
 /// Specific opcodes for bindings.
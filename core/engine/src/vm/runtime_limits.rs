@@ -1,3 +1,13 @@
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc,
+};
+
+use crate::{
+    sys::time::{Duration, Instant},
+    JsNativeError, JsResult,
+};
+
 /// Represents the limits of different runtime operations.
 #[derive(Debug, Clone, Copy)]
 pub struct RuntimeLimits {
@@ -9,6 +19,15 @@ pub struct RuntimeLimits {
 
     /// Max function recursion limit
     resursion_limit: usize,
+
+    /// Max instruction cost spent by a single execution before an error is thrown.
+    instruction_limit: u64,
+
+    /// Max wall-clock time taken by a single execution before an error is thrown.
+    execution_time_limit: Duration,
+
+    /// Max size of the heap in bytes before an error is thrown.
+    heap_limit: usize,
 }
 
 impl Default for RuntimeLimits {
@@ -18,6 +37,9 @@ impl Default for RuntimeLimits {
             loop_iteration_limit: u64::MAX,
             resursion_limit: 512,
             stack_size_limit: 1024 * 10,
+            instruction_limit: u64::MAX,
+            execution_time_limit: Duration::MAX,
+            heap_limit: usize::MAX,
         }
     }
 }
@@ -75,4 +97,283 @@ impl RuntimeLimits {
     pub fn set_recursion_limit(&mut self, value: usize) {
         self.resursion_limit = value;
     }
+
+    /// Return the instruction limit.
+    ///
+    /// Every executed instruction spends its cost from this limit, and an error is thrown
+    /// once a single execution spends more than the limit. An execution starts when the
+    /// engine is entered from Rust, e.g. when evaluating a script or running a job.
+    ///
+    /// The limit value [`u64::MAX`] means that there is no limit.
+    #[inline]
+    #[must_use]
+    pub const fn instruction_limit(&self) -> u64 {
+        self.instruction_limit
+    }
+
+    /// Set the instruction limit.
+    ///
+    /// Every executed instruction spends its cost from this limit, and an error is thrown
+    /// once a single execution spends more than the limit.
+    ///
+    /// Setting the limit to [`u64::MAX`] means that there is no limit.
+    #[inline]
+    pub fn set_instruction_limit(&mut self, value: u64) {
+        self.instruction_limit = value;
+    }
+
+    /// Disable instruction limit.
+    #[inline]
+    pub fn disable_instruction_limit(&mut self) {
+        self.instruction_limit = u64::MAX;
+    }
+
+    /// Return the execution time limit.
+    ///
+    /// If a single execution runs for longer than this it will throw an error.
+    ///
+    /// The limit value [`Duration::MAX`] means that there is no limit.
+    #[inline]
+    #[must_use]
+    pub const fn execution_time_limit(&self) -> Duration {
+        self.execution_time_limit
+    }
+
+    /// Set the execution time limit.
+    ///
+    /// If a single execution runs for longer than this it will throw an error.
+    ///
+    /// Setting the limit to [`Duration::MAX`] means that there is no limit.
+    #[inline]
+    pub fn set_execution_time_limit(&mut self, value: Duration) {
+        self.execution_time_limit = value;
+    }
+
+    /// Disable execution time limit.
+    #[inline]
+    pub fn disable_execution_time_limit(&mut self) {
+        self.execution_time_limit = Duration::MAX;
+    }
+
+    /// Return the heap size limit in bytes.
+    ///
+    /// If an allocation exceeds the limit even after collecting the garbage it will throw an
+    /// error. The heap is shared by all the contexts of a thread, and the limit of a context
+    /// applies to it while that context is executing, after which the previous limit is
+    /// restored. Near limit warnings can be received with
+    /// [`boa_gc::set_near_heap_limit_callback`].
+    ///
+    /// The limit value [`usize::MAX`] means that there is no limit.
+    #[inline]
+    #[must_use]
+    pub const fn heap_limit(&self) -> usize {
+        self.heap_limit
+    }
+
+    /// Set the heap size limit in bytes.
+    ///
+    /// If an allocation exceeds the limit even after collecting the garbage it will throw an
+    /// error.
+    ///
+    /// Setting the limit to [`usize::MAX`] means that there is no limit.
+    #[inline]
+    pub fn set_heap_limit(&mut self, value: usize) {
+        self.heap_limit = value;
+    }
+
+    /// Disable heap size limit.
+    #[inline]
+    pub fn disable_heap_limit(&mut self) {
+        self.heap_limit = usize::MAX;
+    }
+}
+
+/// No interrupt was requested.
+const NOT_INTERRUPTED: u8 = 0;
+
+/// An interrupt that throws a catchable error was requested.
+const INTERRUPTED_CATCHABLE: u8 = 1;
+
+/// An interrupt that throws an uncatchable [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit]
+/// error was requested.
+const INTERRUPTED_UNCATCHABLE: u8 = 2;
+
+/// A handle that can interrupt the execution of a [`Context`][crate::Context] from another thread.
+///
+/// The handle is obtained with [`Context::interrupt_handle`][crate::Context::interrupt_handle],
+/// and can be cloned and sent to any thread. An interrupt is observed by the running
+/// execution shortly after it is requested, which then throws an error; if nothing is
+/// executing, the next execution throws instead.
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    state: Arc<AtomicU8>,
+}
+
+impl InterruptHandle {
+    /// Interrupts the execution with an uncatchable
+    /// [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit] error.
+    ///
+    /// The error cannot be caught by the script, and unwinds all the way to the Rust caller.
+    pub fn interrupt(&self) {
+        self.state.store(INTERRUPTED_UNCATCHABLE, Ordering::Relaxed);
+    }
+
+    /// Interrupts the execution with a catchable `Error`.
+    ///
+    /// This gives the script a chance to handle the interruption, e.g. by running its
+    /// `finally` blocks. A pending uncatchable interrupt is not downgraded by this.
+    pub fn interrupt_catchable(&self) {
+        let _ = self.state.compare_exchange(
+            NOT_INTERRUPTED,
+            INTERRUPTED_CATCHABLE,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Returns `true` if an interrupt was requested and has not been observed yet.
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.state.load(Ordering::Relaxed) != NOT_INTERRUPTED
+    }
+
+    /// Clears a requested interrupt that has not been observed yet.
+    pub fn clear(&self) {
+        self.state.store(NOT_INTERRUPTED, Ordering::Relaxed);
+    }
+
+    /// Takes the requested interrupt, returning the error it should throw.
+    pub(crate) fn take(&self) -> JsResult<()> {
+        match self.state.swap(NOT_INTERRUPTED, Ordering::Relaxed) {
+            NOT_INTERRUPTED => Ok(()),
+            INTERRUPTED_CATCHABLE => Err(JsNativeError::error()
+                .with_message("execution interrupted")
+                .into()),
+            _ => Err(JsNativeError::runtime_limit()
+                .with_message("execution interrupted")
+                .into()),
+        }
+    }
+}
+
+/// The number of instruction cost units between checks of the execution time limit and
+/// the interrupt handle.
+const CHECK_INTERVAL: u64 = 1024;
+
+/// Tracks the instructions spent and the deadline of the current execution.
+///
+/// An execution spans a whole script evaluation or a whole [`Context::run_jobs`] call, so the
+/// jobs run by it share a single instruction budget and deadline.
+///
+/// [`Context::run_jobs`]: crate::Context::run_jobs
+#[derive(Debug, Default)]
+pub(crate) struct ExecutionTracker {
+    /// The number of nested executions currently running.
+    depth: u32,
+
+    /// The instruction cost spent by the current execution.
+    spent: u64,
+
+    /// The spent cost at which the limits have to be checked again.
+    next_check: u64,
+
+    /// The instant when the current execution has to stop.
+    deadline: Option<Instant>,
+
+    /// The heap limit of the thread before the current execution set its own.
+    previous_heap_limit: Option<usize>,
+
+    /// The shared state of the [`InterruptHandle`]s of this context.
+    pub(crate) interrupt: InterruptHandle,
+}
+
+impl ExecutionTracker {
+    /// Enters an execution, starting a new one if no other execution is running.
+    pub(crate) fn enter(&mut self, limits: &RuntimeLimits) {
+        if self.depth == 0 {
+            self.spent = 0;
+            self.next_check = 0;
+            if limits.heap_limit() != usize::MAX {
+                self.previous_heap_limit = Some(boa_gc::heap_limit());
+                boa_gc::set_heap_limit(limits.heap_limit());
+            }
+
+            let time_limit = limits.execution_time_limit();
+            self.deadline = if time_limit == Duration::MAX {
+                None
+            } else {
+                Instant::now().checked_add(time_limit)
+            };
+        }
+        self.depth += 1;
+    }
+
+    /// Exits an execution entered with [`ExecutionTracker::enter`].
+    pub(crate) fn exit(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            if let Some(limit) = self.previous_heap_limit.take() {
+                boa_gc::set_heap_limit(limit);
+            }
+        }
+    }
+
+    /// Spends the `cost` of an instruction, throwing if a limit is exceeded.
+    #[inline]
+    pub(crate) fn spend(&mut self, cost: u8, limits: &RuntimeLimits) -> JsResult<()> {
+        self.spent = self.spent.saturating_add(u64::from(cost));
+        if self.spent < self.next_check {
+            return Ok(());
+        }
+        self.check(limits)
+    }
+
+    /// Checks all the limits of the current execution.
+    #[cold]
+    fn check(&mut self, limits: &RuntimeLimits) -> JsResult<()> {
+        self.interrupt.take()?;
+        check_heap_limit()?;
+
+        let limit = limits.instruction_limit();
+        if self.spent > limit {
+            return Err(JsNativeError::runtime_limit()
+                .with_message(format!("Maximum instruction limit {limit} exceeded"))
+                .into());
+        }
+
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(JsNativeError::runtime_limit()
+                .with_message("Maximum execution time limit exceeded")
+                .into());
+        }
+
+        self.next_check = self
+            .spent
+            .saturating_add(CHECK_INTERVAL)
+            .min(limit.saturating_add(1));
+        Ok(())
+    }
+}
+
+/// Throws if an allocation exceeded the heap limit since the last check.
+pub(crate) fn check_heap_limit() -> JsResult<()> {
+    if boa_gc::take_heap_limit_exceeded() {
+        return Err(JsNativeError::runtime_limit()
+            .with_message("exceeded maximum heap size")
+            .into());
+    }
+    Ok(())
+}
+
+/// Throws if allocating `size` bytes outside the garbage collector would exceed the heap limit.
+pub(crate) fn check_heap_allocation(size: usize) -> JsResult<()> {
+    if !boa_gc::fits_heap_limit(size) {
+        return Err(JsNativeError::runtime_limit()
+            .with_message("exceeded maximum heap size")
+            .into());
+    }
+    Ok(())
 }
@@ -1,10 +1,11 @@
 use crate::{
-    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue,
-    TestAction,
+    js_string, property::Attribute, run_test_actions, run_test_actions_with, sys::time::Duration,
+    vm::InterruptHandle, Context, JsNativeErrorKind, JsValue, TestAction,
 };
 use boa_macros::js_str;
 use boa_parser::Source;
 use indoc::indoc;
+use std::thread;
 
 #[test]
 fn typeof_string() {
@@ -315,6 +316,260 @@ fn recursion_runtime_limit() {
     ]);
 }
 
+#[test]
+fn instruction_runtime_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_instruction_limit(10_000);
+        }),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let sum = 0;
+                for (let i = 0; i < 10; ++i) { sum += i; }
+                sum
+            "#},
+            JsValue::new(45),
+        ),
+        TestAction::assert_native_error(
+            "while (true) { }",
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum instruction limit 10000 exceeded",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                try {
+                    while (true) { }
+                } catch {
+                    "caught"
+                }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum instruction limit 10000 exceeded",
+        ),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().disable_instruction_limit();
+        }),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let count = 0;
+                while (count < 10000) { count++; }
+                count
+            "#},
+            JsValue::new(10_000),
+        ),
+    ]);
+}
+
+#[test]
+fn instruction_runtime_limit_across_jobs() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_instruction_limit(10_000);
+        }),
+        TestAction::run(indoc! {r#"
+            var count = 0;
+            (async () => {
+                while (true) {
+                    count++;
+                    await 0;
+                }
+            })();
+        "#}),
+        // Every job only spends a few instructions, so this only returns if all the jobs share
+        // the same budget.
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("count > 1 && count < 10000"),
+    ]);
+}
+
+#[test]
+fn execution_time_runtime_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .runtime_limits_mut()
+                .set_execution_time_limit(Duration::from_millis(50));
+        }),
+        TestAction::assert_eq("1 + 1", JsValue::new(2)),
+        TestAction::assert_native_error(
+            "while (true) { }",
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum execution time limit exceeded",
+        ),
+        TestAction::assert_eq("2 + 2", JsValue::new(4)),
+    ]);
+}
+
+#[test]
+fn interrupt_handle() {
+    fn interrupt_after(handle: InterruptHandle, catchable: bool) -> thread::JoinHandle<()> {
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            if catchable {
+                handle.interrupt_catchable();
+            } else {
+                handle.interrupt();
+            }
+        })
+    }
+
+    let context = &mut Context::default();
+    let handle = context.interrupt_handle();
+
+    let thread = interrupt_after(handle.clone(), false);
+    run_test_actions_with(
+        [TestAction::assert_native_error(
+            indoc! {r#"
+                try {
+                    while (true) { }
+                } catch {
+                    "caught"
+                }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "execution interrupted",
+        )],
+        context,
+    );
+    thread.join().unwrap();
+    assert!(!handle.is_interrupted());
+
+    let thread = interrupt_after(handle.clone(), true);
+    run_test_actions_with(
+        [TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    while (true) { }
+                } catch (e) {
+                    e.message
+                }
+            "#},
+            js_str!("execution interrupted"),
+        )],
+        context,
+    );
+    thread.join().unwrap();
+
+    handle.interrupt();
+    assert!(handle.is_interrupted());
+    handle.clear();
+    run_test_actions_with([TestAction::assert_eq("1 + 1", JsValue::new(2))], context);
+}
+
+#[test]
+fn heap_runtime_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .runtime_limits_mut()
+                .set_heap_limit(16 * 1024 * 1024);
+        }),
+        TestAction::assert_eq("new Array(1000).fill(0).length", JsValue::new(1000)),
+        TestAction::assert_native_error(
+            "new Array(1e9).fill(0)",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const arrays = [];
+                try {
+                    while (true) {
+                        arrays.push(new Array(1e5).fill(1.5));
+                    }
+                } catch {
+                    "caught"
+                }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::run("arrays.length = 0"),
+        TestAction::assert_eq("new Array(1000).fill(0).length", JsValue::new(1000)),
+    ]);
+}
+
+#[test]
+fn heap_runtime_limit_external_memory() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .runtime_limits_mut()
+                .set_heap_limit(16 * 1024 * 1024);
+        }),
+        TestAction::assert_eq("new ArrayBuffer(1024).byteLength", JsValue::new(1024)),
+        TestAction::assert_native_error(
+            "new ArrayBuffer(64 * 1024 * 1024)",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::assert_native_error(
+            "new SharedArrayBuffer(64 * 1024 * 1024)",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const buffer = new ArrayBuffer(0, { maxByteLength: 64 * 1024 * 1024 });
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const strings = [];
+                for (let i = 0; i < 64; i++) {
+                    strings.push("x".repeat(1024 * 1024));
+                }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::run("strings.length = 0"),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const map = new Map();
+                for (let i = 0; i < 1e6; i++) {
+                    map.set(i, i);
+                }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::run("map.clear()"),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const set = new Set();
+                for (let i = 0; i < 1e6; i++) {
+                    set.add(i);
+                }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+    ]);
+}
+
+#[test]
+fn heap_runtime_limit_is_restored() {
+    boa_gc::set_heap_limit(64 * 1024 * 1024);
+    run_test_actions([
+        TestAction::assert_eq("1 + 1", JsValue::new(2)),
+        TestAction::inspect_context(|_| assert_eq!(boa_gc::heap_limit(), 64 * 1024 * 1024)),
+        TestAction::inspect_context(|context| {
+            context
+                .runtime_limits_mut()
+                .set_heap_limit(16 * 1024 * 1024);
+        }),
+        TestAction::assert_native_error(
+            "new Array(1e9).fill(0)",
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        ),
+        TestAction::inspect_context(|_| assert_eq!(boa_gc::heap_limit(), 64 * 1024 * 1024)),
+    ]);
+}
+
 #[test]
 fn arguments_object_constructor_valid_index() {
     run_test_actions([TestAction::assert_eq(
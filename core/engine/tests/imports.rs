@@ -48,3 +48,42 @@ fn subdirectories() {
         }
     }
 }
+
+/// Test that the simple module loader loads JSON modules when requested with `type: "json"`.
+#[test]
+fn json_modules() {
+    let assets_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("tests/assets");
+
+    let loader = Rc::new(SimpleModuleLoader::new(assets_dir).unwrap());
+    let mut context = Context::builder().module_loader(loader).build().unwrap();
+
+    let source = Source::from_bytes(
+        b"import data from 'dir1/data.json' with { type: 'json' };
+          export const name = data.name;
+          export const sum = data.values.reduce((a, b) => a + b);",
+    );
+    let module = boa_engine::Module::parse(source, None, &mut context).unwrap();
+    let result = module.load_link_evaluate(&mut context);
+
+    context.run_jobs();
+    match result.state() {
+        PromiseState::Pending => {}
+        PromiseState::Fulfilled(v) => {
+            assert!(v.is_undefined());
+
+            let namespace = module.namespace(&mut context);
+            assert_eq!(
+                namespace.get(js_string!("name"), &mut context).unwrap(),
+                JsValue::String(js_string!("boa"))
+            );
+            assert_eq!(
+                namespace.get(js_string!("sum"), &mut context).unwrap(),
+                JsValue::from(6)
+            );
+        }
+        PromiseState::Rejected(reason) => {
+            panic!("Module failed to load: {}", reason.display());
+        }
+    }
+}
@@ -1,9 +1,9 @@
 #![allow(unused_crate_dependencies, missing_docs)]
 
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, ModuleType, Referrer};
 use boa_engine::{js_string, Context, JsResult, JsString, Module, Source};
 
 #[test]
@@ -13,11 +13,11 @@ fn test_json_module_from_str() {
         fn load_imported_module(
             &self,
             _referrer: Referrer,
-            specifier: JsString,
+            request: ModuleRequest,
             finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
             context: &mut Context,
         ) {
-            assert_eq!(specifier.to_std_string_escaped(), "basic");
+            assert_eq!(request.specifier().to_std_string_escaped(), "basic");
 
             finish_load(
                 Ok(Module::parse_json(self.0.clone(), context).unwrap()),
@@ -63,3 +63,86 @@ fn test_json_module_from_str() {
         json_string
     );
 }
+
+#[test]
+fn test_import_attributes() {
+    #[derive(Default)]
+    struct TestModuleLoader(RefCell<Option<Module>>);
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            request: ModuleRequest,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            let module = match request.module_type() {
+                Ok(ModuleType::Json) => {
+                    assert_eq!(request.specifier().to_std_string_escaped(), "data.json");
+                    // Loading the same request twice must return the same module.
+                    let mut json = self.0.borrow_mut();
+                    if json.is_none() {
+                        *json = Some(
+                            Module::parse_json(js_string!(r#"{"key":"value"}"#), context).unwrap(),
+                        );
+                    }
+                    Ok(json.clone().unwrap())
+                }
+                Ok(ModuleType::JavaScript) => Module::parse(
+                    Source::from_bytes(b"export default 'javascript';"),
+                    None,
+                    context,
+                ),
+                Err(err) => Err(err),
+            };
+            finish_load(module, context);
+        }
+    }
+
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader::default()))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        import data from 'data.json' with { type: 'json' };
+        import js from 'data.json';
+        export { default as reexported } from 'data.json' with { 'type': 'json' };
+        export const key = data.key;
+        export const same = data === (await import('data.json', { with: { type: 'json' } })).default;
+        export const text = js;
+        export const error = await import('data.json', { with: { type: 'css' } })
+            .catch((e) => e.constructor.name);
+        export const unsupported = await import('data.json', { with: { other: 'json' } })
+            .catch((e) => e.constructor.name);
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    match promise.state() {
+        PromiseState::Fulfilled(v) => assert!(v.is_undefined()),
+        PromiseState::Rejected(e) => {
+            panic!("Unexpected error: {:?}", e.to_string(&mut context).unwrap());
+        }
+        PromiseState::Pending => panic!("module evaluation did not finish"),
+    }
+
+    let namespace = module.namespace(&mut context);
+    let mut get = |name: &str| {
+        namespace
+            .get(JsString::from(name), &mut context)
+            .unwrap()
+            .display()
+            .to_string()
+    };
+
+    assert_eq!(get("key"), "\"value\"");
+    assert_eq!(get("same"), "true");
+    assert_eq!(get("text"), "\"javascript\"");
+    assert_eq!(get("error"), "\"TypeError\"");
+    assert_eq!(get("unsupported"), "\"SyntaxError\"");
+}
@@ -1,4 +1,4 @@
-use crate::{trace::Trace, Gc, GcBox, Tracer};
+use crate::{trace::Trace, Gc, GcBox, GcErasedPointer, Tracer};
 use std::{cell::UnsafeCell, ptr::NonNull};
 
 use super::GcHeader;
@@ -126,6 +126,21 @@ pub(crate) trait ErasedEphemeronBox {
 
     fn trace_non_roots(&self);
 
+    /// Traces through the `EphemeronBox`'s held value regardless of whether its key is marked,
+    /// and returns the key, or `None` without tracing anything if the ephemeron was cleared or
+    /// its key isn't allocated by the garbage collector.
+    ///
+    /// # Safety
+    ///
+    /// The key of the ephemeron must still be alive, which is only guaranteed outside of a
+    /// collection.
+    unsafe fn trace_entry(&self, tracer: &mut Tracer) -> Option<GcErasedPointer>;
+
+    /// Returns `true` if the key of the `EphemeronBox` is in the young generation and wasn't
+    /// marked as reachable by the last minor collection, or if it's an external key that was
+    /// already dropped.
+    fn is_young_key_unmarked(&self) -> bool;
+
     /// Runs the finalization logic of the `EphemeronBox`'s held value, if the key is still live,
     /// and clears its contents.
     fn finalize_and_clear(&self);
@@ -173,6 +188,24 @@ impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
         }
     }
 
+    unsafe fn trace_entry(&self, tracer: &mut Tracer) -> Option<GcErasedPointer> {
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization.
+        let data = unsafe { &*self.data.get() };
+        let data = data.as_ref()?;
+
+        // SAFETY: the caller ensures the key is alive, so every value reachable from it is too.
+        unsafe { data.value.trace(tracer) };
+
+        Some(data.key.cast())
+    }
+
+    fn is_young_key_unmarked(&self) -> bool {
+        // SAFETY: The garbage collector only invalidates `key` when it is unreachable, and
+        // clears this ephemeron before that, making `key` always valid.
+        unsafe { self.key() }.is_some_and(|key| !key.header.is_old() && !key.is_marked())
+    }
+
     fn finalize_and_clear(&self) {
         // SAFETY: the invariants of the garbage collector ensures this is only executed when
         // there are no remaining references to the inner data.
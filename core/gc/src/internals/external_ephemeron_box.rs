@@ -0,0 +1,119 @@
+use crate::{trace::Trace, ExternalKey, GcErasedPointer, Tracer};
+use std::cell::UnsafeCell;
+
+use super::{ErasedEphemeronBox, GcHeader};
+
+/// The inner allocation of an [`ExternalEphemeron`][crate::ExternalEphemeron] pointer.
+pub(crate) struct ExternalEphemeronBox<K: ExternalKey, V: Trace + 'static> {
+    pub(crate) header: GcHeader,
+    key: K,
+    value: UnsafeCell<Option<V>>,
+}
+
+impl<K: ExternalKey, V: Trace> ExternalEphemeronBox<K, V> {
+    /// Creates a new `ExternalEphemeronBox` that tracks `key` and has `value` as its inner data.
+    pub(crate) fn new(key: K, value: V) -> Self {
+        Self {
+            header: GcHeader::new(),
+            key,
+            value: UnsafeCell::new(Some(value)),
+        }
+    }
+
+    /// Returns a reference to the ephemeron's key.
+    pub(crate) const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns a reference to the ephemeron's value or None.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure there are no live mutable references to the ephemeron box's data
+    /// before calling this method.
+    pub(crate) unsafe fn value(&self) -> Option<&V> {
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization.
+        unsafe { (*self.value.get()).as_ref() }
+    }
+
+    /// Marks this `ExternalEphemeronBox` as live.
+    ///
+    /// This doesn't mark the inner value of the ephemeron. [`ErasedEphemeronBox::trace`]
+    /// does this, and it's called by the garbage collector on demand.
+    pub(crate) unsafe fn mark(&self) {
+        self.header.mark();
+    }
+
+    #[inline]
+    pub(crate) fn inc_ref_count(&self) {
+        self.header.inc_ref_count();
+    }
+
+    #[inline]
+    pub(crate) fn dec_ref_count(&self) {
+        self.header.dec_ref_count();
+    }
+
+    #[inline]
+    pub(crate) fn inc_non_root_count(&self) {
+        self.header.inc_non_root_count();
+    }
+}
+
+impl<K: ExternalKey, V: Trace> ErasedEphemeronBox for ExternalEphemeronBox<K, V> {
+    fn header(&self) -> &GcHeader {
+        &self.header
+    }
+
+    unsafe fn trace(&self, tracer: &mut Tracer) -> bool {
+        if !self.header.is_marked() {
+            return false;
+        }
+
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization.
+        let Some(value) = (unsafe { self.value() }) else {
+            return true;
+        };
+
+        // A dead key never comes back to life, so the value can be cleared once this returns
+        // `false`.
+        let is_key_alive = self.key.is_alive();
+
+        if is_key_alive {
+            // SAFETY: this is safe to call, since we want to trace all reachable objects
+            // from a marked ephemeron that holds a live `key`.
+            unsafe { value.trace(tracer) }
+        }
+
+        is_key_alive
+    }
+
+    fn trace_non_roots(&self) {
+        // SAFETY: Tracing always executes before collecting, meaning this cannot cause
+        // use after free.
+        unsafe {
+            if let Some(value) = self.value() {
+                value.trace_non_roots();
+            }
+        }
+    }
+
+    unsafe fn trace_entry(&self, _tracer: &mut Tracer) -> Option<GcErasedPointer> {
+        // The key isn't a node of the heap, so there's nothing to attribute the value to.
+        None
+    }
+
+    fn is_young_key_unmarked(&self) -> bool {
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization.
+        unsafe { self.value() }.is_some() && !self.key.is_alive()
+    }
+
+    fn finalize_and_clear(&self) {
+        // SAFETY: the invariants of the garbage collector ensures this is only executed when
+        // there are no remaining references to the inner data.
+        unsafe { (*self.value.get()).take() };
+    }
+}
@@ -1,6 +1,10 @@
 use crate::Trace;
+use std::ptr::NonNull;
 
-use super::{vtable_of, DropFn, GcHeader, RunFinalizerFn, TraceFn, TraceNonRootsFn, VTable};
+use super::{
+    vtable_of, DescribeFn, DropFn, GcHeader, RunFinalizerFn, TraceFn, TraceNonRootsFn, TypeNameFn,
+    VTable,
+};
 
 /// A garbage collected allocation.
 #[derive(Debug)]
@@ -44,8 +48,15 @@ impl<T: Trace + ?Sized> GcBox<T> {
         self.header.dec_ref_count();
     }
 
+    /// Increments the non-roots count, remembering the node if it is old.
+    ///
+    /// Only minor collections count the handles of a heap with old nodes, since a full collection
+    /// clears their old bit first, so the remembered set is not looked up for every edge.
     #[inline]
     pub(crate) fn inc_non_root_count(&self) {
+        if self.header.is_old() && self.header.non_root_count() == 0 {
+            crate::remember_old_box(NonNull::from(self).cast());
+        }
         self.header.inc_non_root_count();
     }
 
@@ -79,7 +90,15 @@ impl<T: Trace + ?Sized> GcBox<T> {
         self.vtable.drop_fn()
     }
 
+    pub(crate) fn describe_fn(&self) -> DescribeFn {
+        self.vtable.describe_fn()
+    }
+
     pub(crate) fn size(&self) -> usize {
         self.vtable.size()
     }
+
+    pub(crate) fn type_name_fn(&self) -> TypeNameFn {
+        self.vtable.type_name_fn()
+    }
 }
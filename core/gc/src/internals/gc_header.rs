@@ -1,14 +1,15 @@
 use std::{cell::Cell, fmt};
 
 const MARK_MASK: u32 = 1 << (u32::BITS - 1);
-const NON_ROOTS_MASK: u32 = !MARK_MASK;
+const OLD_MASK: u32 = 1 << (u32::BITS - 2);
+const NON_ROOTS_MASK: u32 = !(MARK_MASK | OLD_MASK);
 const NON_ROOTS_MAX: u32 = NON_ROOTS_MASK;
 
 /// The `Gcheader` contains the `GcBox`'s and `EphemeronBox`'s current state for the `Collector`'s
 /// Mark/Sweep as well as a pointer to the next node in the heap.
 ///
 /// `ref_count` is the number of Gc instances, and `non_root_count` is the number of
-/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit.
+/// Gc instances in the heap. `non_root_count` also includes the Mark and Old Flag bits.
 ///
 /// The next node is set by the `Allocator` during initialization and by the
 /// `Collector` during the sweep phase.
@@ -48,7 +49,7 @@ impl GcHeader {
         }
     }
 
-    /// Decreases [`GcHeader`]'s current non-roots count.
+    /// Resets [`GcHeader`]'s current non-roots count, keeping the flag bits.
     pub(crate) fn reset_non_root_count(&self) {
         self.non_root_count
             .set(self.non_root_count.get() & !NON_ROOTS_MASK);
@@ -91,12 +92,30 @@ impl GcHeader {
         self.non_root_count
             .set(self.non_root_count.get() & !MARK_MASK);
     }
+
+    /// Returns a bool for whether [`GcHeader`]'s old bit is 1, meaning that the value survived
+    /// a collection and is not part of the young generation anymore.
+    pub(crate) fn is_old(&self) -> bool {
+        self.non_root_count.get() & OLD_MASK != 0
+    }
+
+    /// Sets [`GcHeader`]'s old bit to 1.
+    pub(crate) fn set_old(&self) {
+        self.non_root_count.set(self.non_root_count.get() | OLD_MASK);
+    }
+
+    /// Sets [`GcHeader`]'s old bit to 0.
+    pub(crate) fn clear_old(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() & !OLD_MASK);
+    }
 }
 
 impl fmt::Debug for GcHeader {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GcHeader")
             .field("marked", &self.is_marked())
+            .field("old", &self.is_old())
             .field("ref_count", &self.ref_count.get())
             .field("non_root_count", &self.non_root_count())
             .finish_non_exhaustive()
@@ -1,4 +1,4 @@
-use crate::{GcBox, GcErasedPointer, Trace, Tracer};
+use crate::{GcBox, GcErasedPointer, HeapNodeDescription, Trace, Tracer};
 
 // Workaround: https://users.rust-lang.org/t/custom-vtables-with-integers/78508
 pub(crate) const fn vtable_of<T: Trace + 'static>() -> &'static VTable {
@@ -32,6 +32,13 @@ pub(crate) const fn vtable_of<T: Trace + 'static>() -> &'static VTable {
             Self::run_finalizer(value);
         }
 
+        unsafe fn describe_fn(this: GcErasedPointer, node: &mut HeapNodeDescription) {
+            // SAFETY: The caller must ensure that the passed erased pointer is `GcBox<Self>`.
+            let value = unsafe { this.cast::<GcBox<Self>>().as_ref().value() };
+
+            Self::describe(value, node);
+        }
+
         // SAFETY: The caller must ensure that the passed erased pointer is `GcBox<Self>`.
         unsafe fn drop_fn(this: GcErasedPointer) {
             // SAFETY: The caller must ensure that the passed erased pointer is `GcBox<Self>`.
@@ -48,7 +55,9 @@ pub(crate) const fn vtable_of<T: Trace + 'static>() -> &'static VTable {
             trace_non_roots_fn: T::trace_non_roots_fn,
             run_finalizer_fn: T::run_finalizer_fn,
             drop_fn: T::drop_fn,
+            describe_fn: T::describe_fn,
             size: size_of::<GcBox<T>>(),
+            type_name_fn: std::any::type_name::<T>,
         };
     }
 
@@ -59,6 +68,8 @@ pub(crate) type TraceFn = unsafe fn(this: GcErasedPointer, tracer: &mut Tracer);
 pub(crate) type TraceNonRootsFn = unsafe fn(this: GcErasedPointer);
 pub(crate) type RunFinalizerFn = unsafe fn(this: GcErasedPointer);
 pub(crate) type DropFn = unsafe fn(this: GcErasedPointer);
+pub(crate) type DescribeFn = unsafe fn(this: GcErasedPointer, node: &mut HeapNodeDescription);
+pub(crate) type TypeNameFn = fn() -> &'static str;
 
 #[derive(Debug)]
 pub(crate) struct VTable {
@@ -66,7 +77,9 @@ pub(crate) struct VTable {
     trace_non_roots_fn: TraceNonRootsFn,
     run_finalizer_fn: RunFinalizerFn,
     drop_fn: DropFn,
+    describe_fn: DescribeFn,
     size: usize,
+    type_name_fn: TypeNameFn,
 }
 
 impl VTable {
@@ -86,7 +99,15 @@ impl VTable {
         self.drop_fn
     }
 
+    pub(crate) fn describe_fn(&self) -> DescribeFn {
+        self.describe_fn
+    }
+
     pub(crate) fn size(&self) -> usize {
         self.size
     }
+
+    pub(crate) fn type_name_fn(&self) -> TypeNameFn {
+        self.type_name_fn
+    }
 }
@@ -1,8 +1,15 @@
-use crate::{pointers::RawWeakMap, GcRefCell, Trace, Tracer, WeakGc};
+use crate::{GcRefCell, Trace, Tracer, WeakGc};
 
-/// A box that is used to track [`WeakMap`][`crate::WeakMap`]s.
-pub(crate) struct WeakMapBox<K: Trace + ?Sized + 'static, V: Trace + Sized + 'static> {
-    pub(crate) map: WeakGc<GcRefCell<RawWeakMap<K, V>>>,
+/// A map that holds its keys weakly, and whose expired entries can be cleared.
+pub(crate) trait RawWeakKeyedMap: Trace + 'static {
+    /// Clears all the expired keys in the map.
+    fn clear_expired(&mut self);
+}
+
+/// A box that is used to track [`WeakMap`][`crate::WeakMap`]s and
+/// [`ExternalWeakMap`][`crate::ExternalWeakMap`]s.
+pub(crate) struct WeakMapBox<M: RawWeakKeyedMap> {
+    pub(crate) map: WeakGc<GcRefCell<M>>,
 }
 
 /// A trait that is used to erase the type of a [`WeakMapBox`].
@@ -17,7 +24,7 @@ pub(crate) trait ErasedWeakMapBox {
     unsafe fn trace(&self, tracer: &mut Tracer);
 }
 
-impl<K: Trace + ?Sized, V: Trace + Clone> ErasedWeakMapBox for WeakMapBox<K, V> {
+impl<M: RawWeakKeyedMap> ErasedWeakMapBox for WeakMapBox<M> {
     fn clear_dead_entries(&self) {
         if let Some(map) = self.map.upgrade() {
             if let Ok(mut map) = map.try_borrow_mut() {
@@ -1,7 +1,7 @@
 //! Boa's **`boa_gc`** crate implements a garbage collector.
 //!
 //! # Crate Overview
-//! **`boa_gc`** is a mark-sweep garbage collector that implements a [`Trace`] and [`Finalize`] trait
+//! **`boa_gc`** is a generational mark-sweep garbage collector that implements a [`Trace`] and [`Finalize`] trait
 //! for garbage collected values.
 #![doc = include_str!("../ABOUT.md")]
 #![doc(
@@ -19,30 +19,60 @@ extern crate self as boa_gc;
 
 mod cell;
 mod pointers;
+mod snapshot;
 mod trace;
 
 pub(crate) mod internals;
 
 use boa_profiler::Profiler;
-use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
-use pointers::{NonTraceable, RawWeakMap};
+use internals::{ErasedEphemeronBox, ErasedWeakMapBox, RawWeakKeyedMap, WeakMapBox};
+use pointers::NonTraceable;
 use std::{
     cell::{Cell, RefCell},
     mem,
     ptr::NonNull,
+    rc::Rc,
+    time::Duration,
 };
 
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    feature = "js"
+))]
+use web_time::Instant;
+
+// `wasm32-unknown-unknown` has no clock without the `js` feature, so `std::time::Instant` isn't
+// used there.
+#[cfg(not(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+)))]
+use std::time::Instant;
+
 pub use crate::trace::{Finalize, Trace, Tracer};
 pub use boa_macros::{Finalize, Trace};
 pub use cell::{GcRef, GcRefCell, GcRefMut};
 pub use internals::GcBox;
-pub use pointers::{Ephemeron, Gc, WeakGc, WeakMap};
+pub use pointers::{
+    Ephemeron, ExternalEphemeron, ExternalKey, ExternalWeakMap, Gc, WeakGc, WeakMap,
+};
+pub use snapshot::{
+    HeapEdge, HeapEdgeName, HeapNodeDescription, HeapNodeKind, HeapSnapshot, HeapSnapshotNode,
+};
 
 type GcErasedPointer = NonNull<GcBox<NonTraceable>>;
 type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
 type ErasedWeakMapBoxPointer = NonNull<dyn ErasedWeakMapBox>;
+type NearHeapLimitCallback = Rc<dyn Fn(HeapUsage)>;
+type CollectionHook = Rc<dyn Fn(CollectionPhase, GcStats)>;
 
 thread_local!(static GC_DROPPING: Cell<bool> = const { Cell::new(false) });
+thread_local!(static EXTERNAL_BYTES: Cell<usize> = const { Cell::new(0) });
+thread_local!(static HEAP_LIMIT_EXCEEDED: Cell<bool> = const { Cell::new(false) });
+thread_local!(static NEAR_HEAP_LIMIT_CALLBACK: RefCell<Option<NearHeapLimitCallback>> = RefCell::new(None));
+thread_local!(static COLLECTION_HOOK: RefCell<Option<CollectionHook>> = RefCell::new(None));
+thread_local!(static REMEMBERED_OLD_BOXES: RefCell<Option<Vec<GcErasedPointer>>> = const { RefCell::new(None) });
 thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     config: GcConfig::default(),
     runtime: GcRuntimeData::default(),
@@ -57,25 +87,140 @@ struct GcConfig {
     threshold: usize,
     /// The percentage of used space at which the garbage collector will trigger a collection.
     used_space_percentage: usize,
+    /// The maximum size of the heap, including external memory.
+    heap_limit: usize,
+    /// The number of bytes allocated since the last collection at which the garbage collector
+    /// will trigger a minor collection. Zero disables minor collections.
+    young_generation_size: usize,
 }
 
 // Setting the defaults to an arbitrary value currently.
-//
-// TODO: Add a configure later
 impl Default for GcConfig {
     fn default() -> Self {
         Self {
             // Start at 1MB, the nursary size for V8 is ~1-8MB and SM can be up to 16MB
             threshold: 1_048_576,
             used_space_percentage: 70,
+            heap_limit: usize::MAX,
+            young_generation_size: 1_048_576,
         }
     }
 }
 
+/// The percentage of the heap limit at which the near heap limit callback is called.
+const NEAR_HEAP_LIMIT_PERCENTAGE: usize = 80;
+
+/// The percentage of the heap limit by which a heap that is over the limit has to grow before
+/// another collection is forced.
+const HEAP_LIMIT_GROWTH_PERCENTAGE: usize = 5;
+
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
     collections: usize,
+    minor_collections: usize,
     bytes_allocated: usize,
+    /// The number of bytes of the young generation, allocated since the last collection.
+    young_bytes: usize,
+    /// The index in `BoaGc::strongs` at which the young generation starts.
+    young_start: usize,
+    near_heap_limit_notified: bool,
+    /// The heap usage after the last collection that couldn't bring the heap under its limit.
+    over_heap_limit_used: Option<usize>,
+    last_pause: Duration,
+}
+
+/// A snapshot of the statistics of the garbage collector of the current thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GcStats {
+    /// The number of bytes allocated by the garbage collector.
+    pub bytes_allocated: usize,
+    /// The number of bytes owned by garbage collected values but allocated outside the garbage
+    /// collector, as reported with [`adjust_external_memory`].
+    pub external_bytes: usize,
+    /// The number of bytes allocated at which the next collection is triggered.
+    pub threshold: usize,
+    /// The number of garbage collected values, not including ephemerons.
+    pub object_count: usize,
+    /// The number of ephemerons, used by weak references.
+    pub ephemeron_count: usize,
+    /// The number of weak maps.
+    pub weak_map_count: usize,
+    /// The number of full collections run so far.
+    pub collections: usize,
+    /// The number of minor collections run so far.
+    pub minor_collections: usize,
+    /// The number of garbage collected values in the young generation.
+    pub young_object_count: usize,
+    /// The duration of the last collection, which is always zero on `wasm32-unknown-unknown`
+    /// without the `js` feature.
+    pub last_pause: Duration,
+}
+
+/// Measures the pause of a collection.
+///
+/// On `wasm32-unknown-unknown` without the `js` feature there is no clock, and pauses are always
+/// reported as zero.
+struct PauseTimer {
+    #[cfg(not(all(
+        target_family = "wasm",
+        not(any(target_os = "emscripten", target_os = "wasi")),
+        not(feature = "js")
+    )))]
+    start: Instant,
+}
+
+impl PauseTimer {
+    /// Starts measuring a pause.
+    fn start() -> Self {
+        Self {
+            #[cfg(not(all(
+                target_family = "wasm",
+                not(any(target_os = "emscripten", target_os = "wasi")),
+                not(feature = "js")
+            )))]
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the duration of the pause so far.
+    #[cfg(not(all(
+        target_family = "wasm",
+        not(any(target_os = "emscripten", target_os = "wasi")),
+        not(feature = "js")
+    )))]
+    fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the duration of the pause so far.
+    #[cfg(all(
+        target_family = "wasm",
+        not(any(target_os = "emscripten", target_os = "wasi")),
+        not(feature = "js")
+    ))]
+    #[allow(clippy::unused_self)]
+    fn elapsed(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// The phase of a collection in which the collection hook is called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionPhase {
+    /// The collection is about to start.
+    Start,
+    /// The collection has finished.
+    End,
+}
+
+/// The usage of the heap reported to the near heap limit callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapUsage {
+    /// The number of bytes in use, including external memory.
+    pub used: usize,
+    /// The maximum number of bytes of the heap.
+    pub limit: usize,
 }
 
 #[derive(Debug)]
@@ -87,6 +232,31 @@ struct BoaGc {
     weak_maps: Vec<ErasedWeakMapBoxPointer>,
 }
 
+impl BoaGc {
+    /// Returns a snapshot of the statistics of the garbage collector.
+    fn stats(&self) -> GcStats {
+        GcStats {
+            bytes_allocated: self.runtime.bytes_allocated,
+            external_bytes: EXTERNAL_BYTES.with(Cell::get),
+            threshold: self.config.threshold,
+            object_count: self.strongs.len(),
+            ephemeron_count: self.weaks.len(),
+            weak_map_count: self.weak_maps.len(),
+            collections: self.runtime.collections,
+            minor_collections: self.runtime.minor_collections,
+            young_object_count: self.strongs.len() - self.runtime.young_start,
+            last_pause: self.runtime.last_pause,
+        }
+    }
+
+    /// Returns the number of bytes in use, including external memory.
+    fn heap_used(&self) -> usize {
+        self.runtime
+            .bytes_allocated
+            .saturating_add(EXTERNAL_BYTES.with(Cell::get))
+    }
+}
+
 impl Drop for BoaGc {
     fn drop(&mut self) {
         Collector::dump(self);
@@ -132,30 +302,35 @@ impl Allocator {
     fn alloc_gc<T: Trace>(value: GcBox<T>) -> NonNull<GcBox<T>> {
         let _timer = Profiler::global().start_event("New GcBox", "BoaAlloc");
         let element_size = size_of_val::<GcBox<T>>(&value);
-        BOA_GC.with(|st| {
+        let (ptr, usage) = BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            let collected = Self::manage_state(&mut gc);
+            let usage = Self::manage_heap_limit(&mut gc, element_size, collected);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<GcBox<NonTraceable>> = ptr.cast();
 
             gc.strongs.push(erased);
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.young_bytes += element_size;
 
-            ptr
-        })
+            (ptr, usage)
+        });
+        if let Some(usage) = usage {
+            notify_near_heap_limit(usage);
+        }
+        ptr
     }
 
-    fn alloc_ephemeron<K: Trace + ?Sized, V: Trace>(
-        value: EphemeronBox<K, V>,
-    ) -> NonNull<EphemeronBox<K, V>> {
+    fn alloc_ephemeron<E: ErasedEphemeronBox + 'static>(value: E) -> NonNull<E> {
         let _timer = Profiler::global().start_event("New EphemeronBox", "BoaAlloc");
-        let element_size = size_of_val::<EphemeronBox<K, V>>(&value);
-        BOA_GC.with(|st| {
+        let element_size = size_of_val::<E>(&value);
+        let (ptr, usage) = BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            let collected = Self::manage_state(&mut gc);
+            let usage = Self::manage_heap_limit(&mut gc, element_size, collected);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<dyn ErasedEphemeronBox> = ptr;
@@ -163,17 +338,19 @@ impl Allocator {
             gc.weaks.push(erased);
             gc.runtime.bytes_allocated += element_size;
 
-            ptr
-        })
+            (ptr, usage)
+        });
+        if let Some(usage) = usage {
+            notify_near_heap_limit(usage);
+        }
+        ptr
     }
 
-    fn alloc_weak_map<K: Trace + ?Sized, V: Trace + Clone>() -> WeakMap<K, V> {
+    fn alloc_weak_map<M: RawWeakKeyedMap + Default>() -> Gc<GcRefCell<M>> {
         let _timer = Profiler::global().start_event("New WeakMap", "BoaAlloc");
 
-        let weak_map = WeakMap {
-            inner: Gc::new(GcRefCell::new(RawWeakMap::new())),
-        };
-        let weak = WeakGc::new(&weak_map.inner);
+        let weak_map = Gc::new(GcRefCell::new(M::default()));
+        let weak = WeakGc::new(&weak_map);
 
         BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
@@ -190,7 +367,8 @@ impl Allocator {
         })
     }
 
-    fn manage_state(gc: &mut BoaGc) {
+    /// Triggers a collection if the heap grew enough, returning `true` if a full collection ran.
+    fn manage_state(gc: &mut BoaGc) -> bool {
         if gc.runtime.bytes_allocated > gc.config.threshold {
             Collector::collect(gc);
 
@@ -202,7 +380,92 @@ impl Allocator {
                 gc.config.threshold =
                     gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
             }
+            true
+        } else {
+            if gc.config.young_generation_size != 0
+                && gc.runtime.young_bytes > gc.config.young_generation_size
+            {
+                Collector::collect_young(gc);
+            }
+            false
+        }
+    }
+
+    /// Collects the heap if allocating `additional` bytes would exceed the heap limit.
+    ///
+    /// The collection is skipped if `collected` is `true`, since a full collection just ran, or
+    /// if the heap is still over the limit after a previous collection and didn't grow by
+    /// [`HEAP_LIMIT_GROWTH_PERCENTAGE`] of the limit since, so that allocating on a full heap
+    /// doesn't collect it every time. Returns the heap usage after the allocation, and `true` if
+    /// the allocation exceeds the limit even after collecting.
+    fn collect_over_heap_limit(
+        gc: &mut BoaGc,
+        additional: usize,
+        collected: bool,
+    ) -> (usize, bool) {
+        let limit = gc.config.heap_limit;
+        let used = gc.heap_used().saturating_add(additional);
+        if used <= limit {
+            gc.runtime.over_heap_limit_used = None;
+            return (used, false);
+        }
+
+        let growth = limit / 100 * HEAP_LIMIT_GROWTH_PERCENTAGE;
+        if !collected {
+            if gc
+                .runtime
+                .over_heap_limit_used
+                .is_some_and(|previous| used <= previous.saturating_add(growth))
+            {
+                return (used, false);
+            }
+            Collector::collect(gc);
+        }
+
+        let used = gc.heap_used().saturating_add(additional);
+        let exceeded = used > limit;
+        gc.runtime.over_heap_limit_used = exceeded.then(|| gc.heap_used());
+        (used, exceeded)
+    }
+
+    /// Enforces the heap limit before allocating `additional` bytes.
+    ///
+    /// If the allocation would exceed the limit, a collection is forced, and if the heap is still
+    /// too big, the limit is flagged as exceeded. Returns the heap usage if the near heap limit
+    /// callback has to be called.
+    fn manage_heap_limit(gc: &mut BoaGc, additional: usize, collected: bool) -> Option<HeapUsage> {
+        let limit = gc.config.heap_limit;
+        if limit == usize::MAX {
+            return None;
+        }
+
+        let (used, exceeded) = Self::collect_over_heap_limit(gc, additional, collected);
+        if exceeded {
+            HEAP_LIMIT_EXCEEDED.with(|exceeded| exceeded.set(true));
         }
+
+        if used < limit / 100 * NEAR_HEAP_LIMIT_PERCENTAGE {
+            gc.runtime.near_heap_limit_notified = false;
+            return None;
+        }
+        if gc.runtime.near_heap_limit_notified {
+            return None;
+        }
+        gc.runtime.near_heap_limit_notified = true;
+
+        Some(HeapUsage { used, limit })
+    }
+}
+
+/// Calls the near heap limit callback, if any.
+fn notify_near_heap_limit(usage: HeapUsage) {
+    // The callback is cloned out of the cell, so that it can replace itself.
+    let callback = NEAR_HEAP_LIMIT_CALLBACK
+        .try_with(|callback| callback.borrow().clone())
+        .ok()
+        .flatten();
+    if let Some(callback) = callback {
+        callback(usage);
     }
 }
 
@@ -223,14 +486,37 @@ struct Unreachables {
 /// A better approach in a more concurrent structure may be to reorder.
 ///
 /// Mark -> Sweep -> Finalize
+///
+/// Between full collections, minor collections run the same phases over the young generation
+/// only, which are the values allocated since the last collection, and promote the survivors
+/// to the old generation. Handles from old values are not counted by a minor collection, so any
+/// young value referenced from the old generation is considered a root. This makes a write
+/// barrier unnecessary, because mutating an old value through a `GcRefCell` (or any other
+/// interior mutability) is always seen by the next minor collection.
+///
+/// A full collection clears the old bit of every node before counting the handles, and the
+/// sweep sets it again on the survivors, so that only minor collections remember the old nodes
+/// referenced from the young generation.
 struct Collector;
 
 impl Collector {
     /// Run a collection on the full heap.
     fn collect(gc: &mut BoaGc) {
         let _timer = Profiler::global().start_event("Gc Full Collection", "gc");
+        let hook = COLLECTION_HOOK.with(|hook| hook.borrow().clone());
+        if let Some(hook) = &hook {
+            hook(CollectionPhase::Start, gc.stats());
+        }
+
+        let pause = PauseTimer::start();
         gc.runtime.collections += 1;
 
+        // Old nodes only need to be remembered by minor collections.
+        for node in &gc.strongs {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { node.as_ref() }.header.clear_old();
+        }
+
         Self::trace_non_roots(gc);
 
         let mut tracer = Tracer::new();
@@ -260,27 +546,116 @@ impl Collector {
         }
 
         // Weak maps have to be cleared after the sweep, since the process dereferences GcBoxes.
-        gc.weak_maps.retain(|w| {
-            // SAFETY: The caller must ensure the validity of every node of `heap_start`.
-            let node_ref = unsafe { w.as_ref() };
+        Self::clear_weak_maps(&mut gc.weak_maps);
 
-            if node_ref.is_live() {
-                node_ref.clear_dead_entries();
+        gc.strongs.shrink_to(gc.strongs.len() >> 2);
+        gc.weaks.shrink_to(gc.weaks.len() >> 2);
+        gc.weak_maps.shrink_to(gc.weak_maps.len() >> 2);
 
-                true
-            } else {
-                // SAFETY:
-                // The `Allocator` must always ensure its start node is a valid, non-null pointer that
-                // was allocated by `Box::from_raw(Box::new(..))`.
-                let _unmarked_node = unsafe { Box::from_raw(w.as_ptr()) };
+        // All the survivors are promoted to the old generation.
+        gc.runtime.young_start = gc.strongs.len();
+        gc.runtime.young_bytes = 0;
 
-                false
+        gc.runtime.last_pause = pause.elapsed();
+        if let Some(hook) = &hook {
+            hook(CollectionPhase::End, gc.stats());
+        }
+    }
+
+    /// Run a collection on the young generation.
+    ///
+    /// This intentionally uses neither a write barrier on `GcRefCell` mutations nor incremental
+    /// marking. Instead, the handles of the old generation are not counted, so the reference
+    /// counts already make every young node referenced from an old node (or from outside the
+    /// heap) a root of the minor collection. The pause is then bounded by the size of the young
+    /// generation, while the `Trace` and `Finalize` contract stays the same.
+    fn collect_young(gc: &mut BoaGc) {
+        let _timer = Profiler::global().start_event("Gc Minor Collection", "gc");
+        let hook = COLLECTION_HOOK.with(|hook| hook.borrow().clone());
+        if let Some(hook) = &hook {
+            hook(CollectionPhase::Start, gc.stats());
+        }
+
+        let pause = PauseTimer::start();
+        gc.runtime.minor_collections += 1;
+        let young_start = gc.runtime.young_start;
+
+        // Count the handles located in the young generation, remembering the old nodes that are
+        // referenced from it. Their counts are only valid during a full collection, so they are
+        // reset right away.
+        REMEMBERED_OLD_BOXES.with(|remembered| *remembered.borrow_mut() = Some(Vec::new()));
+        for node in &gc.strongs[young_start..] {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let trace_non_roots_fn = unsafe { node.as_ref() }.trace_non_roots_fn();
+
+            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+            unsafe {
+                trace_non_roots_fn(*node);
             }
-        });
+        }
+        let remembered = REMEMBERED_OLD_BOXES
+            .with(|remembered| remembered.borrow_mut().take())
+            .unwrap_or_default();
+        for node in remembered {
+            // SAFETY: old nodes cannot be dropped by a minor collection.
+            unsafe { node.as_ref() }.reset_non_root_count();
+        }
 
-        gc.strongs.shrink_to(gc.strongs.len() >> 2);
-        gc.weaks.shrink_to(gc.weaks.len() >> 2);
-        gc.weak_maps.shrink_to(gc.weak_maps.len() >> 2);
+        let mut tracer = Tracer::new();
+
+        let unreachables = Self::mark_young(&mut tracer, &gc.strongs[young_start..]);
+
+        assert!(tracer.is_empty(), "The queue should be empty");
+
+        if !unreachables.is_empty() {
+            // SAFETY: All passed pointers are valid, since we won't deallocate until `Self::sweep_young`.
+            unsafe {
+                Self::finalize(Unreachables {
+                    strong: unreachables,
+                    weak: Vec::new(),
+                });
+            }
+
+            // Reuse the tracer's already allocated capacity.
+            let _final_unreachables = Self::mark_young(&mut tracer, &gc.strongs[young_start..]);
+        }
+
+        // Ephemerons are never collected by a minor collection, but the ones whose key is about
+        // to be dropped have to be cleared. Tracing young nodes could also have marked them.
+        let mut cleared_ephemerons = false;
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let eph_ref = unsafe { eph.as_ref() };
+            let header = eph_ref.header();
+            header.unmark();
+            header.reset_non_root_count();
+
+            if eph_ref.is_young_key_unmarked() {
+                eph_ref.finalize_and_clear();
+                cleared_ephemerons = true;
+            }
+        }
+
+        // SAFETY: The young generation only contains valid nodes per the invariants of our GC.
+        unsafe {
+            Self::sweep_young(
+                &mut gc.strongs,
+                young_start,
+                &mut gc.runtime.bytes_allocated,
+            );
+        }
+
+        if cleared_ephemerons {
+            Self::clear_weak_maps(&mut gc.weak_maps);
+        }
+
+        gc.runtime.young_start = gc.strongs.len();
+        gc.runtime.young_bytes = 0;
+
+        gc.runtime.last_pause = pause.elapsed();
+        if let Some(hook) = &hook {
+            hook(CollectionPhase::End, gc.stats());
+        }
     }
 
     fn trace_non_roots(gc: &BoaGc) {
@@ -378,15 +753,7 @@ impl Collector {
                 pending_ephemerons.push(*eph);
             }
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            Self::mark_enqueued(tracer);
         }
 
         // 2. Trace all the weak pointers in the live weak maps to make sure they do not get swept.
@@ -397,15 +764,7 @@ impl Collector {
             // SAFETY: The garbage collector ensures that all nodes are valid.
             unsafe { node_ref.trace(tracer) };
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            Self::mark_enqueued(tracer);
         }
 
         // 3. Iterate through all pending ephemerons, removing the ones which have been successfully
@@ -419,15 +778,7 @@ impl Collector {
                 // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
                 let is_key_marked = unsafe { !eph_ref.trace(tracer) };
 
-                while let Some(node) = tracer.next() {
-                    // SAFETY: node must be valid as this phase cannot drop any node.
-                    let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                    // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                    unsafe {
-                        trace_fn(node, tracer);
-                    }
-                }
+                Self::mark_enqueued(tracer);
 
                 is_key_marked
             });
@@ -453,6 +804,63 @@ impl Collector {
         }
     }
 
+    /// Marks and traces the nodes enqueued in `tracer`, and all the nodes reachable from them.
+    fn mark_enqueued(tracer: &mut Tracer) {
+        while let Some(node) = tracer.next() {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+
+            if !node_ref.header.is_marked() {
+                node_ref.header.mark();
+
+                let trace_fn = node_ref.trace_fn();
+
+                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                unsafe { trace_fn(node, tracer) }
+            }
+        }
+    }
+
+    /// Walk the young generation and mark any nodes deemed reachable, returning the unreachable
+    /// ones.
+    ///
+    /// Old nodes are neither marked nor traced, since a minor collection cannot drop them.
+    fn mark_young(tracer: &mut Tracer, young: &[GcErasedPointer]) -> Vec<GcErasedPointer> {
+        let _timer = Profiler::global().start_event("Gc Minor Marking", "gc");
+
+        let mut strong_dead = Vec::new();
+        for node in young {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+            if node_ref.is_rooted() {
+                tracer.enqueue(*node);
+
+                while let Some(node) = tracer.next() {
+                    // SAFETY: the gc heap object should be alive if there is a root.
+                    let node_ref = unsafe { node.as_ref() };
+
+                    if !node_ref.header.is_old() && !node_ref.header.is_marked() {
+                        node_ref.header.mark();
+
+                        let trace_fn = node_ref.trace_fn();
+
+                        // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                        unsafe { trace_fn(node, tracer) }
+                    }
+                }
+            } else if !node_ref.is_marked() {
+                strong_dead.push(*node);
+            }
+        }
+
+        strong_dead.retain_mut(|node| {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { !node.as_ref().is_marked() }
+        });
+
+        strong_dead
+    }
+
     /// # Safety
     ///
     /// Passing a `strong` or a `weak` vec with invalid pointers will result in Undefined Behaviour.
@@ -495,6 +903,7 @@ impl Collector {
             if node_ref.is_marked() {
                 node_ref.header.unmark();
                 node_ref.reset_non_root_count();
+                node_ref.header.set_old();
 
                 true
             } else {
@@ -534,6 +943,72 @@ impl Collector {
         });
     }
 
+    /// # Safety
+    ///
+    /// - Providing a `strong` vec with invalid pointers after `young_start` will result in
+    ///   Undefined Behaviour.
+    /// - Providing a list of pointers that weren't allocated by `Box::into_raw(Box::new(..))`
+    ///   will result in Undefined Behaviour.
+    unsafe fn sweep_young(
+        strong: &mut Vec<GcErasedPointer>,
+        young_start: usize,
+        total_allocated: &mut usize,
+    ) {
+        let _timer = Profiler::global().start_event("Gc Minor Sweeping", "gc");
+        let _guard = DropGuard::new();
+
+        let mut survivors = young_start;
+        for index in young_start..strong.len() {
+            let node = strong[index];
+            // SAFETY: The caller must ensure the validity of every young node.
+            let node_ref = unsafe { node.as_ref() };
+            if node_ref.is_marked() {
+                node_ref.header.unmark();
+                node_ref.reset_non_root_count();
+                node_ref.header.set_old();
+
+                // Survivors keep their allocation order.
+                strong[survivors] = node;
+                survivors += 1;
+            } else {
+                // SAFETY: The algorithm ensures only unmarked/unreachable pointers are dropped.
+                // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
+                let drop_fn = node_ref.drop_fn();
+                let size = node_ref.size();
+                *total_allocated -= size;
+
+                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                unsafe {
+                    drop_fn(node);
+                }
+            }
+        }
+        strong.truncate(survivors);
+    }
+
+    /// Drops the weak maps that are not live anymore, and clears the dead entries of the rest.
+    ///
+    /// This has to run after sweeping, since the process dereferences `GcBox`es.
+    fn clear_weak_maps(weak_maps: &mut Vec<ErasedWeakMapBoxPointer>) {
+        weak_maps.retain(|w| {
+            // SAFETY: The caller must ensure the validity of every node of `heap_start`.
+            let node_ref = unsafe { w.as_ref() };
+
+            if node_ref.is_live() {
+                node_ref.clear_dead_entries();
+
+                true
+            } else {
+                // SAFETY:
+                // The `Allocator` must always ensure its start node is a valid, non-null pointer that
+                // was allocated by `Box::from_raw(Box::new(..))`.
+                let _unmarked_node = unsafe { Box::from_raw(w.as_ptr()) };
+
+                false
+            }
+        });
+    }
+
     // Clean up the heap when BoaGc is dropped
     fn dump(gc: &mut BoaGc) {
         // Weak maps have to be dropped first, since the process dereferences GcBoxes.
@@ -580,6 +1055,216 @@ pub fn force_collect() {
     });
 }
 
+/// Returns a snapshot of the statistics of the garbage collector of the current thread.
+#[must_use]
+pub fn stats() -> GcStats {
+    BOA_GC.with(|current| current.borrow().stats())
+}
+
+/// Takes a snapshot of the garbage collected heap of the current thread.
+///
+/// Every node is described through [`Trace::describe`], so those implementations must not
+/// allocate garbage collected values.
+///
+/// # Panics
+///
+/// Panics if called during a collection.
+#[must_use]
+pub fn heap_snapshot() -> HeapSnapshot {
+    BOA_GC.with(|current| HeapSnapshot::capture(&current.borrow()))
+}
+
+/// Sets the number of bytes allocated at which the next collection is triggered.
+///
+/// The threshold keeps growing after collections that free too little memory, see
+/// [`set_used_space_percentage`].
+pub fn set_collection_threshold(threshold: usize) {
+    BOA_GC.with(|current| current.borrow_mut().config.threshold = threshold);
+}
+
+/// Sets the growth policy of the collection threshold.
+///
+/// If the bytes still allocated after a collection are more than `percentage` percent of the
+/// threshold, the threshold grows so that they are exactly `percentage` percent of it. The
+/// percentage is clamped to the `1..=100` range, and defaults to `70`.
+pub fn set_used_space_percentage(percentage: usize) {
+    BOA_GC.with(|current| {
+        current.borrow_mut().config.used_space_percentage = percentage.clamp(1, 100);
+    });
+}
+
+/// Sets the number of bytes allocated since the last collection at which a minor collection
+/// is triggered.
+///
+/// A minor collection only scans the values allocated since the last collection, so its pause
+/// is bounded by this size instead of by the size of the whole heap. Setting the size to `0`
+/// disables minor collections. Defaults to 1MB.
+pub fn set_young_generation_size(size: usize) {
+    BOA_GC.with(|current| current.borrow_mut().config.young_generation_size = size);
+}
+
+/// Sets the hook called before and after each collection of the current thread, including
+/// minor collections, receiving the statistics of the garbage collector at that point.
+///
+/// # Panics
+///
+/// The garbage collector is in use while the hook runs, so the hook panics if it allocates
+/// garbage collected values or calls the functions of this crate that access the heap.
+pub fn set_collection_hook(hook: Option<Box<dyn Fn(CollectionPhase, GcStats)>>) {
+    COLLECTION_HOOK.with(|current| *current.borrow_mut() = hook.map(Rc::from));
+}
+
+/// Returns the maximum size of the heap of the current thread, in bytes.
+///
+/// The value [`usize::MAX`] means that there is no limit.
+#[must_use]
+pub fn heap_limit() -> usize {
+    BOA_GC.with(|current| current.borrow().config.heap_limit)
+}
+
+/// Sets the maximum size of the heap of the current thread, in bytes.
+///
+/// The heap size includes the memory reported with [`adjust_external_memory`]. If an allocation
+/// would exceed the limit, a collection is forced first, and if the heap is still too big the
+/// allocation proceeds but the limit is flagged as exceeded; the embedder is expected to check
+/// [`take_heap_limit_exceeded`] and abort the work that is allocating. While the heap stays over
+/// the limit, collections are only forced again once it has grown by 5% of the limit.
+///
+/// Setting the limit to [`usize::MAX`] means that there is no limit.
+pub fn set_heap_limit(limit: usize) {
+    BOA_GC.with(|current| current.borrow_mut().config.heap_limit = limit);
+}
+
+/// Returns `true` if `additional` bytes can be allocated outside the garbage collector without
+/// exceeding the heap limit of the current thread.
+///
+/// A collection is forced first if they wouldn't fit. Unlike the allocations of the garbage
+/// collector, this doesn't flag the limit as exceeded, so callers are expected to fail the
+/// allocation themselves when this returns `false`.
+#[must_use]
+pub fn fits_heap_limit(additional: usize) -> bool {
+    BOA_GC.with(|current| {
+        let mut gc = current.borrow_mut();
+        if gc.config.heap_limit == usize::MAX {
+            return true;
+        }
+        let (used, _) = Allocator::collect_over_heap_limit(&mut gc, additional, false);
+        used <= gc.config.heap_limit
+    })
+}
+
+/// Sets the callback called once the heap of the current thread grows close to its limit.
+///
+/// The callback is called once when the heap reaches 80% of the limit, and is called again
+/// only after the heap shrinks below that.
+pub fn set_near_heap_limit_callback(callback: Option<Box<dyn Fn(HeapUsage)>>) {
+    NEAR_HEAP_LIMIT_CALLBACK.with(|current| *current.borrow_mut() = callback.map(Rc::from));
+}
+
+/// Returns `true` if the heap limit was exceeded since the last call, clearing the flag.
+#[must_use]
+pub fn take_heap_limit_exceeded() -> bool {
+    HEAP_LIMIT_EXCEEDED.with(|exceeded| exceeded.replace(false))
+}
+
+/// Reports a change in the size of the memory owned by a garbage collected value, but not
+/// allocated by the garbage collector, from `old_size` to `new_size` bytes.
+///
+/// This memory counts towards the heap limit, so growing it can force a collection. Prefer
+/// [`ExternalMemory`] to keep the reported size in sync with the lifetime of the value.
+pub fn adjust_external_memory(old_size: usize, new_size: usize) {
+    if old_size == new_size {
+        return;
+    }
+
+    // This can be called while dropping the values of the heap, even when the thread is exiting.
+    let _ = EXTERNAL_BYTES.try_with(|bytes| {
+        bytes.set(
+            bytes
+                .get()
+                .saturating_sub(old_size)
+                .saturating_add(new_size),
+        );
+    });
+
+    if new_size < old_size {
+        return;
+    }
+
+    let usage = BOA_GC
+        .try_with(|current| {
+            // The heap is already borrowed if this is called while collecting.
+            current
+                .try_borrow_mut()
+                .ok()
+                .and_then(|mut gc| Allocator::manage_heap_limit(&mut gc, 0, false))
+        })
+        .ok()
+        .flatten();
+    if let Some(usage) = usage {
+        notify_near_heap_limit(usage);
+    }
+}
+
+/// The memory owned by a garbage collected value but allocated outside the garbage collector,
+/// reported with [`adjust_external_memory`] while it is alive.
+///
+/// The reported size is removed when this is dropped, so it must be dropped on the thread that
+/// created it.
+#[derive(Debug, Default, Finalize)]
+pub struct ExternalMemory {
+    size: usize,
+}
+
+impl ExternalMemory {
+    /// Reports `size` bytes of external memory.
+    #[must_use]
+    pub fn new(size: usize) -> Self {
+        adjust_external_memory(0, size);
+        Self { size }
+    }
+
+    /// Returns the reported size, in bytes.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Changes the reported size to `size` bytes.
+    pub fn set(&mut self, size: usize) {
+        adjust_external_memory(self.size, size);
+        self.size = size;
+    }
+}
+
+impl Clone for ExternalMemory {
+    /// Reports the size again, for the copy of the memory owned by the clone.
+    fn clone(&self) -> Self {
+        Self::new(self.size)
+    }
+}
+
+impl Drop for ExternalMemory {
+    fn drop(&mut self) {
+        adjust_external_memory(self.size, 0);
+    }
+}
+
+// SAFETY: `ExternalMemory` doesn't contain any traceable types.
+unsafe impl Trace for ExternalMemory {
+    empty_trace!();
+}
+
+/// Records an old node referenced from the young generation while a minor collection counts
+/// the handles of the heap.
+pub(crate) fn remember_old_box(node: GcErasedPointer) {
+    REMEMBERED_OLD_BOXES.with(|remembered| {
+        if let Some(remembered) = remembered.borrow_mut().as_mut() {
+            remembered.push(node);
+        }
+    });
+}
+
 #[cfg(test)]
 mod test;
 
@@ -0,0 +1,131 @@
+use crate::{
+    finalizer_safe,
+    internals::ExternalEphemeronBox,
+    trace::{Finalize, Trace},
+    Allocator, Tracer,
+};
+use std::ptr::NonNull;
+
+use super::addr_eq;
+
+/// A weak reference to a value that isn't allocated by the garbage collector, which can be used
+/// as the key of an [`ExternalEphemeron`] or an [`ExternalWeakMap`][crate::ExternalWeakMap].
+///
+/// This allows associating values with keys whose lifetime is managed elsewhere, like a
+/// reference counted value. The garbage collector checks if the key is alive on every
+/// collection, and clears the ephemerons of dead keys.
+///
+/// Note that the values associated with a key cannot keep it alive, so a key that is only
+/// referenced by its own values is never considered dead.
+pub trait ExternalKey: 'static {
+    /// Returns `true` if the value referenced by this key wasn't dropped yet.
+    ///
+    /// Once this returns `false`, it must never return `true` again.
+    fn is_alive(&self) -> bool;
+}
+
+/// An [`Ephemeron`][crate::Ephemeron] whose key isn't allocated by the garbage collector.
+///
+/// The value becomes unaccesible after the first collection that finds the key dead.
+#[derive(Debug)]
+pub struct ExternalEphemeron<K: ExternalKey, V: Trace + 'static> {
+    inner_ptr: NonNull<ExternalEphemeronBox<K, V>>,
+}
+
+impl<K: ExternalKey, V: Trace + Clone> ExternalEphemeron<K, V> {
+    /// Gets the stored value of this `ExternalEphemeron`, or `None` if it was already cleared.
+    ///
+    /// This needs to return a clone of the value because holding a reference to it between
+    /// garbage collection passes could drop the underlying allocation, causing an Use After Free.
+    #[must_use]
+    pub fn value(&self) -> Option<V> {
+        // SAFETY: this is safe because `ExternalEphemeron` is tracked to always point to a valid
+        // pointer `inner_ptr`.
+        unsafe { self.inner_ptr.as_ref().value().cloned() }
+    }
+}
+
+impl<K: ExternalKey, V: Trace> ExternalEphemeron<K, V> {
+    /// Creates a new `ExternalEphemeron`.
+    #[must_use]
+    pub fn new(key: K, value: V) -> Self {
+        let inner_ptr = Allocator::alloc_ephemeron(ExternalEphemeronBox::new(key, value));
+        Self { inner_ptr }
+    }
+
+    /// Gets the key of this `ExternalEphemeron`.
+    #[must_use]
+    pub fn key(&self) -> &K {
+        self.inner().key()
+    }
+
+    /// Checks if the [`ExternalEphemeron`] has a value.
+    #[must_use]
+    pub fn has_value(&self) -> bool {
+        // SAFETY: this is safe because `ExternalEphemeron` is tracked to always point to a valid
+        // pointer `inner_ptr`.
+        unsafe { self.inner_ptr.as_ref().value().is_some() }
+    }
+
+    /// Returns `true` if the two `ExternalEphemeron`s point to the same allocation.
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        addr_eq(this.inner(), other.inner())
+    }
+
+    pub(crate) fn inner_ptr(&self) -> NonNull<ExternalEphemeronBox<K, V>> {
+        assert!(finalizer_safe());
+        self.inner_ptr
+    }
+
+    pub(crate) fn inner(&self) -> &ExternalEphemeronBox<K, V> {
+        // SAFETY: Please see Gc::inner_ptr()
+        unsafe { self.inner_ptr().as_ref() }
+    }
+}
+
+impl<K: ExternalKey, V: Trace> Finalize for ExternalEphemeron<K, V> {
+    fn finalize(&self) {
+        // SAFETY: inner_ptr should be alive when calling finalize.
+        // We don't call inner_ptr() to avoid overhead of calling finalizer_safe().
+        unsafe {
+            self.inner_ptr.as_ref().dec_ref_count();
+        }
+    }
+}
+
+// SAFETY: `ExternalEphemeron`s trace implementation only marks its inner box because we want to
+// stop tracing through weakly held pointers.
+unsafe impl<K: ExternalKey, V: Trace> Trace for ExternalEphemeron<K, V> {
+    unsafe fn trace(&self, _tracer: &mut Tracer) {
+        // SAFETY: We need to mark the inner box of the `ExternalEphemeron` since it is reachable
+        // from a root and this means it cannot be dropped.
+        unsafe {
+            self.inner().mark();
+        }
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        self.inner().inc_non_root_count();
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
+impl<K: ExternalKey, V: Trace> Clone for ExternalEphemeron<K, V> {
+    fn clone(&self) -> Self {
+        let ptr = self.inner_ptr();
+        self.inner().inc_ref_count();
+        Self { inner_ptr: ptr }
+    }
+}
+
+impl<K: ExternalKey, V: Trace> Drop for ExternalEphemeron<K, V> {
+    fn drop(&mut self) {
+        if finalizer_safe() {
+            Finalize::finalize(self);
+        }
+    }
+}
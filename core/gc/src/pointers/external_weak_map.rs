@@ -0,0 +1,125 @@
+use hashbrown::HashMap;
+
+use crate::{
+    custom_trace, internals::RawWeakKeyedMap, Allocator, ExternalEphemeron, ExternalKey, Finalize,
+    Gc, GcRefCell, Trace,
+};
+use std::hash::Hash;
+
+/// A map that holds weak references to keys which aren't allocated by the garbage collector, and
+/// is traced by the garbage collector.
+///
+/// See [`ExternalKey`] for more information about how the keys are held.
+#[derive(Clone, Debug, Finalize)]
+pub struct ExternalWeakMap<K: ExternalKey + Eq + Hash, V: Trace + 'static> {
+    pub(crate) inner: Gc<GcRefCell<RawExternalWeakMap<K, V>>>,
+}
+
+// SAFETY: The map is the only traced field.
+unsafe impl<K: ExternalKey + Eq + Hash, V: Trace + 'static> Trace for ExternalWeakMap<K, V> {
+    custom_trace!(this, mark, {
+        mark(&this.inner);
+    });
+}
+
+impl<K: ExternalKey + Eq + Hash + Clone, V: Trace + Clone> ExternalWeakMap<K, V> {
+    /// Creates a new `ExternalWeakMap`.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: Allocator::alloc_weak_map(),
+        }
+    }
+
+    /// Inserts a key-value pair into the map.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) {
+        self.inner.borrow_mut().insert(key, value);
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    #[inline]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.inner.borrow_mut().remove(key)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[must_use]
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.inner.borrow().get(key).is_some()
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.inner.borrow().get(key)
+    }
+}
+
+impl<K: ExternalKey + Eq + Hash + Clone, V: Trace + Clone> Default for ExternalWeakMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A hash map where the bucket type is an <code>[ExternalEphemeron]\<K, V\></code>.
+#[derive(Debug)]
+pub(crate) struct RawExternalWeakMap<K: ExternalKey + Eq + Hash, V: Trace + 'static> {
+    entries: HashMap<K, ExternalEphemeron<K, V>>,
+}
+
+impl<K: ExternalKey + Eq + Hash, V: Trace> Default for RawExternalWeakMap<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<K: ExternalKey + Eq + Hash, V: Trace> Finalize for RawExternalWeakMap<K, V> {}
+
+// SAFETY: The implementation correctly marks all ephemerons inside the map.
+unsafe impl<K: ExternalKey + Eq + Hash, V: Trace> Trace for RawExternalWeakMap<K, V> {
+    custom_trace!(this, mark, {
+        for eph in this.entries.values() {
+            mark(eph);
+        }
+    });
+}
+
+impl<K: ExternalKey + Eq + Hash + Clone, V: Trace + Clone> RawExternalWeakMap<K, V> {
+    /// Returns the number of elements in the map.
+    ///
+    /// This is an upper bound; the map might contain some expired keys which haven't been
+    /// removed.
+    #[allow(unused)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the value corresponding to the supplied key.
+    fn get(&self, key: &K) -> Option<V> {
+        self.entries.get(key)?.value()
+    }
+
+    /// Inserts a key-value pair into the map, replacing the previous value of the key.
+    fn insert(&mut self, key: K, value: V) {
+        let eph = ExternalEphemeron::new(key.clone(), value);
+        self.entries.insert(key, eph);
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key)?.value()
+    }
+}
+
+impl<K: ExternalKey + Eq + Hash, V: Trace> RawWeakKeyedMap for RawExternalWeakMap<K, V> {
+    fn clear_expired(&mut self) {
+        self.entries.retain(|_, eph| eph.has_value());
+    }
+}
@@ -1,17 +1,20 @@
 //! Pointers represents the External types returned by the Boa Garbage Collector
 
 mod ephemeron;
+mod external_ephemeron;
+mod external_weak_map;
 mod gc;
 mod weak;
 mod weak_map;
 
 pub use ephemeron::Ephemeron;
+pub use external_ephemeron::{ExternalEphemeron, ExternalKey};
+pub use external_weak_map::ExternalWeakMap;
 pub use gc::Gc;
 pub use weak::WeakGc;
 pub use weak_map::WeakMap;
 
 pub(crate) use gc::NonTraceable;
-pub(crate) use weak_map::RawWeakMap;
 
 // Replace with std::ptr::addr_eq when 1.76 releases
 #[allow(clippy::ptr_as_ptr, clippy::ptr_eq)]
@@ -6,7 +6,9 @@ use hashbrown::{
     DefaultHashBuilder, HashTable, TryReserveError,
 };
 
-use crate::{custom_trace, Allocator, Ephemeron, Finalize, Gc, GcRefCell, Trace};
+use crate::{
+    custom_trace, internals::RawWeakKeyedMap, Allocator, Ephemeron, Finalize, Gc, GcRefCell, Trace,
+};
 use std::{fmt, hash::BuildHasher, marker::PhantomData};
 
 /// A map that holds weak references to its keys and is traced by the garbage collector.
@@ -26,7 +28,9 @@ impl<K: Trace + ?Sized, V: Trace + Clone> WeakMap<K, V> {
     #[must_use]
     #[inline]
     pub fn new() -> Self {
-        Allocator::alloc_weak_map()
+        Self {
+            inner: Allocator::alloc_weak_map(),
+        }
     }
 
     /// Inserts a key-value pair into the map.
@@ -110,6 +114,7 @@ where
     ///
     /// The map is initially created with a capacity of 0, so it will not allocate until it
     /// is first inserted into.
+    #[allow(unused)]
     pub(crate) fn new() -> Self {
         Self::default()
     }
@@ -324,9 +329,15 @@ where
             .0
             .value()
     }
+}
 
-    /// Clears all the expired keys in the map.
-    pub(crate) fn clear_expired(&mut self) {
+impl<K, V, S> RawWeakKeyedMap for RawWeakMap<K, V, S>
+where
+    K: Trace + ?Sized + 'static,
+    V: Trace + Clone + 'static,
+    S: 'static,
+{
+    fn clear_expired(&mut self) {
         self.retain(|eph| eph.value().is_some());
     }
 }
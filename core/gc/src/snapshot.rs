@@ -0,0 +1,434 @@
+//! Heap snapshots of the garbage collected heap.
+//!
+//! A [`HeapSnapshot`] records every value owned by a [`Gc`] as a node, and the handles between
+//! them as edges, which can be exported in the `.heapsnapshot` format understood by the memory
+//! tools of Chrome `DevTools`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Write},
+};
+
+use boa_profiler::Profiler;
+
+use crate::{BoaGc, Collector, Gc, GcErasedPointer, Trace, Tracer};
+
+/// The kind of a node of a [`HeapSnapshot`], matching the node types of V8 heap snapshots.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HeapNodeKind {
+    /// An internal value of the engine.
+    #[default]
+    Hidden,
+    /// An array.
+    Array,
+    /// An object.
+    Object,
+    /// Compiled code.
+    Code,
+    /// A function.
+    Closure,
+    /// A regular expression.
+    RegExp,
+    /// A native value, not part of the language.
+    Native,
+    /// A symbol.
+    Symbol,
+    /// A bigint.
+    BigInt,
+    /// The shape of one or more objects.
+    ObjectShape,
+}
+
+impl HeapNodeKind {
+    /// The index of the kind in the `node_types` field of a V8 heap snapshot.
+    const fn v8_index(self) -> u32 {
+        match self {
+            Self::Hidden => 0,
+            Self::Array => 1,
+            Self::Object => 3,
+            Self::Code => 4,
+            Self::Closure => 5,
+            Self::RegExp => 6,
+            Self::Native => 8,
+            Self::Symbol => 12,
+            Self::BigInt => 13,
+            Self::ObjectShape => 14,
+        }
+    }
+}
+
+/// The name of an edge of a [`HeapSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeapEdgeName {
+    /// A named property.
+    Property(String),
+    /// An indexed element.
+    Element(u32),
+    /// An internal reference, named after the slot holding it.
+    Internal(String),
+    /// An unnamed reference, found by [`Trace::trace`].
+    Hidden,
+    /// A reference from the key of an ephemeron to its value, which only keeps the value alive
+    /// while the key is alive.
+    Weak,
+}
+
+/// The information recorded by [`Trace::describe`] for a node of a [`HeapSnapshot`].
+#[derive(Debug, Default)]
+pub struct HeapNodeDescription {
+    name: Option<String>,
+    kind: HeapNodeKind,
+    edges: Vec<(HeapEdgeName, GcErasedPointer)>,
+}
+
+impl HeapNodeDescription {
+    /// Sets the name of the node.
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = Some(name.into());
+    }
+
+    /// Sets the kind of the node.
+    pub fn set_kind(&mut self, kind: HeapNodeKind) {
+        self.kind = kind;
+    }
+
+    /// Adds a named edge from the node to the value owned by `to`.
+    pub fn add_edge<T: Trace + ?Sized>(&mut self, name: HeapEdgeName, to: &Gc<T>) {
+        self.edges.push((name, to.as_erased()));
+    }
+}
+
+/// An edge of a [`HeapSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapEdge {
+    name: HeapEdgeName,
+    to: usize,
+}
+
+impl HeapEdge {
+    /// Returns the name of the edge.
+    #[must_use]
+    pub const fn name(&self) -> &HeapEdgeName {
+        &self.name
+    }
+
+    /// Returns the index in [`HeapSnapshot::nodes`] of the node referenced by this edge.
+    #[must_use]
+    pub const fn to(&self) -> usize {
+        self.to
+    }
+}
+
+/// A node of a [`HeapSnapshot`].
+#[derive(Debug, Clone)]
+pub struct HeapSnapshotNode {
+    name: String,
+    kind: HeapNodeKind,
+    self_size: usize,
+    rooted: bool,
+    edges: Vec<HeapEdge>,
+}
+
+impl HeapSnapshotNode {
+    /// Returns the name of the node, which is the name of its type unless described otherwise.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of the node.
+    #[must_use]
+    pub const fn kind(&self) -> HeapNodeKind {
+        self.kind
+    }
+
+    /// Returns the size of the allocation of the node, in bytes.
+    #[must_use]
+    pub const fn self_size(&self) -> usize {
+        self.self_size
+    }
+
+    /// Returns `true` if the node is referenced from outside the garbage collected heap.
+    #[must_use]
+    pub const fn is_rooted(&self) -> bool {
+        self.rooted
+    }
+
+    /// Returns the outgoing edges of the node.
+    #[must_use]
+    pub fn edges(&self) -> &[HeapEdge] {
+        &self.edges
+    }
+}
+
+/// A snapshot of the garbage collected heap of a thread.
+///
+/// Created by [`crate::heap_snapshot`].
+#[derive(Debug, Clone, Default)]
+pub struct HeapSnapshot {
+    nodes: Vec<HeapSnapshotNode>,
+}
+
+/// The meta information of a V8 heap snapshot, describing the layout of its fields.
+const V8_SNAPSHOT_META: &str = r#"{"node_fields":["type","name","id","self_size","edge_count","trace_node_id","detachedness"],"node_types":[["hidden","array","string","object","code","closure","regexp","number","native","synthetic","concatenated string","sliced string","symbol","bigint","object shape"],"string","number","number","number","number","number"],"edge_fields":["type","name_or_index","to_node"],"edge_types":[["context","element","property","internal","hidden","shortcut","weak"],"string_or_number","node"],"trace_function_info_fields":["function_id","name","script_name","script_id","line","column"],"trace_node_fields":["id","function_info_index","count","size","children"],"sample_fields":["timestamp_us","last_assigned_id"],"location_fields":["object_index","script_id","line","column"]}"#;
+
+/// The number of fields of a node of a V8 heap snapshot.
+const V8_NODE_FIELD_COUNT: usize = 7;
+
+/// The index of the synthetic node type in the `node_types` field of a V8 heap snapshot.
+const V8_SYNTHETIC_NODE: u32 = 9;
+
+impl HeapSnapshot {
+    /// Records the nodes of the heap.
+    ///
+    /// Handles are counted like a collection does to find the roots, so this must not run
+    /// during a collection.
+    pub(crate) fn capture(gc: &BoaGc) -> Self {
+        let _timer = Profiler::global().start_event("Gc Heap Snapshot", "gc");
+
+        Collector::trace_non_roots(gc);
+
+        let indices: HashMap<GcErasedPointer, usize> = gc
+            .strongs
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (*node, index))
+            .collect();
+
+        let mut nodes: Vec<HeapSnapshotNode> = gc
+            .strongs
+            .iter()
+            .map(|node| {
+                // SAFETY: node must be valid as this phase cannot drop any node.
+                let node_ref = unsafe { node.as_ref() };
+                HeapSnapshotNode {
+                    name: node_ref.type_name_fn()().to_string(),
+                    kind: HeapNodeKind::Hidden,
+                    self_size: node_ref.size(),
+                    rooted: node_ref.is_rooted(),
+                    edges: Vec::new(),
+                }
+            })
+            .collect();
+
+        // The handle counts are only valid while collecting, so they are reset right away.
+        for node in &gc.strongs {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { node.as_ref() }.reset_non_root_count();
+        }
+
+        let mut tracer = Tracer::new();
+        for (node, snapshot_node) in gc.strongs.iter().zip(&mut nodes) {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+
+            let mut description = HeapNodeDescription::default();
+            let describe_fn = node_ref.describe_fn();
+
+            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+            unsafe {
+                describe_fn(*node, &mut description);
+            }
+
+            if let Some(name) = description.name {
+                snapshot_node.name = name;
+            }
+            snapshot_node.kind = description.kind;
+
+            let mut described = HashSet::new();
+            for (name, to) in description.edges {
+                if let Some(&to) = indices.get(&to) {
+                    described.insert(to);
+                    snapshot_node.edges.push(HeapEdge { name, to });
+                }
+            }
+
+            let trace_fn = node_ref.trace_fn();
+
+            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+            unsafe {
+                trace_fn(*node, &mut tracer);
+            }
+
+            while let Some(child) = tracer.next() {
+                if let Some(&to) = indices.get(&child) {
+                    if described.insert(to) {
+                        snapshot_node.edges.push(HeapEdge {
+                            name: HeapEdgeName::Hidden,
+                            to,
+                        });
+                    }
+                }
+            }
+        }
+
+        // The value of an ephemeron is reachable through its key.
+        let mut weak_edges = HashSet::new();
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let eph_ref = unsafe { eph.as_ref() };
+
+            // SAFETY: Keys are alive outside of a collection.
+            let Some(key) = (unsafe { eph_ref.trace_entry(&mut tracer) }) else {
+                continue;
+            };
+            let from = indices.get(&key).copied();
+
+            while let Some(child) = tracer.next() {
+                let (Some(from), Some(&to)) = (from, indices.get(&child)) else {
+                    continue;
+                };
+                if weak_edges.insert((from, to)) {
+                    nodes[from].edges.push(HeapEdge {
+                        name: HeapEdgeName::Weak,
+                        to,
+                    });
+                }
+            }
+        }
+
+        // Tracing marks the ephemerons reachable from the heap, and counting the handles could
+        // have counted some ephemerons too.
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let header = unsafe { eph.as_ref() }.header();
+            header.unmark();
+            header.reset_non_root_count();
+        }
+
+        Self { nodes }
+    }
+
+    /// Returns the nodes of the snapshot.
+    #[must_use]
+    pub fn nodes(&self) -> &[HeapSnapshotNode] {
+        &self.nodes
+    }
+
+    /// Writes the snapshot as a V8 `.heapsnapshot` JSON file, which can be loaded in the memory
+    /// tab of Chrome `DevTools`.
+    ///
+    /// The first node is a synthetic root, referencing every rooted node of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut strings = StringTable::default();
+
+        let roots = self.nodes.iter().filter(|node| node.rooted).count();
+        let edge_count = roots
+            + self
+                .nodes
+                .iter()
+                .map(|node| node.edges.len())
+                .sum::<usize>();
+
+        write!(
+            writer,
+            r#"{{"snapshot":{{"meta":{V8_SNAPSHOT_META},"node_count":{},"edge_count":{edge_count},"trace_function_count":0}},"#,
+            self.nodes.len() + 1
+        )?;
+
+        // Node ids are odd numbers, as in the snapshots taken by V8.
+        write!(
+            writer,
+            "\n\"nodes\":[{V8_SYNTHETIC_NODE},{},1,0,{roots},0,0",
+            strings.index("")
+        )?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            write!(
+                writer,
+                ",\n{},{},{},{},{},0,0",
+                node.kind.v8_index(),
+                strings.index(&node.name),
+                index * 2 + 3,
+                node.self_size,
+                node.edges.len()
+            )?;
+        }
+
+        writer.write_all(b"],\n\"edges\":[")?;
+        let mut separator = "";
+        let mut element = 0;
+        for (index, node) in self.nodes.iter().enumerate() {
+            if node.rooted {
+                element += 1;
+                write!(
+                    writer,
+                    "{separator}1,{element},{}",
+                    (index + 1) * V8_NODE_FIELD_COUNT
+                )?;
+                separator = ",\n";
+            }
+        }
+        for node in &self.nodes {
+            for (position, edge) in node.edges.iter().enumerate() {
+                let (edge_type, name_or_index) = match &edge.name {
+                    HeapEdgeName::Element(index) => (1, *index as usize),
+                    HeapEdgeName::Property(name) => (2, strings.index(name)),
+                    HeapEdgeName::Internal(name) => (3, strings.index(name)),
+                    HeapEdgeName::Hidden => (4, position),
+                    HeapEdgeName::Weak => (6, position),
+                };
+                write!(
+                    writer,
+                    "{separator}{edge_type},{name_or_index},{}",
+                    (edge.to + 1) * V8_NODE_FIELD_COUNT
+                )?;
+                separator = ",\n";
+            }
+        }
+
+        writer.write_all(
+            b"],\n\"trace_function_infos\":[],\n\"trace_tree\":[],\n\"samples\":[],\n\"locations\":[],\n\"strings\":[",
+        )?;
+        for (index, string) in strings.strings.iter().enumerate() {
+            if index != 0 {
+                writer.write_all(b",\n")?;
+            }
+            write_json_string(&mut writer, string)?;
+        }
+        writer.write_all(b"]}\n")?;
+
+        writer.flush()
+    }
+}
+
+/// The deduplicated strings of a V8 heap snapshot.
+#[derive(Default)]
+struct StringTable {
+    strings: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl StringTable {
+    /// Returns the index of `string` in the table, adding it if needed.
+    fn index(&mut self, string: &str) -> usize {
+        if let Some(&index) = self.indices.get(string) {
+            return index;
+        }
+
+        let index = self.strings.len();
+        self.strings.push(string.to_string());
+        self.indices.insert(string.to_string(), index);
+        index
+    }
+}
+
+/// Writes `string` as a quoted JSON string.
+fn write_json_string<W: Write>(writer: &mut W, string: &str) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    for c in string.chars() {
+        match c {
+            '"' => writer.write_all(b"\\\"")?,
+            '\\' => writer.write_all(b"\\\\")?,
+            '\n' => writer.write_all(b"\\n")?,
+            '\r' => writer.write_all(b"\\r")?,
+            '\t' => writer.write_all(b"\\t")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", u32::from(c))?,
+            c => write!(writer, "{c}")?,
+        }
+    }
+    writer.write_all(b"\"")
+}
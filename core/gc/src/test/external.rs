@@ -0,0 +1,119 @@
+use std::rc::{Rc, Weak};
+
+use super::run_test;
+use crate::{
+    force_collect, stats, Collector, ExternalEphemeron, ExternalKey, ExternalWeakMap, Gc, WeakGc,
+    BOA_GC,
+};
+
+/// A key held by an `Rc`, which is compared by address.
+#[derive(Debug, Clone)]
+struct RcKey(Weak<()>);
+
+impl RcKey {
+    fn new(rc: &Rc<()>) -> Self {
+        Self(Rc::downgrade(rc))
+    }
+}
+
+impl PartialEq for RcKey {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for RcKey {}
+
+impl std::hash::Hash for RcKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.0.as_ptr(), state);
+    }
+}
+
+impl ExternalKey for RcKey {
+    fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+}
+
+fn collect_young() {
+    BOA_GC.with(|current| Collector::collect_young(&mut current.borrow_mut()));
+}
+
+#[test]
+fn external_ephemeron() {
+    run_test(|| {
+        let key = Rc::new(());
+        let value = Gc::new(String::from("value"));
+        let weak_value = WeakGc::new(&value);
+        let eph = ExternalEphemeron::new(RcKey::new(&key), value);
+
+        // The value is only reachable through the ephemeron while its key is alive.
+        force_collect();
+        assert!(eph.has_value());
+        assert_eq!(
+            *eph.value().expect("the key is still alive"),
+            String::from("value")
+        );
+        assert!(weak_value.upgrade().is_some());
+
+        drop(key);
+        assert!(eph.has_value());
+
+        force_collect();
+        assert!(!eph.has_value());
+        assert!(eph.value().is_none());
+        assert!(weak_value.upgrade().is_none());
+
+        drop((eph, weak_value));
+        force_collect();
+        assert_eq!(stats().ephemeron_count, 0);
+    });
+}
+
+#[test]
+fn external_ephemeron_minor_collection() {
+    run_test(|| {
+        let key = Rc::new(());
+        let eph = ExternalEphemeron::new(RcKey::new(&key), Gc::new(1_u8));
+
+        collect_young();
+        assert!(eph.has_value());
+
+        drop(key);
+        collect_young();
+        assert!(!eph.has_value());
+    });
+}
+
+#[test]
+fn external_weak_map() {
+    run_test(|| {
+        let key1 = Rc::new(());
+        let key2 = Rc::new(());
+        let value = Gc::new(String::from("value"));
+        let weak_value = WeakGc::new(&value);
+
+        let mut map = ExternalWeakMap::new();
+        map.insert(RcKey::new(&key1), value);
+        map.insert(RcKey::new(&key2), Gc::new(String::from("other")));
+
+        force_collect();
+        assert!(map.contains_key(&RcKey::new(&key1)));
+        assert!(map.contains_key(&RcKey::new(&key2)));
+        assert!(!map.contains_key(&RcKey::new(&Rc::new(()))));
+        assert!(weak_value.upgrade().is_some());
+
+        drop(key1);
+        force_collect();
+        assert!(weak_value.upgrade().is_none());
+        assert_eq!(map.inner.borrow().len(), 1);
+
+        assert_eq!(
+            map.remove(&RcKey::new(&key2)).as_deref(),
+            Some(&String::from("other"))
+        );
+        assert!(!map.contains_key(&RcKey::new(&key2)));
+        assert_eq!(map.inner.borrow().len(), 0);
+    });
+}
@@ -0,0 +1,145 @@
+use super::run_test;
+use crate::{
+    force_collect, set_young_generation_size, stats, Collector, Finalize, Gc, GcRefCell, Trace,
+    WeakGc, BOA_GC,
+};
+
+fn collect_young() {
+    BOA_GC.with(|current| Collector::collect_young(&mut current.borrow_mut()));
+}
+
+#[derive(Trace, Finalize)]
+struct Node {
+    next: GcRefCell<Option<Gc<Node>>>,
+}
+
+impl Node {
+    fn new() -> Gc<Self> {
+        Gc::new(Self {
+            next: GcRefCell::new(None),
+        })
+    }
+}
+
+#[test]
+fn gc_minor_collection() {
+    run_test(|| {
+        let old = Node::new();
+        force_collect();
+        assert_eq!(stats().young_object_count, 0);
+
+        // A young value only referenced by an old value must survive.
+        *old.next.borrow_mut() = Some(Gc::new(Node {
+            next: GcRefCell::new(None),
+        }));
+
+        // A young cycle is garbage.
+        let a = Node::new();
+        let b = Node::new();
+        *a.next.borrow_mut() = Some(b.clone());
+        *b.next.borrow_mut() = Some(a.clone());
+        drop((a, b));
+
+        assert_eq!(stats().young_object_count, 3);
+        collect_young();
+
+        let current = stats();
+        assert_eq!(current.minor_collections, 1);
+        assert_eq!(current.collections, 1);
+        assert_eq!(current.object_count, 2);
+        assert_eq!(current.young_object_count, 0);
+        assert!(old.next.borrow().is_some());
+    });
+}
+
+#[test]
+fn gc_minor_collection_remembers_old_values() {
+    run_test(|| {
+        let old = Node::new();
+        force_collect();
+
+        // The handle from the young value to the old one is only counted by the minor collection.
+        let young = Node::new();
+        *young.next.borrow_mut() = Some(old.clone());
+        collect_young();
+        assert_eq!(stats().object_count, 2);
+
+        // The old value is still rooted, and survives a full collection as old.
+        force_collect();
+        assert_eq!(stats().object_count, 2);
+        collect_young();
+        assert_eq!(stats().object_count, 2);
+
+        drop((old, young));
+        force_collect();
+        assert_eq!(stats().object_count, 0);
+    });
+}
+
+#[test]
+fn gc_minor_collection_keeps_old_garbage() {
+    run_test(|| {
+        let old = Node::new();
+        force_collect();
+        drop(old);
+
+        let young = Node::new();
+        collect_young();
+        assert_eq!(stats().object_count, 2);
+
+        // The young value has been promoted, and is only collected by a full collection.
+        drop(young);
+        collect_young();
+        assert_eq!(stats().object_count, 2);
+
+        force_collect();
+        assert_eq!(stats().object_count, 0);
+    });
+}
+
+#[test]
+fn gc_minor_collection_weak() {
+    run_test(|| {
+        let old = Gc::new(1_u8);
+        force_collect();
+
+        let old_weak = WeakGc::new(&old);
+        let young = Gc::new(2_u8);
+        let young_weak = WeakGc::new(&young);
+        let dead_weak = WeakGc::new(&Gc::new(3_u8));
+
+        collect_young();
+        assert_eq!(old_weak.upgrade().as_deref(), Some(&1));
+        assert_eq!(young_weak.upgrade().as_deref(), Some(&2));
+        assert!(dead_weak.upgrade().is_none());
+
+        drop((old, young));
+        force_collect();
+        assert!(old_weak.upgrade().is_none());
+        assert!(young_weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn gc_young_generation_size() {
+    run_test(|| {
+        set_young_generation_size(1024);
+
+        let root = Node::new();
+        for _ in 0..1024 {
+            let _unrooted = Node::new();
+        }
+
+        let current = stats();
+        assert!(current.minor_collections > 0);
+        assert_eq!(current.collections, 0);
+        assert!(current.object_count < 1024);
+
+        set_young_generation_size(0);
+        for _ in 0..1024 {
+            let _unrooted = Node::new();
+        }
+        assert_eq!(stats().minor_collections, current.minor_collections);
+        assert!(root.next.borrow().is_none());
+    });
+}
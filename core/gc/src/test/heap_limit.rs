@@ -0,0 +1,159 @@
+use std::{cell::Cell, rc::Rc};
+
+use super::{run_test, Harness};
+use crate::{
+    adjust_external_memory, fits_heap_limit, force_collect, set_heap_limit,
+    set_near_heap_limit_callback, stats, take_heap_limit_exceeded, ExternalMemory, Gc, GcBox,
+    HeapUsage,
+};
+
+#[test]
+fn gc_heap_limit_exceeded() {
+    run_test(|| {
+        set_heap_limit(16 * 1024);
+
+        let mut roots = Vec::new();
+        while !take_heap_limit_exceeded() {
+            roots.push(Gc::new([0_u8; 64]));
+        }
+
+        // The limit was only flagged after trying to make space.
+        Harness::assert_collections(1);
+
+        // Garbage is collected before flagging the limit as exceeded.
+        drop(roots);
+        for _ in 0..1024 {
+            let _unrooted = Gc::new([0_u8; 64]);
+        }
+        assert!(!take_heap_limit_exceeded());
+    });
+}
+
+#[test]
+fn gc_heap_limit_collections_are_rate_limited() {
+    run_test(|| {
+        set_heap_limit(16 * 1024);
+
+        let mut roots = Vec::new();
+        while !take_heap_limit_exceeded() {
+            roots.push(Gc::new([0_u8; 64]));
+        }
+        Harness::assert_collections(1);
+
+        // Allocating on a heap that stays over the limit doesn't collect it every time.
+        for _ in 0..4 {
+            roots.push(Gc::new([0_u8; 64]));
+        }
+        Harness::assert_collections(1);
+        assert!(!take_heap_limit_exceeded());
+
+        // Once the heap grows enough, it is collected and flagged again.
+        while !take_heap_limit_exceeded() {
+            roots.push(Gc::new([0_u8; 64]));
+        }
+        Harness::assert_collections(2);
+    });
+}
+
+#[test]
+fn gc_fits_heap_limit() {
+    run_test(|| {
+        set_heap_limit(1024);
+        assert!(fits_heap_limit(512));
+
+        // Garbage is collected to make space.
+        let garbage = Gc::new([0_u8; 512]);
+        drop(garbage);
+        assert!(fits_heap_limit(1000));
+        Harness::assert_collections(1);
+
+        // Not fitting doesn't flag the limit as exceeded.
+        let root = Gc::new([0_u8; 512]);
+        assert!(!fits_heap_limit(1000));
+        assert!(!take_heap_limit_exceeded());
+        drop(root);
+    });
+}
+
+#[test]
+fn gc_heap_limit_external_memory() {
+    run_test(|| {
+        set_heap_limit(1024);
+
+        adjust_external_memory(0, 512);
+        assert!(!take_heap_limit_exceeded());
+
+        adjust_external_memory(512, 2048);
+        assert!(take_heap_limit_exceeded());
+        assert!(!take_heap_limit_exceeded());
+
+        adjust_external_memory(2048, 0);
+        let root = Gc::new(0_u8);
+        assert!(!take_heap_limit_exceeded());
+        Harness::assert_exact_bytes_allocated(size_of::<GcBox<u8>>());
+
+        drop(root);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn gc_external_memory_lifetime() {
+    run_test(|| {
+        set_heap_limit(1024);
+
+        let mut memory = ExternalMemory::new(512);
+        assert_eq!(stats().external_bytes, 512);
+        assert!(!take_heap_limit_exceeded());
+
+        let copy = memory.clone();
+        assert_eq!(stats().external_bytes, 1024);
+
+        memory.set(2048);
+        assert_eq!(stats().external_bytes, 2560);
+        assert!(take_heap_limit_exceeded());
+
+        drop(memory);
+        drop(copy);
+        assert_eq!(stats().external_bytes, 0);
+    });
+}
+
+#[test]
+fn gc_near_heap_limit_callback() {
+    run_test(|| {
+        let notified = Rc::new(Cell::new(None));
+        set_heap_limit(1000);
+        set_near_heap_limit_callback(Some(Box::new({
+            let notified = notified.clone();
+            move |usage| notified.set(Some(usage))
+        })));
+
+        adjust_external_memory(0, 500);
+        assert_eq!(notified.take(), None);
+
+        adjust_external_memory(500, 850);
+        assert_eq!(
+            notified.take(),
+            Some(HeapUsage {
+                used: 850,
+                limit: 1000
+            })
+        );
+
+        // The callback is only called once while the heap stays close to the limit.
+        adjust_external_memory(850, 900);
+        assert_eq!(notified.take(), None);
+
+        // Shrinking below the threshold rearms the callback.
+        adjust_external_memory(900, 100);
+        adjust_external_memory(100, 200);
+        adjust_external_memory(200, 950);
+        assert_eq!(notified.take().map(|usage| usage.used), Some(950));
+        assert!(!take_heap_limit_exceeded());
+
+        set_near_heap_limit_callback(None);
+        adjust_external_memory(950, 0);
+    });
+}
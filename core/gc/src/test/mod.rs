@@ -2,6 +2,11 @@ use crate::BOA_GC;
 
 mod allocation;
 mod cell;
+mod external;
+mod generational;
+mod heap_limit;
+mod snapshot;
+mod stats;
 mod weak;
 mod weak_map;
 
@@ -0,0 +1,131 @@
+use super::run_test;
+use crate::{
+    custom_trace, force_collect, heap_snapshot, stats, Ephemeron, Finalize, Gc, GcRefCell,
+    HeapEdgeName, HeapNodeDescription, HeapNodeKind, Trace,
+};
+
+#[derive(Finalize)]
+struct Named {
+    name: &'static str,
+    child: GcRefCell<Option<Gc<Named>>>,
+}
+
+// SAFETY: `child` is the only traced field, and it is marked.
+unsafe impl Trace for Named {
+    custom_trace!(this, mark, mark(&this.child));
+
+    fn describe(&self, node: &mut HeapNodeDescription) {
+        node.set_name(self.name);
+        node.set_kind(HeapNodeKind::Object);
+        if let Some(child) = &*self.child.borrow() {
+            node.add_edge(HeapEdgeName::Property("child".to_string()), child);
+        }
+    }
+}
+
+#[test]
+fn gc_heap_snapshot() {
+    run_test(|| {
+        let leaf = Gc::new(Named {
+            name: "leaf",
+            child: GcRefCell::new(None),
+        });
+        let root = Gc::new(Named {
+            name: "root",
+            child: GcRefCell::new(Some(leaf.clone())),
+        });
+        let boxed = Gc::new(Some(root.clone()));
+        drop(leaf);
+
+        let snapshot = heap_snapshot();
+        let nodes = snapshot.nodes();
+        assert_eq!(nodes.len(), 3);
+
+        let (leaf_node, root_node, boxed_node) = (&nodes[0], &nodes[1], &nodes[2]);
+        assert_eq!(leaf_node.name(), "leaf");
+        assert_eq!(leaf_node.kind(), HeapNodeKind::Object);
+        assert!(!leaf_node.is_rooted());
+        assert!(leaf_node.edges().is_empty());
+
+        // Described edges aren't repeated as unnamed edges.
+        assert_eq!(root_node.name(), "root");
+        assert!(root_node.is_rooted());
+        assert_eq!(root_node.edges().len(), 1);
+        assert_eq!(
+            root_node.edges()[0].name(),
+            &HeapEdgeName::Property("child".to_string())
+        );
+        assert_eq!(root_node.edges()[0].to(), 0);
+
+        // Values without a description are named after their type, with unnamed edges.
+        assert_eq!(boxed_node.kind(), HeapNodeKind::Hidden);
+        assert!(boxed_node.name().contains("Option"));
+        assert!(boxed_node.is_rooted());
+        assert_eq!(boxed_node.edges().len(), 1);
+        assert_eq!(boxed_node.edges()[0].name(), &HeapEdgeName::Hidden);
+        assert_eq!(boxed_node.edges()[0].to(), 1);
+
+        // Taking a snapshot doesn't affect the next collection.
+        drop(boxed);
+        force_collect();
+        assert_eq!(stats().object_count, 2);
+        assert!(root.child.borrow().is_some());
+    });
+}
+
+#[test]
+fn gc_heap_snapshot_ephemeron() {
+    run_test(|| {
+        let key = Gc::new(Named {
+            name: "key",
+            child: GcRefCell::new(None),
+        });
+        let value = Gc::new(Named {
+            name: "value",
+            child: GcRefCell::new(None),
+        });
+        let _eph = Ephemeron::new(&key, value);
+
+        let snapshot = heap_snapshot();
+        let nodes = snapshot.nodes();
+        assert_eq!(nodes.len(), 2);
+
+        let (key_node, value_node) = (&nodes[0], &nodes[1]);
+        assert_eq!(key_node.name(), "key");
+        assert_eq!(value_node.name(), "value");
+        assert!(!value_node.is_rooted());
+        assert_eq!(key_node.edges().len(), 1);
+        assert_eq!(key_node.edges()[0].name(), &HeapEdgeName::Weak);
+        assert_eq!(key_node.edges()[0].to(), 1);
+
+        let mut json = Vec::new();
+        snapshot
+            .write_json(&mut json)
+            .expect("writing to a vector cannot fail");
+        let json = String::from_utf8(json).expect("snapshots are valid UTF-8");
+        assert!(json.contains("\"edges\":[1,1,7,\n6,0,14]"));
+    });
+}
+
+#[test]
+fn gc_heap_snapshot_json() {
+    run_test(|| {
+        let _root = Gc::new(Named {
+            name: "a \"quoted\" name",
+            child: GcRefCell::new(None),
+        });
+
+        let mut json = Vec::new();
+        heap_snapshot()
+            .write_json(&mut json)
+            .expect("writing to a vector cannot fail");
+        let json = String::from_utf8(json).expect("snapshots are valid UTF-8");
+
+        assert!(json.starts_with(r#"{"snapshot":{"meta":"#));
+        assert!(json.contains(r#""node_count":2,"edge_count":1,"#));
+        // The synthetic root references the rooted node.
+        assert!(json.contains("\"nodes\":[9,0,1,0,1,0,0,\n3,1,3,"));
+        assert!(json.contains("\"edges\":[1,1,7]"));
+        assert!(json.contains("\"strings\":[\"\",\n\"a \\\"quoted\\\" name\"]"));
+    });
+}
@@ -0,0 +1,83 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::run_test;
+use crate::{
+    force_collect, set_collection_hook, set_collection_threshold, stats, CollectionPhase, Gc,
+    GcBox, WeakGc, WeakMap,
+};
+
+#[test]
+fn gc_stats() {
+    run_test(|| {
+        let initial = stats();
+        assert_eq!(initial.bytes_allocated, 0);
+        assert_eq!(initial.object_count, 0);
+        assert_eq!(initial.collections, 0);
+
+        let a = Gc::new(1_u64);
+        let b = Gc::new(2_u64);
+        let _weak = WeakGc::new(&a);
+        let _map = WeakMap::<u64, ()>::new();
+
+        let current = stats();
+        // The weak map allocates its own garbage collected storage and weak reference.
+        assert_eq!(current.object_count, 3);
+        assert_eq!(current.ephemeron_count, 2);
+        assert_eq!(current.weak_map_count, 1);
+        assert!(current.bytes_allocated >= 2 * size_of::<GcBox<u64>>());
+
+        drop(b);
+        force_collect();
+
+        let collected = stats();
+        assert_eq!(collected.object_count, 2);
+        assert_eq!(collected.collections, 1);
+        assert_eq!(
+            collected.bytes_allocated,
+            current.bytes_allocated - size_of::<GcBox<u64>>()
+        );
+        assert_eq!(*a, 1);
+    });
+}
+
+#[test]
+fn gc_collection_threshold() {
+    run_test(|| {
+        set_collection_threshold(1024);
+        assert_eq!(stats().threshold, 1024);
+
+        for _ in 0..1024 {
+            let _unrooted = Gc::new([0_u8; 32]);
+        }
+
+        let current = stats();
+        assert!(current.collections > 0);
+        assert!(current.bytes_allocated <= 1024 + size_of::<GcBox<[u8; 32]>>());
+    });
+}
+
+#[test]
+fn gc_collection_hook() {
+    run_test(|| {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        set_collection_hook(Some(Box::new({
+            let events = events.clone();
+            move |phase, stats| events.borrow_mut().push((phase, stats.object_count))
+        })));
+
+        let root = Gc::new(0_u8);
+        let garbage = Gc::new(1_u8);
+        drop(garbage);
+        force_collect();
+
+        assert_eq!(
+            *events.borrow(),
+            [(CollectionPhase::Start, 2), (CollectionPhase::End, 1)]
+        );
+
+        set_collection_hook(None);
+        force_collect();
+        assert_eq!(events.borrow().len(), 2);
+        assert_eq!(*root, 0);
+    });
+}
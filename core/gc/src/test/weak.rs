@@ -290,3 +290,26 @@ fn eph_gc_finalizer() {
         assert_eq!(val.inner.get(), 1);
     });
 }
+
+#[test]
+fn eph_value_only_reachable_through_ephemeron() {
+    run_test(|| {
+        let key = Gc::new(1_u8);
+        let value = Gc::new(GcRefCell::new(None::<Gc<String>>));
+        *value.borrow_mut() = Some(Gc::new(String::from("inner")));
+        let weak_value = WeakGc::new(&value);
+        let ephemeron = Ephemeron::new(&key, value);
+
+        force_collect();
+        force_collect();
+
+        let value = ephemeron.value().expect("the key is still alive");
+        assert_eq!(value.borrow().as_deref().map(String::as_str), Some("inner"));
+        drop(value);
+
+        drop(key);
+        force_collect();
+        assert!(ephemeron.value().is_none());
+        assert!(weak_value.upgrade().is_none());
+    });
+}
@@ -14,7 +14,7 @@ use std::{
     sync::atomic,
 };
 
-use crate::GcErasedPointer;
+use crate::{GcErasedPointer, HeapNodeDescription};
 
 /// A queue used to trace [`crate::Gc<T>`] non-recursively.
 #[doc(hidden)]
@@ -76,6 +76,13 @@ pub unsafe trait Trace: Finalize {
     /// Runs [`Finalize::finalize`] on this object and all
     /// contained subobjects.
     fn run_finalizer(&self);
+
+    /// Describes this value when it is recorded as a node of a heap snapshot.
+    ///
+    /// This is only called for values directly owned by a [`crate::Gc`]. Edges found by
+    /// [`Trace::trace`] that aren't described here are recorded as unnamed hidden edges. By
+    /// default, the node is named after its type.
+    fn describe(&self, _node: &mut HeapNodeDescription) {}
 }
 
 /// Utility macro to define an empty implementation of [`Trace`].
@@ -92,15 +92,18 @@ macro_rules! provider_from_icu_crate {
 static PROVIDER: Lazy<LocaleFallbackProvider<MultiForkByKeyProvider<LazyBufferProvider>>> =
     Lazy::new(|| {
         let provider = MultiForkByKeyProvider::new(alloc::vec![
+            provider_from_icu_crate!(icu_calendar),
             provider_from_icu_crate!(icu_casemap),
             provider_from_icu_crate!(icu_collator),
             provider_from_icu_crate!(icu_datetime),
             provider_from_icu_crate!(icu_decimal),
+            provider_from_icu_crate!(icu_experimental),
             provider_from_icu_crate!(icu_list),
             provider_from_icu_crate!(icu_locid_transform),
             provider_from_icu_crate!(icu_normalizer),
             provider_from_icu_crate!(icu_plurals),
             provider_from_icu_crate!(icu_segmenter),
+            provider_from_icu_crate!(icu_timezone),
         ]);
         LocaleFallbackProvider::try_new_with_buffer_provider(provider)
             .expect("The statically compiled data file should be valid.")
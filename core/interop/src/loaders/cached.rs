@@ -1,12 +1,17 @@
 //! A module loader that caches modules once they're resolved.
-use boa_engine::module::{resolve_module_specifier, ModuleLoader, Referrer};
-use boa_engine::{Context, JsError, JsNativeError, JsResult, JsString, Module};
+use boa_engine::module::{
+    resolve_module_specifier, ModuleLoader, ModuleRequest, ModuleType, Referrer,
+};
+use boa_engine::{Context, JsError, JsNativeError, JsResult, Module};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::rc::Rc;
 
 /// A module loader that caches modules once they're resolved.
+///
+/// Modules are cached by their resolved path and their requested [`ModuleType`], so the
+/// same file imported as JavaScript and as JSON results in two different modules.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug)]
 pub struct CachedModuleLoader<B>
@@ -15,7 +20,7 @@ where
 {
     inner: B,
     // TODO: Use a specifier instead of a PathBuf.
-    cache: Rc<RefCell<HashMap<PathBuf, Module>>>,
+    cache: Rc<RefCell<HashMap<(PathBuf, ModuleType), Module>>>,
 }
 
 impl<B> CachedModuleLoader<B>
@@ -39,36 +44,45 @@ where
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let path = match resolve_module_specifier(None, &specifier, referrer.path(), context) {
-            Ok(path) => path,
+        let module_type = match request.module_type() {
+            Ok(module_type) => module_type,
             Err(err) => {
-                finish_load(
-                    Err(JsError::from_native(
-                        JsNativeError::typ()
-                            .with_message("could not resolve module specifier")
-                            .with_cause(err),
-                    )),
-                    context,
-                );
+                finish_load(Err(err), context);
                 return;
             }
         };
+        let path =
+            match resolve_module_specifier(None, request.specifier(), referrer.path(), context) {
+                Ok(path) => path,
+                Err(err) => {
+                    finish_load(
+                        Err(JsError::from_native(
+                            JsNativeError::typ()
+                                .with_message("could not resolve module specifier")
+                                .with_cause(err),
+                        )),
+                        context,
+                    );
+                    return;
+                }
+            };
 
-        if let Some(module) = self.cache.borrow().get(&path).cloned() {
+        let key = (path, module_type);
+        if let Some(module) = self.cache.borrow().get(&key).cloned() {
             finish_load(Ok(module), context);
         } else {
             self.inner.load_imported_module(
                 referrer,
-                specifier,
+                request,
                 {
                     let cache = self.cache.clone();
                     Box::new(move |result: JsResult<Module>, context| {
                         if let Ok(module) = &result {
-                            cache.borrow_mut().insert(path, module.clone());
+                            cache.borrow_mut().insert(key, module.clone());
                         }
                         finish_load(result, context);
                     })
@@ -5,7 +5,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::Path;
 
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, ModuleType, Referrer};
 use boa_engine::{Context, JsNativeError, JsResult, JsString, Module, Source};
 
 /// Create a module loader that embeds files from the filesystem at build
@@ -14,8 +14,8 @@ use boa_engine::{Context, JsNativeError, JsResult, JsString, Module, Source};
 /// By default, will error if the total file size exceeds 1MB. This can be
 /// changed by specifying the `max_size` parameter.
 ///
-/// The embedded module will only contain files that have the `.js`, `.mjs`,
-/// or `.cjs` extension.
+/// Files imported with the `type: "json"` import attribute are parsed as JSON
+/// modules, and any other file is parsed as a JavaScript module.
 #[macro_export]
 macro_rules! embed_module {
     ($path: literal, max_size = $max_size: literal) => {
@@ -29,42 +29,53 @@ macro_rules! embed_module {
 }
 
 #[derive(Debug, Clone)]
-enum EmbeddedModuleEntry {
-    Source(JsString, &'static [u8]),
-    Module(Module),
+struct EmbeddedModuleEntry {
+    path: JsString,
+    source: &'static [u8],
+    module: Option<Module>,
+    json_module: Option<Module>,
 }
 
 impl EmbeddedModuleEntry {
     fn from_source(path: JsString, source: &'static [u8]) -> Self {
-        Self::Source(path, source)
+        Self {
+            path,
+            source,
+            module: None,
+            json_module: None,
+        }
     }
 
-    fn cache(&mut self, context: &mut Context) -> JsResult<&Module> {
-        if let Self::Source(path, source) = self {
-            let mut bytes: &[u8] = source;
-            let path = path.to_std_string_escaped();
-            let source = Source::from_reader(&mut bytes, Some(Path::new(&path)));
-            match Module::parse(source, None, context) {
-                Ok(module) => {
-                    *self = Self::Module(module);
-                }
-                Err(err) => {
-                    return Err(err);
-                }
-            }
+    fn cache(&mut self, module_type: ModuleType, context: &mut Context) -> JsResult<&Module> {
+        let slot = match module_type {
+            ModuleType::JavaScript => &mut self.module,
+            ModuleType::Json => &mut self.json_module,
         };
 
-        match self {
-            Self::Module(module) => Ok(module),
-            EmbeddedModuleEntry::Source(_, _) => unreachable!(),
+        if slot.is_none() {
+            let module = match module_type {
+                ModuleType::JavaScript => {
+                    let mut bytes: &[u8] = self.source;
+                    let path = self.path.to_std_string_escaped();
+                    let source = Source::from_reader(&mut bytes, Some(Path::new(&path)));
+                    Module::parse(source, None, context)?
+                }
+                ModuleType::Json => {
+                    let json = std::str::from_utf8(self.source).map_err(|err| {
+                        JsNativeError::syntax()
+                            .with_message(format!("invalid UTF-8 in JSON module: {err}"))
+                    })?;
+                    Module::parse_json(JsString::from(json), context)?
+                }
+            };
+            *slot = Some(module);
         }
+
+        Ok(slot.as_ref().expect("module was cached above"))
     }
 
     fn as_module(&self) -> Option<&Module> {
-        match self {
-            Self::Module(module) => Some(module),
-            Self::Source(_, _) => None,
-        }
+        self.module.as_ref()
     }
 }
 
@@ -96,16 +107,21 @@ impl ModuleLoader for EmbeddedModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let Ok(specifier_path) = boa_engine::module::resolve_module_specifier(
-            None,
-            &specifier,
-            referrer.path(),
-            context,
-        ) else {
+        let module_type = match request.module_type() {
+            Ok(module_type) => module_type,
+            Err(err) => {
+                finish_load(Err(err), context);
+                return;
+            }
+        };
+        let specifier = request.specifier();
+        let Ok(specifier_path) =
+            boa_engine::module::resolve_module_specifier(None, specifier, referrer.path(), context)
+        else {
             let err = JsNativeError::typ().with_message(format!(
                 "could not resolve module specifier `{}`",
                 specifier.to_std_string_escaped()
@@ -119,7 +135,7 @@ impl ModuleLoader for EmbeddedModuleLoader {
             .get(&JsString::from(specifier_path.to_string_lossy().as_ref()))
         {
             let mut embedded = module.borrow_mut();
-            let module = embedded.cache(context);
+            let module = embedded.cache(module_type, context);
 
             finish_load(module.cloned(), context);
         } else {
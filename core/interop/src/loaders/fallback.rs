@@ -1,6 +1,6 @@
 //! A module loader that tries to load modules from multiple loaders.
-use boa_engine::module::{ModuleLoader, Referrer};
-use boa_engine::{Context, JsResult, JsString, Module};
+use boa_engine::module::{ModuleLoader, ModuleRequest, Referrer};
+use boa_engine::{Context, JsResult, Module};
 
 /// A [`ModuleLoader`] that tries to load a module from one loader, and if that fails,
 /// falls back to another loader.
@@ -23,20 +23,20 @@ where
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         self.0.load_imported_module(
             referrer.clone(),
-            specifier.clone(),
+            request.clone(),
             {
                 let fallback = self.1.clone();
                 Box::new(move |result, context| {
                     if result.is_ok() {
                         finish_load(result, context);
                     } else {
-                        fallback.load_imported_module(referrer, specifier, finish_load, context);
+                        fallback.load_imported_module(referrer, request, finish_load, context);
                     }
                 })
             },
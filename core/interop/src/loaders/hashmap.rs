@@ -1,13 +1,14 @@
 //! A `ModuleLoader` that loads modules from a `HashMap` based on the name.
 use rustc_hash::FxHashMap;
 
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, Referrer};
 use boa_engine::{Context, JsNativeError, JsResult, JsString, Module};
 use boa_gc::GcRefCell;
 
 /// A `ModuleLoader` that loads modules from a `HashMap` based on the name.
 /// After registering modules, this loader will look for the exact name
-/// in its internal map to resolve.
+/// in its internal map to resolve. Requests with a `type` import attribute
+/// other than `"json"` are rejected.
 #[derive(Debug, Clone)]
 pub struct HashMapModuleLoader(GcRefCell<FxHashMap<JsString, Module>>);
 
@@ -41,12 +42,18 @@ impl ModuleLoader for HashMapModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
+        if let Err(err) = request.module_type() {
+            finish_load(Err(err), context);
+            return;
+        }
+
+        let specifier = request.specifier();
         // First, try to resolve from our internal cached.
-        if let Some(module) = self.0.borrow().get(&specifier) {
+        if let Some(module) = self.0.borrow().get(specifier) {
             finish_load(Ok(module.clone()), context);
         } else {
             let err = JsNativeError::typ().with_message(format!(
@@ -67,3 +67,42 @@ fn simple() {
         PromiseState::Pending => panic!("Promise was not settled"),
     }
 }
+
+#[test]
+fn json() {
+    #[cfg(target_family = "unix")]
+    let module_loader = Rc::new(embed_module!("tests/embedded/"));
+    #[cfg(target_family = "windows")]
+    let module_loader = Rc::new(embed_module!("tests\\embedded\\"));
+
+    let mut context = Context::builder()
+        .module_loader(module_loader)
+        .build()
+        .unwrap();
+
+    let module = Module::parse(
+        Source::from_bytes(
+            b"import data from '/dir1/data.json' with { type: 'json' }; export const answer = data.answer;",
+        ),
+        None,
+        &mut context,
+    )
+    .expect("failed to parse module");
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    match promise.state() {
+        PromiseState::Fulfilled(_) => {
+            let answer = module
+                .namespace(&mut context)
+                .get(js_string!("answer"), &mut context)
+                .unwrap();
+            assert_eq!(answer.as_number(), Some(42.));
+        }
+        PromiseState::Rejected(err) => panic!(
+            "promise was not fulfilled: {:?}",
+            err.to_string(&mut context)
+        ),
+        PromiseState::Pending => panic!("Promise was not settled"),
+    }
+}
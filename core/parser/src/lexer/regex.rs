@@ -127,7 +127,19 @@ impl<R> Tokenizer<R> for RegexLiteral {
             }
         }
 
-        if let Err(error) = Regex::from_unicode(body.into_iter(), flags_str) {
+        let desugared = desugar_duplicate_named_groups(
+            &body,
+            RegExpFlags::from_str(flags_str).unwrap_or_default(),
+        )
+        .map_err(|error| {
+            Error::Syntax(
+                format!("Invalid regular expression literal: {error}").into(),
+                start_pos,
+            )
+        })?;
+        let pattern = desugared.map_or(body, |desugared| desugared.pattern);
+
+        if let Err(error) = Regex::from_unicode(pattern.into_iter(), flags_str) {
             return Err(Error::Syntax(
                 format!("Invalid regular expression literal: {error}").into(),
                 start_pos,
@@ -260,3 +272,235 @@ impl From<RegExpFlags> for Flags {
         }
     }
 }
+
+/// A regular expression pattern with its duplicate named capture groups renamed.
+///
+/// `regress` rejects patterns that reuse a capture group name, even if the groups are in
+/// different alternatives of a disjunction, so those groups get a unique internal name and
+/// every backreference to them is rewritten to try all the renamed groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesugaredPattern {
+    /// The rewritten pattern, as code points.
+    pub pattern: Vec<u32>,
+
+    /// Pairs of internal group names and the original name they were renamed from.
+    pub group_aliases: Box<[(Box<str>, Box<str>)]>,
+}
+
+/// Rewrites `pattern` so that duplicate named capture groups can be compiled by `regress`.
+///
+/// Returns `Ok(None)` if the pattern does not contain duplicate group names.
+///
+/// # Errors
+///
+/// Returns an error if two groups with the same name could both participate in a match.
+///
+/// More information:
+///  - [Proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-duplicate-named-capturing-groups/
+pub fn desugar_duplicate_named_groups(
+    pattern: &[u32],
+    flags: RegExpFlags,
+) -> Result<Option<DesugaredPattern>, String> {
+    /// A named capture group, with the `(id, alternative)` of every enclosing disjunction.
+    struct Group {
+        name: String,
+        name_range: std::ops::Range<usize>,
+        number: usize,
+        path: Vec<(usize, usize)>,
+    }
+
+    let unicode_sets = flags.contains(RegExpFlags::UNICODE_SETS);
+    let cp = |c: char| u32::from(c);
+
+    let mut groups = Vec::new();
+    let mut backreferences = Vec::new();
+    let mut disjunctions = vec![(0, 0)];
+    let mut next_id = 1;
+    let mut captures = 0;
+    let mut class_depth = 0usize;
+
+    let mut i = 0;
+    while i < pattern.len() {
+        let c = pattern[i];
+        if c == cp('\\') {
+            if class_depth == 0
+                && pattern.get(i + 1) == Some(&cp('k'))
+                && pattern.get(i + 2) == Some(&cp('<'))
+            {
+                if let Some((name, end)) = parse_group_name(pattern, i + 3) {
+                    backreferences.push((name, i..end + 1));
+                    i = end + 1;
+                    continue;
+                }
+            }
+            i += 2;
+            continue;
+        }
+
+        if c == cp('[') {
+            if class_depth == 0 || unicode_sets {
+                class_depth += 1;
+            }
+        } else if c == cp(']') && class_depth > 0 {
+            class_depth -= 1;
+        } else if class_depth > 0 {
+            // Everything else inside a character class is a literal.
+        } else if c == cp('(') {
+            if pattern.get(i + 1) != Some(&cp('?')) {
+                captures += 1;
+            } else if pattern.get(i + 2) == Some(&cp('<'))
+                && !matches!(pattern.get(i + 3), Some(&c) if c == cp('=') || c == cp('!'))
+            {
+                captures += 1;
+                if let Some((name, end)) = parse_group_name(pattern, i + 3) {
+                    groups.push(Group {
+                        name,
+                        name_range: i + 3..end,
+                        number: captures,
+                        path: disjunctions.clone(),
+                    });
+                }
+            }
+            disjunctions.push((next_id, 0));
+            next_id += 1;
+        } else if c == cp(')') {
+            if disjunctions.len() > 1 {
+                disjunctions.pop();
+            }
+        } else if c == cp('|') {
+            if let Some((_, alternative)) = disjunctions.last_mut() {
+                *alternative += 1;
+            }
+        }
+        i += 1;
+    }
+
+    let mut used_names = groups
+        .iter()
+        .map(|group| group.name.clone())
+        .collect::<std::collections::HashSet<_>>();
+    if used_names.len() == groups.len() {
+        return Ok(None);
+    }
+
+    // Two groups might both participate in a match unless they are in different alternatives
+    // of a disjunction that contains both of them.
+    let might_both_participate = |x: &Group, y: &Group| {
+        !x.path
+            .iter()
+            .zip(&y.path)
+            .take_while(|(x, y)| x.0 == y.0)
+            .any(|(x, y)| x.1 != y.1)
+    };
+
+    let mut edits: Vec<(std::ops::Range<usize>, Vec<u32>)> = Vec::new();
+    let mut group_aliases = Vec::new();
+    for (index, group) in groups.iter().enumerate() {
+        let mut previous = groups[..index].iter().filter(|g| g.name == group.name);
+        if previous.clone().next().is_none() {
+            continue;
+        }
+        if previous.any(|g| might_both_participate(g, group)) {
+            return Err(format!("duplicate capture group name `{}`", group.name));
+        }
+
+        let mut renamed = format!("{}${index}", group.name);
+        while used_names.contains(&renamed) {
+            renamed.push('$');
+        }
+        used_names.insert(renamed.clone());
+
+        edits.push((
+            group.name_range.clone(),
+            renamed.chars().map(u32::from).collect(),
+        ));
+        group_aliases.push((
+            renamed.into_boxed_str(),
+            group.name.clone().into_boxed_str(),
+        ));
+    }
+
+    // A backreference to a group that did not participate matches the empty string, so a
+    // backreference to a duplicated name can be replaced by a backreference to each of its groups.
+    // All named backreferences are rewritten to numbered ones, since `regress` resolves named
+    // backreferences by counting every parenthesis before the group, including the ones of the
+    // non-capturing groups inserted here.
+    for (name, range) in backreferences {
+        let mut replacement = String::from("(?:");
+        for group in groups.iter().filter(|group| group.name == name) {
+            let _ = write!(replacement, "\\{}", group.number);
+        }
+        if replacement.len() == 3 {
+            continue;
+        }
+        replacement.push(')');
+        edits.push((range, replacement.chars().map(u32::from).collect()));
+    }
+
+    edits.sort_by_key(|(range, _)| range.start);
+    let mut rewritten = Vec::with_capacity(pattern.len());
+    let mut last = 0;
+    for (range, replacement) in edits {
+        rewritten.extend_from_slice(&pattern[last..range.start]);
+        rewritten.extend(replacement);
+        last = range.end;
+    }
+    rewritten.extend_from_slice(&pattern[last..]);
+
+    Ok(Some(DesugaredPattern {
+        pattern: rewritten,
+        group_aliases: group_aliases.into_boxed_slice(),
+    }))
+}
+
+/// Parses a `GroupName` starting after its `<`, returning the name and the index of its `>`.
+fn parse_group_name(pattern: &[u32], mut i: usize) -> Option<(String, usize)> {
+    fn hex_digits(digits: &[u32]) -> Option<u32> {
+        digits.iter().try_fold(0u32, |acc, &d| {
+            Some(acc.checked_mul(16)? + char::from_u32(d)?.to_digit(16)?)
+        })
+    }
+
+    let mut name = String::new();
+    loop {
+        let c = *pattern.get(i)?;
+        if c == u32::from('>') {
+            return Some((name, i));
+        }
+
+        if c != u32::from('\\') {
+            name.push(char::from_u32(c)?);
+            i += 1;
+            continue;
+        }
+
+        // `\u{X}`, `\uXXXX` or a surrogate pair written as `\uXXXX\uXXXX`.
+        if pattern.get(i + 1) != Some(&u32::from('u')) {
+            return None;
+        }
+        let code_point = if pattern.get(i + 2) == Some(&u32::from('{')) {
+            let end = i + 3 + pattern[i + 3..].iter().position(|&c| c == u32::from('}'))?;
+            let code_point = hex_digits(&pattern[i + 3..end])?;
+            i = end + 1;
+            code_point
+        } else {
+            let lead = hex_digits(pattern.get(i + 2..i + 6)?)?;
+            i += 6;
+            let trail = pattern
+                .get(i..i + 6)
+                .filter(|s| s[0] == u32::from('\\') && s[1] == u32::from('u'))
+                .and_then(|s| hex_digits(&s[2..]))
+                .filter(|trail| (0xDC00..=0xDFFF).contains(trail));
+            match trail {
+                Some(trail) if (0xD800..=0xDBFF).contains(&lead) => {
+                    i += 6;
+                    0x1_0000 + ((lead - 0xD800) << 10) + (trail - 0xDC00)
+                }
+                _ => lead,
+            }
+        };
+        name.push(char::from_u32(code_point)?);
+    }
+}
@@ -698,6 +698,64 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_duplicate_named_groups() {
+    let mut lexer = Lexer::from(&br"/(?<a>x)|(?<a>y)\k<a>/"[..]);
+    let interner = &mut Interner::default();
+
+    let expected = [TokenKind::regular_expression_literal(
+        interner.get_or_intern_static(r"(?<a>x)|(?<a>y)\k<a>", utf16!(r"(?<a>x)|(?<a>y)\k<a>")),
+        Sym::EMPTY_STRING,
+    )];
+
+    expect_tokens(&mut lexer, &expected, interner);
+
+    let mut lexer = Lexer::from(&br"/(?<a>x)(?:(?<a>y)|z)/"[..]);
+    let interner = &mut Interner::default();
+
+    lexer
+        .next(interner)
+        .expect_err("Lexer did not reject duplicate named groups in the same alternative");
+}
+
+#[test]
+fn desugar_duplicate_named_groups() {
+    use crate::lexer::regex::{desugar_duplicate_named_groups, RegExpFlags};
+
+    let desugar = |pattern: &str| {
+        let pattern = pattern.chars().map(u32::from).collect::<Vec<_>>();
+        desugar_duplicate_named_groups(&pattern, RegExpFlags::empty()).map(|desugared| {
+            desugared.map(|desugared| {
+                (
+                    desugared
+                        .pattern
+                        .into_iter()
+                        .filter_map(char::from_u32)
+                        .collect::<String>(),
+                    desugared.group_aliases.into_vec(),
+                )
+            })
+        })
+    };
+
+    assert_eq!(desugar(r"(?<a>x)|(?<b>y)"), Ok(None));
+    assert_eq!(
+        desugar(r"(?:(?<a>x)|(?<a>y))\k<a>"),
+        Ok(Some((
+            r"(?:(?<a>x)|(?<a$1>y))(?:\1\2)".to_owned(),
+            vec![("a$1".into(), "a".into())]
+        )))
+    );
+    assert_eq!(
+        desugar(r"(?<a>[(|])|(?<a$1>y)|(?<a>z)"),
+        Ok(Some((
+            r"(?<a>[(|])|(?<a$1>y)|(?<a$2>z)".to_owned(),
+            vec![("a$2".into(), "a".into())]
+        )))
+    );
+    assert!(desugar(r"(?<a>x)|((?<a>y)(?<a>z))").is_err());
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::from(&b"1+1"[..]);
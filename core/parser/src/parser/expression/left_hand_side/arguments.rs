@@ -29,14 +29,14 @@ use boa_profiler::Profiler;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Argument
 /// [spec]: https://tc39.es/ecma262/#prod-Arguments
 #[derive(Debug, Clone, Copy)]
-pub(in crate::parser::expression) struct Arguments {
+pub(in crate::parser) struct Arguments {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl Arguments {
     /// Creates a new `Arguments` parser.
-    pub(in crate::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
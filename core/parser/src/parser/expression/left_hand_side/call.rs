@@ -24,7 +24,7 @@ use boa_ast::{
         access::{PrivatePropertyAccess, SimplePropertyAccess},
         Call,
     },
-    Punctuator,
+    Position, Punctuator,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -40,14 +40,18 @@ pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     first_member_expr: ast::Expression,
+    position: Position,
 }
 
 impl CallExpression {
     /// Creates a new `CallExpression` parser.
+    ///
+    /// `position` is the start of `first_member_expr` in the source code.
     pub(super) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
         first_member_expr: ast::Expression,
+        position: Position,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -57,6 +61,7 @@ impl CallExpression {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             first_member_expr,
+            position,
         }
     }
 }
@@ -75,7 +80,9 @@ where
         let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            Call::new(self.first_member_expr, args)
+                .with_position(self.position)
+                .into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -122,18 +129,26 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let mut lhs = self.call;
 
+        // The position of the last accessed property, used as the position of the next call.
+        let mut callee_position = None;
+
         while let Some(tok) = cursor.peek(0, interner)? {
             let token = tok.clone();
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let position = callee_position
+                        .take()
+                        .unwrap_or_else(|| token.span().start());
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs = ast::Expression::from(Call::new(lhs, args).with_position(position));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.advance(interner);
 
-                    let access = match cursor.next(interner).or_abrupt()?.kind() {
+                    let name = cursor.next(interner).or_abrupt()?;
+                    callee_position = Some(name.span().start());
+                    let access = match name.kind() {
                         TokenKind::IdentifierName((name, _)) => {
                             SimplePropertyAccess::new(lhs, *name).into()
                         }
@@ -165,6 +180,7 @@ where
                     lhs = ast::Expression::PropertyAccess(access);
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    callee_position = Some(token.span().start());
                     cursor.advance(interner);
                     let idx = Expression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
@@ -173,6 +189,7 @@ where
                         ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    callee_position = None;
                     lhs = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
@@ -149,7 +149,7 @@ where
                         }
                         _ => Box::new([]),
                     };
-                    let call_node = Call::new(lhs_inner, args);
+                    let call_node = Call::new(lhs_inner, args).with_position(position);
 
                     ast::Expression::from(New::from(call_node))
                 };
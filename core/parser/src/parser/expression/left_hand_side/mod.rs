@@ -10,7 +10,7 @@
 #[cfg(test)]
 mod tests;
 
-mod arguments;
+pub(in crate::parser) mod arguments;
 mod call;
 mod member;
 mod optional;
@@ -28,7 +28,7 @@ use crate::{
             },
             AssignmentExpression,
         },
-        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
     Error,
@@ -130,25 +130,41 @@ where
             let arg = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            // `,` AssignmentExpression? `,`?
+            let mut options = None;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+                && cursor.peek(0, interner).or_abrupt()?.kind()
+                    != &TokenKind::Punctuator(Punctuator::CloseParen)
+            {
+                options = Some(
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                );
+                cursor.next_if(Punctuator::Comma, interner)?;
+            }
+
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseParen),
                 "import call",
                 interner,
             )?;
 
-            CallExpressionTail::new(
-                self.allow_yield,
-                self.allow_await,
-                ImportCall::new(arg).into(),
-            )
-            .parse(cursor, interner)?
+            let call = match options {
+                Some(options) => ImportCall::with_options(arg, options),
+                None => ImportCall::new(arg),
+            };
+
+            CallExpressionTail::new(self.allow_yield, self.allow_await, call.into())
+                .parse(cursor, interner)?
         } else {
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let mut member = MemberExpression::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
             if let Some(tok) = cursor.peek(0, interner)? {
                 if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                    member = CallExpression::new(self.allow_yield, self.allow_await, member)
-                        .parse(cursor, interner)?;
+                    member =
+                        CallExpression::new(self.allow_yield, self.allow_await, member, position)
+                            .parse(cursor, interner)?;
                 }
             }
             member
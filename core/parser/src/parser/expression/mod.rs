@@ -45,8 +45,8 @@ use boa_profiler::Profiler;
 
 pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
-    identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    identifiers::{BindingIdentifier, IdentifierReference, LabelIdentifier},
+    left_hand_side::{arguments::Arguments, LeftHandSideExpression},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
@@ -36,7 +36,7 @@ use crate::{
             identifiers::IdentifierReference, primary::template::TemplateLiteral,
             BindingIdentifier, Expression,
         },
-        statement::{ArrayBindingPattern, ObjectBindingPattern},
+        statement::{ArrayBindingPattern, DecoratorList, ObjectBindingPattern},
         AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
@@ -133,6 +133,14 @@ where
                     .parse(cursor, interner)
                     .map(Into::into)
             }
+            TokenKind::Punctuator(Punctuator::At) => {
+                let decorators = DecoratorList::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                cursor.expect((Keyword::Class, false), "class expression", interner)?;
+                ClassExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(|class| class.with_decorators(decorators).into())
+            }
             TokenKind::Keyword((Keyword::Debugger, _)) => {
                 cursor.advance(interner);
                 Ok(ast::Expression::Debugger)
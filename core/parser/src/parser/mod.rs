@@ -20,6 +20,7 @@ use crate::{
     Error, Source,
 };
 use boa_ast::{
+    declaration::LexicalDeclaration,
     expression::Identifier,
     function::{FormalParameterList, FunctionBody},
     operations::{
@@ -27,7 +28,7 @@ use boa_ast::{
         lexically_declared_names, var_declared_names, ContainsSymbol,
     },
     scope::Scope,
-    Position, StatementList,
+    Declaration, Position, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use rustc_hash::FxHashSet;
@@ -399,6 +400,24 @@ where
             )));
         }
 
+        // It is a Syntax Error if the goal symbol is Script and UsingDeclaration is not contained,
+        // either directly or indirectly, within a Block, ForStatement, ForInOfStatement,
+        // FunctionBody, GeneratorBody, AsyncGeneratorBody, AsyncFunctionBody,
+        // ClassStaticBlockBody, or ClassBody.
+        if body.statements().iter().any(|item| {
+            matches!(
+                item,
+                StatementListItem::Declaration(Declaration::Lexical(
+                    LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_)
+                ))
+            )
+        }) {
+            return Err(Error::lex(LexError::Syntax(
+                "using declarations are not allowed at the top level of a script".into(),
+                Position::new(1, 1),
+            )));
+        }
+
         Ok(body)
     }
 }
@@ -5,8 +5,9 @@ use crate::{
     lexer::{token::ContainsEscapeSequence, Error as LexError, TokenKind},
     parser::{
         expression::{
-            AssignmentExpression, AsyncGeneratorMethod, AsyncMethod, BindingIdentifier,
-            GeneratorMethod, LeftHandSideExpression, PropertyName,
+            self, Arguments, AssignmentExpression, AsyncGeneratorMethod, AsyncMethod,
+            BindingIdentifier, GeneratorMethod, IdentifierReference, LeftHandSideExpression,
+            PropertyName,
         },
         function::{FunctionBody, UniqueFormalParameters, FUNCTION_BREAK_TOKENS},
         statement::StatementList,
@@ -24,11 +25,14 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
-    expression::Identifier,
+    expression::{
+        access::{PrivatePropertyAccess, PropertyAccess, SimplePropertyAccess},
+        Call, Identifier,
+    },
     function::{
-        self, ClassDeclaration as ClassDeclarationNode, ClassElementName, ClassFieldDefinition,
-        ClassMethodDefinition, FormalParameterList, FunctionExpression, PrivateFieldDefinition,
-        StaticBlockBody,
+        self, ClassAccessorDefinition, ClassDeclaration as ClassDeclarationNode, ClassElementName,
+        ClassFieldDefinition, ClassMethodDefinition, FormalParameterList, FunctionExpression,
+        PrivateFieldDefinition, StaticBlockBody,
     },
     operations::{contains, contains_arguments, ContainsSymbol},
     Expression, Keyword, Punctuator,
@@ -75,6 +79,8 @@ where
     type Output = ClassDeclarationNode;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         cursor.expect((Keyword::Class, false), "class declaration", interner)?;
         let strict = cursor.strict();
         cursor.set_strict(true);
@@ -100,12 +106,10 @@ where
         let (super_ref, constructor, elements) =
             ClassTail::new(name, self.allow_yield, self.allow_await).parse(cursor, interner)?;
 
-        Ok(ClassDeclarationNode::new(
-            name,
-            super_ref,
-            constructor,
-            elements.into_boxed_slice(),
-        ))
+        Ok(
+            ClassDeclarationNode::new(name, super_ref, constructor, elements.into_boxed_slice())
+                .with_decorators(decorators),
+        )
     }
 }
 
@@ -434,8 +438,8 @@ where
                         ));
                     }
                 }
-                function::ClassElement::PrivateStaticFieldDefinition(name, init) => {
-                    if let Some(node) = init {
+                function::ClassElement::PrivateStaticFieldDefinition(field) => {
+                    if let Some(node) = field.field() {
                         if contains(node, ContainsSymbol::SuperCall) {
                             return Err(Error::lex(LexError::Syntax(
                                 "invalid super usage".into(),
@@ -444,7 +448,7 @@ where
                         }
                     }
                     if private_elements_names
-                        .insert(name.description(), PrivateElement::StaticValue)
+                        .insert(field.name().description(), PrivateElement::StaticValue)
                         .is_some()
                     {
                         return Err(Error::general(
@@ -464,6 +468,34 @@ where
                         }
                     }
                 }
+                function::ClassElement::AccessorDefinition(accessor) => {
+                    if let Some(field) = accessor.field() {
+                        if contains(field, ContainsSymbol::SuperCall) {
+                            return Err(Error::lex(LexError::Syntax(
+                                "invalid super usage".into(),
+                                position,
+                            )));
+                        }
+                    }
+                    if let ClassElementName::PrivateName(name) = accessor.name() {
+                        if private_elements_names
+                            .insert(
+                                name.description(),
+                                if accessor.is_static() {
+                                    PrivateElement::StaticValue
+                                } else {
+                                    PrivateElement::Value
+                                },
+                            )
+                            .is_some()
+                        {
+                            return Err(Error::general(
+                                "private identifier has already been declared",
+                                position,
+                            ));
+                        }
+                    }
+                }
                 function::ClassElement::StaticBlock(_) => {}
             }
             elements.push(element);
@@ -522,8 +554,17 @@ where
     type Output = (Option<FunctionExpression>, Option<function::ClassElement>);
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let r#static = match token.kind() {
+            TokenKind::Punctuator(Punctuator::Semicolon) if !decorators.is_empty() => {
+                return Err(Error::general(
+                    "decorators must be followed by a class element",
+                    token.span().start(),
+                ));
+            }
             TokenKind::Punctuator(Punctuator::Semicolon) => {
                 cursor.advance(interner);
                 return Ok((None, None));
@@ -567,10 +608,29 @@ where
             )
         );
 
+        // `accessor` is only a keyword when directly followed, on the same line, by a class element name.
+        let is_accessor = cursor.peek_is_line_terminator(1, interner)? == Some(false)
+            && matches!(
+                cursor.peek(1, interner).or_abrupt()?.kind(),
+                TokenKind::IdentifierName(_)
+                    | TokenKind::StringLiteral(_)
+                    | TokenKind::NumericLiteral(_)
+                    | TokenKind::Keyword(_)
+                    | TokenKind::NullLiteral(_)
+                    | TokenKind::PrivateIdentifier(_)
+                    | TokenKind::Punctuator(Punctuator::OpenBracket)
+            );
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let position = token.span().start();
         let element = match token.kind() {
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators cannot be applied to a class constructor",
+                        position,
+                    ));
+                }
                 cursor.advance(interner);
                 let strict = cursor.strict();
                 cursor.set_strict(true);
@@ -596,6 +656,12 @@ where
                 ));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) if r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators cannot be applied to a class static block",
+                        position,
+                    ));
+                }
                 cursor.advance(interner);
                 let statement_list = if cursor
                     .next_if(TokenKind::Punctuator(Punctuator::CloseBlock), interner)?
@@ -1068,6 +1134,89 @@ where
                     }
                 }
             }
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(true)))
+                if is_accessor =>
+            {
+                return Err(Error::general(
+                    "keyword must not contain escaped characters",
+                    token.span().start(),
+                ))
+            }
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(false)))
+                if is_accessor =>
+            {
+                cursor.advance(interner);
+                let token = cursor.peek(0, interner).or_abrupt()?;
+                let name_position = token.span().start();
+                let name = match token.kind() {
+                    TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
+                        return Err(Error::general(
+                            "class constructor may not be a private method",
+                            name_position,
+                        ))
+                    }
+                    TokenKind::PrivateIdentifier(name) => {
+                        let name = *name;
+                        cursor.advance(interner);
+                        ClassElementName::PrivateName(PrivateName::new(name))
+                    }
+                    _ => {
+                        let name = PropertyName::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                        if let Some(name) = name.literal() {
+                            if r#static {
+                                if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name) {
+                                    return Err(Error::general(
+                                        "class may not have static accessor definitions named 'constructor' or 'prototype'",
+                                        name_position,
+                                    ));
+                                }
+                            } else if name == Sym::CONSTRUCTOR {
+                                return Err(Error::general(
+                                    "class may not have accessor definitions named 'constructor'",
+                                    name_position,
+                                ));
+                            }
+                        }
+                        ClassElementName::PropertyName(name)
+                    }
+                };
+                let rhs = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+                    let strict = cursor.strict();
+                    cursor.set_strict(true);
+                    let mut rhs =
+                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                    cursor.set_strict(strict);
+                    match &name {
+                        ClassElementName::PropertyName(name) => {
+                            if let Some(name) = name.literal() {
+                                rhs.set_anonymous_function_definition_name(&Identifier::new(name));
+                            }
+                        }
+                        ClassElementName::PrivateName(name) => {
+                            let function_name = interner.get_or_intern(
+                                [
+                                    utf16!("#"),
+                                    interner.resolve_expect(name.description()).utf16(),
+                                ]
+                                .concat()
+                                .as_slice(),
+                            );
+                            rhs.set_anonymous_function_definition_name(&Identifier::new(
+                                function_name,
+                            ));
+                        }
+                    }
+                    Some(rhs)
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("expected semicolon", interner)?;
+                function::ClassElement::AccessorDefinition(ClassAccessorDefinition::new(
+                    name, rhs, r#static,
+                ))
+            }
             TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
                 return Err(Error::general(
                     "class constructor may not be a private method",
@@ -1096,8 +1245,7 @@ where
                         rhs.set_anonymous_function_definition_name(&Identifier::new(function_name));
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(
-                                PrivateName::new(name),
-                                Some(rhs),
+                                PrivateFieldDefinition::new(PrivateName::new(name), Some(rhs)),
                             )
                         } else {
                             function::ClassElement::PrivateFieldDefinition(
@@ -1143,8 +1291,7 @@ where
                         cursor.expect_semicolon("expected semicolon", interner)?;
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(
-                                PrivateName::new(name),
-                                None,
+                                PrivateFieldDefinition::new(PrivateName::new(name), None),
                             )
                         } else {
                             function::ClassElement::PrivateFieldDefinition(
@@ -1283,7 +1430,8 @@ where
                     }
                 }
             }
-            function::ClassElement::PrivateFieldDefinition(field) => {
+            function::ClassElement::PrivateFieldDefinition(field)
+            | function::ClassElement::PrivateStaticFieldDefinition(field) => {
                 if let Some(node) = field.field() {
                     if contains_arguments(node) {
                         return Err(Error::general(
@@ -1293,18 +1441,170 @@ where
                     }
                 }
             }
-            function::ClassElement::PrivateStaticFieldDefinition(_, Some(node)) => {
-                if contains_arguments(node) {
-                    return Err(Error::general(
-                        "'arguments' not allowed in class field definition",
-                        position,
-                    ));
+            function::ClassElement::AccessorDefinition(accessor) => {
+                if let Some(node) = accessor.field() {
+                    if contains_arguments(node) {
+                        return Err(Error::general(
+                            "'arguments' not allowed in class field definition",
+                            position,
+                        ));
+                    }
                 }
             }
 
             _ => {}
         }
 
-        Ok((None, Some(element)))
+        Ok((None, Some(element.with_decorators(decorators))))
+    }
+}
+
+/// `DecoratorList` parsing.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417&id=sec-class-definitions
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: ReadChar,
+{
+    type Output = Box<[function::Decorator]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        // Decorators are part of the class definition, which is always strict mode code.
+        let strict = cursor.strict();
+        cursor.set_strict(true);
+
+        let mut decorators = Vec::new();
+        while cursor
+            .peek(0, interner)?
+            .is_some_and(|token| token.kind() == &TokenKind::Punctuator(Punctuator::At))
+        {
+            decorators
+                .push(Decorator::new(self.allow_yield, self.allow_await).parse(cursor, interner)?);
+        }
+
+        cursor.set_strict(strict);
+
+        Ok(decorators.into_boxed_slice())
+    }
+}
+
+/// `Decorator` parsing.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417&id=sec-class-definitions
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct Decorator {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl Decorator {
+    /// Creates a new `Decorator` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for Decorator
+where
+    R: ReadChar,
+{
+    type Output = function::Decorator;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        cursor.expect(Punctuator::At, "decorator", interner)?;
+
+        // Decorator :
+        //     @ DecoratorParenthesizedExpression
+        if cursor.next_if(Punctuator::OpenParen, interner)?.is_some() {
+            let decorator = expression::Expression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            cursor.expect(Punctuator::CloseParen, "decorator", interner)?;
+            return Ok(function::Decorator::new(decorator));
+        }
+
+        // DecoratorMemberExpression :
+        //     IdentifierReference
+        //     DecoratorMemberExpression . IdentifierName
+        //     DecoratorMemberExpression . PrivateIdentifier
+        let mut decorator: Expression =
+            IdentifierReference::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?
+                .into();
+        while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+            let token = cursor.next(interner).or_abrupt()?;
+            let access: PropertyAccess = match token.kind() {
+                TokenKind::IdentifierName((name, _)) => {
+                    SimplePropertyAccess::new(decorator, *name).into()
+                }
+                TokenKind::Keyword((kw, _)) => {
+                    SimplePropertyAccess::new(decorator, kw.to_sym()).into()
+                }
+                TokenKind::BooleanLiteral((true, _)) => {
+                    SimplePropertyAccess::new(decorator, Sym::TRUE).into()
+                }
+                TokenKind::BooleanLiteral((false, _)) => {
+                    SimplePropertyAccess::new(decorator, Sym::FALSE).into()
+                }
+                TokenKind::NullLiteral(_) => SimplePropertyAccess::new(decorator, Sym::NULL).into(),
+                TokenKind::PrivateIdentifier(name) => {
+                    PrivatePropertyAccess::new(decorator, PrivateName::new(*name)).into()
+                }
+                _ => {
+                    return Err(Error::expected(
+                        ["identifier".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
+                        "decorator",
+                    ));
+                }
+            };
+            decorator = access.into();
+        }
+
+        // DecoratorCallExpression :
+        //     DecoratorMemberExpression Arguments
+        if cursor
+            .peek(0, interner)?
+            .is_some_and(|token| token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen))
+        {
+            let args =
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            decorator = Call::new(decorator, args).into();
+        }
+
+        Ok(function::Decorator::new(decorator))
     }
 }
@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
@@ -7,8 +7,9 @@ use boa_ast::{
         Call, Identifier,
     },
     function::{
-        ClassDeclaration, ClassElement, ClassFieldDefinition, ClassMethodDefinition,
-        FormalParameterList, FunctionBody, FunctionExpression,
+        ClassAccessorDefinition, ClassDeclaration, ClassElement, ClassElementName,
+        ClassFieldDefinition, ClassMethodDefinition, Decorator, FormalParameterList, FunctionBody,
+        FunctionExpression,
     },
     property::{MethodDefinitionKind, PropertyName},
     Declaration, Expression, Statement, StatementListItem,
@@ -21,7 +22,7 @@ fn check_async_ordinary_method() {
     let interner = &mut Interner::default();
 
     let elements = vec![ClassElement::MethodDefinition(ClassMethodDefinition::new(
-        boa_ast::function::ClassElementName::PropertyName(PropertyName::Literal(
+        ClassElementName::PropertyName(PropertyName::Literal(
             interner.get_or_intern_static("async", utf16!("async")),
         )),
         FormalParameterList::default(),
@@ -169,3 +170,71 @@ fn check_new_target_with_property_access() {
         interner,
     );
 }
+
+#[test]
+fn check_decorators() {
+    let interner = &mut Interner::default();
+
+    let dec = Decorator::new(
+        Identifier::from(interner.get_or_intern_static("dec", utf16!("dec"))).into(),
+    );
+    let elements = vec![
+        ClassElement::MethodDefinition(
+            ClassMethodDefinition::new(
+                ClassElementName::PropertyName(PropertyName::Literal(
+                    interner.get_or_intern_static("m", utf16!("m")),
+                )),
+                FormalParameterList::default(),
+                FunctionBody::default(),
+                MethodDefinitionKind::Ordinary,
+                false,
+            )
+            .with_decorators(
+                [Decorator::new(Expression::Call(Call::new(
+                    Identifier::from(interner.get_or_intern_static("make", utf16!("make"))).into(),
+                    [Literal::from(1).into()].into(),
+                )))]
+                .into(),
+            ),
+        ),
+        ClassElement::AccessorDefinition(
+            ClassAccessorDefinition::new(
+                ClassElementName::PropertyName(PropertyName::Literal(
+                    interner.get_or_intern_static("x", utf16!("x")),
+                )),
+                Some(Literal::from(2).into()),
+                true,
+            )
+            .with_decorators([dec.clone()].into()),
+        ),
+    ];
+
+    check_script_parser(
+        "@dec
+        class A {
+            @make(1) m() { }
+            @dec static accessor x = 2;
+        }
+        ",
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                interner.get_or_intern_static("A", utf16!("A")).into(),
+                None,
+                None,
+                elements.into(),
+            )
+            .with_decorators([dec].into()),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_invalid_decorators() {
+    check_invalid_script("class A { @dec constructor() {} }");
+    check_invalid_script("class A { @dec static {} }");
+    check_invalid_script("class A { @dec; }");
+    check_invalid_script("class A { static @dec m() {} }");
+    check_invalid_script("@dec function f() {}");
+}
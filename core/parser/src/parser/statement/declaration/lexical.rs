@@ -1,14 +1,16 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit Resource Management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::{
-    lexer::{Error as LexError, Token, TokenKind},
+    lexer::{token::ContainsEscapeSequence, Error as LexError, Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -70,32 +72,32 @@ where
         let _timer = Profiler::global().start_event("LexicalDeclaration", "Parsing");
         let tok = cursor.next(interner).or_abrupt()?;
 
-        let lexical_declaration = match tok.kind() {
+        let kind = match tok.kind() {
             TokenKind::Keyword((Keyword::Const | Keyword::Let, true)) => {
                 return Err(Error::general(
                     "Keyword must not contain escaped characters",
                     tok.span().start(),
                 ))
             }
-            TokenKind::Keyword((Keyword::Const, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                true,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
-            TokenKind::Keyword((Keyword::Let, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                false,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Const, false)) => DeclarationKind::Const,
+            TokenKind::Keyword((Keyword::Let, false)) => DeclarationKind::Let,
+            TokenKind::IdentifierName((Sym::USING, _)) => DeclarationKind::Using,
+            TokenKind::Keyword((Keyword::Await, _)) => {
+                cursor.advance(interner);
+                DeclarationKind::AwaitUsing
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
+        let lexical_declaration = BindingList::new(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            kind,
+            self.loop_init,
+        )
+        .parse(cursor, interner)?;
+
         if !self.loop_init {
             cursor.expect_semicolon("lexical declaration", interner)?;
         }
@@ -138,10 +140,109 @@ pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     )
 }
 
+/// Checks if the next tokens start a `using` declaration.
+///
+/// `using` is only a declaration if it's followed, on the same line, by a binding identifier.
+/// Inside the head of a `for` statement, `using of` always starts an expression.
+pub(in crate::parser) fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+    loop_head: bool,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    if !matches!(
+        cursor.peek(0, interner)?.map(Token::kind),
+        Some(TokenKind::IdentifierName((
+            Sym::USING,
+            ContainsEscapeSequence(false)
+        )))
+    ) {
+        return Ok(false);
+    }
+    let skip_n = usize::from(cursor.peek_is_line_terminator(0, interner).or_abrupt()?);
+    if cursor
+        .peek_is_line_terminator(skip_n + 1, interner)?
+        .unwrap_or(true)
+    {
+        return Ok(false);
+    }
+    Ok(allowed_token_after_using(
+        cursor.peek(1, interner)?,
+        loop_head,
+    ))
+}
+
+/// Checks if the next tokens start an `await using` declaration.
+///
+/// Both `await` and `using` must be followed by the next token on the same line, and `using`
+/// must be followed by a binding identifier.
+pub(in crate::parser) fn is_await_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+    loop_head: bool,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    if !matches!(
+        cursor.peek(0, interner)?.map(Token::kind),
+        Some(TokenKind::Keyword((Keyword::Await, false)))
+    ) {
+        return Ok(false);
+    }
+    let skip_n = usize::from(cursor.peek_is_line_terminator(0, interner).or_abrupt()?);
+    if cursor
+        .peek_is_line_terminator(skip_n + 1, interner)?
+        .unwrap_or(true)
+        || !matches!(
+            cursor.peek(1, interner)?.map(Token::kind),
+            Some(TokenKind::IdentifierName((
+                Sym::USING,
+                ContainsEscapeSequence(false)
+            )))
+        )
+        || cursor
+            .peek_is_line_terminator(skip_n + 2, interner)?
+            .unwrap_or(true)
+    {
+        return Ok(false);
+    }
+    Ok(allowed_token_after_using(
+        cursor.peek(2, interner)?,
+        loop_head,
+    ))
+}
+
+/// Check if the given token is valid after the `using` keyword of a `using` declaration.
+fn allowed_token_after_using(token: Option<&Token>, loop_head: bool) -> bool {
+    match token.map(Token::kind) {
+        Some(TokenKind::Keyword((Keyword::Of, _))) => !loop_head,
+        Some(
+            TokenKind::IdentifierName(_)
+            | TokenKind::Keyword((
+                Keyword::Await | Keyword::Yield | Keyword::Let | Keyword::Async,
+                _,
+            )),
+        ) => true,
+        _ => false,
+    }
+}
+
+/// The kind of declaration parsed by a [`BindingList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclarationKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const`, `using` or `await using` declaration is being parsed
+/// and there is no initializer, or if a `using` declaration contains a binding pattern.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -152,7 +253,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: DeclarationKind,
     loop_init: bool,
 }
 
@@ -162,7 +263,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: DeclarationKind,
         loop_init: bool,
     ) -> Self
     where
@@ -174,7 +275,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -190,27 +291,38 @@ where
         let _timer = Profiler::global().start_event("BindingList", "Parsing");
 
         // Create vectors to store the variable declarations
-        // Const and Let signatures are slightly different, Const needs definitions, Lets don't
+        // Let declarations may omit the initializer, while all other declarations need one
         let mut decls = Vec::new();
 
         loop {
-            let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
-                .parse(cursor, interner)?;
-
-            if self.is_const {
-                let init_is_some = decl.init().is_some();
-
-                if init_is_some || self.loop_init {
-                    decls.push(decl);
-                } else {
-                    let next = cursor.next(interner).or_abrupt()?;
+            if self.kind != DeclarationKind::Let && self.kind != DeclarationKind::Const {
+                let next = cursor.peek(0, interner).or_abrupt()?;
+                if matches!(
+                    next.kind(),
+                    TokenKind::Punctuator(Punctuator::OpenBlock | Punctuator::OpenBracket)
+                ) {
                     return Err(Error::general(
-                        "Expected initializer for const declaration",
+                        "`using` declarations may not contain binding patterns",
                         next.span().start(),
                     ));
                 }
-            } else {
+            }
+
+            let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+
+            if self.kind == DeclarationKind::Let || decl.init().is_some() || self.loop_init {
                 decls.push(decl);
+            } else {
+                let next = cursor.next(interner).or_abrupt()?;
+                return Err(Error::general(
+                    if self.kind == DeclarationKind::Const {
+                        "Expected initializer for const declaration"
+                    } else {
+                        "Expected initializer for using declaration"
+                    },
+                    next.span().start(),
+                ));
             }
 
             match cursor.peek_semicolon(interner)? {
@@ -253,11 +365,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            DeclarationKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            DeclarationKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            DeclarationKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            DeclarationKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
@@ -1,8 +1,10 @@
 use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, VarDeclaration, Variable},
-    expression::literal::Literal,
-    Declaration, Statement,
+    expression::{literal::Literal, Identifier},
+    function::{AsyncFunctionDeclaration, FormalParameterList, FunctionBody},
+    statement::Block,
+    Declaration, Statement, StatementListItem,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -360,3 +362,71 @@ fn lexical_declaration_early_errors() {
     check_invalid_script("for (let a = 0, a = 0; ; ) {}");
     check_invalid_script("for (const a = 0, a = 0; ; ) {}");
 }
+
+/// Checks `using` declaration parsing.
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    check_script_parser(
+        "{ using a = b, using = null; }",
+        vec![Statement::Block(Block::from(vec![StatementListItem::from(
+            Declaration::Lexical(LexicalDeclaration::Using(
+                vec![
+                    Variable::from_identifier(a.into(), Some(Identifier::new(b).into())),
+                    Variable::from_identifier(Sym::USING.into(), Some(Literal::Null.into())),
+                ]
+                .try_into()
+                .unwrap(),
+            )),
+        )]))
+        .into()],
+        interner,
+    );
+}
+
+/// Checks `await using` declaration parsing.
+#[test]
+fn await_using_declaration() {
+    let interner = &mut Interner::default();
+    let f = interner.get_or_intern_static("f", utf16!("f"));
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    check_script_parser(
+        "async function f() { await using a = null; }",
+        vec![
+            Declaration::AsyncFunctionDeclaration(AsyncFunctionDeclaration::new(
+                f.into(),
+                FormalParameterList::default(),
+                FunctionBody::new(
+                    [StatementListItem::from(Declaration::Lexical(
+                        LexicalDeclaration::AwaitUsing(
+                            vec![Variable::from_identifier(
+                                a.into(),
+                                Some(Literal::Null.into()),
+                            )]
+                            .try_into()
+                            .unwrap(),
+                        ),
+                    ))],
+                    false,
+                ),
+            ))
+            .into(),
+        ],
+        interner,
+    );
+}
+
+/// Checks `using` declaration early errors.
+#[test]
+fn using_declaration_early_errors() {
+    check_invalid_script("using a = null;");
+    check_invalid_script("{ using a; }");
+    check_invalid_script("{ using {a} = null; }");
+    check_invalid_script("{ using a = null, a = null; }");
+    check_invalid_script("{ using let = null; }");
+    check_invalid_script("for (using a in b) {}");
+    check_invalid_script("async function f() { for (await using a in b) {} }");
+    check_invalid_script("function f() { await using a = null; }");
+}
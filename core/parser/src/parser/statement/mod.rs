@@ -26,7 +26,10 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{allowed_token_after_let, Declaration, ExportDeclaration, ImportDeclaration},
+    declaration::{
+        allowed_token_after_let, is_await_using_declaration, is_using_declaration,
+        ClassDeclaration, Declaration, ExportDeclaration, ImportDeclaration,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -56,11 +59,11 @@ use boa_ast::{
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPatternElement},
     Keyword, Punctuator,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use boa_profiler::Profiler;
 
-pub(in crate::parser) use declaration::ClassTail;
+pub(in crate::parser) use declaration::{ClassTail, DecoratorList};
 
 /// Statement parsing.
 ///
@@ -413,7 +416,8 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind().clone() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Class | Keyword::Const, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 Declaration::new(self.allow_yield, self.allow_await)
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
@@ -425,6 +429,20 @@ where
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
             }
+            TokenKind::IdentifierName((Sym::USING, _))
+                if is_using_declaration(cursor, interner, false)? =>
+            {
+                Declaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(ast::StatementListItem::from)
+            }
+            TokenKind::Keyword((Keyword::Await, false))
+                if self.allow_await.0 && is_await_using_declaration(cursor, interner, false)? =>
+            {
+                Declaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(ast::StatementListItem::from)
+            }
             TokenKind::Keyword((Keyword::Async, false)) => {
                 let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
                     2
@@ -961,6 +979,49 @@ where
             TokenKind::Keyword((Keyword::Export, false)) => ExportDeclaration
                 .parse(cursor, interner)
                 .map(Self::Output::ExportDeclaration),
+            TokenKind::Punctuator(Punctuator::At) => {
+                let decorators = DecoratorList::new(false, true).parse(cursor, interner)?;
+                let tok = cursor.peek(0, interner).or_abrupt()?;
+                let position = tok.span().start();
+
+                // Decorators placed before `export` apply to the exported class declaration.
+                if tok.kind() != &TokenKind::Keyword((Keyword::Export, false)) {
+                    return ClassDeclaration::new(false, true, false)
+                        .parse(cursor, interner)
+                        .map(|class| {
+                            Self::Output::StatementListItem(
+                                ast::Declaration::ClassDeclaration(
+                                    class.with_decorators(decorators),
+                                )
+                                .into(),
+                            )
+                        });
+                }
+
+                let export = match ExportDeclaration.parse(cursor, interner)? {
+                    ast::declaration::ExportDeclaration::Declaration(
+                        ast::Declaration::ClassDeclaration(class),
+                    ) if class.decorators().is_empty() => {
+                        ast::declaration::ExportDeclaration::Declaration(
+                            ast::Declaration::ClassDeclaration(class.with_decorators(decorators)),
+                        )
+                    }
+                    ast::declaration::ExportDeclaration::DefaultClassDeclaration(class)
+                        if class.decorators().is_empty() =>
+                    {
+                        ast::declaration::ExportDeclaration::DefaultClassDeclaration(
+                            class.with_decorators(decorators),
+                        )
+                    }
+                    _ => {
+                        return Err(Error::general(
+                            "decorators must be followed by a class declaration",
+                            position,
+                        ))
+                    }
+                };
+                Ok(Self::Output::ExportDeclaration(export))
+            }
             TokenKind::Keyword((Keyword::Import, false)) => {
                 if ImportDeclaration::test(cursor, interner)? {
                     ImportDeclaration
@@ -45,7 +45,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ThrowStatement", "Parsing");
-        cursor.expect((Keyword::Throw, false), "throw statement", interner)?;
+        let position = cursor
+            .expect((Keyword::Throw, false), "throw statement", interner)?
+            .span()
+            .start();
 
         cursor.peek_expect_no_lineterminator(0, "throw statement", interner)?;
 
@@ -54,6 +57,6 @@ where
 
         cursor.expect_semicolon("throw statement", interner)?;
 
-        Ok(Throw::new(expr))
+        Ok(Throw::new(expr).with_position(position))
     }
 }
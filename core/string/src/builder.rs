@@ -1,6 +1,6 @@
 use crate::{
-    alloc_overflow, tagged::Tagged, JsStr, JsStrVariant, JsString, RawJsString, RefCount,
-    TaggedLen, DATA_OFFSET,
+    alloc_overflow, report_allocation, tagged::Tagged, JsStr, JsStrVariant, JsString, RawJsString,
+    RefCount, TaggedLen, DATA_OFFSET,
 };
 
 use std::{
@@ -362,10 +362,11 @@ impl<D: Copy> JsStringBuilder<D> {
         let len = self.len();
 
         // Shrink to fit the length.
+        let layout = Self::new_layout(len);
         if len != self.capacity() {
-            let layout = Self::new_layout(self.len());
             self.allocate_inner(layout);
         }
+        report_allocation(0, layout.size());
 
         let inner = self.inner;
 
@@ -89,12 +89,15 @@ impl StaticJsStrings {
         (EMPTY_STRING, ""),
         (LENGTH, "length"),
         // Symbols
+        (SYMBOL_ASYNC_DISPOSE, "Symbol.asyncDispose"),
         (SYMBOL_ASYNC_ITERATOR, "Symbol.asyncIterator"),
+        (SYMBOL_DISPOSE, "Symbol.dispose"),
         (SYMBOL_HAS_INSTANCE, "Symbol.hasInstance"),
         (SYMBOL_IS_CONCAT_SPREADABLE, "Symbol.isConcatSpreadable"),
         (SYMBOL_ITERATOR, "Symbol.iterator"),
         (SYMBOL_MATCH, "Symbol.match"),
         (SYMBOL_MATCH_ALL, "Symbol.matchAll"),
+        (SYMBOL_METADATA, "Symbol.metadata"),
         (SYMBOL_REPLACE, "Symbol.replace"),
         (SYMBOL_SEARCH, "Symbol.search"),
         (SYMBOL_SPECIES, "Symbol.species"),
@@ -102,12 +105,15 @@ impl StaticJsStrings {
         (SYMBOL_TO_PRIMITIVE, "Symbol.toPrimitive"),
         (SYMBOL_TO_STRING_TAG, "Symbol.toStringTag"),
         (SYMBOL_UNSCOPABLES, "Symbol.unscopables"),
+        (FN_SYMBOL_ASYNC_DISPOSE, "[Symbol.asyncDispose]"),
         (FN_SYMBOL_ASYNC_ITERATOR, "[Symbol.asyncIterator]"),
+        (FN_SYMBOL_DISPOSE, "[Symbol.dispose]"),
         (FN_SYMBOL_HAS_INSTANCE, "[Symbol.hasInstance]"),
         (FN_SYMBOL_IS_CONCAT_SPREADABLE, "[Symbol.isConcatSpreadable]"),
         (FN_SYMBOL_ITERATOR, "[Symbol.iterator]"),
         (FN_SYMBOL_MATCH, "[Symbol.match]"),
         (FN_SYMBOL_MATCH_ALL, "[Symbol.matchAll]"),
+        (FN_SYMBOL_METADATA, "[Symbol.metadata]"),
         (FN_SYMBOL_REPLACE, "[Symbol.replace]"),
         (FN_SYMBOL_SEARCH, "[Symbol.search]"),
         (FN_SYMBOL_SPECIES, "[Symbol.species]"),
@@ -135,12 +141,14 @@ impl StaticJsStrings {
         (SYNTAX_ERROR, "SyntaxError"),
         (TYPE_ERROR, "TypeError"),
         (URI_ERROR, "URIError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (ESCAPE, "escape"),
         (UNESCAPE, "unescape"),
         (EVAL, "eval"),
         (FUNCTION, "Function"),
         (GENERATOR, "Generator"),
         (GENERATOR_FUNCTION, "GeneratorFunction"),
+        (ITERATOR, "Iterator"),
         (INTL, "Intl"),
         (COLLATOR, "Collator"),
         (LIST_FORMAT, "ListFormat"),
@@ -153,6 +161,9 @@ impl StaticJsStrings {
         (MATH, "Math"),
         (NUMBER, "Number"),
         (NUMBER_FORMAT, "NumberFormat"),
+        (RELATIVE_TIME_FORMAT, "RelativeTimeFormat"),
+        (DISPLAY_NAMES, "DisplayNames"),
+        (DURATION_FORMAT, "DurationFormat"),
         (IS_FINITE, "isFinite"),
         (IS_NAN, "isNaN"),
         (PARSE_INT, "parseInt"),
@@ -175,6 +186,7 @@ impl StaticJsStrings {
         (UINT32_ARRAY, "Uint32Array"),
         (BIG_INT64_ARRAY, "BigInt64Array"),
         (BIG_UINT64_ARRAY, "BigUint64Array"),
+        (FLOAT16_ARRAY, "Float16Array"),
         (FLOAT32_ARRAY, "Float32Array"),
         (FLOAT64_ARRAY, "Float64Array"),
         (ENCODE_URI, "encodeURI"),
@@ -184,6 +196,10 @@ impl StaticJsStrings {
         (WEAK_REF, "WeakRef"),
         (WEAK_MAP, "WeakMap"),
         (WEAK_SET, "WeakSet"),
+        (FINALIZATION_REGISTRY, "FinalizationRegistry"),
+        (SHADOW_REALM, "ShadowRealm"),
+        (DISPOSABLE_STACK, "DisposableStack"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
         (TEMPORAL, "Temporal"),
         (NOW_TAG, "Temporal.Now"),
         (INSTANT_TAG, "Temporal.Instant"),
@@ -233,8 +249,12 @@ static RAW_STATICS_CACHE: LazyLock<FxHashMap<JsStr<'static>, usize>> = LazyLock:
 const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("".as_bytes()),
     // Well known symbols
+    JsStr::latin1("Symbol.asyncDispose".as_bytes()),
+    JsStr::latin1("[Symbol.asyncDispose]".as_bytes()),
     JsStr::latin1("Symbol.asyncIterator".as_bytes()),
     JsStr::latin1("[Symbol.asyncIterator]".as_bytes()),
+    JsStr::latin1("Symbol.dispose".as_bytes()),
+    JsStr::latin1("[Symbol.dispose]".as_bytes()),
     JsStr::latin1("Symbol.hasInstance".as_bytes()),
     JsStr::latin1("[Symbol.hasInstance]".as_bytes()),
     JsStr::latin1("Symbol.isConcatSpreadable".as_bytes()),
@@ -245,6 +265,8 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("[Symbol.match]".as_bytes()),
     JsStr::latin1("Symbol.matchAll".as_bytes()),
     JsStr::latin1("[Symbol.matchAll]".as_bytes()),
+    JsStr::latin1("Symbol.metadata".as_bytes()),
+    JsStr::latin1("[Symbol.metadata]".as_bytes()),
     JsStr::latin1("Symbol.replace".as_bytes()),
     JsStr::latin1("[Symbol.replace]".as_bytes()),
     JsStr::latin1("Symbol.search".as_bytes()),
@@ -281,12 +303,14 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("SyntaxError".as_bytes()),
     JsStr::latin1("TypeError".as_bytes()),
     JsStr::latin1("URIError".as_bytes()),
+    JsStr::latin1("SuppressedError".as_bytes()),
     JsStr::latin1("escape".as_bytes()),
     JsStr::latin1("unescape".as_bytes()),
     JsStr::latin1("eval".as_bytes()),
     JsStr::latin1("Function".as_bytes()),
     JsStr::latin1("Generator".as_bytes()),
     JsStr::latin1("GeneratorFunction".as_bytes()),
+    JsStr::latin1("Iterator".as_bytes()),
     JsStr::latin1("Intl".as_bytes()),
     JsStr::latin1("Collator".as_bytes()),
     JsStr::latin1("ListFormat".as_bytes()),
@@ -299,6 +323,9 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Math".as_bytes()),
     JsStr::latin1("Number".as_bytes()),
     JsStr::latin1("NumberFormat".as_bytes()),
+    JsStr::latin1("RelativeTimeFormat".as_bytes()),
+    JsStr::latin1("DisplayNames".as_bytes()),
+    JsStr::latin1("DurationFormat".as_bytes()),
     JsStr::latin1("isFinite".as_bytes()),
     JsStr::latin1("isNaN".as_bytes()),
     JsStr::latin1("parseInt".as_bytes()),
@@ -321,6 +348,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Uint32Array".as_bytes()),
     JsStr::latin1("BigInt64Array".as_bytes()),
     JsStr::latin1("BigUint64Array".as_bytes()),
+    JsStr::latin1("Float16Array".as_bytes()),
     JsStr::latin1("Float32Array".as_bytes()),
     JsStr::latin1("Float64Array".as_bytes()),
     JsStr::latin1("encodeURI".as_bytes()),
@@ -330,6 +358,10 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("WeakRef".as_bytes()),
     JsStr::latin1("WeakMap".as_bytes()),
     JsStr::latin1("WeakSet".as_bytes()),
+    JsStr::latin1("FinalizationRegistry".as_bytes()),
+    JsStr::latin1("ShadowRealm".as_bytes()),
+    JsStr::latin1("DisposableStack".as_bytes()),
+    JsStr::latin1("AsyncDisposableStack".as_bytes()),
     JsStr::latin1("Temporal".as_bytes()),
     JsStr::latin1("Temporal.Now".as_bytes()),
     JsStr::latin1("Temporal.Instant".as_bytes()),
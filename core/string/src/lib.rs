@@ -47,12 +47,33 @@ use std::{
     process::abort,
     ptr::{self, addr_of, addr_of_mut, NonNull},
     str::FromStr,
+    sync::OnceLock,
 };
 
 fn alloc_overflow() -> ! {
     panic!("detected overflow during string allocation")
 }
 
+/// The function called when the memory allocated by strings changes.
+static ALLOCATION_HOOK: OnceLock<fn(usize, usize)> = OnceLock::new();
+
+/// Sets the function called with the old and new size in bytes whenever the allocation of a
+/// string is created or freed, which allows counting the memory of strings towards a heap limit.
+///
+/// The hook is called on the thread owning the string. It can only be set once, and setting it
+/// again does nothing.
+pub fn set_allocation_hook(hook: fn(usize, usize)) {
+    let _ = ALLOCATION_HOOK.set(hook);
+}
+
+/// Reports a change in the size of a string allocation to the allocation hook, if any.
+#[inline]
+pub(crate) fn report_allocation(old_size: usize, new_size: usize) {
+    if let Some(hook) = ALLOCATION_HOOK.get() {
+        hook(old_size, new_size);
+    }
+}
+
 /// Helper function to check if a `char` is trimmable.
 pub(crate) const fn is_trimmable_whitespace(c: char) -> bool {
     // The rust implementation of `trim` does not regard the same characters whitespace as ecma standard does
@@ -766,6 +787,7 @@ impl JsString {
         // we should abort, since an allocation error is pretty unrecoverable for us
         // right now.
         let inner = NonNull::new(inner).ok_or(Some(layout))?;
+        report_allocation(0, layout.size());
 
         // SAFETY:
         // `NonNull` verified for us that the pointer returned by `alloc` is valid,
@@ -1066,6 +1088,8 @@ impl Drop for JsString {
                 }
             };
 
+            report_allocation(layout.size(), 0);
+
             // SAFETY:
             // If refcount is 0 and we call drop, that means this is the last `JsString` which
             // points to this memory allocation, so deallocating it is safe.
@@ -49,5 +49,23 @@ fn main() -> Result<(), JsError> {
 
     set.clear(context)?;
 
+    // Combine sets using the set methods.
+    let odds = JsSet::from_iter([JsValue::new(1), JsValue::new(3), JsValue::new(5)], context);
+
+    let union = slice_set.union(odds.clone(), context)?;
+    assert_eq!(union.size()?, 4);
+
+    let intersection = slice_set.intersection(odds.clone(), context)?;
+    assert_eq!(intersection.size()?, 2);
+    assert!(intersection.is_subset_of(slice_set.clone(), context)?);
+    assert!(union.is_superset_of(odds.clone(), context)?);
+
+    let difference = slice_set.difference(odds.clone(), context)?;
+    assert!(difference.has(2, context)?);
+    assert!(difference.is_disjoint_from(odds.clone(), context)?);
+
+    let symmetric_difference = slice_set.symmetric_difference(odds, context)?;
+    assert_eq!(symmetric_difference.size()?, 2);
+
     Ok(())
 }
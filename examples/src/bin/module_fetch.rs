@@ -8,8 +8,8 @@ use boa_engine::{
     builtins::promise::PromiseState,
     job::{FutureJob, JobQueue, NativeJob},
     js_string,
-    module::ModuleLoader,
-    Context, JsNativeError, JsResult, JsString, JsValue, Module,
+    module::{ModuleLoader, ModuleRequest},
+    Context, JsNativeError, JsResult, JsValue, Module,
 };
 use boa_parser::Source;
 use futures_util::{stream::FuturesUnordered, StreamExt};
@@ -29,11 +29,11 @@ impl ModuleLoader for HttpModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: boa_engine::module::Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let url = specifier.to_std_string_escaped();
+        let url = request.specifier().to_std_string_escaped();
 
         let fetch = async move {
             // Adding some prints to show the non-deterministic nature of the async fetches.
@@ -1,3 +1,5 @@
+use std::{thread, time::Duration};
+
 use boa_engine::{Context, JsValue, Source};
 
 fn main() {
@@ -81,4 +83,79 @@ fn main() {
     // Run exceeding limit by 1 and assert that it fails.
     let result = context.eval(Source::from_bytes("factorial(11)"));
     assert!(result.is_err());
+
+    // -----------------------------------------
+    //  Instruction Limit
+    // -----------------------------------------
+
+    // Create a new context, since the previous limits still apply to `context`.
+    let mut context = Context::default();
+
+    // Every executed instruction spends its cost from the instruction limit of an execution.
+    context.runtime_limits_mut().set_instruction_limit(10_000);
+
+    // Short scripts run within the limit.
+    let result = context.eval(Source::from_bytes("1 + 2 + 3"));
+    assert_eq!(result, Ok(JsValue::new(6)));
+
+    // An infinite loop runs out of instructions, even without a loop iteration limit.
+    let result = context.eval(Source::from_bytes("while (true) { }"));
+    assert!(result.is_err());
+
+    context.runtime_limits_mut().disable_instruction_limit();
+
+    // -----------------------------------------
+    //  Execution Time Limit
+    // -----------------------------------------
+
+    // Limit the wall-clock time a single execution can take.
+    context
+        .runtime_limits_mut()
+        .set_execution_time_limit(Duration::from_millis(100));
+
+    let result = context.eval(Source::from_bytes("while (true) { }"));
+    assert!(result.is_err());
+
+    context.runtime_limits_mut().disable_execution_time_limit();
+
+    // -----------------------------------------
+    //  Heap Limit
+    // -----------------------------------------
+
+    // Limit the heap to 32MB, including the elements of arrays.
+    context
+        .runtime_limits_mut()
+        .set_heap_limit(32 * 1024 * 1024);
+
+    // Allocating past the limit throws a `RuntimeLimit` error instead of exhausting the memory.
+    let result = context.eval(Source::from_bytes("new Array(1e9).fill(0)"));
+    assert!(result.is_err());
+
+    context.runtime_limits_mut().disable_heap_limit();
+
+    // -----------------------------------------
+    //  Interrupt Handle
+    // -----------------------------------------
+
+    // An interrupt handle can stop the execution from another thread.
+    let handle = context.interrupt_handle();
+    let interrupter = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        handle.interrupt();
+    });
+
+    // Like the other limits, the interrupt cannot be caught in JavaScript.
+    let result = context.eval(Source::from_bytes(
+        r"
+            try {
+                while (true) { }
+            } catch (e) {
+
+            }
+        ",
+    ));
+    assert!(result.is_err());
+    interrupter
+        .join()
+        .expect("the interrupting thread should not panic");
 }
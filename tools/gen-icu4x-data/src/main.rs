@@ -13,6 +13,7 @@ const EXPORT_PATH: &str = "core/icu_provider/data";
 ///
 /// This must be kept in sync with the list of implemented services of `Intl`.
 const SERVICES: &[(&str, &[DataKey])] = &[
+    ("icu_calendar", icu_calendar::provider::KEYS),
     ("icu_casemap", icu_casemap::provider::KEYS),
     ("icu_collator", icu_collator::provider::KEYS),
     ("icu_datetime", icu_datetime::provider::KEYS),
@@ -22,8 +23,15 @@ const SERVICES: &[(&str, &[DataKey])] = &[
     ("icu_normalizer", icu_normalizer::provider::KEYS),
     ("icu_plurals", icu_plurals::provider::KEYS),
     ("icu_segmenter", icu_segmenter::provider::KEYS),
+    ("icu_timezone", icu_timezone::provider::KEYS),
 ];
 
+/// List of key path prefixes of `icu_experimental` used by `Intl` components.
+///
+/// `icu_experimental` bundles the data of many unstable components, so only the keys of the
+/// components used by `Intl` are exported to avoid bloating the data provider.
+const EXPERIMENTAL_KEY_PREFIXES: &[&str] = &["displaynames/", "relativetime/"];
+
 fn export_for_service(
     service: &str,
     keys: &[DataKey],
@@ -76,5 +84,16 @@ fn main() -> Result<(), Box<dyn Error>> {
         export_for_service(service, keys, provider, driver.clone())?;
     }
 
+    let experimental_keys = icu_experimental::provider::KEYS
+        .iter()
+        .copied()
+        .filter(|key| {
+            EXPERIMENTAL_KEY_PREFIXES
+                .iter()
+                .any(|prefix| key.path().get().starts_with(prefix))
+        })
+        .collect::<Vec<_>>();
+    export_for_service("icu_experimental", &experimental_keys, provider, driver)?;
+
     Ok(())
 }